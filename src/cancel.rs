@@ -0,0 +1,169 @@
+use std::{
+    collections::HashMap,
+    future::Future,
+    pin::Pin,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Mutex,
+    },
+    task::{Context, Poll, Waker},
+};
+
+use futures_util::future::{self, Either};
+
+use crate::ShopifyAPIError;
+
+/// Token used to cancel in-flight queries
+///
+/// Clones share the same state: cancelling one clone cancels every query using the token.
+/// A cancelled query stops its current attempt or backoff and returns [`ShopifyAPIError::Cancelled`].
+/// Queries can also be aborted by dropping their future, e.g. in a `tokio::select!` branch.
+/// # Example
+/// ```no_run
+/// use std::collections::HashMap;
+/// use shopify_api::*;
+/// use shopify_api::cancel::CancellationToken;
+/// use shopify_api::rest::ShopifyAPIRestType;
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let shopify = Shopify::new("myshop", "myapikey", ShopifyAPIVersion::V2026_10, None);
+///     let token = CancellationToken::new();
+///     let options = RequestOptions::new().cancellation_token(token.clone());
+///
+///     tokio::spawn(async move {
+///         tokio::time::sleep(std::time::Duration::from_secs(5)).await;
+///         token.cancel();
+///     });
+///
+///     let result = shopify
+///         .rest_query_with_options::<serde_json::Value>(&ShopifyAPIRestType::Get("products.json", &HashMap::new()), &None, &options)
+///         .await;
+///
+///     if let Err(ShopifyAPIError::Cancelled) = result {
+///         println!("The query was cancelled");
+///     }
+/// }
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct CancellationToken {
+    inner: Arc<Inner>,
+}
+
+#[derive(Debug, Default)]
+struct Inner {
+    cancelled: AtomicBool,
+    wakers: Mutex<Wakers>,
+}
+
+/// Wakers of the pending [`Cancelled`] futures, removed when they are dropped so that a
+/// long-lived token does not accumulate the wakers of the queries that completed
+#[derive(Debug, Default)]
+struct Wakers {
+    next_key: u64,
+    wakers: HashMap<u64, Waker>,
+}
+
+impl CancellationToken {
+    /// Create a token that is not cancelled
+    pub fn new() -> CancellationToken {
+        CancellationToken::default()
+    }
+
+    /// Cancel every query using the token
+    /// # Example
+    /// ```
+    /// use shopify_api::cancel::CancellationToken;
+    ///
+    /// let token = CancellationToken::new();
+    /// let clone = token.clone();
+    /// clone.cancel();
+    ///
+    /// assert!(token.is_cancelled());
+    /// ```
+    pub fn cancel(&self) {
+        self.inner.cancelled.store(true, Ordering::SeqCst);
+
+        let wakers = std::mem::take(&mut self.inner.wakers.lock().unwrap().wakers);
+        for waker in wakers.into_values() {
+            waker.wake();
+        }
+    }
+
+    /// Check if the token was cancelled
+    pub fn is_cancelled(&self) -> bool {
+        self.inner.cancelled.load(Ordering::SeqCst)
+    }
+
+    /// Wait until the token is cancelled
+    pub fn cancelled(&self) -> Cancelled<'_> {
+        Cancelled {
+            token: self,
+            key: None,
+        }
+    }
+
+    /// Run a future until it completes or the token is cancelled
+    pub(crate) async fn run<F, Out>(&self, fut: F) -> Result<Out, ShopifyAPIError>
+    where
+        F: Future<Output = Out>,
+    {
+        if self.is_cancelled() {
+            return Err(ShopifyAPIError::Cancelled);
+        }
+
+        let fut = std::pin::pin!(fut);
+        match future::select(fut, self.cancelled()).await {
+            Either::Left((out, _)) => Ok(out),
+            Either::Right(_) => Err(ShopifyAPIError::Cancelled),
+        }
+    }
+}
+
+/// Future returned by [`CancellationToken::cancelled`]
+#[derive(Debug)]
+pub struct Cancelled<'a> {
+    token: &'a CancellationToken,
+    /// Key of the waker registered on the token, once polled
+    key: Option<u64>,
+}
+
+impl Future for Cancelled<'_> {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        let this = self.get_mut();
+        if this.token.is_cancelled() {
+            return Poll::Ready(());
+        }
+
+        let mut wakers = this.token.inner.wakers.lock().unwrap();
+        let key = *this.key.get_or_insert_with(|| {
+            wakers.next_key += 1;
+            wakers.next_key
+        });
+        match wakers.wakers.get_mut(&key) {
+            Some(waker) if waker.will_wake(cx.waker()) => {}
+            Some(waker) => *waker = cx.waker().clone(),
+            None => {
+                wakers.wakers.insert(key, cx.waker().clone());
+            }
+        }
+        drop(wakers);
+
+        // The token may have been cancelled while the waker was registered
+        if this.token.is_cancelled() {
+            Poll::Ready(())
+        } else {
+            Poll::Pending
+        }
+    }
+}
+
+impl Drop for Cancelled<'_> {
+    fn drop(&mut self) {
+        if let Some(key) = self.key {
+            self.token.inner.wakers.lock().unwrap().wakers.remove(&key);
+        }
+    }
+}
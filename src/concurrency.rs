@@ -0,0 +1,251 @@
+use std::{
+    collections::VecDeque,
+    future::Future,
+    pin::Pin,
+    sync::Mutex,
+    task::{Context, Poll, Waker},
+};
+
+use crate::{options::Priority, Shopify};
+
+/// Semaphore limiting the number of requests in flight, shared by the clones of a client
+#[derive(Debug, Default)]
+pub(crate) struct ConcurrencyLimit {
+    state: Mutex<State>,
+}
+
+#[derive(Debug, Default)]
+struct State {
+    max: Option<usize>,
+    in_flight: usize,
+    next_id: u64,
+    /// Waiting requests, by decreasing priority then in arrival order
+    waiters: VecDeque<Waiter>,
+}
+
+#[derive(Debug)]
+struct Waiter {
+    id: u64,
+    priority: Priority,
+    waker: Waker,
+}
+
+impl State {
+    fn has_room_for(&self, requests: usize) -> bool {
+        self.max.is_none_or(|max| self.in_flight + requests <= max)
+    }
+
+    /// Wake the waiters that can take the free slots
+    ///
+    /// Waiters stay in the queue until they take their slot, so the ones behind them keep waiting.
+    fn wake_waiters(&self) {
+        let free = match self.max {
+            Some(max) => max.saturating_sub(self.in_flight),
+            None => self.waiters.len(),
+        };
+        for waiter in self.waiters.iter().take(free) {
+            waiter.waker.wake_by_ref();
+        }
+    }
+}
+
+impl ConcurrencyLimit {
+    fn set_max(&self, max: Option<usize>) {
+        let mut state = self.state.lock().unwrap();
+        state.max = max;
+        state.wake_waiters();
+    }
+
+    fn max(&self) -> Option<usize> {
+        self.state.lock().unwrap().max
+    }
+
+    /// Wait for a free slot, released when the permit is dropped
+    ///
+    /// Waiting requests of a higher priority get the next free slots.
+    pub(crate) fn acquire(&self, priority: Priority) -> Acquire<'_> {
+        Acquire {
+            limit: self,
+            priority,
+            id: None,
+        }
+    }
+
+    /// Block the current thread until a slot is free
+    #[cfg(feature = "blocking")]
+    pub(crate) fn acquire_blocking(&self, priority: Priority) -> Permit<'_> {
+        use std::{sync::Arc, task::Wake, thread::Thread};
+
+        struct ThreadWaker(Thread);
+
+        impl Wake for ThreadWaker {
+            fn wake(self: Arc<Self>) {
+                self.0.unpark();
+            }
+        }
+
+        let waker = Waker::from(Arc::new(ThreadWaker(std::thread::current())));
+        let mut cx = Context::from_waker(&waker);
+        let mut acquire = std::pin::pin!(self.acquire(priority));
+
+        loop {
+            if let Poll::Ready(permit) = acquire.as_mut().poll(&mut cx) {
+                return permit;
+            }
+            std::thread::park();
+        }
+    }
+}
+
+/// Future returned by [`ConcurrencyLimit::acquire`]
+pub(crate) struct Acquire<'a> {
+    limit: &'a ConcurrencyLimit,
+    priority: Priority,
+    id: Option<u64>,
+}
+
+impl<'a> Future for Acquire<'a> {
+    type Output = Permit<'a>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let limit = self.limit;
+        let mut state = limit.state.lock().unwrap();
+
+        // Leave the free slots to the requests ahead in the queue
+        let priority = self.priority;
+        let ahead = match self.id {
+            Some(id) => state
+                .waiters
+                .iter()
+                .position(|waiter| waiter.id == id)
+                .unwrap_or(0),
+            None => state
+                .waiters
+                .iter()
+                .filter(|waiter| waiter.priority >= priority)
+                .count(),
+        };
+        if state.has_room_for(ahead + 1) {
+            state.in_flight += 1;
+            if let Some(id) = self.id.take() {
+                state.waiters.retain(|waiter| waiter.id != id);
+            }
+            return Poll::Ready(Permit { limit });
+        }
+
+        let id = match self.id {
+            Some(id) => id,
+            None => {
+                state.next_id += 1;
+                state.next_id
+            }
+        };
+        self.id = Some(id);
+
+        match state.waiters.iter_mut().find(|waiter| waiter.id == id) {
+            Some(waiter) => waiter.waker.clone_from(cx.waker()),
+            None => {
+                let position = state
+                    .waiters
+                    .iter()
+                    .position(|waiter| waiter.priority < priority)
+                    .unwrap_or(state.waiters.len());
+                state.waiters.insert(
+                    position,
+                    Waiter {
+                        id,
+                        priority,
+                        waker: cx.waker().clone(),
+                    },
+                );
+            }
+        }
+
+        Poll::Pending
+    }
+}
+
+impl Drop for Acquire<'_> {
+    fn drop(&mut self) {
+        let Some(id) = self.id else {
+            return;
+        };
+
+        // Pass the slot on if the future was woken up but dropped before taking it
+        let mut state = self.limit.state.lock().unwrap();
+        state.waiters.retain(|waiter| waiter.id != id);
+        state.wake_waiters();
+    }
+}
+
+/// Slot of a request in flight
+pub(crate) struct Permit<'a> {
+    limit: &'a ConcurrencyLimit,
+}
+
+impl Drop for Permit<'_> {
+    fn drop(&mut self) {
+        let mut state = self.limit.state.lock().unwrap();
+        state.in_flight -= 1;
+        state.wake_waiters();
+    }
+}
+
+impl Shopify {
+    /// Limit the number of requests sent at the same time by this client and its clones
+    ///
+    /// Requests over the limit wait for a previous request to finish before being sent,
+    /// so many queries can be started at once without opening as many connections.
+    /// The limit is raised to 1 if 0 is given.
+    /// # Example
+    /// ```no_run
+    /// use std::collections::HashMap;
+    /// use futures_util::future::join_all;
+    /// use shopify_api::*;
+    /// use shopify_api::rest::ShopifyAPIRestType;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let shopify = Shopify::new("myshop", "myapikey", ShopifyAPIVersion::V2026_10, None);
+    ///     shopify.set_max_concurrency(4);
+    ///
+    ///     let params = HashMap::new();
+    ///     let endpoints: Vec<String> = (1..=200).map(|id| format!("orders/{id}.json")).collect();
+    ///     let queries: Vec<_> = endpoints
+    ///         .iter()
+    ///         .map(|endpoint| ShopifyAPIRestType::Get(endpoint, &params))
+    ///         .collect();
+    ///
+    ///     // At most 4 requests are in flight at any time
+    ///     let orders = join_all(
+    ///         queries
+    ///             .iter()
+    ///             .map(|query| shopify.rest_query::<serde_json::Value>(query, &None)),
+    ///     )
+    ///     .await;
+    /// }
+    /// ```
+    pub fn set_max_concurrency(&self, max_concurrency: usize) {
+        self.concurrency.set_max(Some(max_concurrency.max(1)));
+    }
+
+    /// Remove the limit on the number of requests sent at the same time
+    pub fn clear_max_concurrency(&self) {
+        self.concurrency.set_max(None);
+    }
+
+    /// Get the maximum number of requests sent at the same time, if limited
+    /// # Example
+    /// ```
+    /// use shopify_api::*;
+    ///
+    /// let shopify = Shopify::new("myshop", "myapikey", ShopifyAPIVersion::V2026_10, None);
+    /// assert_eq!(shopify.get_max_concurrency(), None);
+    ///
+    /// shopify.clone().set_max_concurrency(10);
+    /// assert_eq!(shopify.get_max_concurrency(), Some(10));
+    /// ```
+    pub fn get_max_concurrency(&self) -> Option<usize> {
+        self.concurrency.max()
+    }
+}
@@ -0,0 +1,131 @@
+use std::{
+    fmt,
+    sync::{Arc, Mutex},
+};
+
+use reqwest::header::HeaderMap;
+use serde::Serialize;
+
+use crate::{response::header_string, Shopify};
+
+/// Header sent by Shopify when a request uses a deprecated endpoint, field or API version
+const DEPRECATED_REASON_HEADER: &str = "X-Shopify-API-Deprecated-Reason";
+
+/// Distinct warnings kept by a client, the oldest ones being dropped first
+const MAX_WARNINGS: usize = 100;
+
+/// Use of a deprecated REST endpoint or GraphQL field, reported by Shopify in the
+/// `X-Shopify-API-Deprecated-Reason` header
+///
+/// Deprecated features are removed when their API version is no longer supported, so these warnings
+/// should be fixed before the sunset of the version in use.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct DeprecationWarning {
+    /// Path of the request, e.g. `/admin/api/2024-01/products.json` or `/admin/api/2024-01/graphql.json`
+    pub path: String,
+    /// Reason given by Shopify, usually a link to the changelog
+    pub reason: String,
+    /// Version that served the request, from the `X-Shopify-API-Version` header
+    pub api_version: Option<String>,
+    /// `X-Request-Id` header of the response
+    pub request_id: Option<String>,
+}
+
+/// Warnings received by a client and its clones, without duplicates
+#[derive(Debug, Default)]
+pub(crate) struct DeprecationLog(Mutex<Vec<DeprecationWarning>>);
+
+type DeprecationHookFn = Arc<dyn Fn(&DeprecationWarning) + Send + Sync>;
+
+/// Hook called with every deprecation warning received
+#[derive(Clone, Default)]
+pub(crate) struct DeprecationHook(Option<DeprecationHookFn>);
+
+impl DeprecationHook {
+    pub(crate) fn new<F>(hook: F) -> DeprecationHook
+    where
+        F: Fn(&DeprecationWarning) + Send + Sync + 'static,
+    {
+        DeprecationHook(Some(Arc::new(hook)))
+    }
+}
+
+impl fmt::Debug for DeprecationHook {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            Some(_) => f.write_str("DeprecationHook(Some(..))"),
+            None => f.write_str("DeprecationHook(None)"),
+        }
+    }
+}
+
+impl Shopify {
+    /// Record the deprecation warning of a response, if any
+    pub(crate) fn check_deprecation(&self, url: &reqwest::Url, headers: &HeaderMap) {
+        let Some(reason) = header_string(headers, DEPRECATED_REASON_HEADER) else {
+            return;
+        };
+
+        let warning = DeprecationWarning {
+            path: url.path().to_string(),
+            reason,
+            api_version: header_string(headers, "X-Shopify-API-Version"),
+            request_id: header_string(headers, "X-Request-Id"),
+        };
+
+        {
+            let mut warnings = self.deprecations.0.lock().unwrap();
+            let known = warnings
+                .iter()
+                .any(|known| known.path == warning.path && known.reason == warning.reason);
+            if !known {
+                log::warn!(
+                    "shopify deprecation on {}: {}",
+                    warning.path,
+                    warning.reason
+                );
+                if warnings.len() == MAX_WARNINGS {
+                    warnings.remove(0);
+                }
+                warnings.push(warning.clone());
+            }
+        }
+
+        if let Some(hook) = &self.deprecation_hook.0 {
+            hook(&warning);
+        }
+    }
+
+    /// Get the distinct deprecation warnings received by this client and its clones
+    ///
+    /// Only the last 100 distinct warnings, by path and reason, are kept. Each one is also
+    /// logged once with `log::warn!`.
+    /// # Example
+    /// ```
+    /// use shopify_api::*;
+    ///
+    /// let shopify = Shopify::new("myshop", "myapikey", ShopifyAPIVersion::V2026_10, None);
+    /// assert!(shopify.warnings().is_empty());
+    /// ```
+    pub fn warnings(&self) -> Vec<DeprecationWarning> {
+        self.deprecations.0.lock().unwrap().clone()
+    }
+
+    /// Call the given hook with every deprecation warning received, duplicates included
+    /// # Example
+    /// ```
+    /// use shopify_api::*;
+    ///
+    /// let shopify = Shopify::new("myshop", "myapikey", ShopifyAPIVersion::V2026_10, None)
+    ///     .with_deprecation_hook(|warning| {
+    ///         log::error!("deprecated call to {}: {}", warning.path, warning.reason);
+    ///     });
+    /// ```
+    pub fn with_deprecation_hook<F>(mut self, hook: F) -> Shopify
+    where
+        F: Fn(&DeprecationWarning) + Send + Sync + 'static,
+    {
+        self.deprecation_hook = DeprecationHook::new(hook);
+        self
+    }
+}
@@ -0,0 +1,143 @@
+use std::{fmt, str::FromStr};
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::ShopifyAPIError;
+
+const GID_PREFIX: &str = "gid://shopify/";
+
+/// Global ID of a GraphQL resource, e.g. `gid://shopify/Product/123`
+///
+/// REST resources are identified by the numeric part of the GID, given by [`Gid::numeric_id`].
+/// A `Gid` is serialized as its string.
+/// # Example
+/// ```
+/// use shopify_api::Gid;
+///
+/// let gid = Gid::product(123);
+/// assert_eq!(gid.to_string(), "gid://shopify/Product/123");
+///
+/// let gid: Gid = "gid://shopify/ProductVariant/456".parse().unwrap();
+/// assert_eq!(gid.resource_type(), "ProductVariant");
+/// assert_eq!(gid.numeric_id(), Some(456));
+///
+/// assert!("Product/123".parse::<Gid>().is_err());
+///
+/// let json = serde_json::to_string(&Gid::order(789)).unwrap();
+/// assert_eq!(json, r#""gid://shopify/Order/789""#);
+/// assert_eq!(serde_json::from_str::<Gid>(&json).unwrap(), Gid::order(789));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Gid {
+    resource_type: String,
+    id: String,
+}
+
+impl Gid {
+    /// Build the GID of a resource from its type and numeric ID
+    pub fn new(resource_type: &str, id: u64) -> Gid {
+        Gid {
+            resource_type: resource_type.to_string(),
+            id: id.to_string(),
+        }
+    }
+
+    pub fn product(id: u64) -> Gid {
+        Gid::new("Product", id)
+    }
+
+    pub fn product_variant(id: u64) -> Gid {
+        Gid::new("ProductVariant", id)
+    }
+
+    pub fn collection(id: u64) -> Gid {
+        Gid::new("Collection", id)
+    }
+
+    pub fn customer(id: u64) -> Gid {
+        Gid::new("Customer", id)
+    }
+
+    pub fn order(id: u64) -> Gid {
+        Gid::new("Order", id)
+    }
+
+    pub fn draft_order(id: u64) -> Gid {
+        Gid::new("DraftOrder", id)
+    }
+
+    pub fn location(id: u64) -> Gid {
+        Gid::new("Location", id)
+    }
+
+    pub fn inventory_item(id: u64) -> Gid {
+        Gid::new("InventoryItem", id)
+    }
+
+    pub fn fulfillment_order(id: u64) -> Gid {
+        Gid::new("FulfillmentOrder", id)
+    }
+
+    pub fn metafield(id: u64) -> Gid {
+        Gid::new("Metafield", id)
+    }
+
+    /// Get the type of the resource, e.g. `Product`
+    pub fn resource_type(&self) -> &str {
+        &self.resource_type
+    }
+
+    /// Get the ID of the resource, as written in the GID
+    ///
+    /// Some resources, e.g. carts, have IDs that are not numbers.
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// Get the numeric ID of the resource, the one used by the REST API
+    pub fn numeric_id(&self) -> Option<u64> {
+        let id = self.id.split_once('?').map_or(&*self.id, |(id, _)| id);
+        id.parse().ok()
+    }
+}
+
+impl fmt::Display for Gid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}/{}", GID_PREFIX, self.resource_type, self.id)
+    }
+}
+
+impl FromStr for Gid {
+    type Err = ShopifyAPIError;
+
+    /// Parse a GID, failing with [`ShopifyAPIError::InvalidInput`] if it is not a Shopify GID
+    fn from_str(gid: &str) -> Result<Self, Self::Err> {
+        gid.strip_prefix(GID_PREFIX)
+            .and_then(|path| path.split_once('/'))
+            .filter(|(resource_type, id)| !resource_type.is_empty() && !id.is_empty())
+            .map(|(resource_type, id)| Gid {
+                resource_type: resource_type.to_string(),
+                id: id.to_string(),
+            })
+            .ok_or_else(|| ShopifyAPIError::InvalidInput(format!("invalid GID `{}`", gid)))
+    }
+}
+
+impl From<Gid> for String {
+    fn from(gid: Gid) -> Self {
+        gid.to_string()
+    }
+}
+
+impl Serialize for Gid {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Gid {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let gid = String::deserialize(deserializer)?;
+        gid.parse().map_err(serde::de::Error::custom)
+    }
+}
@@ -0,0 +1,87 @@
+//! Installation of the app on the shop, read with the `currentAppInstallation` query
+//!
+//! The installation holds the access scopes granted to the app, see also
+//! [`Shopify::missing_access_scopes`].
+
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+use crate::{
+    rest::access_scopes::AccessScope, utils::ReadJsonTreeSteps, Gid, Shopify, ShopifyAPIError,
+};
+
+/// App installed on the shop
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct App {
+    pub id: Gid,
+    pub title: String,
+    pub handle: Option<String>,
+    /// Client ID of the app
+    pub api_key: Option<String>,
+    pub developer_name: Option<String>,
+    /// Whether the app is shown in the Shopify admin
+    pub embedded: Option<bool>,
+}
+
+/// Installation of the app on the shop
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AppInstallation {
+    pub id: Gid,
+    pub app: App,
+    #[serde(default)]
+    pub access_scopes: Vec<AccessScope>,
+    /// URL opening the app in the Shopify admin
+    pub launch_url: Option<String>,
+    /// URL the merchant uninstalls the app at
+    pub uninstall_url: Option<String>,
+}
+
+impl AppInstallation {
+    /// Check if the app was granted `scope`, see [`AccessScope::grants`]
+    pub fn has_access_scope(&self, scope: &str) -> bool {
+        self.access_scopes
+            .iter()
+            .any(|access_scope| access_scope.grants(scope))
+    }
+}
+
+impl Shopify {
+    /// Get the installation of the app on the shop with `currentAppInstallation`
+    ///
+    /// # Example
+    /// ```no_run
+    /// use shopify_api::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let shopify = Shopify::new("myshop", "myapikey", ShopifyAPIVersion::V2026_10, None);
+    ///
+    ///     let installation = shopify.current_app_installation().await.unwrap();
+    ///     if !installation.has_access_scope("read_orders") {
+    ///         println!("{} can't read the orders", installation.app.title);
+    ///     }
+    /// }
+    /// ```
+    pub async fn current_app_installation(&self) -> Result<AppInstallation, ShopifyAPIError> {
+        let query = r#"
+            query {
+                currentAppInstallation {
+                    id launchUrl uninstallUrl
+                    app { id title handle apiKey developerName embedded }
+                    accessScopes { handle description }
+                }
+            }"#;
+
+        self.graphql_query(
+            query,
+            &json!({}),
+            &vec![
+                ReadJsonTreeSteps::Key("data"),
+                ReadJsonTreeSteps::Key("currentAppInstallation"),
+            ],
+        )
+        .await
+    }
+}
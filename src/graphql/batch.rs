@@ -0,0 +1,252 @@
+use serde::de::DeserializeOwned;
+
+use super::{GraphQLError, GraphQLResponse};
+use crate::{RequestOptions, Shopify, ShopifyAPIError};
+
+/// Several fields sent as a single GraphQL request, each under its own alias
+///
+/// Each field is added with its variables, which are renamed to be unique in the merged query,
+/// e.g. `$id` of the second field becomes `$b1_id`. The fields must not have an alias of their own.
+/// [`Shopify::graphql_batch`] sends the request and splits the response back into one result per
+/// field, in the order they were added.
+/// # Example
+/// ```
+/// use serde_json::json;
+/// use shopify_api::graphql::Batch;
+///
+/// let batch = Batch::query()
+///     .add("product(id: $id) { id title }", [("id", "ID!", json!("gid://shopify/Product/1"))])
+///     .add("product(id: $id) { id title }", [("id", "ID!", json!("gid://shopify/Product/2"))]);
+///
+/// let (query, variables) = batch.build().unwrap();
+/// assert_eq!(
+///     query,
+///     "query($b0_id: ID!, $b1_id: ID!) { \
+///     b0: product(id: $b0_id) { id title } \
+///     b1: product(id: $b1_id) { id title } }"
+/// );
+/// assert_eq!(
+///     variables,
+///     json!({ "b0_id": "gid://shopify/Product/1", "b1_id": "gid://shopify/Product/2" })
+/// );
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Batch {
+    operation: &'static str,
+    fields: Vec<BatchField>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct BatchField {
+    selection: String,
+    /// Name, GraphQL type and value of each variable
+    variables: Vec<(String, String, serde_json::Value)>,
+}
+
+impl Batch {
+    /// Batch fields of the `QueryRoot`
+    pub fn query() -> Batch {
+        Batch {
+            operation: "query",
+            fields: Vec::new(),
+        }
+    }
+
+    /// Batch mutations, which Shopify runs one after the other
+    pub fn mutation() -> Batch {
+        Batch {
+            operation: "mutation",
+            fields: Vec::new(),
+        }
+    }
+
+    /// Add a field with its selection, e.g. `product(id: $id) { id title }`, and the name, type and
+    /// value of the variables it uses
+    pub fn add<Name, Type>(
+        mut self,
+        selection: &str,
+        variables: impl IntoIterator<Item = (Name, Type, serde_json::Value)>,
+    ) -> Self
+    where
+        Name: Into<String>,
+        Type: Into<String>,
+    {
+        self.fields.push(BatchField {
+            selection: selection.trim().to_string(),
+            variables: variables
+                .into_iter()
+                .map(|(name, variable_type, value)| (name.into(), variable_type.into(), value))
+                .collect(),
+        });
+        self
+    }
+
+    pub fn len(&self) -> usize {
+        self.fields.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.fields.is_empty()
+    }
+
+    /// Build the merged query and its variables
+    ///
+    /// This fails with [`ShopifyAPIError::InvalidQuery`] if the batch is empty or a field uses a
+    /// variable it does not declare.
+    pub fn build(&self) -> Result<(String, serde_json::Value), ShopifyAPIError> {
+        if self.fields.is_empty() {
+            return Err(ShopifyAPIError::InvalidQuery("empty batch".to_string()));
+        }
+
+        let mut definitions = Vec::new();
+        let mut selections = Vec::new();
+        let mut variables = serde_json::Map::new();
+        for (index, field) in self.fields.iter().enumerate() {
+            let prefix = alias(index);
+            for (name, variable_type, value) in &field.variables {
+                definitions.push(format!("${prefix}_{name}: {variable_type}"));
+                variables.insert(format!("{prefix}_{name}"), value.clone());
+            }
+            selections.push(format!("{prefix}: {}", rename_variables(field, &prefix)?));
+        }
+
+        let definitions = match definitions.is_empty() {
+            true => String::new(),
+            false => format!("({})", definitions.join(", ")),
+        };
+        let query = format!(
+            "{}{} {{ {} }}",
+            self.operation,
+            definitions,
+            selections.join(" ")
+        );
+
+        Ok((query, serde_json::Value::Object(variables)))
+    }
+}
+
+fn alias(index: usize) -> String {
+    format!("b{}", index)
+}
+
+/// Prefix the variables used by a field with its alias, leaving string literals untouched
+fn rename_variables(field: &BatchField, prefix: &str) -> Result<String, ShopifyAPIError> {
+    let mut renamed = String::with_capacity(field.selection.len());
+    let mut chars = field.selection.chars().peekable();
+    let mut in_string = false;
+
+    while let Some(c) = chars.next() {
+        renamed.push(c);
+        match c {
+            '\\' if in_string => {
+                if let Some(escaped) = chars.next() {
+                    renamed.push(escaped);
+                }
+            }
+            '"' => in_string = !in_string,
+            '$' if !in_string => {
+                let mut name = String::new();
+                while let Some(&c) = chars.peek() {
+                    if !(c.is_ascii_alphanumeric() || c == '_') {
+                        break;
+                    }
+                    name.push(c);
+                    chars.next();
+                }
+                if !field
+                    .variables
+                    .iter()
+                    .any(|(declared, _, _)| *declared == name)
+                {
+                    return Err(ShopifyAPIError::InvalidQuery(format!(
+                        "variable `${}` of `{}` is not declared",
+                        name, field.selection
+                    )));
+                }
+                renamed.push_str(&format!("{prefix}_{name}"));
+            }
+            _ => {}
+        }
+    }
+
+    Ok(renamed)
+}
+
+impl Shopify {
+    /// Send a batch of fields in a single request, returning the result of each field in order
+    ///
+    /// A field failing, e.g. with `ACCESS_DENIED`, only fails its own result with
+    /// [`ShopifyAPIError::GraphQL`]. The whole batch fails if the request could not run. The cost of
+    /// the request is the sum of the costs of its fields, so batches should stay small.
+    /// # Example
+    /// ```no_run
+    /// use serde::Deserialize;
+    /// use serde_json::json;
+    /// use shopify_api::*;
+    /// use shopify_api::graphql::Batch;
+    ///
+    /// #[derive(Deserialize)]
+    /// struct Product {
+    ///     title: String,
+    /// }
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let shopify = Shopify::new("myshop", "myapikey", ShopifyAPIVersion::V2026_10, None);
+    ///     let batch = [1, 2, 3].into_iter().fold(Batch::query(), |batch, id| {
+    ///         batch.add("product(id: $id) { title }", [("id", "ID!", json!(Gid::product(id)))])
+    ///     });
+    ///
+    ///     let products = shopify.graphql_batch::<Option<Product>>(&batch).await.unwrap();
+    ///     for product in products {
+    ///         match product {
+    ///             Ok(product) => println!("{:?}", product.map(|product| product.title)),
+    ///             Err(error) => println!("{}", error),
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub async fn graphql_batch<ReturnType>(
+        &self,
+        batch: &Batch,
+    ) -> Result<Vec<Result<ReturnType, ShopifyAPIError>>, ShopifyAPIError>
+    where
+        ReturnType: DeserializeOwned,
+    {
+        let (query, variables) = batch.build()?;
+        let response: GraphQLResponse<serde_json::Value> = self
+            .graphql_response(&query, &variables, &RequestOptions::new())
+            .await?;
+
+        let Some(mut data) = response.data.filter(|data| !data.is_null()) else {
+            return Err(ShopifyAPIError::GraphQL {
+                errors: response.errors,
+                request_id: None,
+            });
+        };
+
+        Ok((0..batch.len())
+            .map(|index| {
+                let alias = alias(index);
+                let errors: Vec<GraphQLError> = response
+                    .errors
+                    .iter()
+                    .filter(|error| error.path.first().and_then(|key| key.as_str()) == Some(&alias))
+                    .cloned()
+                    .collect();
+                if !errors.is_empty() {
+                    return Err(ShopifyAPIError::GraphQL {
+                        errors,
+                        request_id: None,
+                    });
+                }
+
+                let value = data
+                    .get_mut(&alias)
+                    .map(serde_json::Value::take)
+                    .unwrap_or_default();
+                Ok(serde_json::from_value(value)?)
+            })
+            .collect())
+    }
+}
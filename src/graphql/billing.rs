@@ -0,0 +1,547 @@
+//! Billing of the app, managed with the `appSubscriptionCreate`, `appPurchaseOneTimeCreate`,
+//! `appUsageRecordCreate` and `appSubscriptionCancel` mutations
+//!
+//! A subscription is made of line items, each either a recurring price or a capped usage
+//! price. The mutations return a `confirmation_url` the merchant is sent to, to approve the
+//! charge before being sent back to the `return_url`.
+
+use serde::{Deserialize, Serialize, Serializer};
+use serde_json::json;
+
+use crate::{utils::ReadJsonTreeSteps, Gid, Shopify, ShopifyAPIError};
+
+const APP_SUBSCRIPTION_FIELDS: &str = "id name status test trialDays currentPeriodEnd createdAt lineItems { id plan { pricingDetails { __typename ... on AppRecurringPricing { price { amount currencyCode } interval } ... on AppUsagePricing { cappedAmount { amount currencyCode } balanceUsed { amount currencyCode } terms } } } }";
+
+const APP_PURCHASE_ONE_TIME_FIELDS: &str =
+    "id name status test createdAt price { amount currencyCode }";
+
+/// Amount of money in a currency
+///
+/// # Example
+/// ```
+/// use shopify_api::graphql::billing::Money;
+///
+/// assert_eq!(
+///     serde_json::to_value(Money::new("10.00", "USD")).unwrap(),
+///     serde_json::json!({ "amount": "10.00", "currencyCode": "USD" })
+/// );
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Money {
+    /// Decimal amount, e.g. `"10.00"`
+    pub amount: String,
+    /// ISO 4217 code of the currency, e.g. `USD`
+    pub currency_code: String,
+}
+
+impl Money {
+    pub fn new(amount: &str, currency_code: &str) -> Money {
+        Money {
+            amount: amount.to_string(),
+            currency_code: currency_code.to_string(),
+        }
+    }
+}
+
+/// Billing period of a recurring price
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum AppPricingInterval {
+    #[serde(rename = "EVERY_30_DAYS")]
+    Every30Days,
+    Annual,
+    #[serde(other)]
+    Unknown,
+}
+
+/// Pricing of a line item of a subscription
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AppSubscriptionLineItem {
+    /// Price billed every interval
+    Recurring {
+        price: Money,
+        interval: AppPricingInterval,
+    },
+    /// Usage records billed every 30 days, up to `capped_amount`
+    Usage { capped_amount: Money, terms: String },
+}
+
+impl Serialize for AppSubscriptionLineItem {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let value = match self {
+            AppSubscriptionLineItem::Recurring { price, interval } => json!({
+                "plan": { "appRecurringPricingDetails": { "price": price, "interval": interval } }
+            }),
+            AppSubscriptionLineItem::Usage {
+                capped_amount,
+                terms,
+            } => json!({
+                "plan": { "appUsagePricingDetails": { "cappedAmount": capped_amount, "terms": terms } }
+            }),
+        };
+
+        value.serialize(serializer)
+    }
+}
+
+/// How a new subscription replaces the active one
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum AppSubscriptionReplacementBehavior {
+    /// Cancel the active subscription now
+    ApplyImmediately,
+    /// Cancel the active subscription at the end of its billing period
+    ApplyOnNextBillingCycle,
+    /// Let Shopify choose, e.g. immediately when upgrading
+    Standard,
+}
+
+/// Subscription to create with [`Shopify::app_subscription_create`]
+///
+/// # Example
+/// ```
+/// use shopify_api::graphql::billing::{AppPricingInterval, AppSubscriptionInput, AppSubscriptionLineItem, Money};
+///
+/// let input = AppSubscriptionInput::new("Pro", "https://example.com/billing")
+///     .line_item(AppSubscriptionLineItem::Recurring {
+///         price: Money::new("19.00", "USD"),
+///         interval: AppPricingInterval::Every30Days,
+///     })
+///     .line_item(AppSubscriptionLineItem::Usage {
+///         capped_amount: Money::new("100.00", "USD"),
+///         terms: "$0.10 for each order".to_string(),
+///     })
+///     .trial_days(7)
+///     .test(true);
+///
+/// assert_eq!(
+///     serde_json::to_value(&input).unwrap(),
+///     serde_json::json!({
+///         "name": "Pro",
+///         "returnUrl": "https://example.com/billing",
+///         "lineItems": [
+///             { "plan": { "appRecurringPricingDetails": {
+///                 "price": { "amount": "19.00", "currencyCode": "USD" },
+///                 "interval": "EVERY_30_DAYS"
+///             } } },
+///             { "plan": { "appUsagePricingDetails": {
+///                 "cappedAmount": { "amount": "100.00", "currencyCode": "USD" },
+///                 "terms": "$0.10 for each order"
+///             } } }
+///         ],
+///         "trialDays": 7,
+///         "test": true
+///     })
+/// );
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AppSubscriptionInput {
+    name: String,
+    return_url: String,
+    line_items: Vec<AppSubscriptionLineItem>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    trial_days: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    test: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    replacement_behavior: Option<AppSubscriptionReplacementBehavior>,
+}
+
+impl AppSubscriptionInput {
+    /// Describe a subscription, the merchant being sent back to `return_url` once they approved
+    /// or declined it
+    pub fn new(name: &str, return_url: &str) -> AppSubscriptionInput {
+        AppSubscriptionInput {
+            name: name.to_string(),
+            return_url: return_url.to_string(),
+            ..AppSubscriptionInput::default()
+        }
+    }
+
+    /// Add a line item, a subscription having at most one of each kind
+    pub fn line_item(mut self, line_item: AppSubscriptionLineItem) -> Self {
+        self.line_items.push(line_item);
+        self
+    }
+
+    /// Set the days before the first bill
+    pub fn trial_days(mut self, trial_days: u32) -> Self {
+        self.trial_days = Some(trial_days);
+        self
+    }
+
+    /// Set whether the subscription is a test, not billed to the merchant
+    pub fn test(mut self, test: bool) -> Self {
+        self.test = Some(test);
+        self
+    }
+
+    pub fn replacement_behavior(
+        mut self,
+        replacement_behavior: AppSubscriptionReplacementBehavior,
+    ) -> Self {
+        self.replacement_behavior = Some(replacement_behavior);
+        self
+    }
+}
+
+/// One-time purchase to create with [`Shopify::app_purchase_one_time_create`]
+///
+/// # Example
+/// ```
+/// use shopify_api::graphql::billing::{AppPurchaseOneTimeInput, Money};
+///
+/// let input = AppPurchaseOneTimeInput::new("Premium theme", Money::new("49.00", "USD"), "https://example.com/billing");
+///
+/// assert_eq!(
+///     serde_json::to_value(&input).unwrap(),
+///     serde_json::json!({
+///         "name": "Premium theme",
+///         "price": { "amount": "49.00", "currencyCode": "USD" },
+///         "returnUrl": "https://example.com/billing"
+///     })
+/// );
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AppPurchaseOneTimeInput {
+    name: String,
+    price: Money,
+    return_url: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    test: Option<bool>,
+}
+
+impl AppPurchaseOneTimeInput {
+    pub fn new(name: &str, price: Money, return_url: &str) -> AppPurchaseOneTimeInput {
+        AppPurchaseOneTimeInput {
+            name: name.to_string(),
+            price,
+            return_url: return_url.to_string(),
+            test: None,
+        }
+    }
+
+    /// Set whether the purchase is a test, not billed to the merchant
+    pub fn test(mut self, test: bool) -> Self {
+        self.test = Some(test);
+        self
+    }
+}
+
+/// Status of a subscription
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum AppSubscriptionStatus {
+    /// Waiting for the approval of the merchant
+    Pending,
+    Active,
+    Declined,
+    Expired,
+    Frozen,
+    Cancelled,
+    #[serde(other)]
+    Unknown,
+}
+
+/// Status of a one-time purchase
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum AppPurchaseStatus {
+    Pending,
+    Active,
+    Declined,
+    Expired,
+    #[serde(other)]
+    Unknown,
+}
+
+/// Pricing of a line item returned with a subscription
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "__typename")]
+pub enum AppPricingDetails {
+    #[serde(rename = "AppRecurringPricing")]
+    Recurring {
+        price: Money,
+        interval: AppPricingInterval,
+    },
+    #[serde(rename = "AppUsagePricing", rename_all = "camelCase")]
+    Usage {
+        capped_amount: Money,
+        /// Usage records of the current billing period
+        balance_used: Money,
+        terms: String,
+    },
+}
+
+/// Line item of a subscription, its ID being used to create usage records
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(from = "RawAppSubscriptionLineItem")]
+pub struct AppSubscriptionLineItemDetails {
+    pub id: Gid,
+    pub pricing_details: AppPricingDetails,
+}
+
+#[derive(Deserialize)]
+struct RawAppSubscriptionLineItem {
+    id: Gid,
+    plan: RawAppPlan,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RawAppPlan {
+    pricing_details: AppPricingDetails,
+}
+
+impl From<RawAppSubscriptionLineItem> for AppSubscriptionLineItemDetails {
+    fn from(raw: RawAppSubscriptionLineItem) -> AppSubscriptionLineItemDetails {
+        AppSubscriptionLineItemDetails {
+            id: raw.id,
+            pricing_details: raw.plan.pricing_details,
+        }
+    }
+}
+
+/// Subscription of the shop to the app
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AppSubscription {
+    pub id: Gid,
+    pub name: String,
+    pub status: AppSubscriptionStatus,
+    pub test: bool,
+    pub trial_days: Option<u32>,
+    /// End of the current billing period, unset before the approval
+    pub current_period_end: Option<String>,
+    pub created_at: Option<String>,
+    #[serde(default)]
+    pub line_items: Vec<AppSubscriptionLineItemDetails>,
+}
+
+impl AppSubscription {
+    /// Get the usage line item, to create usage records with
+    /// [`Shopify::app_usage_record_create`]
+    pub fn usage_line_item(&self) -> Option<&AppSubscriptionLineItemDetails> {
+        self.line_items
+            .iter()
+            .find(|line_item| matches!(line_item.pricing_details, AppPricingDetails::Usage { .. }))
+    }
+}
+
+/// One-time purchase of the app by the shop
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AppPurchaseOneTime {
+    pub id: Gid,
+    pub name: String,
+    pub status: AppPurchaseStatus,
+    pub test: bool,
+    pub price: Money,
+    pub created_at: Option<String>,
+}
+
+/// Subscription returned by [`Shopify::app_subscription_create`]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AppSubscriptionCreated {
+    pub app_subscription: AppSubscription,
+    /// URL the merchant approves the subscription at
+    pub confirmation_url: String,
+}
+
+/// Purchase returned by [`Shopify::app_purchase_one_time_create`]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AppPurchaseOneTimeCreated {
+    pub app_purchase_one_time: AppPurchaseOneTime,
+    /// URL the merchant approves the purchase at
+    pub confirmation_url: String,
+}
+
+/// Usage record returned by [`Shopify::app_usage_record_create`]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AppUsageRecord {
+    pub id: Gid,
+    pub description: String,
+    pub price: Money,
+    pub created_at: Option<String>,
+}
+
+impl Shopify {
+    /// Create a pending subscription with `appSubscriptionCreate`
+    ///
+    /// This fails with [`ShopifyAPIError::UserErrors`] if the subscription is invalid, e.g. when
+    /// it has two line items of the same kind.
+    /// # Example
+    /// ```no_run
+    /// use shopify_api::*;
+    /// use shopify_api::graphql::billing::{AppPricingInterval, AppSubscriptionInput, AppSubscriptionLineItem, Money};
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let shopify = Shopify::new("myshop", "myapikey", ShopifyAPIVersion::V2026_10, None);
+    ///     let input = AppSubscriptionInput::new("Pro", "https://example.com/billing")
+    ///         .line_item(AppSubscriptionLineItem::Recurring {
+    ///             price: Money::new("19.00", "USD"),
+    ///             interval: AppPricingInterval::Every30Days,
+    ///         })
+    ///         .trial_days(7);
+    ///
+    ///     let created = shopify.app_subscription_create(&input).await.unwrap();
+    ///     println!("Approve at {}", created.confirmation_url);
+    /// }
+    /// ```
+    pub async fn app_subscription_create(
+        &self,
+        input: &AppSubscriptionInput,
+    ) -> Result<AppSubscriptionCreated, ShopifyAPIError> {
+        let mutation = format!(
+            r#"
+            mutation($name: String!, $returnUrl: URL!, $lineItems: [AppSubscriptionLineItemInput!]!, $trialDays: Int, $test: Boolean, $replacementBehavior: AppSubscriptionReplacementBehavior) {{
+                appSubscriptionCreate(name: $name, returnUrl: $returnUrl, lineItems: $lineItems, trialDays: $trialDays, test: $test, replacementBehavior: $replacementBehavior) {{
+                    appSubscription {{ {APP_SUBSCRIPTION_FIELDS} }}
+                    confirmationUrl
+                    userErrors {{ field message }}
+                }}
+            }}"#
+        );
+
+        self.graphql_mutation(
+            &mutation,
+            input,
+            &vec![
+                ReadJsonTreeSteps::Key("data"),
+                ReadJsonTreeSteps::Key("appSubscriptionCreate"),
+            ],
+        )
+        .await
+    }
+
+    /// Create a pending one-time purchase with `appPurchaseOneTimeCreate`
+    pub async fn app_purchase_one_time_create(
+        &self,
+        input: &AppPurchaseOneTimeInput,
+    ) -> Result<AppPurchaseOneTimeCreated, ShopifyAPIError> {
+        let mutation = format!(
+            r#"
+            mutation($name: String!, $price: MoneyInput!, $returnUrl: URL!, $test: Boolean) {{
+                appPurchaseOneTimeCreate(name: $name, price: $price, returnUrl: $returnUrl, test: $test) {{
+                    appPurchaseOneTime {{ {APP_PURCHASE_ONE_TIME_FIELDS} }}
+                    confirmationUrl
+                    userErrors {{ field message }}
+                }}
+            }}"#
+        );
+
+        self.graphql_mutation(
+            &mutation,
+            input,
+            &vec![
+                ReadJsonTreeSteps::Key("data"),
+                ReadJsonTreeSteps::Key("appPurchaseOneTimeCreate"),
+            ],
+        )
+        .await
+    }
+
+    /// Bill the usage line item of a subscription with `appUsageRecordCreate`
+    ///
+    /// An `idempotency_key` makes sending the same record again harmless, e.g. after a timeout.
+    pub async fn app_usage_record_create(
+        &self,
+        line_item_id: &Gid,
+        description: &str,
+        price: &Money,
+        idempotency_key: Option<&str>,
+    ) -> Result<AppUsageRecord, ShopifyAPIError> {
+        let mutation = r#"
+            mutation($subscriptionLineItemId: ID!, $description: String!, $price: MoneyInput!, $idempotencyKey: String) {
+                appUsageRecordCreate(subscriptionLineItemId: $subscriptionLineItemId, description: $description, price: $price, idempotencyKey: $idempotencyKey) {
+                    appUsageRecord { id description createdAt price { amount currencyCode } }
+                    userErrors { field message }
+                }
+            }"#;
+
+        self.graphql_mutation(
+            mutation,
+            &json!({
+                "subscriptionLineItemId": line_item_id,
+                "description": description,
+                "price": price,
+                "idempotencyKey": idempotency_key,
+            }),
+            &vec![
+                ReadJsonTreeSteps::Key("data"),
+                ReadJsonTreeSteps::Key("appUsageRecordCreate"),
+                ReadJsonTreeSteps::Key("appUsageRecord"),
+            ],
+        )
+        .await
+    }
+
+    /// Cancel a subscription with `appSubscriptionCancel`, refunding the rest of the billing
+    /// period with `prorate`
+    pub async fn app_subscription_cancel(
+        &self,
+        id: &Gid,
+        prorate: bool,
+    ) -> Result<AppSubscription, ShopifyAPIError> {
+        let mutation = format!(
+            r#"
+            mutation($id: ID!, $prorate: Boolean) {{
+                appSubscriptionCancel(id: $id, prorate: $prorate) {{
+                    appSubscription {{ {APP_SUBSCRIPTION_FIELDS} }}
+                    userErrors {{ field message }}
+                }}
+            }}"#
+        );
+
+        self.graphql_mutation(
+            &mutation,
+            &json!({ "id": id, "prorate": prorate }),
+            &vec![
+                ReadJsonTreeSteps::Key("data"),
+                ReadJsonTreeSteps::Key("appSubscriptionCancel"),
+                ReadJsonTreeSteps::Key("appSubscription"),
+            ],
+        )
+        .await
+    }
+
+    /// Get the active subscriptions of the shop to the app, empty when the shop doesn't pay
+    ///
+    /// # Example
+    /// ```no_run
+    /// use shopify_api::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let shopify = Shopify::new("myshop", "myapikey", ShopifyAPIVersion::V2026_10, None);
+    ///
+    ///     let subscriptions = shopify.app_active_subscriptions().await.unwrap();
+    ///     if subscriptions.is_empty() {
+    ///         println!("Send the merchant to the pricing page");
+    ///     }
+    /// }
+    /// ```
+    pub async fn app_active_subscriptions(&self) -> Result<Vec<AppSubscription>, ShopifyAPIError> {
+        let query = format!(
+            "query {{ currentAppInstallation {{ activeSubscriptions {{ {APP_SUBSCRIPTION_FIELDS} }} }} }}"
+        );
+
+        self.graphql_query(
+            &query,
+            &json!({}),
+            &vec![
+                ReadJsonTreeSteps::Key("data"),
+                ReadJsonTreeSteps::Key("currentAppInstallation"),
+                ReadJsonTreeSteps::Key("activeSubscriptions"),
+            ],
+        )
+        .await
+    }
+}
@@ -0,0 +1,541 @@
+//! Bulk operations, the way to export large datasets
+//!
+//! A bulk query runs on Shopify's side without pagination nor rate limits. Once it is completed,
+//! its results can be downloaded as a JSONL file with one line per node. Nodes of nested connections
+//! are returned on their own lines, with the ID of their parent in a `__parentId` field.
+//!
+//! [`Shopify::bulk_query`] runs a query and returns its results, see
+//! [`Shopify::bulk_run_query`], [`Shopify::wait_for_current_bulk`] and
+//! [`Shopify::download_bulk_results`] for the separate steps. Large results can be streamed with
+//! [`Shopify::bulk_results_stream`] and [`Shopify::bulk_records_stream`].
+
+use std::{future::Future, time::Duration};
+
+use futures_util::{stream, Stream};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+pub use super::bulk_query::{
+    ShopifyBulk, ShopifyBulkErrorCode, ShopifyBulkOperationRunQuery, ShopifyBulkStatus,
+    ShopifyUserError,
+};
+use super::uploads::{StagedUploadInput, StagedUploadResource};
+use crate::{utils::ReadJsonTreeSteps, Shopify, ShopifyAPIError};
+
+/// Delay before the first poll of a bulk operation, doubled after each poll
+const POLL_INITIAL_DELAY: Duration = Duration::from_secs(1);
+
+/// Maximum delay between two polls of a bulk operation
+const POLL_MAX_DELAY: Duration = Duration::from_secs(30);
+
+const BULK_FIELDS: &str = "id status errorCode url partialDataUrl";
+
+impl ShopifyBulkStatus {
+    /// Check if the operation stopped, successfully or not
+    pub fn is_finished(&self) -> bool {
+        !matches!(
+            self,
+            ShopifyBulkStatus::Created | ShopifyBulkStatus::Running | ShopifyBulkStatus::Canceling
+        )
+    }
+}
+
+impl Shopify {
+    /// Run a bulk query and download its results
+    ///
+    /// The query is a regular query without pagination arguments, e.g.
+    /// `{ products { edges { node { id title } } } }`. The results are read from the JSONL file,
+    /// see the [module documentation](crate::graphql::bulk). This fails if the operation does not complete.
+    /// # Example
+    /// ```no_run
+    /// use shopify_api::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let shopify = Shopify::new("myshop", "myapikey", ShopifyAPIVersion::V2026_10, None);
+    ///     let query = "{ products { edges { node { id title } } } }";
+    ///
+    ///     let products: Vec<serde_json::Value> = shopify.bulk_query(query).await.unwrap();
+    ///     println!("{} products", products.len());
+    /// }
+    /// ```
+    pub async fn bulk_query<ItemType>(&self, query: &str) -> Result<Vec<ItemType>, ShopifyAPIError>
+    where
+        ItemType: serde::de::DeserializeOwned,
+    {
+        self.bulk_run_query(query).await?;
+        let bulk = self.wait_for_current_bulk().await?;
+
+        if bulk.status != ShopifyBulkStatus::Completed {
+            return Err(ShopifyAPIError::Other(format!(
+                "Bulk operation {:?}: {:?}",
+                bulk.status, bulk.error_code
+            )));
+        }
+
+        match bulk.url {
+            Some(url) => self.download_bulk_results(&url).await,
+            // Shopify sends no file when the query has no results
+            None => Ok(Vec::new()),
+        }
+    }
+
+    /// Start a bulk query with `bulkOperationRunQuery`
+    ///
+    /// This fails with [`ShopifyAPIError::UserErrors`] if the query is rejected, e.g. when another
+    /// bulk query is already running.
+    pub async fn bulk_run_query(&self, query: &str) -> Result<ShopifyBulk, ShopifyAPIError> {
+        let mutation = format!(
+            r#"
+            mutation($query: String!) {{
+                bulkOperationRunQuery(query: $query) {{
+                    bulkOperation {{ {BULK_FIELDS} }}
+                    userErrors {{ field message }}
+                }}
+            }}"#
+        );
+
+        self.graphql_mutation(
+            &mutation,
+            &json!({ "query": query }),
+            &vec![
+                ReadJsonTreeSteps::Key("data"),
+                ReadJsonTreeSteps::Key("bulkOperationRunQuery"),
+                ReadJsonTreeSteps::Key("bulkOperation"),
+            ],
+        )
+        .await
+    }
+
+    /// Get the last bulk query of the app, from `currentBulkOperation`
+    pub async fn current_bulk_operation(&self) -> Result<Option<ShopifyBulk>, ShopifyAPIError> {
+        self.graphql_query(
+            &format!("{{ currentBulkOperation {{ {BULK_FIELDS} }} }}"),
+            &json!({}),
+            &vec![
+                ReadJsonTreeSteps::Key("data"),
+                ReadJsonTreeSteps::Key("currentBulkOperation"),
+            ],
+        )
+        .await
+    }
+
+    /// Poll `currentBulkOperation` until it is finished, and return it
+    ///
+    /// The delay between two polls starts at 1 second and doubles up to 30 seconds.
+    /// The operation is returned whatever its final status, see [`ShopifyBulkStatus::is_finished`].
+    pub async fn wait_for_current_bulk(&self) -> Result<ShopifyBulk, ShopifyAPIError> {
+        poll_until_finished(|| async {
+            self.current_bulk_operation()
+                .await?
+                .ok_or_else(|| ShopifyAPIError::Other("Bulk operation not found".to_string()))
+        })
+        .await
+    }
+
+    /// Get a bulk operation by its ID
+    pub async fn bulk_operation(&self, id: &str) -> Result<ShopifyBulk, ShopifyAPIError> {
+        let query = format!(
+            r#"
+            query($id: ID!) {{
+                node(id: $id) {{
+                    ... on BulkOperation {{ {BULK_FIELDS} }}
+                }}
+            }}"#
+        );
+        let bulk: Option<ShopifyBulk> = self
+            .graphql_query(
+                &query,
+                &json!({ "id": id }),
+                &vec![
+                    ReadJsonTreeSteps::Key("data"),
+                    ReadJsonTreeSteps::Key("node"),
+                ],
+            )
+            .await?;
+
+        bulk.ok_or_else(|| ShopifyAPIError::Other("Bulk operation not found".to_string()))
+    }
+
+    /// Run a mutation once for each of the given variables with `bulkOperationRunMutation`
+    ///
+    /// The variables are written to a JSONL file, uploaded with `stagedUploadsCreate`, and the
+    /// mutation is started on them. The returned handle polls the operation, whose result file has
+    /// the response of each mutation on its own line.
+    /// This fails with [`ShopifyAPIError::UserErrors`] if the upload or the mutation is rejected.
+    /// # Example
+    /// ```no_run
+    /// use shopify_api::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let shopify = Shopify::new("myshop", "myapikey", ShopifyAPIVersion::V2026_10, None);
+    ///     let mutation = r#"
+    ///         mutation($input: ProductInput!) {
+    ///             productCreate(input: $input) {
+    ///                 product { id }
+    ///                 userErrors { field message }
+    ///             }
+    ///         }
+    ///     "#;
+    ///     let variables = ["Red shirt", "Blue shirt"]
+    ///         .into_iter()
+    ///         .map(|title| serde_json::json!({ "input": { "title": title } }));
+    ///
+    ///     let handle = shopify.bulk_run_mutation(mutation, variables).await.unwrap();
+    ///     let bulk = handle.wait(&shopify).await.unwrap();
+    ///     let results: Vec<serde_json::Value> = shopify
+    ///         .download_bulk_results(&bulk.url.unwrap())
+    ///         .await
+    ///         .unwrap();
+    /// }
+    /// ```
+    pub async fn bulk_run_mutation<VariablesType, VariablesIterator>(
+        &self,
+        mutation: &str,
+        variables: VariablesIterator,
+    ) -> Result<BulkOperationHandle, ShopifyAPIError>
+    where
+        VariablesType: serde::Serialize,
+        VariablesIterator: IntoIterator<Item = VariablesType>,
+    {
+        let mut jsonl = String::new();
+        for variables in variables {
+            jsonl.push_str(&serde_json::to_string(&variables)?);
+            jsonl.push('\n');
+        }
+        let staged_upload_path = self.stage_bulk_variables(jsonl).await?;
+
+        let run_mutation = format!(
+            r#"
+            mutation($mutation: String!, $stagedUploadPath: String!) {{
+                bulkOperationRunMutation(
+                    mutation: $mutation,
+                    stagedUploadPath: $stagedUploadPath
+                ) {{
+                    bulkOperation {{ {BULK_FIELDS} }}
+                    userErrors {{ field message }}
+                }}
+            }}"#
+        );
+        let bulk: ShopifyBulk = self
+            .graphql_mutation(
+                &run_mutation,
+                &json!({ "mutation": mutation, "stagedUploadPath": staged_upload_path }),
+                &vec![
+                    ReadJsonTreeSteps::Key("data"),
+                    ReadJsonTreeSteps::Key("bulkOperationRunMutation"),
+                    ReadJsonTreeSteps::Key("bulkOperation"),
+                ],
+            )
+            .await?;
+
+        let id = bulk.id.ok_or_else(|| {
+            ShopifyAPIError::NotWantedJsonFormat("bulk operation without id".to_string())
+        })?;
+
+        Ok(BulkOperationHandle { id })
+    }
+
+    /// Upload the JSONL variables of a bulk mutation, returning the path given to the mutation
+    async fn stage_bulk_variables(&self, jsonl: String) -> Result<String, ShopifyAPIError> {
+        let input = StagedUploadInput::new(
+            StagedUploadResource::BulkMutationVariables,
+            "bulk_op_vars.jsonl",
+            "text/jsonl",
+        );
+        let target = self.staged_upload(&input, jsonl.into_bytes()).await?;
+
+        target.parameter("key").map(str::to_string).ok_or_else(|| {
+            ShopifyAPIError::NotWantedJsonFormat("staged upload without key".to_string())
+        })
+    }
+
+    /// Download the JSONL results of a bulk operation, reading one item per line
+    ///
+    /// See [`Shopify::bulk_results_stream`] to process large files without keeping every item.
+    pub async fn download_bulk_results<ItemType>(
+        &self,
+        url: &str,
+    ) -> Result<Vec<ItemType>, ShopifyAPIError>
+    where
+        ItemType: serde::de::DeserializeOwned,
+    {
+        let mut lines = self.bulk_lines(url).await?;
+
+        let mut items = Vec::new();
+        while let Some(line) = lines.next_line().await? {
+            items.push(serde_json::from_slice(&line)?);
+        }
+
+        Ok(items)
+    }
+
+    /// Stream the JSONL results of a bulk operation, reading one item per line as the file is received
+    ///
+    /// The file is never kept whole in memory, only the line being read. The stream ends after
+    /// the last line, or after the first error.
+    /// # Example
+    /// ```no_run
+    /// use futures_util::StreamExt;
+    /// use shopify_api::*;
+    ///
+    /// #[derive(serde::Deserialize)]
+    /// struct Line {
+    ///     id: String,
+    ///     #[serde(rename = "__parentId")]
+    ///     parent_id: Option<String>,
+    /// }
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let shopify = Shopify::new("myshop", "myapikey", ShopifyAPIVersion::V2026_10, None);
+    ///     let bulk = shopify.wait_for_current_bulk().await.unwrap();
+    ///
+    ///     let mut lines = shopify
+    ///         .bulk_results_stream::<Line>(&bulk.url.unwrap())
+    ///         .await
+    ///         .unwrap();
+    ///     while let Some(line) = lines.next().await {
+    ///         let line = line.unwrap();
+    ///         println!("{} (parent: {:?})", line.id, line.parent_id);
+    ///     }
+    /// }
+    /// ```
+    pub async fn bulk_results_stream<ItemType>(
+        &self,
+        url: &str,
+    ) -> Result<impl Stream<Item = Result<ItemType, ShopifyAPIError>> + Unpin, ShopifyAPIError>
+    where
+        ItemType: serde::de::DeserializeOwned,
+    {
+        let lines = self.bulk_lines(url).await?;
+
+        Ok(Box::pin(stream::unfold(Some(lines), |lines| async move {
+            let mut lines = lines?;
+            match lines.next_line().await {
+                Ok(Some(line)) => Some((
+                    serde_json::from_slice(&line).map_err(ShopifyAPIError::JsonParseError),
+                    Some(lines),
+                )),
+                Ok(None) => None,
+                Err(error) => Some((Err(error), None)),
+            }
+        })))
+    }
+
+    /// Stream the JSONL results of a bulk operation, grouping the nested nodes with their top-level node
+    ///
+    /// Shopify writes the nodes of the nested connections after their parent, with a `__parentId`
+    /// field. Each [`BulkRecord`] holds a top-level node with all the nodes nested in it, at any
+    /// depth, so their `__parentId` tells which node they belong to. As with
+    /// [`Shopify::bulk_results_stream`], the file is read as it is received.
+    /// # Example
+    /// ```no_run
+    /// use futures_util::StreamExt;
+    /// use shopify_api::*;
+    /// use shopify_api::graphql::bulk::BulkRecord;
+    ///
+    /// #[derive(serde::Deserialize)]
+    /// struct Product {
+    ///     id: String,
+    ///     title: String,
+    /// }
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let shopify = Shopify::new("myshop", "myapikey", ShopifyAPIVersion::V2026_10, None);
+    ///     let bulk = shopify.wait_for_current_bulk().await.unwrap();
+    ///
+    ///     let mut products = shopify
+    ///         .bulk_records_stream::<Product, serde_json::Value>(&bulk.url.unwrap())
+    ///         .await
+    ///         .unwrap();
+    ///     while let Some(product) = products.next().await {
+    ///         let BulkRecord { node, children } = product.unwrap();
+    ///         println!("{} has {} variants", node.title, children.len());
+    ///     }
+    /// }
+    /// ```
+    pub async fn bulk_records_stream<NodeType, ChildType>(
+        &self,
+        url: &str,
+    ) -> Result<
+        impl Stream<Item = Result<BulkRecord<NodeType, ChildType>, ShopifyAPIError>> + Unpin,
+        ShopifyAPIError,
+    >
+    where
+        NodeType: serde::de::DeserializeOwned,
+        ChildType: serde::de::DeserializeOwned,
+    {
+        let state = RecordsState {
+            lines: self.bulk_lines(url).await?,
+            record: None,
+        };
+
+        Ok(Box::pin(stream::unfold(Some(state), |state| async move {
+            let mut state = state?;
+            match state.next_record().await {
+                Ok(Some(record)) => Some((Ok(record), Some(state))),
+                Ok(None) => None,
+                Err(error) => Some((Err(error), None)),
+            }
+        })))
+    }
+
+    /// Start downloading a bulk operation result file
+    async fn bulk_lines(&self, url: &str) -> Result<JsonLines, ShopifyAPIError> {
+        // The url is signed, the access token must not be sent with it
+        let response = self.client.get(url).send().await?.error_for_status()?;
+
+        Ok(JsonLines {
+            response: Some(response),
+            buffer: Vec::new(),
+        })
+    }
+}
+
+/// Bulk operation started by [`Shopify::bulk_run_mutation`]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct BulkOperationHandle {
+    /// ID of the operation, e.g. `gid://shopify/BulkOperation/1`
+    pub id: String,
+}
+
+impl BulkOperationHandle {
+    /// Get the current state of the operation
+    pub async fn status(&self, shopify: &Shopify) -> Result<ShopifyBulk, ShopifyAPIError> {
+        shopify.bulk_operation(&self.id).await
+    }
+
+    /// Poll the operation until it is finished, and return it
+    ///
+    /// The delay between two polls starts at 1 second and doubles up to 30 seconds.
+    /// The operation is returned whatever its final status, see [`ShopifyBulkStatus::is_finished`].
+    pub async fn wait(&self, shopify: &Shopify) -> Result<ShopifyBulk, ShopifyAPIError> {
+        poll_until_finished(|| self.status(shopify)).await
+    }
+}
+
+/// Poll a bulk operation with an exponential backoff until it is finished
+async fn poll_until_finished<PollType, FutureType>(
+    mut poll: PollType,
+) -> Result<ShopifyBulk, ShopifyAPIError>
+where
+    PollType: FnMut() -> FutureType,
+    FutureType: Future<Output = Result<ShopifyBulk, ShopifyAPIError>>,
+{
+    let mut delay = POLL_INITIAL_DELAY;
+
+    loop {
+        let bulk = poll().await?;
+        if bulk.status.is_finished() {
+            return Ok(bulk);
+        }
+
+        crate::utils::sleep(delay).await;
+        delay = (delay * 2).min(POLL_MAX_DELAY);
+    }
+}
+
+/// Top-level node of a bulk query result, with the nodes nested in it
+#[derive(Clone, Debug, PartialEq)]
+pub struct BulkRecord<NodeType, ChildType = serde_json::Value> {
+    pub node: NodeType,
+    /// Nodes with a `__parentId`, in the order of the file
+    pub children: Vec<ChildType>,
+}
+
+/// Lines of a JSONL response body, read as the body is received
+struct JsonLines {
+    response: Option<reqwest::Response>,
+    /// Start of the next lines, received but not read yet
+    buffer: Vec<u8>,
+}
+
+impl JsonLines {
+    /// Read the next non-empty line, without its line break
+    async fn next_line(&mut self) -> Result<Option<Vec<u8>>, ShopifyAPIError> {
+        loop {
+            if let Some(end) = self.buffer.iter().position(|byte| *byte == b'\n') {
+                let mut line: Vec<u8> = self.buffer.drain(..=end).collect();
+                line.pop();
+                if !line.trim_ascii().is_empty() {
+                    return Ok(Some(line));
+                }
+                continue;
+            }
+
+            match next_chunk(&mut self.response).await? {
+                Some(chunk) => self.buffer.extend_from_slice(chunk.as_ref()),
+                None => {
+                    let line = std::mem::take(&mut self.buffer);
+                    return Ok((!line.trim_ascii().is_empty()).then_some(line));
+                }
+            }
+        }
+    }
+}
+
+/// Read the next chunk of a response body
+#[cfg(not(target_arch = "wasm32"))]
+async fn next_chunk(
+    response: &mut Option<reqwest::Response>,
+) -> Result<Option<impl AsRef<[u8]>>, ShopifyAPIError> {
+    match response {
+        Some(response) => Ok(response.chunk().await?),
+        None => Ok(None),
+    }
+}
+
+/// Read the next chunk of a response body, the whole body on wasm32 where it cannot be streamed
+#[cfg(target_arch = "wasm32")]
+async fn next_chunk(
+    response: &mut Option<reqwest::Response>,
+) -> Result<Option<impl AsRef<[u8]>>, ShopifyAPIError> {
+    match response.take() {
+        Some(response) => Ok(Some(response.bytes().await?)),
+        None => Ok(None),
+    }
+}
+
+/// Position of a records stream in a bulk result file
+struct RecordsState<NodeType, ChildType> {
+    lines: JsonLines,
+    /// Record being read, yielded when the next top-level node is found
+    record: Option<BulkRecord<NodeType, ChildType>>,
+}
+
+impl<NodeType, ChildType> RecordsState<NodeType, ChildType>
+where
+    NodeType: serde::de::DeserializeOwned,
+    ChildType: serde::de::DeserializeOwned,
+{
+    async fn next_record(
+        &mut self,
+    ) -> Result<Option<BulkRecord<NodeType, ChildType>>, ShopifyAPIError> {
+        while let Some(line) = self.lines.next_line().await? {
+            let json: serde_json::Value = serde_json::from_slice(&line)?;
+
+            if json.get("__parentId").is_some() {
+                let record = self.record.as_mut().ok_or_else(|| {
+                    ShopifyAPIError::NotWantedJsonFormat(
+                        "nested node before any top-level node".to_string(),
+                    )
+                })?;
+                record.children.push(serde_json::from_value(json)?);
+                continue;
+            }
+
+            let record = BulkRecord {
+                node: serde_json::from_value(json)?,
+                children: Vec::new(),
+            };
+            if let Some(record) = self.record.replace(record) {
+                return Ok(Some(record));
+            }
+        }
+
+        Ok(self.record.take())
+    }
+}
@@ -178,7 +178,7 @@ impl Shopify {
             }
 
             bulk = get_bulk.unwrap();
-            tokio::time::sleep(tokio::time::Duration::from_secs(1)).await;
+            crate::utils::sleep(std::time::Duration::from_secs(1)).await;
         }
 
         Ok(bulk)
@@ -0,0 +1,543 @@
+use std::collections::HashMap;
+
+use crate::ShopifyAPIError;
+
+/// Cost of each top-level field of a mutation, whatever it returns
+const MUTATION_COST: f64 = 10.0;
+
+/// Cost of a connection, added to the cost of the nodes it returns
+const CONNECTION_COST: f64 = 2.0;
+
+/// Estimate the cost of a GraphQL query before sending it, with the rules documented by Shopify
+///
+/// Objects cost 1 point, scalars and enums are free, connections cost 2 points plus the cost of
+/// the `first` or `last` nodes they return, and every top-level field of a mutation costs 10 points.
+/// The selections of interfaces and unions count the most expensive fragment. The sizes of the
+/// connections may be given through `variables`, or the default values of the query variables.
+///
+/// The estimate is an upper bound of the `requestedQueryCost` computed by Shopify, which can be
+/// compared to the points available in [`crate::Shopify::rate_limit_state`] to budget a batch job.
+/// Queries nested more than 64 levels deep are rejected with [`ShopifyAPIError::InvalidQuery`].
+/// # Example
+/// ```
+/// use shopify_api::graphql::estimate_query_cost;
+///
+/// let query = r#"
+///     query($first: Int!) {
+///         products(first: $first) {
+///             nodes {
+///                 title
+///                 variants(first: 5) { nodes { id sku } }
+///             }
+///             pageInfo { hasNextPage endCursor }
+///         }
+///     }
+/// "#;
+///
+/// // 2 + 10 products * (1 + 2 + 5 variants)
+/// let cost = estimate_query_cost(query, &serde_json::json!({ "first": 10 })).unwrap();
+/// assert_eq!(cost, 82.0);
+/// ```
+pub fn estimate_query_cost(
+    graphql_query: &str,
+    variables: &serde_json::Value,
+) -> Result<f64, ShopifyAPIError> {
+    let document = Parser::new(graphql_query)?.document()?;
+    let operation = document
+        .operation
+        .ok_or_else(|| invalid_query("no operation found"))?;
+
+    let estimator = Estimator {
+        fragments: &document.fragments,
+        variables,
+        defaults: &operation.defaults,
+    };
+
+    if operation.is_mutation {
+        let fields = operation
+            .selections
+            .iter()
+            .filter(|selection| matches!(selection, Selection::Field(_)))
+            .count();
+        Ok(fields as f64 * MUTATION_COST)
+    } else {
+        estimator.selections_cost(&operation.selections, 0)
+    }
+}
+
+fn invalid_query(message: &str) -> ShopifyAPIError {
+    ShopifyAPIError::InvalidQuery(message.to_string())
+}
+
+/// Maximum depth of the selections, stopping the cycles of fragments, and of the nested
+/// selection sets, values and types read by the parser
+const MAX_DEPTH: usize = 64;
+
+/// Compute the cost of the selections of an operation
+struct Estimator<'a> {
+    fragments: &'a HashMap<String, Vec<Selection>>,
+    variables: &'a serde_json::Value,
+    defaults: &'a HashMap<String, u64>,
+}
+
+impl Estimator<'_> {
+    /// Cost of fields, plus the most expensive fragment
+    fn selections_cost(
+        &self,
+        selections: &[Selection],
+        depth: usize,
+    ) -> Result<f64, ShopifyAPIError> {
+        if depth > MAX_DEPTH {
+            return Err(invalid_query("selections nested too deeply"));
+        }
+
+        let mut cost = 0.0;
+        let mut fragments_cost: f64 = 0.0;
+        for selection in selections {
+            match selection {
+                Selection::Field(field) => cost += self.field_cost(field, depth)?,
+                Selection::InlineFragment(selections) => {
+                    fragments_cost =
+                        fragments_cost.max(self.selections_cost(selections, depth + 1)?)
+                }
+                Selection::FragmentSpread(name) => {
+                    let selections = self
+                        .fragments
+                        .get(name)
+                        .ok_or_else(|| invalid_query(&format!("unknown fragment `{}`", name)))?;
+                    fragments_cost =
+                        fragments_cost.max(self.selections_cost(selections, depth + 1)?)
+                }
+            }
+        }
+
+        Ok(cost + fragments_cost)
+    }
+
+    fn field_cost(&self, field: &Field, depth: usize) -> Result<f64, ShopifyAPIError> {
+        if field.selections.is_empty() {
+            return Ok(0.0);
+        }
+
+        match &field.size {
+            Some(size) => {
+                let size = self.size(field, size)? as f64;
+                let mut node_cost: f64 = 0.0;
+                let mut cost = CONNECTION_COST;
+                for selection in &field.selections {
+                    match selection {
+                        Selection::Field(child) if child.name == "nodes" => {
+                            node_cost = node_cost
+                                .max(1.0 + self.selections_cost(&child.selections, depth + 1)?)
+                        }
+                        Selection::Field(child) if child.name == "edges" => {
+                            node_cost = node_cost.max(self.edges_cost(child, depth + 1)?)
+                        }
+                        Selection::Field(child) if child.name == "pageInfo" => {}
+                        selection => {
+                            cost += self.selections_cost(std::slice::from_ref(selection), depth)?
+                        }
+                    }
+                }
+                Ok(cost + size * node_cost)
+            }
+            None => Ok(1.0 + self.selections_cost(&field.selections, depth + 1)?),
+        }
+    }
+
+    /// Cost of a node of a connection selected with `edges { cursor node { ... } }`
+    fn edges_cost(&self, edges: &Field, depth: usize) -> Result<f64, ShopifyAPIError> {
+        let mut cost = 0.0;
+        for selection in &edges.selections {
+            match selection {
+                Selection::Field(child) if child.name == "node" => {
+                    cost += 1.0 + self.selections_cost(&child.selections, depth + 1)?
+                }
+                selection => {
+                    cost += self.selections_cost(std::slice::from_ref(selection), depth)?
+                }
+            }
+        }
+
+        Ok(cost)
+    }
+
+    /// Number of nodes returned by a connection
+    fn size(&self, field: &Field, size: &Value) -> Result<u64, ShopifyAPIError> {
+        match size {
+            Value::Int(size) => Some(*size),
+            Value::Variable(name) => self.variables[name]
+                .as_u64()
+                .or_else(|| self.defaults.get(name).copied()),
+        }
+        .ok_or_else(|| {
+            invalid_query(&format!(
+                "unknown number of nodes returned by `{}`",
+                field.name
+            ))
+        })
+    }
+}
+
+/// Parsed query, keeping what the cost depends on
+struct Document {
+    operation: Option<Operation>,
+    fragments: HashMap<String, Vec<Selection>>,
+}
+
+struct Operation {
+    is_mutation: bool,
+    /// Integer default values of the variables
+    defaults: HashMap<String, u64>,
+    selections: Vec<Selection>,
+}
+
+enum Selection {
+    Field(Field),
+    InlineFragment(Vec<Selection>),
+    FragmentSpread(String),
+}
+
+struct Field {
+    name: String,
+    /// `first` or `last` argument of a connection
+    size: Option<Value>,
+    selections: Vec<Selection>,
+}
+
+enum Value {
+    Int(u64),
+    Variable(String),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Token<'a> {
+    Name(&'a str),
+    Int(u64),
+    /// Any other value: floats and strings
+    Literal,
+    Punctuator(char),
+    Spread,
+}
+
+/// Recursive descent parser of the GraphQL query language
+struct Parser<'a> {
+    tokens: Vec<Token<'a>>,
+    position: usize,
+    /// Number of selection sets, values and types being parsed
+    depth: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn new(query: &'a str) -> Result<Self, ShopifyAPIError> {
+        Ok(Parser {
+            tokens: tokenize(query)?,
+            position: 0,
+            depth: 0,
+        })
+    }
+
+    /// Enter a nested selection set, value or type, left with [`Parser::leave`]
+    fn enter(&mut self) -> Result<(), ShopifyAPIError> {
+        self.depth += 1;
+        match self.depth > MAX_DEPTH {
+            true => Err(invalid_query("query nested too deeply")),
+            false => Ok(()),
+        }
+    }
+
+    fn leave(&mut self) {
+        self.depth -= 1;
+    }
+
+    fn peek(&self) -> Option<&Token<'a>> {
+        self.tokens.get(self.position)
+    }
+
+    fn next(&mut self) -> Result<Token<'a>, ShopifyAPIError> {
+        let token = self
+            .tokens
+            .get(self.position)
+            .cloned()
+            .ok_or_else(|| invalid_query("unexpected end of query"))?;
+        self.position += 1;
+        Ok(token)
+    }
+
+    /// Consume the next token if it is the given punctuator
+    fn eat(&mut self, punctuator: char) -> bool {
+        let found = self.peek() == Some(&Token::Punctuator(punctuator));
+        if found {
+            self.position += 1;
+        }
+        found
+    }
+
+    fn expect(&mut self, punctuator: char) -> Result<(), ShopifyAPIError> {
+        match self.eat(punctuator) {
+            true => Ok(()),
+            false => Err(invalid_query(&format!("expected `{}`", punctuator))),
+        }
+    }
+
+    fn name(&mut self) -> Result<&'a str, ShopifyAPIError> {
+        match self.next()? {
+            Token::Name(name) => Ok(name),
+            _ => Err(invalid_query("expected a name")),
+        }
+    }
+
+    fn document(&mut self) -> Result<Document, ShopifyAPIError> {
+        let mut document = Document {
+            operation: None,
+            fragments: HashMap::new(),
+        };
+
+        while let Some(token) = self.peek() {
+            match token {
+                Token::Name("fragment") => {
+                    self.position += 1;
+                    let name = self.name()?.to_string();
+                    if self.name()? != "on" {
+                        return Err(invalid_query("expected `on`"));
+                    }
+                    self.name()?;
+                    self.directives()?;
+                    let selections = self.selection_set()?;
+                    document.fragments.insert(name, selections);
+                }
+                _ => {
+                    let operation = self.operation()?;
+                    // The first operation is estimated
+                    document.operation.get_or_insert(operation);
+                }
+            }
+        }
+
+        Ok(document)
+    }
+
+    fn operation(&mut self) -> Result<Operation, ShopifyAPIError> {
+        let mut operation = Operation {
+            is_mutation: false,
+            defaults: HashMap::new(),
+            selections: Vec::new(),
+        };
+
+        if let Some(Token::Name(kind)) = self.peek() {
+            operation.is_mutation = *kind == "mutation";
+            self.position += 1;
+            if let Some(Token::Name(_)) = self.peek() {
+                self.position += 1;
+            }
+            if self.eat('(') {
+                while !self.eat(')') {
+                    self.expect('$')?;
+                    let name = self.name()?.to_string();
+                    self.expect(':')?;
+                    self.skip_type()?;
+                    if self.eat('=') {
+                        if let Value::Int(default) = self.value()? {
+                            operation.defaults.insert(name, default);
+                        }
+                    }
+                    self.directives()?;
+                }
+            }
+            self.directives()?;
+        }
+        operation.selections = self.selection_set()?;
+
+        Ok(operation)
+    }
+
+    /// Skip a variable type, e.g. `[ID!]!`
+    fn skip_type(&mut self) -> Result<(), ShopifyAPIError> {
+        if self.eat('[') {
+            self.enter()?;
+            self.skip_type()?;
+            self.expect(']')?;
+            self.leave();
+        } else {
+            self.name()?;
+        }
+        self.eat('!');
+        Ok(())
+    }
+
+    fn directives(&mut self) -> Result<(), ShopifyAPIError> {
+        while self.eat('@') {
+            self.name()?;
+            self.arguments()?;
+        }
+        Ok(())
+    }
+
+    /// Parse the arguments of a field, returning its `first` or `last` argument
+    fn arguments(&mut self) -> Result<Option<Value>, ShopifyAPIError> {
+        let mut size = None;
+        if self.eat('(') {
+            while !self.eat(')') {
+                let name = self.name()?;
+                self.expect(':')?;
+                let value = self.value()?;
+                if name == "first" || name == "last" {
+                    size = Some(value);
+                }
+            }
+        }
+        Ok(size)
+    }
+
+    /// Parse a value, returning `Value::Int(0)` for the values other than integers and variables
+    fn value(&mut self) -> Result<Value, ShopifyAPIError> {
+        match self.next()? {
+            Token::Int(value) => Ok(Value::Int(value)),
+            Token::Punctuator('$') => Ok(Value::Variable(self.name()?.to_string())),
+            Token::Punctuator('[') => {
+                self.enter()?;
+                while !self.eat(']') {
+                    self.value()?;
+                }
+                self.leave();
+                Ok(Value::Int(0))
+            }
+            Token::Punctuator('{') => {
+                self.enter()?;
+                while !self.eat('}') {
+                    self.name()?;
+                    self.expect(':')?;
+                    self.value()?;
+                }
+                self.leave();
+                Ok(Value::Int(0))
+            }
+            Token::Name(_) | Token::Literal => Ok(Value::Int(0)),
+            _ => Err(invalid_query("expected a value")),
+        }
+    }
+
+    fn selection_set(&mut self) -> Result<Vec<Selection>, ShopifyAPIError> {
+        self.expect('{')?;
+        self.enter()?;
+
+        let mut selections = Vec::new();
+        while !self.eat('}') {
+            if self.peek() == Some(&Token::Spread) {
+                self.position += 1;
+                match self.peek() {
+                    Some(Token::Name("on")) => {
+                        self.position += 1;
+                        self.name()?;
+                        self.directives()?;
+                        selections.push(Selection::InlineFragment(self.selection_set()?));
+                    }
+                    Some(Token::Name(_)) => {
+                        let name = self.name()?.to_string();
+                        self.directives()?;
+                        selections.push(Selection::FragmentSpread(name));
+                    }
+                    _ => {
+                        self.directives()?;
+                        selections.push(Selection::InlineFragment(self.selection_set()?));
+                    }
+                }
+                continue;
+            }
+
+            let mut name = self.name()?;
+            // Aliased field
+            if self.eat(':') {
+                name = self.name()?;
+            }
+            let size = self.arguments()?;
+            self.directives()?;
+            let selections_of_field = match self.peek() {
+                Some(Token::Punctuator('{')) => self.selection_set()?,
+                _ => Vec::new(),
+            };
+
+            selections.push(Selection::Field(Field {
+                name: name.to_string(),
+                size,
+                selections: selections_of_field,
+            }));
+        }
+
+        self.leave();
+        Ok(selections)
+    }
+}
+
+fn tokenize(query: &str) -> Result<Vec<Token<'_>>, ShopifyAPIError> {
+    let mut tokens = Vec::new();
+    let bytes = query.as_bytes();
+    let mut i = 0;
+
+    while i < bytes.len() {
+        let c = bytes[i];
+        match c {
+            b' ' | b'\t' | b'\n' | b'\r' | b',' => i += 1,
+            b'#' => {
+                while i < bytes.len() && bytes[i] != b'\n' {
+                    i += 1;
+                }
+            }
+            b'.' if query[i..].starts_with("...") => {
+                tokens.push(Token::Spread);
+                i += 3;
+            }
+            b'"' => {
+                if query[i..].starts_with("\"\"\"") {
+                    let end = query[i + 3..]
+                        .find("\"\"\"")
+                        .ok_or_else(|| invalid_query("unterminated string"))?;
+                    i += end + 6;
+                } else {
+                    i += 1;
+                    while i < bytes.len() && bytes[i] != b'"' {
+                        i += if bytes[i] == b'\\' { 2 } else { 1 };
+                    }
+                    if i >= bytes.len() {
+                        return Err(invalid_query("unterminated string"));
+                    }
+                    i += 1;
+                }
+                tokens.push(Token::Literal);
+            }
+            b'-' | b'0'..=b'9' => {
+                let start = i;
+                i += 1;
+                while i < bytes.len()
+                    && (bytes[i].is_ascii_alphanumeric() || matches!(bytes[i], b'.' | b'+' | b'-'))
+                {
+                    i += 1;
+                }
+                tokens.push(match query[start..i].parse() {
+                    Ok(value) => Token::Int(value),
+                    Err(_) => Token::Literal,
+                });
+            }
+            b'_' | b'a'..=b'z' | b'A'..=b'Z' => {
+                let start = i;
+                while i < bytes.len() && (bytes[i].is_ascii_alphanumeric() || bytes[i] == b'_') {
+                    i += 1;
+                }
+                tokens.push(Token::Name(&query[start..i]));
+            }
+            b'{' | b'}' | b'(' | b')' | b'[' | b']' | b':' | b'$' | b'!' | b'=' | b'@' | b'|'
+            | b'&' => {
+                tokens.push(Token::Punctuator(c as char));
+                i += 1;
+            }
+            _ => {
+                return Err(invalid_query(&format!(
+                    "unexpected character `{}`",
+                    query[i..].chars().next().unwrap_or_default()
+                )))
+            }
+        }
+    }
+
+    Ok(tokens)
+}
@@ -0,0 +1,521 @@
+//! Basic code discounts, managed with the `discountCodeBasicCreate`, `discountCodeBasicUpdate`,
+//! `discountCodeActivate`, `discountCodeDeactivate` and `discountCodeDelete` mutations
+//!
+//! A basic code discount takes an amount or a percentage off products or whole orders when the
+//! customer enters its code. [`DiscountCodeBasicInput`] assembles the nested GraphQL input objects.
+
+use serde::{Deserialize, Serialize, Serializer};
+use serde_json::json;
+
+use crate::{utils::ReadJsonTreeSteps, Gid, Shopify, ShopifyAPIError};
+
+const CODE_DISCOUNT_NODE_FIELDS: &str = "id codeDiscount { ... on DiscountCodeBasic { title status startsAt endsAt usageLimit asyncUsageCount codes(first: 250) { nodes { code } } } }";
+
+/// Value of a discount
+#[derive(Clone, Debug, PartialEq)]
+pub enum DiscountValue {
+    /// Fraction taken off, e.g. `0.1` for 10% off
+    Percentage(f64),
+    /// Amount taken off in the currency of the shop, e.g. `"5.00"`, from each item or once from
+    /// the order
+    Amount {
+        amount: String,
+        applies_on_each_item: bool,
+    },
+}
+
+impl Serialize for DiscountValue {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let value = match self {
+            DiscountValue::Percentage(percentage) => json!({ "percentage": percentage }),
+            DiscountValue::Amount {
+                amount,
+                applies_on_each_item,
+            } => json!({
+                "discountAmount": { "amount": amount, "appliesOnEachItem": applies_on_each_item }
+            }),
+        };
+
+        value.serialize(serializer)
+    }
+}
+
+/// Items a discount applies to
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum DiscountItems {
+    /// Every item of the order
+    All,
+    Products {
+        products: Vec<Gid>,
+        variants: Vec<Gid>,
+    },
+    Collections(Vec<Gid>),
+}
+
+impl Serialize for DiscountItems {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let value = match self {
+            DiscountItems::All => json!({ "all": true }),
+            DiscountItems::Products { products, variants } => json!({
+                "products": { "productsToAdd": products, "productVariantsToAdd": variants }
+            }),
+            DiscountItems::Collections(collections) => {
+                json!({ "collections": { "add": collections } })
+            }
+        };
+
+        value.serialize(serializer)
+    }
+}
+
+/// Customers who can use a discount
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum DiscountContext {
+    All,
+    Customers(Vec<Gid>),
+    CustomerSegments(Vec<Gid>),
+}
+
+impl Serialize for DiscountContext {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let value = match self {
+            DiscountContext::All => json!({ "all": "ALL" }),
+            DiscountContext::Customers(customers) => {
+                json!({ "customers": { "add": customers } })
+            }
+            DiscountContext::CustomerSegments(segments) => {
+                json!({ "customerSegments": { "add": segments } })
+            }
+        };
+
+        value.serialize(serializer)
+    }
+}
+
+/// Minimum the order must reach for a discount to apply
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum DiscountMinimumRequirement {
+    /// Minimum subtotal, e.g. `"50.00"`
+    Subtotal(String),
+    /// Minimum number of items
+    Quantity(u64),
+}
+
+impl Serialize for DiscountMinimumRequirement {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let value = match self {
+            DiscountMinimumRequirement::Subtotal(subtotal) => {
+                json!({ "subtotal": { "greaterThanOrEqualToSubtotal": subtotal } })
+            }
+            DiscountMinimumRequirement::Quantity(quantity) => {
+                json!({ "quantity": { "greaterThanOrEqualToQuantity": quantity.to_string() } })
+            }
+        };
+
+        value.serialize(serializer)
+    }
+}
+
+/// Other kinds of discounts a discount combines with
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DiscountCombinesWith {
+    pub order_discounts: bool,
+    pub product_discounts: bool,
+    pub shipping_discounts: bool,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+struct DiscountCustomerGetsInput {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    value: Option<DiscountValue>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    items: Option<DiscountItems>,
+}
+
+/// Basic code discount to create or update
+///
+/// # Example
+/// ```
+/// use chrono::{TimeZone, Utc};
+/// use shopify_api::Gid;
+/// use shopify_api::graphql::discounts::{DiscountCodeBasicInput, DiscountItems, DiscountMinimumRequirement, DiscountValue};
+///
+/// // 10% off the products of a collection, for orders of 50.00 or more
+/// let starts_at = Utc.with_ymd_and_hms(2026, 6, 1, 0, 0, 0).unwrap();
+/// let discount = DiscountCodeBasicInput::new("Summer sale", "SUMMER10", starts_at, DiscountValue::Percentage(0.1))
+///     .items(DiscountItems::Collections(vec![Gid::collection(1)]))
+///     .minimum_requirement(DiscountMinimumRequirement::Subtotal("50.00".to_string()))
+///     .applies_once_per_customer(true);
+///
+/// assert_eq!(
+///     serde_json::to_value(&discount).unwrap(),
+///     serde_json::json!({
+///         "title": "Summer sale",
+///         "code": "SUMMER10",
+///         "startsAt": "2026-06-01T00:00:00+00:00",
+///         "appliesOncePerCustomer": true,
+///         "context": { "all": "ALL" },
+///         "customerGets": {
+///             "value": { "percentage": 0.1 },
+///             "items": { "collections": { "add": ["gid://shopify/Collection/1"] } }
+///         },
+///         "minimumRequirement": { "subtotal": { "greaterThanOrEqualToSubtotal": "50.00" } }
+///     })
+/// );
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DiscountCodeBasicInput {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    code: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    starts_at: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    ends_at: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    usage_limit: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    applies_once_per_customer: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    context: Option<DiscountContext>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    customer_gets: Option<DiscountCustomerGetsInput>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    minimum_requirement: Option<DiscountMinimumRequirement>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    combines_with: Option<DiscountCombinesWith>,
+}
+
+impl DiscountCodeBasicInput {
+    /// Describe a new discount applying `value` to every item for every customer, for
+    /// [`Shopify::discount_code_basic_create`]
+    pub fn new(
+        title: &str,
+        code: &str,
+        starts_at: chrono::DateTime<chrono::Utc>,
+        value: DiscountValue,
+    ) -> DiscountCodeBasicInput {
+        DiscountCodeBasicInput {
+            title: Some(title.to_string()),
+            code: Some(code.to_string()),
+            starts_at: Some(starts_at.to_rfc3339()),
+            context: Some(DiscountContext::All),
+            customer_gets: Some(DiscountCustomerGetsInput {
+                value: Some(value),
+                items: Some(DiscountItems::All),
+            }),
+            ..DiscountCodeBasicInput::default()
+        }
+    }
+
+    /// Describe the changes of an existing discount, for [`Shopify::discount_code_basic_update`]
+    pub fn update() -> DiscountCodeBasicInput {
+        DiscountCodeBasicInput::default()
+    }
+
+    pub fn title(mut self, title: &str) -> Self {
+        self.title = Some(title.to_string());
+        self
+    }
+
+    pub fn code(mut self, code: &str) -> Self {
+        self.code = Some(code.to_string());
+        self
+    }
+
+    pub fn starts_at(mut self, starts_at: chrono::DateTime<chrono::Utc>) -> Self {
+        self.starts_at = Some(starts_at.to_rfc3339());
+        self
+    }
+
+    pub fn ends_at(mut self, ends_at: chrono::DateTime<chrono::Utc>) -> Self {
+        self.ends_at = Some(ends_at.to_rfc3339());
+        self
+    }
+
+    /// Set the number of times the discount can be used in total
+    pub fn usage_limit(mut self, usage_limit: u64) -> Self {
+        self.usage_limit = Some(usage_limit);
+        self
+    }
+
+    pub fn applies_once_per_customer(mut self, applies_once_per_customer: bool) -> Self {
+        self.applies_once_per_customer = Some(applies_once_per_customer);
+        self
+    }
+
+    pub fn context(mut self, context: DiscountContext) -> Self {
+        self.context = Some(context);
+        self
+    }
+
+    pub fn value(mut self, value: DiscountValue) -> Self {
+        self.customer_gets
+            .get_or_insert_with(Default::default)
+            .value = Some(value);
+        self
+    }
+
+    pub fn items(mut self, items: DiscountItems) -> Self {
+        self.customer_gets
+            .get_or_insert_with(Default::default)
+            .items = Some(items);
+        self
+    }
+
+    pub fn minimum_requirement(mut self, minimum_requirement: DiscountMinimumRequirement) -> Self {
+        self.minimum_requirement = Some(minimum_requirement);
+        self
+    }
+
+    pub fn combines_with(mut self, combines_with: DiscountCombinesWith) -> Self {
+        self.combines_with = Some(combines_with);
+        self
+    }
+}
+
+/// Status of a discount
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum DiscountStatus {
+    Active,
+    Expired,
+    Scheduled,
+    #[serde(other)]
+    Unknown,
+}
+
+/// Code discount returned by the discount mutations
+///
+/// The fields other than `id` are only read for basic code discounts.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(from = "RawCodeDiscountNode")]
+pub struct CodeDiscountNode {
+    pub id: Gid,
+    pub title: Option<String>,
+    pub status: Option<DiscountStatus>,
+    pub starts_at: Option<String>,
+    pub ends_at: Option<String>,
+    pub usage_limit: Option<u64>,
+    /// Number of times the discount was used, updated asynchronously
+    pub async_usage_count: Option<u64>,
+    /// Codes of the discount, at most 250
+    pub codes: Vec<String>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RawCodeDiscountNode {
+    id: Gid,
+    #[serde(default)]
+    code_discount: Option<RawCodeDiscount>,
+}
+
+#[derive(Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RawCodeDiscount {
+    title: Option<String>,
+    status: Option<DiscountStatus>,
+    starts_at: Option<String>,
+    ends_at: Option<String>,
+    usage_limit: Option<u64>,
+    async_usage_count: Option<u64>,
+    codes: Option<RawCodes>,
+}
+
+#[derive(Deserialize)]
+struct RawCodes {
+    nodes: Vec<RawCode>,
+}
+
+#[derive(Deserialize)]
+struct RawCode {
+    code: String,
+}
+
+impl From<RawCodeDiscountNode> for CodeDiscountNode {
+    fn from(raw: RawCodeDiscountNode) -> CodeDiscountNode {
+        let discount = raw.code_discount.unwrap_or_default();
+
+        CodeDiscountNode {
+            id: raw.id,
+            title: discount.title,
+            status: discount.status,
+            starts_at: discount.starts_at,
+            ends_at: discount.ends_at,
+            usage_limit: discount.usage_limit,
+            async_usage_count: discount.async_usage_count,
+            codes: discount
+                .codes
+                .map(|codes| codes.nodes.into_iter().map(|node| node.code).collect())
+                .unwrap_or_default(),
+        }
+    }
+}
+
+impl Shopify {
+    /// Create a basic code discount with `discountCodeBasicCreate`
+    ///
+    /// This fails with [`ShopifyAPIError::UserErrors`] if the discount is invalid, e.g. when its
+    /// code is already used.
+    /// # Example
+    /// ```no_run
+    /// use shopify_api::*;
+    /// use shopify_api::graphql::discounts::{DiscountCodeBasicInput, DiscountValue};
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let shopify = Shopify::new("myshop", "myapikey", ShopifyAPIVersion::V2026_10, None);
+    ///     let value = DiscountValue::Amount {
+    ///         amount: "5.00".to_string(),
+    ///         applies_on_each_item: false,
+    ///     };
+    ///     let input = DiscountCodeBasicInput::new("Welcome", "WELCOME5", chrono::Utc::now(), value)
+    ///         .usage_limit(100);
+    ///
+    ///     let discount = shopify.discount_code_basic_create(&input).await.unwrap();
+    ///     println!("{} {:?}", discount.id, discount.status);
+    /// }
+    /// ```
+    pub async fn discount_code_basic_create(
+        &self,
+        input: &DiscountCodeBasicInput,
+    ) -> Result<CodeDiscountNode, ShopifyAPIError> {
+        let mutation = format!(
+            r#"
+            mutation($basicCodeDiscount: DiscountCodeBasicInput!) {{
+                discountCodeBasicCreate(basicCodeDiscount: $basicCodeDiscount) {{
+                    codeDiscountNode {{ {CODE_DISCOUNT_NODE_FIELDS} }}
+                    userErrors {{ field message code }}
+                }}
+            }}"#
+        );
+
+        self.graphql_mutation(
+            &mutation,
+            &json!({ "basicCodeDiscount": input }),
+            &vec![
+                ReadJsonTreeSteps::Key("data"),
+                ReadJsonTreeSteps::Key("discountCodeBasicCreate"),
+                ReadJsonTreeSteps::Key("codeDiscountNode"),
+            ],
+        )
+        .await
+    }
+
+    /// Update a basic code discount with `discountCodeBasicUpdate`
+    ///
+    /// Only the fields set on the [`DiscountCodeBasicInput::update`] are changed.
+    /// # Example
+    /// ```no_run
+    /// use shopify_api::*;
+    /// use shopify_api::graphql::discounts::{DiscountCodeBasicInput, DiscountValue};
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let shopify = Shopify::new("myshop", "myapikey", ShopifyAPIVersion::V2026_10, None);
+    ///     let input = DiscountCodeBasicInput::update().value(DiscountValue::Percentage(0.15));
+    ///
+    ///     shopify
+    ///         .discount_code_basic_update(&Gid::new("DiscountCodeNode", 1), &input)
+    ///         .await
+    ///         .unwrap();
+    /// }
+    /// ```
+    pub async fn discount_code_basic_update(
+        &self,
+        id: &Gid,
+        input: &DiscountCodeBasicInput,
+    ) -> Result<CodeDiscountNode, ShopifyAPIError> {
+        let mutation = format!(
+            r#"
+            mutation($id: ID!, $basicCodeDiscount: DiscountCodeBasicInput!) {{
+                discountCodeBasicUpdate(id: $id, basicCodeDiscount: $basicCodeDiscount) {{
+                    codeDiscountNode {{ {CODE_DISCOUNT_NODE_FIELDS} }}
+                    userErrors {{ field message code }}
+                }}
+            }}"#
+        );
+
+        self.graphql_mutation(
+            &mutation,
+            &json!({ "id": id, "basicCodeDiscount": input }),
+            &vec![
+                ReadJsonTreeSteps::Key("data"),
+                ReadJsonTreeSteps::Key("discountCodeBasicUpdate"),
+                ReadJsonTreeSteps::Key("codeDiscountNode"),
+            ],
+        )
+        .await
+    }
+
+    /// Activate a code discount with `discountCodeActivate`
+    pub async fn discount_code_activate(
+        &self,
+        id: &Gid,
+    ) -> Result<CodeDiscountNode, ShopifyAPIError> {
+        self.discount_code_status_mutation("discountCodeActivate", id)
+            .await
+    }
+
+    /// Deactivate a code discount with `discountCodeDeactivate`, ending it now
+    pub async fn discount_code_deactivate(
+        &self,
+        id: &Gid,
+    ) -> Result<CodeDiscountNode, ShopifyAPIError> {
+        self.discount_code_status_mutation("discountCodeDeactivate", id)
+            .await
+    }
+
+    /// Delete a code discount with `discountCodeDelete`, returning its ID
+    pub async fn discount_code_delete(&self, id: &Gid) -> Result<Gid, ShopifyAPIError> {
+        let mutation = r#"
+            mutation($id: ID!) {
+                discountCodeDelete(id: $id) {
+                    deletedCodeDiscountId
+                    userErrors { field message code }
+                }
+            }"#;
+
+        self.graphql_mutation(
+            mutation,
+            &json!({ "id": id }),
+            &vec![
+                ReadJsonTreeSteps::Key("data"),
+                ReadJsonTreeSteps::Key("discountCodeDelete"),
+                ReadJsonTreeSteps::Key("deletedCodeDiscountId"),
+            ],
+        )
+        .await
+    }
+
+    async fn discount_code_status_mutation(
+        &self,
+        mutation_name: &str,
+        id: &Gid,
+    ) -> Result<CodeDiscountNode, ShopifyAPIError> {
+        let mutation = format!(
+            r#"
+            mutation($id: ID!) {{
+                {mutation_name}(id: $id) {{
+                    codeDiscountNode {{ {CODE_DISCOUNT_NODE_FIELDS} }}
+                    userErrors {{ field message code }}
+                }}
+            }}"#
+        );
+
+        self.graphql_mutation(
+            &mutation,
+            &json!({ "id": id }),
+            &vec![
+                ReadJsonTreeSteps::Key("data"),
+                ReadJsonTreeSteps::Key(mutation_name),
+                ReadJsonTreeSteps::Key("codeDiscountNode"),
+            ],
+        )
+        .await
+    }
+}
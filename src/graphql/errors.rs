@@ -0,0 +1,149 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+/// Code of a GraphQL error, read from its `extensions.code`
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum GraphQLErrorCode {
+    /// The query cost exceeded the available points, it can be retried later
+    Throttled,
+    /// The app is missing an access scope for the query
+    AccessDenied,
+    /// The shop is frozen or closed
+    ShopInactive,
+    /// The query cost exceeds the maximum cost of a single query
+    MaxCostExceeded,
+    InternalServerError,
+    /// Any other code, such as the validation codes (`undefinedField`...)
+    Other(String),
+}
+
+impl GraphQLErrorCode {
+    /// Get the code as sent by Shopify
+    pub fn as_str(&self) -> &str {
+        match self {
+            GraphQLErrorCode::Throttled => "THROTTLED",
+            GraphQLErrorCode::AccessDenied => "ACCESS_DENIED",
+            GraphQLErrorCode::ShopInactive => "SHOP_INACTIVE",
+            GraphQLErrorCode::MaxCostExceeded => "MAX_COST_EXCEEDED",
+            GraphQLErrorCode::InternalServerError => "INTERNAL_SERVER_ERROR",
+            GraphQLErrorCode::Other(code) => code,
+        }
+    }
+
+    /// Check if a query failing with this code may succeed when sent again
+    pub fn is_retryable(&self) -> bool {
+        matches!(
+            self,
+            GraphQLErrorCode::Throttled | GraphQLErrorCode::InternalServerError
+        )
+    }
+}
+
+impl From<&str> for GraphQLErrorCode {
+    fn from(code: &str) -> Self {
+        match code {
+            "THROTTLED" => GraphQLErrorCode::Throttled,
+            "ACCESS_DENIED" => GraphQLErrorCode::AccessDenied,
+            "SHOP_INACTIVE" => GraphQLErrorCode::ShopInactive,
+            "MAX_COST_EXCEEDED" => GraphQLErrorCode::MaxCostExceeded,
+            "INTERNAL_SERVER_ERROR" => GraphQLErrorCode::InternalServerError,
+            code => GraphQLErrorCode::Other(code.to_string()),
+        }
+    }
+}
+
+impl fmt::Display for GraphQLErrorCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Location of a GraphQL error in the query
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GraphQLErrorLocation {
+    pub line: u32,
+    pub column: u32,
+}
+
+/// Top-level error of a GraphQL response
+/// # Example
+/// ```
+/// use shopify_api::graphql::{GraphQLError, GraphQLErrorCode};
+///
+/// let errors = GraphQLError::from_response(&serde_json::json!({
+///     "errors": [{
+///         "message": "Access denied for orders field.",
+///         "extensions": { "code": "ACCESS_DENIED" }
+///     }]
+/// }));
+///
+/// assert_eq!(errors[0].code(), Some(GraphQLErrorCode::AccessDenied));
+/// assert!(!errors[0].code().unwrap().is_retryable());
+/// ```
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct GraphQLError {
+    pub message: String,
+    #[serde(default)]
+    pub locations: Vec<GraphQLErrorLocation>,
+    /// Path of the field that failed, made of field names and list indexes
+    #[serde(default)]
+    pub path: Vec<serde_json::Value>,
+    #[serde(default)]
+    pub extensions: Option<serde_json::Map<String, serde_json::Value>>,
+}
+
+impl GraphQLError {
+    /// Read the `errors` of a GraphQL response
+    ///
+    /// An error sent as a single message is read as an error without code.
+    pub fn from_response(json: &serde_json::Value) -> Vec<GraphQLError> {
+        match &json["errors"] {
+            serde_json::Value::Array(errors) => errors
+                .iter()
+                .map(|error| {
+                    serde_json::from_value(error.clone()).unwrap_or_else(|_| GraphQLError {
+                        message: error.to_string(),
+                        locations: Vec::new(),
+                        path: Vec::new(),
+                        extensions: None,
+                    })
+                })
+                .collect(),
+            serde_json::Value::String(message) => vec![GraphQLError {
+                message: message.clone(),
+                locations: Vec::new(),
+                path: Vec::new(),
+                extensions: None,
+            }],
+            _ => Vec::new(),
+        }
+    }
+
+    /// Get the code of the error, from `extensions.code`
+    pub fn code(&self) -> Option<GraphQLErrorCode> {
+        self.extensions
+            .as_ref()?
+            .get("code")?
+            .as_str()
+            .map(GraphQLErrorCode::from)
+    }
+}
+
+impl fmt::Display for GraphQLError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.code() {
+            Some(code) => write!(f, "{} ({})", self.message, code),
+            None => f.write_str(&self.message),
+        }
+    }
+}
+
+/// Join GraphQL errors for the error message
+pub(crate) fn format_graphql_errors(errors: &[GraphQLError]) -> String {
+    errors
+        .iter()
+        .map(GraphQLError::to_string)
+        .collect::<Vec<_>>()
+        .join("; ")
+}
@@ -0,0 +1,492 @@
+//! Inventory quantities, changed with the `inventorySetOnHandQuantities`, `inventoryAdjustQuantities`
+//! and `inventoryMoveQuantities` mutations
+//!
+//! Each change is recorded by Shopify in an [`InventoryAdjustmentGroup`], with a [`InventoryReason`]
+//! and an optional reference document URI identifying the change in the app, e.g.
+//! `gid://my-app/InventorySync/42`. Rejected changes fail with [`ShopifyAPIError::UserErrors`],
+//! whose codes can be read with [`InventoryErrorCode`].
+
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::json;
+
+use crate::{utils::ReadJsonTreeSteps, Gid, Shopify, ShopifyAPIError};
+
+const ADJUSTMENT_GROUP_FIELDS: &str = "id reason referenceDocumentUri \
+    changes { name delta quantityAfterChange item { id } location { id } }";
+
+/// Reason of an inventory change
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum InventoryReason {
+    Correction,
+    CycleCountAvailable,
+    Damaged,
+    MovementCanceled,
+    MovementCreated,
+    MovementReceived,
+    MovementUpdated,
+    Other,
+    Promotion,
+    QualityControl,
+    Received,
+    ReservationCreated,
+    ReservationDeleted,
+    ReservationUpdated,
+    Restock,
+    SafetyStock,
+    Shrinkage,
+}
+
+/// Name of an inventory quantity, e.g. `available` or `on_hand`
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum InventoryQuantityName {
+    Available,
+    Committed,
+    Damaged,
+    Incoming,
+    OnHand,
+    QualityControl,
+    Reserved,
+    SafetyStock,
+}
+
+/// Code of a user error of the inventory mutations
+///
+/// # Example
+/// ```
+/// use shopify_api::graphql::inventory::InventoryErrorCode;
+/// use shopify_api::graphql::UserError;
+///
+/// let error = UserError {
+///     field: Some(vec!["input".to_string(), "changes".to_string()]),
+///     message: "The item is not stocked at the location".to_string(),
+///     code: Some("ITEM_NOT_STOCKED_AT_LOCATION".to_string()),
+/// };
+/// assert_eq!(InventoryErrorCode::of(&error), Some(InventoryErrorCode::ItemNotStockedAtLocation));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum InventoryErrorCode {
+    InternalLedgerDocument,
+    InvalidAvailableDocument,
+    InvalidInventoryItem,
+    InvalidLedgerDocument,
+    InvalidLocation,
+    InvalidQuantityDocument,
+    InvalidQuantityName,
+    InvalidQuantityNegative,
+    InvalidQuantityTooHigh,
+    InvalidQuantityTooLow,
+    InvalidReason,
+    InvalidReferenceDocument,
+    ItemNotStockedAtLocation,
+    MaxOneLedgerDocument,
+    NonMutableInventoryItem,
+
+    /// Any other code
+    Other(String),
+}
+
+impl InventoryErrorCode {
+    /// Read the code of a user error, `None` if it has no code
+    pub fn of(error: &super::UserError) -> Option<InventoryErrorCode> {
+        error.code.as_deref().map(InventoryErrorCode::from)
+    }
+}
+
+impl From<&str> for InventoryErrorCode {
+    fn from(code: &str) -> Self {
+        match code {
+            "INTERNAL_LEDGER_DOCUMENT" => InventoryErrorCode::InternalLedgerDocument,
+            "INVALID_AVAILABLE_DOCUMENT" => InventoryErrorCode::InvalidAvailableDocument,
+            "INVALID_INVENTORY_ITEM" => InventoryErrorCode::InvalidInventoryItem,
+            "INVALID_LEDGER_DOCUMENT" => InventoryErrorCode::InvalidLedgerDocument,
+            "INVALID_LOCATION" => InventoryErrorCode::InvalidLocation,
+            "INVALID_QUANTITY_DOCUMENT" => InventoryErrorCode::InvalidQuantityDocument,
+            "INVALID_QUANTITY_NAME" => InventoryErrorCode::InvalidQuantityName,
+            "INVALID_QUANTITY_NEGATIVE" => InventoryErrorCode::InvalidQuantityNegative,
+            "INVALID_QUANTITY_TOO_HIGH" => InventoryErrorCode::InvalidQuantityTooHigh,
+            "INVALID_QUANTITY_TOO_LOW" => InventoryErrorCode::InvalidQuantityTooLow,
+            "INVALID_REASON" => InventoryErrorCode::InvalidReason,
+            "INVALID_REFERENCE_DOCUMENT" => InventoryErrorCode::InvalidReferenceDocument,
+            "ITEM_NOT_STOCKED_AT_LOCATION" => InventoryErrorCode::ItemNotStockedAtLocation,
+            "MAX_ONE_LEDGER_DOCUMENT" => InventoryErrorCode::MaxOneLedgerDocument,
+            "NON_MUTABLE_INVENTORY_ITEM" => InventoryErrorCode::NonMutableInventoryItem,
+            other => InventoryErrorCode::Other(other.to_string()),
+        }
+    }
+}
+
+/// Input of [`Shopify::inventory_set_on_hand_quantities`]
+///
+/// # Example
+/// ```
+/// use shopify_api::Gid;
+/// use shopify_api::graphql::inventory::{InventoryReason, InventorySetOnHandInput};
+///
+/// let input = InventorySetOnHandInput::new(InventoryReason::CycleCountAvailable)
+///     .reference_document_uri("gid://my-app/CycleCount/1")
+///     .quantity(&Gid::inventory_item(10), &Gid::location(1), 25);
+///
+/// assert_eq!(
+///     serde_json::to_value(&input).unwrap(),
+///     serde_json::json!({
+///         "reason": "cycle_count_available",
+///         "referenceDocumentUri": "gid://my-app/CycleCount/1",
+///         "setQuantities": [{
+///             "inventoryItemId": "gid://shopify/InventoryItem/10",
+///             "locationId": "gid://shopify/Location/1",
+///             "quantity": 25
+///         }]
+///     })
+/// );
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InventorySetOnHandInput {
+    reason: InventoryReason,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    reference_document_uri: Option<String>,
+    set_quantities: Vec<InventorySetQuantity>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct InventorySetQuantity {
+    inventory_item_id: Gid,
+    location_id: Gid,
+    quantity: i64,
+}
+
+impl InventorySetOnHandInput {
+    pub fn new(reason: InventoryReason) -> InventorySetOnHandInput {
+        InventorySetOnHandInput {
+            reason,
+            reference_document_uri: None,
+            set_quantities: Vec::new(),
+        }
+    }
+
+    /// Identify the change in the app, e.g. `gid://my-app/InventorySync/42`
+    pub fn reference_document_uri(mut self, uri: &str) -> Self {
+        self.reference_document_uri = Some(uri.to_string());
+        self
+    }
+
+    /// Set the on hand quantity of an item at a location
+    pub fn quantity(mut self, inventory_item: &Gid, location: &Gid, quantity: i64) -> Self {
+        self.set_quantities.push(InventorySetQuantity {
+            inventory_item_id: inventory_item.clone(),
+            location_id: location.clone(),
+            quantity,
+        });
+        self
+    }
+}
+
+/// Input of [`Shopify::inventory_adjust_quantities`]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InventoryAdjustInput {
+    reason: InventoryReason,
+    name: InventoryQuantityName,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    reference_document_uri: Option<String>,
+    changes: Vec<InventoryAdjustChange>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct InventoryAdjustChange {
+    inventory_item_id: Gid,
+    location_id: Gid,
+    delta: i64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    ledger_document_uri: Option<String>,
+}
+
+impl InventoryAdjustInput {
+    /// Adjust the quantities of the given name, e.g. [`InventoryQuantityName::Available`]
+    pub fn new(reason: InventoryReason, name: InventoryQuantityName) -> InventoryAdjustInput {
+        InventoryAdjustInput {
+            reason,
+            name,
+            reference_document_uri: None,
+            changes: Vec::new(),
+        }
+    }
+
+    /// Identify the change in the app, e.g. `gid://my-app/InventorySync/42`
+    pub fn reference_document_uri(mut self, uri: &str) -> Self {
+        self.reference_document_uri = Some(uri.to_string());
+        self
+    }
+
+    /// Add a delta to the quantity of an item at a location
+    pub fn change(mut self, inventory_item: &Gid, location: &Gid, delta: i64) -> Self {
+        self.changes.push(InventoryAdjustChange {
+            inventory_item_id: inventory_item.clone(),
+            location_id: location.clone(),
+            delta,
+            ledger_document_uri: None,
+        });
+        self
+    }
+
+    /// Add a delta with the URI of the ledger document, required for every quantity but `available`
+    pub fn ledger_change(
+        mut self,
+        inventory_item: &Gid,
+        location: &Gid,
+        delta: i64,
+        ledger_document_uri: &str,
+    ) -> Self {
+        self.changes.push(InventoryAdjustChange {
+            inventory_item_id: inventory_item.clone(),
+            location_id: location.clone(),
+            delta,
+            ledger_document_uri: Some(ledger_document_uri.to_string()),
+        });
+        self
+    }
+}
+
+/// Input of [`Shopify::inventory_move_quantities`]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InventoryMoveInput {
+    reason: InventoryReason,
+    reference_document_uri: String,
+    changes: Vec<InventoryMoveChange>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct InventoryMoveChange {
+    inventory_item_id: Gid,
+    quantity: i64,
+    from: InventoryMoveTerminal,
+    to: InventoryMoveTerminal,
+}
+
+/// Quantity a move takes units from or gives units to
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InventoryMoveTerminal {
+    location_id: Gid,
+    name: InventoryQuantityName,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    ledger_document_uri: Option<String>,
+}
+
+impl InventoryMoveTerminal {
+    pub fn new(location: &Gid, name: InventoryQuantityName) -> InventoryMoveTerminal {
+        InventoryMoveTerminal {
+            location_id: location.clone(),
+            name,
+            ledger_document_uri: None,
+        }
+    }
+
+    /// Set the URI of the ledger document, required for every quantity but `available`
+    pub fn ledger_document_uri(mut self, uri: &str) -> Self {
+        self.ledger_document_uri = Some(uri.to_string());
+        self
+    }
+}
+
+impl InventoryMoveInput {
+    /// Move quantities, identified in the app by the reference document URI
+    pub fn new(reason: InventoryReason, reference_document_uri: &str) -> InventoryMoveInput {
+        InventoryMoveInput {
+            reason,
+            reference_document_uri: reference_document_uri.to_string(),
+            changes: Vec::new(),
+        }
+    }
+
+    /// Move units of an item from a quantity to another
+    pub fn change(
+        mut self,
+        inventory_item: &Gid,
+        quantity: i64,
+        from: InventoryMoveTerminal,
+        to: InventoryMoveTerminal,
+    ) -> Self {
+        self.changes.push(InventoryMoveChange {
+            inventory_item_id: inventory_item.clone(),
+            quantity,
+            from,
+            to,
+        });
+        self
+    }
+}
+
+/// Changes applied by an inventory mutation
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InventoryAdjustmentGroup {
+    pub id: Gid,
+    pub reason: String,
+    pub reference_document_uri: Option<String>,
+    pub changes: Vec<InventoryChange>,
+}
+
+/// Change of a quantity of an item at a location
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InventoryChange {
+    /// Name of the quantity, e.g. `available`
+    pub name: String,
+    pub delta: i64,
+    pub quantity_after_change: Option<i64>,
+    #[serde(rename = "item", deserialize_with = "node_id")]
+    pub inventory_item_id: Gid,
+    #[serde(rename = "location", deserialize_with = "node_id")]
+    pub location_id: Gid,
+}
+
+impl Shopify {
+    /// Set on hand quantities with `inventorySetOnHandQuantities`
+    ///
+    /// Returns `None` when no quantity changed.
+    /// # Example
+    /// ```no_run
+    /// use shopify_api::*;
+    /// use shopify_api::graphql::inventory::{InventoryErrorCode, InventoryReason, InventorySetOnHandInput};
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let shopify = Shopify::new("myshop", "myapikey", ShopifyAPIVersion::V2026_10, None);
+    ///     let input = InventorySetOnHandInput::new(InventoryReason::Correction)
+    ///         .reference_document_uri("gid://my-app/InventorySync/42")
+    ///         .quantity(&Gid::inventory_item(10), &Gid::location(1), 25);
+    ///
+    ///     match shopify.inventory_set_on_hand_quantities(&input).await {
+    ///         Err(ShopifyAPIError::UserErrors(errors)) => {
+    ///             for error in errors {
+    ///                 println!("{:?}: {}", InventoryErrorCode::of(&error), error.message);
+    ///             }
+    ///         }
+    ///         result => println!("{:?}", result),
+    ///     }
+    /// }
+    /// ```
+    pub async fn inventory_set_on_hand_quantities(
+        &self,
+        input: &InventorySetOnHandInput,
+    ) -> Result<Option<InventoryAdjustmentGroup>, ShopifyAPIError> {
+        self.inventory_mutation(
+            "inventorySetOnHandQuantities",
+            "InventorySetOnHandQuantitiesInput",
+            json!({ "input": input }),
+        )
+        .await
+    }
+
+    /// Add deltas to quantities with `inventoryAdjustQuantities`
+    ///
+    /// # Example
+    /// ```no_run
+    /// use shopify_api::*;
+    /// use shopify_api::graphql::inventory::{InventoryAdjustInput, InventoryQuantityName, InventoryReason};
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let shopify = Shopify::new("myshop", "myapikey", ShopifyAPIVersion::V2026_10, None);
+    ///     let input = InventoryAdjustInput::new(InventoryReason::Received, InventoryQuantityName::Available)
+    ///         .change(&Gid::inventory_item(10), &Gid::location(1), 5);
+    ///
+    ///     let group = shopify.inventory_adjust_quantities(&input).await.unwrap();
+    ///     println!("{:?}", group.map(|group| group.changes));
+    /// }
+    /// ```
+    pub async fn inventory_adjust_quantities(
+        &self,
+        input: &InventoryAdjustInput,
+    ) -> Result<Option<InventoryAdjustmentGroup>, ShopifyAPIError> {
+        self.inventory_mutation(
+            "inventoryAdjustQuantities",
+            "InventoryAdjustQuantitiesInput",
+            json!({ "input": input }),
+        )
+        .await
+    }
+
+    /// Move units between quantities or locations with `inventoryMoveQuantities`
+    ///
+    /// # Example
+    /// ```no_run
+    /// use shopify_api::*;
+    /// use shopify_api::graphql::inventory::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let shopify = Shopify::new("myshop", "myapikey", ShopifyAPIVersion::V2026_10, None);
+    ///     let location = Gid::location(1);
+    ///     let input = InventoryMoveInput::new(InventoryReason::Damaged, "gid://my-app/Inspection/7").change(
+    ///         &Gid::inventory_item(10),
+    ///         2,
+    ///         InventoryMoveTerminal::new(&location, InventoryQuantityName::Available),
+    ///         InventoryMoveTerminal::new(&location, InventoryQuantityName::Damaged)
+    ///             .ledger_document_uri("gid://my-app/Inspection/7"),
+    ///     );
+    ///
+    ///     shopify.inventory_move_quantities(&input).await.unwrap();
+    /// }
+    /// ```
+    pub async fn inventory_move_quantities(
+        &self,
+        input: &InventoryMoveInput,
+    ) -> Result<Option<InventoryAdjustmentGroup>, ShopifyAPIError> {
+        self.inventory_mutation(
+            "inventoryMoveQuantities",
+            "InventoryMoveQuantitiesInput",
+            json!({ "input": input }),
+        )
+        .await
+    }
+
+    /// Run an inventory mutation taking an `input` and returning an `inventoryAdjustmentGroup`
+    async fn inventory_mutation(
+        &self,
+        mutation_name: &str,
+        input_type: &str,
+        variables: serde_json::Value,
+    ) -> Result<Option<InventoryAdjustmentGroup>, ShopifyAPIError> {
+        let mutation = format!(
+            r#"
+            mutation($input: {input_type}!) {{
+                {mutation_name}(input: $input) {{
+                    inventoryAdjustmentGroup {{ {ADJUSTMENT_GROUP_FIELDS} }}
+                    userErrors {{ field message code }}
+                }}
+            }}"#
+        );
+
+        self.graphql_mutation(
+            &mutation,
+            &variables,
+            &vec![
+                ReadJsonTreeSteps::Key("data"),
+                ReadJsonTreeSteps::Key(mutation_name),
+                ReadJsonTreeSteps::Key("inventoryAdjustmentGroup"),
+            ],
+        )
+        .await
+    }
+}
+
+/// Read the `id` of a node selected as `{ id }`
+fn node_id<'de, D>(deserializer: D) -> Result<Gid, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    struct Node {
+        id: Gid,
+    }
+
+    Ok(Node::deserialize(deserializer)?.id)
+}
@@ -0,0 +1,231 @@
+//! Metafields, set in batches with the `metafieldsSet` mutation
+//!
+//! [`Shopify::metafields_set`] creates or updates up to [`METAFIELDS_SET_LIMIT`] metafields at once.
+//! The types are checked before sending the mutation, and the user errors are returned with the
+//! index of the input they refer to.
+
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::json;
+
+use crate::{utils::ReadJsonTreeSteps, Shopify, ShopifyAPIError};
+
+/// Maximum number of metafields of a `metafieldsSet` mutation
+pub const METAFIELDS_SET_LIMIT: usize = 25;
+
+/// Types that can be used in a list, as `list.<type>`
+const LIST_TYPES: [&str; 24] = [
+    "article_reference",
+    "collection_reference",
+    "color",
+    "company_reference",
+    "customer_reference",
+    "date",
+    "date_time",
+    "dimension",
+    "file_reference",
+    "id",
+    "link",
+    "metaobject_reference",
+    "mixed_reference",
+    "number_decimal",
+    "number_integer",
+    "page_reference",
+    "product_reference",
+    "product_taxonomy_value_reference",
+    "rating",
+    "single_line_text_field",
+    "url",
+    "variant_reference",
+    "volume",
+    "weight",
+];
+
+/// Types that cannot be used in a list
+const SINGLE_TYPES: [&str; 6] = [
+    "boolean",
+    "json",
+    "money",
+    "multi_line_text_field",
+    "order_reference",
+    "rich_text_field",
+];
+
+const METAFIELD_FIELDS: &str = "id namespace key value type";
+
+/// Check that a metafield type is known by Shopify, e.g. `single_line_text_field` or `list.product_reference`
+///
+/// # Example
+/// ```
+/// use shopify_api::graphql::metafields::is_valid_metafield_type;
+///
+/// assert!(is_valid_metafield_type("json"));
+/// assert!(is_valid_metafield_type("list.single_line_text_field"));
+/// assert!(!is_valid_metafield_type("list.json"));
+/// assert!(!is_valid_metafield_type("string"));
+/// ```
+pub fn is_valid_metafield_type(metafield_type: &str) -> bool {
+    match metafield_type.strip_prefix("list.") {
+        Some(item_type) => LIST_TYPES.contains(&item_type),
+        None => LIST_TYPES.contains(&metafield_type) || SINGLE_TYPES.contains(&metafield_type),
+    }
+}
+
+/// Metafield to create or update with [`Shopify::metafields_set`]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MetafieldsSetInput {
+    /// GID of the resource owning the metafield, e.g. `gid://shopify/Product/1`
+    pub owner_id: String,
+    /// Namespace of the metafield, the app reserved namespace when not set
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub namespace: Option<String>,
+    pub key: String,
+    #[serde(rename = "type")]
+    pub metafield_type: String,
+    /// Value of the metafield, always a string, e.g. `"42"` or a serialized JSON
+    pub value: String,
+}
+
+impl MetafieldsSetInput {
+    pub fn new(
+        owner_id: &str,
+        namespace: &str,
+        key: &str,
+        metafield_type: &str,
+        value: &str,
+    ) -> MetafieldsSetInput {
+        MetafieldsSetInput {
+            owner_id: owner_id.to_string(),
+            namespace: Some(namespace.to_string()),
+            key: key.to_string(),
+            metafield_type: metafield_type.to_string(),
+            value: value.to_string(),
+        }
+    }
+}
+
+/// Metafield returned by `metafieldsSet`
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Metafield {
+    pub id: String,
+    pub namespace: String,
+    pub key: String,
+    pub value: String,
+    #[serde(rename = "type")]
+    pub metafield_type: String,
+}
+
+/// User error of `metafieldsSet`
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MetafieldsSetUserError {
+    /// Path of the invalid input field, e.g. `["metafields", "0", "value"]`
+    #[serde(default)]
+    pub field: Option<Vec<String>>,
+    pub message: String,
+    #[serde(default)]
+    pub code: Option<String>,
+    /// Index of the invalid element of a list value
+    #[serde(default)]
+    pub element_index: Option<usize>,
+}
+
+impl MetafieldsSetUserError {
+    /// Get the index of the input the error refers to
+    pub fn input_index(&self) -> Option<usize> {
+        match self.field.as_deref() {
+            Some([metafields, index, ..]) if metafields == "metafields" => index.parse().ok(),
+            _ => None,
+        }
+    }
+}
+
+/// Result of [`Shopify::metafields_set`]
+///
+/// Shopify sets no metafield when one of the inputs is invalid, so `metafields` is empty
+/// when `user_errors` is not.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MetafieldsSet {
+    #[serde(default, deserialize_with = "null_as_empty")]
+    pub metafields: Vec<Metafield>,
+    #[serde(default)]
+    pub user_errors: Vec<MetafieldsSetUserError>,
+}
+
+impl Shopify {
+    /// Create or update metafields with `metafieldsSet`
+    ///
+    /// This fails with [`ShopifyAPIError::InvalidInput`] before sending the mutation if there are more
+    /// than [`METAFIELDS_SET_LIMIT`] inputs or a type is unknown, see [`is_valid_metafield_type`].
+    /// The user errors are returned in the result, with [`MetafieldsSetUserError::input_index`]
+    /// giving the input they refer to.
+    /// # Example
+    /// ```no_run
+    /// use shopify_api::*;
+    /// use shopify_api::graphql::metafields::MetafieldsSetInput;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let shopify = Shopify::new("myshop", "myapikey", ShopifyAPIVersion::V2026_10, None);
+    ///     let inputs = vec![
+    ///         MetafieldsSetInput::new("gid://shopify/Product/1", "custom", "material", "single_line_text_field", "Wool"),
+    ///         MetafieldsSetInput::new("gid://shopify/Product/1", "custom", "specs", "json", r#"{"weight":2}"#),
+    ///     ];
+    ///
+    ///     let result = shopify.metafields_set(&inputs).await.unwrap();
+    ///     for error in result.user_errors {
+    ///         println!("Input {:?}: {}", error.input_index(), error.message);
+    ///     }
+    /// }
+    /// ```
+    pub async fn metafields_set(
+        &self,
+        metafields: &[MetafieldsSetInput],
+    ) -> Result<MetafieldsSet, ShopifyAPIError> {
+        if metafields.len() > METAFIELDS_SET_LIMIT {
+            return Err(ShopifyAPIError::InvalidInput(format!(
+                "{} metafields, at most {} can be set at once",
+                metafields.len(),
+                METAFIELDS_SET_LIMIT
+            )));
+        }
+        if let Some(metafield) = metafields
+            .iter()
+            .find(|metafield| !is_valid_metafield_type(&metafield.metafield_type))
+        {
+            return Err(ShopifyAPIError::InvalidInput(format!(
+                "unknown metafield type `{}` for `{}`",
+                metafield.metafield_type, metafield.key
+            )));
+        }
+
+        let mutation = format!(
+            r#"
+            mutation($metafields: [MetafieldsSetInput!]!) {{
+                metafieldsSet(metafields: $metafields) {{
+                    metafields {{ {METAFIELD_FIELDS} }}
+                    userErrors {{ field message code elementIndex }}
+                }}
+            }}"#
+        );
+
+        self.graphql_query(
+            &mutation,
+            &json!({ "metafields": metafields }),
+            &vec![
+                ReadJsonTreeSteps::Key("data"),
+                ReadJsonTreeSteps::Key("metafieldsSet"),
+            ],
+        )
+        .await
+    }
+}
+
+/// Read `null` as an empty list, as returned by `metafieldsSet` when an input is invalid
+fn null_as_empty<'de, D>(deserializer: D) -> Result<Vec<Metafield>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Ok(Option::deserialize(deserializer)?.unwrap_or_default())
+}
@@ -1,75 +1,235 @@
+pub mod app_installation;
+mod batch;
+pub mod billing;
+pub mod bulk;
 mod bulk_query;
+mod cost;
+pub mod discounts;
+mod errors;
+pub mod inventory;
+pub mod metafields;
+mod node;
+mod pagination;
+mod query;
+mod registry;
+mod response;
+#[cfg(feature = "schema-validation")]
+mod schema;
+#[cfg(feature = "graphql-client")]
+mod typed;
+pub mod uploads;
+mod user_errors;
+pub mod variants;
+pub mod web_pixels;
+pub mod webhooks;
+
+pub use batch::Batch;
+pub use cost::estimate_query_cost;
+pub(crate) use errors::format_graphql_errors;
+pub use errors::{GraphQLError, GraphQLErrorCode, GraphQLErrorLocation};
+pub use node::GraphQLNode;
+pub use query::Query;
+pub(crate) use registry::OperationRegistry;
+pub use response::{Extensions, GraphQLResponse};
+#[cfg(feature = "schema-validation")]
+pub use schema::Schema;
+pub(crate) use user_errors::format_user_errors;
+pub use user_errors::{check_user_errors, UserError};
+
+use std::time::Duration;
+
 use crate::{
+    middleware::ShopifyResponse,
+    rate_limit::ThrottleReason,
+    retry,
     utils::{self, read_json_tree, ReadJsonTreeSteps},
-    Shopify, ShopifyAPIError,
+    RequestOptions, ResponseMetadata, Shopify, ShopifyAPIError,
 };
-use reqwest::Response;
 
-async fn shopify_graphql_query<VariablesType, ReturnType>(
-    (shopify, graphql_query, variables, json_finder): &(
+async fn shopify_graphql_query<ReturnType>(
+    (shopify, url, req_body, output, options): &(
         &Shopify,
-        &str,
-        &VariablesType,
-        &Vec<ReadJsonTreeSteps<'_>>,
+        String,
+        String,
+        GraphQLOutput<'_, '_>,
+        &RequestOptions,
     ),
-) -> Result<ReturnType, ShopifyAPIError>
+) -> Result<(ReturnType, ResponseMetadata), ShopifyAPIError>
 where
-    VariablesType: serde::Serialize,
     ReturnType: serde::de::DeserializeOwned,
 {
-    // Prepare the client
-    let client = reqwest::Client::new();
-    let mut headers = reqwest::header::HeaderMap::new();
-    headers.insert("Content-Type", "application/json".parse().unwrap());
-    headers.insert("X-Shopify-Access-Token", shopify.api_key.parse().unwrap());
-    let req_body: &serde_json::Value = &serde_json::json!({
-        "query": graphql_query,
-        "variables": variables
-    });
+    let mut delay = shopify.rate_limit.reserve_graphql();
+    if let Some(delay) = delay {
+        shopify.throttled(ThrottleReason::GraphQLBudget, delay);
+    }
+    let mut throttled_waits = 0;
 
-    // Connection Response
-    let res: Response = client
-        .post(shopify.get_query_url())
-        .headers(headers)
-        .body(req_body.to_string())
-        .send()
-        .await?;
-
-    // Connection data
-    let body = res.text().await;
-    if body.is_err() {
-        return Err(ShopifyAPIError::ResponseBroken);
+    loop {
+        if let Some(delay) = delay {
+            utils::sleep(delay).await;
+        }
+
+        let req = shopify
+            .request(reqwest::Method::POST, url, options)?
+            .body(req_body.clone());
+
+        let response = shopify.send(req, options).await?;
+        match parse_graphql_response(shopify, url, &response, req_body, *output) {
+            Err(ShopifyAPIError::Throttled)
+                if throttled_waits < shopify.graphql_throttle_retry.get_max_waits() =>
+            {
+                throttled_waits += 1;
+                delay = Some(throttled_delay(shopify, throttled_waits));
+            }
+            json => return Ok((json?, response.metadata())),
+        }
     }
-    let body = body.unwrap();
+}
 
+#[cfg(feature = "blocking")]
+fn shopify_graphql_query_blocking<ReturnType>(
+    (shopify, url, req_body, output, options): &(
+        &Shopify,
+        String,
+        String,
+        GraphQLOutput<'_, '_>,
+        &RequestOptions,
+    ),
+) -> Result<(ReturnType, ResponseMetadata), ShopifyAPIError>
+where
+    ReturnType: serde::de::DeserializeOwned,
+{
+    let mut delay = shopify.rate_limit.reserve_graphql();
+    if let Some(delay) = delay {
+        shopify.throttled(ThrottleReason::GraphQLBudget, delay);
+    }
+    let mut throttled_waits = 0;
+
+    loop {
+        if let Some(delay) = delay {
+            std::thread::sleep(delay);
+        }
+
+        let req = shopify
+            .request(reqwest::Method::POST, url, options)?
+            .body(req_body.clone());
+
+        let response = shopify.send_blocking(req, options)?;
+        match parse_graphql_response(shopify, url, &response, req_body, *output) {
+            Err(ShopifyAPIError::Throttled)
+                if throttled_waits < shopify.graphql_throttle_retry.get_max_waits() =>
+            {
+                throttled_waits += 1;
+                delay = Some(throttled_delay(shopify, throttled_waits));
+            }
+            json => return Ok((json?, response.metadata())),
+        }
+    }
+}
+
+/// Part of a GraphQL response returned by a query
+#[derive(Clone, Copy)]
+enum GraphQLOutput<'a, 'b> {
+    /// Data found at a path, failing on any error
+    Data(&'a Vec<ReadJsonTreeSteps<'b>>),
+    /// Whole response as a [`GraphQLResponse`], with its errors
+    Response,
+}
+
+/// Get the delay before sending a THROTTLED query again for the given time, with the
+/// [`crate::retry::GraphQLThrottleRetry`] of the client
+///
+/// The points to restore are computed from the `requestedQueryCost` and the `throttleStatus` of the
+/// response. When the response did not report its cost, the time needed to restore the points of
+/// the most expensive query is used if the plan of the shop is known.
+fn throttled_delay(shopify: &Shopify, wait: u32) -> Duration {
+    let restore_delay = shopify.rate_limit.reserve_graphql().or_else(|| {
+        shopify.plan.map(|plan| {
+            Duration::from_secs_f64(plan.graphql_max_query_cost() / plan.graphql_restore_rate())
+        })
+    });
+    let delay = shopify.graphql_throttle_retry.delay(wait, restore_delay);
+    shopify.retrying(&ShopifyAPIError::Throttled, delay);
+
+    delay
+}
+
+fn parse_graphql_response<ReturnType>(
+    shopify: &Shopify,
+    url: &str,
+    response: &ShopifyResponse,
+    req_body: &str,
+    output: GraphQLOutput<'_, '_>,
+) -> Result<ReturnType, ShopifyAPIError>
+where
+    ReturnType: serde::de::DeserializeOwned,
+{
     log::debug!(
-        "shopify (url: {}) response: {body} \n With body: {}",
-        shopify.get_query_url(),
-        req_body.to_string()
+        "shopify (url: {}) response: {} \n With body: {}",
+        url,
+        response.body,
+        req_body
     );
 
     let json: serde_json::Value =
-        serde_json::from_str(&body).map_err(ShopifyAPIError::JsonParseError)?;
+        serde_json::from_str(&response.body).map_err(ShopifyAPIError::JsonParseError)?;
+
+    shopify
+        .rate_limit
+        .record_graphql_cost(&json["extensions"]["cost"]);
+
+    let errors = GraphQLError::from_response(&json);
+    // A THROTTLED query is sent again once the bucket is refilled
+    if errors
+        .iter()
+        .any(|error| error.code() == Some(GraphQLErrorCode::Throttled))
+    {
+        shopify.stats.record_throttled();
+        return Err(ShopifyAPIError::Throttled);
+    }
+
+    match output {
+        GraphQLOutput::Data(json_finder) => {
+            if !errors.is_empty() {
+                return Err(ShopifyAPIError::GraphQL {
+                    errors,
+                    request_id: response.request_id(),
+                });
+            }
 
-    // Check if the query was THROTTLED
-    if let Some(error) = json["errors"]["01"]["extensions"]["code"].as_str() {
-        if error == "THROTTLED" {
-            return Err(ShopifyAPIError::Throttled);
+            deserialize_json_tree(&json, json_finder)
         }
+        GraphQLOutput::Response => deserialize_json_tree(
+            &serde_json::json!({
+                "data": json["data"],
+                "errors": errors,
+                "extensions": json["extensions"],
+            }),
+            &vec![],
+        ),
     }
+}
 
-    let json = match read_json_tree(&json, json_finder) {
+/// Deserialize the part of the response found at `json_finder`
+fn deserialize_json_tree<ReturnType>(
+    json: &serde_json::Value,
+    json_finder: &Vec<ReadJsonTreeSteps<'_>>,
+) -> Result<ReturnType, ShopifyAPIError>
+where
+    ReturnType: serde::de::DeserializeOwned,
+{
+    let json = match read_json_tree(json, json_finder) {
         Ok(v) => v,
-        Err(_) => {
-            return Err(ShopifyAPIError::NotWantedJsonFormat(json.to_string()));
+        Err(error) => {
+            return Err(ShopifyAPIError::NotWantedJsonFormat(error.to_string()));
         }
     };
 
     let end_json: ReturnType = match serde_json::from_value(json.to_owned()) {
         Ok(v) => v,
-        Err(_) => {
+        Err(error) => {
             // The shopify response is not wanted json
-            return Err(ShopifyAPIError::NotWantedJsonFormat(json.to_string()));
+            return Err(ShopifyAPIError::NotWantedJsonFormat(error.to_string()));
         }
     };
 
@@ -78,6 +238,10 @@ where
 
 impl Shopify {
     /// Query graphql shopify api
+    ///
+    /// `variables` can be any serializable value, e.g. a struct or `serde_json::json!`, and is sent
+    /// in the `{"query": ..., "variables": ...}` body. Pass values through variables rather than
+    /// formatting them into the query text, which would let them change the query.
     /// # Example
     /// ```
     /// use shopify_api::*;
@@ -118,14 +282,269 @@ impl Shopify {
         ReturnType: serde::de::DeserializeOwned,
         VariablesType: serde::Serialize,
     {
-        let args = (self, graphql_query, variables, json_finder);
-        let response_json = utils::retry_async(
-            10,
-            shopify_graphql_query::<VariablesType, ReturnType>,
+        self.graphql_query_with_options(
+            graphql_query,
+            variables,
+            json_finder,
+            &RequestOptions::default(),
+        )
+        .await
+    }
+
+    /// Query graphql shopify api with options applied to this request only
+    /// # Example
+    /// ```no_run
+    /// use std::time::Duration;
+    /// use shopify_api::*;
+    /// use shopify_api::utils::ReadJsonTreeSteps;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let shopify = Shopify::new("myshop", "myapikey", ShopifyAPIVersion::V2023_10, None);
+    ///     let options = RequestOptions::new().timeout(Duration::from_secs(5));
+    ///     let json_finder = vec![ReadJsonTreeSteps::Key("data"), ReadJsonTreeSteps::Key("shop")];
+    ///
+    ///     let shop: serde_json::Value = shopify
+    ///         .graphql_query_with_options("{ shop { name } }", &serde_json::json!({}), &json_finder, &options)
+    ///         .await
+    ///         .unwrap();
+    /// }
+    /// ```
+    pub async fn graphql_query_with_options<ReturnType, VariablesType>(
+        &self,
+        graphql_query: &str,
+        variables: &VariablesType,
+        json_finder: &Vec<ReadJsonTreeSteps<'_>>,
+        options: &RequestOptions,
+    ) -> Result<ReturnType, ShopifyAPIError>
+    where
+        ReturnType: serde::de::DeserializeOwned,
+        VariablesType: serde::Serialize,
+    {
+        let (response_json, _) = self
+            .graphql_query_with_metadata(graphql_query, variables, json_finder, options)
+            .await?;
+
+        Ok(response_json)
+    }
+
+    /// Query graphql shopify api, returning the metadata of the response with the data
+    /// # Example
+    /// ```no_run
+    /// use shopify_api::*;
+    /// use shopify_api::utils::ReadJsonTreeSteps;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let shopify = Shopify::new("myshop", "myapikey", ShopifyAPIVersion::V2026_10, None);
+    ///     let json_finder = vec![ReadJsonTreeSteps::Key("data"), ReadJsonTreeSteps::Key("shop")];
+    ///
+    ///     let (shop, metadata): (serde_json::Value, ResponseMetadata) = shopify
+    ///         .graphql_query_with_metadata("{ shop { name } }", &serde_json::json!({}), &json_finder, &RequestOptions::new())
+    ///         .await
+    ///         .unwrap();
+    ///
+    ///     println!("Request id: {:?}", metadata.request_id);
+    /// }
+    /// ```
+    pub async fn graphql_query_with_metadata<ReturnType, VariablesType>(
+        &self,
+        graphql_query: &str,
+        variables: &VariablesType,
+        json_finder: &Vec<ReadJsonTreeSteps<'_>>,
+        options: &RequestOptions,
+    ) -> Result<(ReturnType, ResponseMetadata), ShopifyAPIError>
+    where
+        ReturnType: serde::de::DeserializeOwned,
+        VariablesType: serde::Serialize,
+    {
+        let req_body = self.graphql_body(graphql_query, variables)?;
+
+        self.send_graphql(req_body, GraphQLOutput::Data(json_finder), options)
+            .await
+    }
+
+    /// Build the body of a GraphQL request, adding the registered fragments spread by the query
+    ///
+    /// With the `schema-validation` feature, the query is validated against the schema of the client.
+    fn graphql_body<VariablesType>(
+        &self,
+        graphql_query: &str,
+        variables: &VariablesType,
+    ) -> Result<String, ShopifyAPIError>
+    where
+        VariablesType: serde::Serialize,
+    {
+        let graphql_query = self.operations.with_fragments(graphql_query);
+        #[cfg(feature = "schema-validation")]
+        if let Some(schema) = &self.schema {
+            schema.validate(&graphql_query)?;
+        }
+
+        Ok(serde_json::json!({
+            "query": graphql_query,
+            "variables": variables
+        })
+        .to_string())
+    }
+
+    /// Send a GraphQL request body through the retry policy, reading the given part of the response
+    async fn send_graphql<ReturnType>(
+        &self,
+        req_body: String,
+        output: GraphQLOutput<'_, '_>,
+        options: &RequestOptions,
+    ) -> Result<(ReturnType, ResponseMetadata), ShopifyAPIError>
+    where
+        ReturnType: serde::de::DeserializeOwned,
+    {
+        let args = (self, self.graphql_url(options), req_body, output, options);
+
+        retry::retry(
+            self.retry_policy(options),
+            options.get_cancellation_token(),
+            &|error, delay| self.retrying(error, delay),
+            shopify_graphql_query::<ReturnType>,
+            &args,
+        )
+        .await
+        .map_err(|failed| self.failed(failed, &args.1))
+    }
+
+    /// Query graphql shopify api without an async runtime
+    ///
+    /// This is the blocking counterpart of [`Shopify::graphql_query_with_options`], available with the `blocking` feature.
+    /// Middlewares are run on the calling thread, so they must not depend on an async runtime.
+    /// # Example
+    /// ```no_run
+    /// use shopify_api::*;
+    /// use shopify_api::utils::ReadJsonTreeSteps;
+    ///
+    /// let shopify = Shopify::new("myshop", "myapikey", ShopifyAPIVersion::V2023_10, None);
+    /// let json_finder = vec![ReadJsonTreeSteps::Key("data"), ReadJsonTreeSteps::Key("shop")];
+    /// let shop: serde_json::Value = shopify
+    ///     .graphql_query_blocking("{ shop { name } }", &serde_json::json!({}), &json_finder, &RequestOptions::new())
+    ///     .unwrap();
+    /// ```
+    #[cfg(feature = "blocking")]
+    pub fn graphql_query_blocking<ReturnType, VariablesType>(
+        &self,
+        graphql_query: &str,
+        variables: &VariablesType,
+        json_finder: &Vec<ReadJsonTreeSteps<'_>>,
+        options: &RequestOptions,
+    ) -> Result<ReturnType, ShopifyAPIError>
+    where
+        ReturnType: serde::de::DeserializeOwned,
+        VariablesType: serde::Serialize,
+    {
+        let req_body = self.graphql_body(graphql_query, variables)?;
+        let args = (
+            self,
+            self.graphql_url(options),
+            req_body,
+            GraphQLOutput::Data(json_finder),
+            options,
+        );
+
+        let (response_json, _) = retry::retry_blocking(
+            self.retry_policy(options),
+            options.get_cancellation_token(),
+            &|error, delay| self.retrying(error, delay),
+            shopify_graphql_query_blocking::<ReturnType>,
             &args,
         )
-        .await?;
+        .map_err(|failed| self.failed(failed, &args.1))?;
 
         Ok(response_json)
     }
+
+    /// Run a graphql mutation, failing with [`ShopifyAPIError::UserErrors`] if it returned `userErrors`
+    /// # Example
+    /// ```no_run
+    /// use shopify_api::*;
+    /// use shopify_api::utils::ReadJsonTreeSteps;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let shopify = Shopify::new("myshop", "myapikey", ShopifyAPIVersion::V2026_10, None);
+    ///     let mutation = r#"
+    ///         mutation($input: ProductInput!) {
+    ///             productCreate(input: $input) {
+    ///                 product { id }
+    ///                 userErrors { field message }
+    ///             }
+    ///         }
+    ///     "#;
+    ///     let variables = serde_json::json!({ "input": { "title": "" } });
+    ///     let json_finder = vec![ReadJsonTreeSteps::Key("data"), ReadJsonTreeSteps::Key("productCreate"), ReadJsonTreeSteps::Key("product")];
+    ///
+    ///     match shopify.graphql_mutation::<serde_json::Value, _>(mutation, &variables, &json_finder).await {
+    ///         Err(ShopifyAPIError::UserErrors(errors)) => println!("Invalid product: {:?}", errors),
+    ///         result => println!("{:?}", result),
+    ///     }
+    /// }
+    /// ```
+    pub async fn graphql_mutation<ReturnType, VariablesType>(
+        &self,
+        graphql_query: &str,
+        variables: &VariablesType,
+        json_finder: &Vec<ReadJsonTreeSteps<'_>>,
+    ) -> Result<ReturnType, ShopifyAPIError>
+    where
+        ReturnType: serde::de::DeserializeOwned,
+        VariablesType: serde::Serialize,
+    {
+        self.graphql_mutation_with_options(
+            graphql_query,
+            variables,
+            json_finder,
+            &RequestOptions::default(),
+        )
+        .await
+    }
+
+    /// Run a graphql mutation with options applied to this request only
+    ///
+    /// See [`Shopify::graphql_mutation`].
+    pub async fn graphql_mutation_with_options<ReturnType, VariablesType>(
+        &self,
+        graphql_query: &str,
+        variables: &VariablesType,
+        json_finder: &Vec<ReadJsonTreeSteps<'_>>,
+        options: &RequestOptions,
+    ) -> Result<ReturnType, ShopifyAPIError>
+    where
+        ReturnType: serde::de::DeserializeOwned,
+        VariablesType: serde::Serialize,
+    {
+        let json: serde_json::Value = self
+            .graphql_query_with_options(graphql_query, variables, &vec![], options)
+            .await?;
+        check_user_errors(&json["data"])?;
+
+        deserialize_json_tree(&json, json_finder)
+    }
+
+    /// Run a graphql mutation without an async runtime
+    ///
+    /// See [`Shopify::graphql_mutation`] and [`Shopify::graphql_query_blocking`].
+    #[cfg(feature = "blocking")]
+    pub fn graphql_mutation_blocking<ReturnType, VariablesType>(
+        &self,
+        graphql_query: &str,
+        variables: &VariablesType,
+        json_finder: &Vec<ReadJsonTreeSteps<'_>>,
+        options: &RequestOptions,
+    ) -> Result<ReturnType, ShopifyAPIError>
+    where
+        ReturnType: serde::de::DeserializeOwned,
+        VariablesType: serde::Serialize,
+    {
+        let json: serde_json::Value =
+            self.graphql_query_blocking(graphql_query, variables, &vec![], options)?;
+        check_user_errors(&json["data"])?;
+
+        deserialize_json_tree(&json, json_finder)
+    }
 }
@@ -0,0 +1,133 @@
+use serde::de::DeserializeOwned;
+use serde_json::json;
+
+use crate::{utils::ReadJsonTreeSteps, Gid, Shopify, ShopifyAPIError};
+
+/// Maximum number of IDs of a `nodes` query
+const NODES_LIMIT: usize = 250;
+
+/// Resource that can be fetched by its ID with [`Shopify::node`] and [`Shopify::nodes`]
+///
+/// # Example
+/// ```
+/// use serde::Deserialize;
+/// use shopify_api::graphql::GraphQLNode;
+///
+/// #[derive(Deserialize)]
+/// struct Product {
+///     id: String,
+///     title: String,
+/// }
+///
+/// impl GraphQLNode for Product {
+///     const TYPE_NAME: &'static str = "Product";
+///     const FIELDS: &'static str = "id title";
+/// }
+/// ```
+pub trait GraphQLNode: DeserializeOwned {
+    /// Name of the GraphQL type, used in the inline fragment, e.g. `Product`
+    const TYPE_NAME: &'static str;
+    /// Fields selected on the node, e.g. `id title handle`
+    const FIELDS: &'static str;
+}
+
+impl Shopify {
+    /// Get a resource by its ID with the `node` field, `None` if it does not exist
+    ///
+    /// # Example
+    /// ```no_run
+    /// use serde::Deserialize;
+    /// use shopify_api::*;
+    /// use shopify_api::graphql::GraphQLNode;
+    ///
+    /// #[derive(Deserialize)]
+    /// struct Product {
+    ///     title: String,
+    /// }
+    ///
+    /// impl GraphQLNode for Product {
+    ///     const TYPE_NAME: &'static str = "Product";
+    ///     const FIELDS: &'static str = "title";
+    /// }
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let shopify = Shopify::new("myshop", "myapikey", ShopifyAPIVersion::V2026_10, None);
+    ///     let product = shopify.node::<Product>(&Gid::product(123)).await.unwrap();
+    ///     println!("{:?}", product.map(|product| product.title));
+    /// }
+    /// ```
+    pub async fn node<NodeType>(&self, id: &Gid) -> Result<Option<NodeType>, ShopifyAPIError>
+    where
+        NodeType: GraphQLNode,
+    {
+        let query = format!(
+            "query($id: ID!) {{ node(id: $id) {{ ... on {} {{ {} }} }} }}",
+            NodeType::TYPE_NAME,
+            NodeType::FIELDS
+        );
+
+        let node: Option<serde_json::Value> = self
+            .graphql_query(
+                &query,
+                &json!({ "id": id }),
+                &vec![
+                    ReadJsonTreeSteps::Key("data"),
+                    ReadJsonTreeSteps::Key("node"),
+                ],
+            )
+            .await?;
+
+        Ok(node.map(read_node).transpose()?.flatten())
+    }
+
+    /// Get resources by their IDs with the `nodes` field, in the order of the IDs
+    ///
+    /// Resources that do not exist, or are not of the requested type, are `None`. IDs are sent
+    /// 250 at a time.
+    pub async fn nodes<NodeType>(
+        &self,
+        ids: &[Gid],
+    ) -> Result<Vec<Option<NodeType>>, ShopifyAPIError>
+    where
+        NodeType: GraphQLNode,
+    {
+        let query = format!(
+            "query($ids: [ID!]!) {{ nodes(ids: $ids) {{ ... on {} {{ {} }} }} }}",
+            NodeType::TYPE_NAME,
+            NodeType::FIELDS
+        );
+
+        let mut nodes = Vec::with_capacity(ids.len());
+        for ids in ids.chunks(NODES_LIMIT) {
+            let page: Vec<Option<serde_json::Value>> = self
+                .graphql_query(
+                    &query,
+                    &json!({ "ids": ids }),
+                    &vec![
+                        ReadJsonTreeSteps::Key("data"),
+                        ReadJsonTreeSteps::Key("nodes"),
+                    ],
+                )
+                .await?;
+
+            for node in page {
+                nodes.push(node.map(read_node).transpose()?.flatten());
+            }
+        }
+
+        Ok(nodes)
+    }
+}
+
+/// Deserialize a node, `None` when the inline fragment did not match and the node is empty
+fn read_node<NodeType>(node: serde_json::Value) -> Result<Option<NodeType>, ShopifyAPIError>
+where
+    NodeType: GraphQLNode,
+{
+    if node.as_object().is_some_and(|fields| fields.is_empty()) {
+        return Ok(None);
+    }
+
+    Ok(Some(serde_json::from_value(node)?))
+}
@@ -0,0 +1,252 @@
+use futures_util::Stream;
+use serde::Deserialize;
+
+use crate::{
+    pagination::{Page, PageCursor, PageStream},
+    utils::ReadJsonTreeSteps,
+    RequestOptions, Shopify, ShopifyAPIError,
+};
+
+/// Page of a GraphQL connection, with its `edges { node }` or its `nodes`
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Connection<NodeType> {
+    edges: Option<Vec<Edge<NodeType>>>,
+    nodes: Option<Vec<NodeType>>,
+    page_info: ConnectionPageInfo,
+}
+
+#[derive(Deserialize)]
+struct Edge<NodeType> {
+    node: NodeType,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ConnectionPageInfo {
+    has_next_page: bool,
+    end_cursor: Option<String>,
+    #[serde(default)]
+    has_previous_page: bool,
+    start_cursor: Option<String>,
+}
+
+impl Shopify {
+    /// Stream every node of a GraphQL connection, following its `endCursor`
+    ///
+    /// The query must take an `$after: String` variable given to the connection, and select its
+    /// `pageInfo { hasNextPage endCursor }` with either `edges { node { ... } }` or `nodes { ... }`.
+    /// `connection_path` leads to the connection in the response, e.g. `data.products`.
+    /// The query is sent again with the `after` variable set to the end cursor of the previous page
+    /// when the nodes of that page have been consumed. The stream ends after the last page,
+    /// or after the first error.
+    /// # Example
+    /// ```no_run
+    /// use futures_util::StreamExt;
+    /// use shopify_api::*;
+    /// use shopify_api::utils::ReadJsonTreeSteps;
+    ///
+    /// #[derive(serde::Deserialize)]
+    /// struct Product {
+    ///     id: String,
+    ///     title: String,
+    /// }
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let shopify = Shopify::new("myshop", "myapikey", ShopifyAPIVersion::V2026_10, None);
+    ///     let query = r#"
+    ///         query($after: String, $query: String) {
+    ///             products(first: 250, after: $after, query: $query) {
+    ///                 edges { node { id title } }
+    ///                 pageInfo { hasNextPage endCursor }
+    ///             }
+    ///         }
+    ///     "#;
+    ///     let variables = serde_json::json!({ "query": "status:active" });
+    ///     let connection_path = vec![ReadJsonTreeSteps::Key("data"), ReadJsonTreeSteps::Key("products")];
+    ///
+    ///     let mut products = shopify.graphql_stream::<Product, _>(query, &variables, &connection_path);
+    ///     while let Some(product) = products.next().await {
+    ///         let product = product.unwrap();
+    ///         println!("{}: {}", product.id, product.title);
+    ///     }
+    /// }
+    /// ```
+    pub fn graphql_stream<'a, NodeType, VariablesType>(
+        &'a self,
+        graphql_query: &'a str,
+        variables: &'a VariablesType,
+        connection_path: &'a Vec<ReadJsonTreeSteps<'a>>,
+    ) -> impl Stream<Item = Result<NodeType, ShopifyAPIError>> + Unpin + 'a
+    where
+        NodeType: serde::de::DeserializeOwned + 'a,
+        VariablesType: serde::Serialize,
+    {
+        self.graphql_stream_with_prefetch(graphql_query, variables, connection_path, 0)
+    }
+
+    /// Stream every node of a GraphQL connection, buffering up to `prefetch` pages ahead
+    ///
+    /// The next page is requested while the nodes of a page are consumed, one request at a time
+    /// and driven by the polls of the stream, see [`Shopify::rest_stream_with_prefetch`]. Each
+    /// prefetched page costs a query against the GraphQL budget even if the stream is dropped
+    /// before reaching it.
+    pub fn graphql_stream_with_prefetch<'a, NodeType, VariablesType>(
+        &'a self,
+        graphql_query: &'a str,
+        variables: &'a VariablesType,
+        connection_path: &'a Vec<ReadJsonTreeSteps<'a>>,
+        prefetch: usize,
+    ) -> impl Stream<Item = Result<NodeType, ShopifyAPIError>> + Unpin + 'a
+    where
+        NodeType: serde::de::DeserializeOwned + 'a,
+        VariablesType: serde::Serialize,
+    {
+        PageStream::new(
+            move |cursor: Option<PageCursor>| async move {
+                self.graphql_page(graphql_query, variables, connection_path, cursor.as_ref())
+                    .await
+            },
+            prefetch,
+        )
+    }
+
+    /// Query a page of a GraphQL connection, starting from the first page without a cursor
+    ///
+    /// The query is sent with the `after` variable set to the cursor, see [`Shopify::graphql_stream`]
+    /// for the expected query. The previous page cursor is only returned if the query selects
+    /// `hasPreviousPage startCursor`.
+    /// # Example
+    /// ```no_run
+    /// use shopify_api::*;
+    /// use shopify_api::pagination::Page;
+    /// use shopify_api::utils::ReadJsonTreeSteps;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let shopify = Shopify::new("myshop", "myapikey", ShopifyAPIVersion::V2026_10, None);
+    ///     let query = r#"
+    ///         query($after: String) {
+    ///             orders(first: 100, after: $after) {
+    ///                 nodes { id name }
+    ///                 pageInfo { hasNextPage endCursor }
+    ///             }
+    ///         }
+    ///     "#;
+    ///     let connection_path = vec![ReadJsonTreeSteps::Key("data"), ReadJsonTreeSteps::Key("orders")];
+    ///
+    ///     let first: Page<serde_json::Value> = shopify
+    ///         .graphql_page(query, &serde_json::json!({}), &connection_path, None)
+    ///         .await
+    ///         .unwrap();
+    ///     let second: Page<serde_json::Value> = shopify
+    ///         .graphql_page(query, &serde_json::json!({}), &connection_path, first.next.as_ref())
+    ///         .await
+    ///         .unwrap();
+    /// }
+    /// ```
+    pub async fn graphql_page<NodeType, VariablesType>(
+        &self,
+        graphql_query: &str,
+        variables: &VariablesType,
+        connection_path: &Vec<ReadJsonTreeSteps<'_>>,
+        cursor: Option<&PageCursor>,
+    ) -> Result<Page<NodeType>, ShopifyAPIError>
+    where
+        NodeType: serde::de::DeserializeOwned,
+        VariablesType: serde::Serialize,
+    {
+        self.graphql_page_with_options(
+            graphql_query,
+            variables,
+            connection_path,
+            cursor,
+            &RequestOptions::new(),
+        )
+        .await
+    }
+
+    /// Query a page of a GraphQL connection with per-request options, see [`Shopify::graphql_page`]
+    ///
+    /// This fails with [`ShopifyAPIError::InvalidInput`] before sending the query if the variables
+    /// are not an object.
+    /// # Example
+    /// ```no_run
+    /// use shopify_api::*;
+    /// use shopify_api::options::Priority;
+    /// use shopify_api::pagination::Page;
+    /// use shopify_api::utils::ReadJsonTreeSteps;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let shopify = Shopify::new("myshop", "myapikey", ShopifyAPIVersion::V2026_10, None);
+    ///     let query = r#"
+    ///         query($after: String) {
+    ///             orders(first: 100, after: $after) {
+    ///                 nodes { id name }
+    ///                 pageInfo { hasNextPage endCursor }
+    ///             }
+    ///         }
+    ///     "#;
+    ///     let connection_path = vec![ReadJsonTreeSteps::Key("data"), ReadJsonTreeSteps::Key("orders")];
+    ///     let options = RequestOptions::new().priority(Priority::Low);
+    ///
+    ///     let page: Page<serde_json::Value> = shopify
+    ///         .graphql_page_with_options(query, &serde_json::json!({}), &connection_path, None, &options)
+    ///         .await
+    ///         .unwrap();
+    /// }
+    /// ```
+    pub async fn graphql_page_with_options<NodeType, VariablesType>(
+        &self,
+        graphql_query: &str,
+        variables: &VariablesType,
+        connection_path: &Vec<ReadJsonTreeSteps<'_>>,
+        cursor: Option<&PageCursor>,
+        options: &RequestOptions,
+    ) -> Result<Page<NodeType>, ShopifyAPIError>
+    where
+        NodeType: serde::de::DeserializeOwned,
+        VariablesType: serde::Serialize,
+    {
+        let mut variables = match serde_json::to_value(variables)? {
+            serde_json::Value::Object(variables) => variables,
+            serde_json::Value::Null => serde_json::Map::new(),
+            _ => {
+                return Err(ShopifyAPIError::InvalidInput(
+                    "GraphQL variables must be an object".to_string(),
+                ))
+            }
+        };
+        variables.insert("after".to_string(), cursor.map(PageCursor::as_str).into());
+
+        let (connection, metadata): (Connection<NodeType>, _) = self
+            .graphql_query_with_metadata(graphql_query, &variables, connection_path, options)
+            .await?;
+
+        let items = match (connection.edges, connection.nodes) {
+            (Some(edges), _) => edges.into_iter().map(|edge| edge.node).collect(),
+            (None, Some(nodes)) => nodes,
+            (None, None) => {
+                return Err(ShopifyAPIError::NotWantedJsonFormat(
+                    "connection without edges nor nodes".to_string(),
+                ))
+            }
+        };
+        let page_info = connection.page_info;
+
+        Ok(Page {
+            items,
+            next: page_info
+                .end_cursor
+                .filter(|_| page_info.has_next_page)
+                .map(PageCursor::new),
+            prev: page_info
+                .start_cursor
+                .filter(|_| page_info.has_previous_page)
+                .map(PageCursor::new),
+            request_id: metadata.request_id,
+        })
+    }
+}
@@ -0,0 +1,249 @@
+use crate::{utils::ReadJsonTreeSteps, ShopifyAPIError};
+
+/// Number of nodes queried per page when [`Query::first`] is not set
+const DEFAULT_FIRST: u32 = 50;
+
+/// Builder of a query on a connection of the Admin API, e.g. `products`
+///
+/// The rendered query takes `$first`, `$after` and `$query` variables, given by [`Query::variables`],
+/// so the filter is never formatted into the query text. It selects `pageInfo { hasNextPage endCursor }`
+/// and can be paginated with [`crate::Shopify::graphql_stream`] and [`crate::Shopify::graphql_page`].
+/// Nested fields are selected with a dotted path, e.g. `featuredImage.url`.
+/// # Example
+/// ```
+/// use shopify_api::graphql::Query;
+///
+/// let query = Query::products()
+///     .first(50)
+///     .fields(["id", "title", "featuredImage.url"])
+///     .filter("status:active");
+///
+/// assert_eq!(
+///     query.build().unwrap(),
+///     "query($first: Int!, $after: String, $query: String) { \
+///     products(first: $first, after: $after, query: $query) { \
+///     nodes { id title featuredImage { url } } pageInfo { hasNextPage endCursor } } }"
+/// );
+/// assert_eq!(
+///     query.variables(),
+///     serde_json::json!({ "first": 50, "query": "status:active" })
+/// );
+/// ```
+///
+/// Streaming the nodes of the query:
+/// ```no_run
+/// use futures_util::StreamExt;
+/// use shopify_api::*;
+/// use shopify_api::graphql::Query;
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let shopify = Shopify::new("myshop", "myapikey", ShopifyAPIVersion::V2026_10, None);
+///     let query = Query::orders().fields(["id", "name"]).filter("financial_status:paid");
+///     let (graphql_query, variables) = (query.build().unwrap(), query.variables());
+///     let connection_path = query.connection_path();
+///
+///     let mut orders = shopify.graphql_stream::<serde_json::Value, _>(&graphql_query, &variables, &connection_path);
+///     while let Some(order) = orders.next().await {
+///         println!("{}", order.unwrap()["name"]);
+///     }
+/// }
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Query {
+    connection: String,
+    first: Option<u32>,
+    fields: Vec<String>,
+    filter: Option<String>,
+    sort_key: Option<String>,
+    reverse: bool,
+}
+
+impl Query {
+    /// Query any connection of the `QueryRoot`, by its field name
+    pub fn connection(name: &str) -> Query {
+        Query {
+            connection: name.to_string(),
+            first: None,
+            fields: Vec::new(),
+            filter: None,
+            sort_key: None,
+            reverse: false,
+        }
+    }
+
+    pub fn products() -> Query {
+        Query::connection("products")
+    }
+
+    pub fn orders() -> Query {
+        Query::connection("orders")
+    }
+
+    pub fn customers() -> Query {
+        Query::connection("customers")
+    }
+
+    pub fn collections() -> Query {
+        Query::connection("collections")
+    }
+
+    pub fn draft_orders() -> Query {
+        Query::connection("draftOrders")
+    }
+
+    pub fn product_variants() -> Query {
+        Query::connection("productVariants")
+    }
+
+    pub fn locations() -> Query {
+        Query::connection("locations")
+    }
+
+    /// Set the number of nodes of each page, 50 by default and at most 250
+    pub fn first(mut self, first: u32) -> Self {
+        self.first = Some(first);
+        self
+    }
+
+    /// Add fields to select on each node, nested fields being given as dotted paths
+    pub fn fields<Field>(mut self, fields: impl IntoIterator<Item = Field>) -> Self
+    where
+        Field: Into<String>,
+    {
+        self.fields.extend(fields.into_iter().map(Into::into));
+        self
+    }
+
+    /// Add a field to select on each node
+    pub fn field(mut self, field: &str) -> Self {
+        self.fields.push(field.to_string());
+        self
+    }
+
+    /// Filter the nodes with Shopify's search syntax, e.g. `status:active AND vendor:Acme`
+    pub fn filter(mut self, filter: &str) -> Self {
+        self.filter = Some(filter.to_string());
+        self
+    }
+
+    /// Sort the nodes by a key of the connection, e.g. `TITLE` or `CREATED_AT`
+    pub fn sort_key(mut self, sort_key: &str) -> Self {
+        self.sort_key = Some(sort_key.to_string());
+        self
+    }
+
+    /// Reverse the order of the nodes
+    pub fn reverse(mut self, reverse: bool) -> Self {
+        self.reverse = reverse;
+        self
+    }
+
+    /// Render the query, failing with [`ShopifyAPIError::InvalidQuery`] if a name is not a valid
+    /// GraphQL name or no field is selected
+    pub fn build(&self) -> Result<String, ShopifyAPIError> {
+        check_name(&self.connection)?;
+        if self.fields.is_empty() {
+            return Err(ShopifyAPIError::InvalidQuery(
+                "no field selected".to_string(),
+            ));
+        }
+
+        let mut selection = Selection::default();
+        for field in &self.fields {
+            let mut fields = &mut selection;
+            for name in field.split('.') {
+                check_name(name)?;
+                fields = fields.child(name);
+            }
+        }
+
+        let mut variables = "$first: Int!, $after: String".to_string();
+        let mut arguments = "first: $first, after: $after".to_string();
+        if self.filter.is_some() {
+            variables.push_str(", $query: String");
+            arguments.push_str(", query: $query");
+        }
+        if let Some(sort_key) = &self.sort_key {
+            check_name(sort_key)?;
+            arguments.push_str(&format!(", sortKey: {}", sort_key));
+        }
+        if self.reverse {
+            arguments.push_str(", reverse: true");
+        }
+
+        Ok(format!(
+            "query({}) {{ {}({}) {{ nodes {} pageInfo {{ hasNextPage endCursor }} }} }}",
+            variables,
+            self.connection,
+            arguments,
+            selection.render()
+        ))
+    }
+
+    /// Get the variables of the query, without the `after` cursor set by the pagination
+    pub fn variables(&self) -> serde_json::Value {
+        let mut variables = serde_json::json!({ "first": self.first.unwrap_or(DEFAULT_FIRST) });
+        if let Some(filter) = &self.filter {
+            variables["query"] = filter.as_str().into();
+        }
+        variables
+    }
+
+    /// Get the path of the connection in the response, e.g. `data.products`
+    pub fn connection_path(&self) -> Vec<ReadJsonTreeSteps<'_>> {
+        vec![
+            ReadJsonTreeSteps::Key("data"),
+            ReadJsonTreeSteps::Key(&self.connection),
+        ]
+    }
+}
+
+/// Fields selected on an object, in the order they were added
+#[derive(Default)]
+struct Selection {
+    fields: Vec<(String, Selection)>,
+}
+
+impl Selection {
+    fn child(&mut self, name: &str) -> &mut Selection {
+        let index = match self.fields.iter().position(|(field, _)| field == name) {
+            Some(index) => index,
+            None => {
+                self.fields.push((name.to_string(), Selection::default()));
+                self.fields.len() - 1
+            }
+        };
+        &mut self.fields[index].1
+    }
+
+    fn render(&self) -> String {
+        let fields = self
+            .fields
+            .iter()
+            .map(|(name, selection)| match selection.fields.is_empty() {
+                true => name.clone(),
+                false => format!("{} {}", name, selection.render()),
+            })
+            .collect::<Vec<_>>()
+            .join(" ");
+        format!("{{ {} }}", fields)
+    }
+}
+
+/// Check that a name can be written in a query as is
+fn check_name(name: &str) -> Result<(), ShopifyAPIError> {
+    let mut chars = name.chars();
+    let valid = chars
+        .next()
+        .is_some_and(|first| first.is_ascii_alphabetic() || first == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_');
+
+    match valid {
+        true => Ok(()),
+        false => Err(ShopifyAPIError::InvalidQuery(format!(
+            "invalid name `{}`",
+            name
+        ))),
+    }
+}
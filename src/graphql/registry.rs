@@ -0,0 +1,179 @@
+use std::{
+    borrow::Cow,
+    collections::{HashMap, HashSet},
+    sync::RwLock,
+};
+
+use crate::{utils::ReadJsonTreeSteps, Shopify, ShopifyAPIError};
+
+/// Fragments and operations registered on a client, shared by its clones
+#[derive(Debug, Default)]
+pub(crate) struct OperationRegistry {
+    fragments: RwLock<HashMap<String, String>>,
+    operations: RwLock<HashMap<String, String>>,
+}
+
+impl OperationRegistry {
+    /// Append the registered fragments spread by the query, or by the fragments it uses,
+    /// that the query does not define itself
+    pub(crate) fn with_fragments<'a>(&self, graphql_query: &'a str) -> Cow<'a, str> {
+        let fragments = self.fragments.read().unwrap();
+        if fragments.is_empty() {
+            return Cow::Borrowed(graphql_query);
+        }
+
+        let mut defined = fragment_definitions(graphql_query);
+        let mut spreads = fragment_spreads(graphql_query);
+        let mut query = Cow::Borrowed(graphql_query);
+        while let Some(name) = spreads.pop() {
+            // Unknown fragments are left for Shopify to report
+            let Some(definition) = fragments.get(name) else {
+                continue;
+            };
+            if !defined.insert(name.to_string()) {
+                continue;
+            }
+
+            spreads.extend(fragment_spreads(definition));
+            let query = query.to_mut();
+            query.push('\n');
+            query.push_str(definition);
+        }
+
+        query
+    }
+}
+
+impl Shopify {
+    /// Register a fragment, added to every GraphQL query of this client and its clones that spreads it
+    ///
+    /// Fragments can spread other registered fragments. This fails with
+    /// [`ShopifyAPIError::InvalidQuery`] if the definition does not start with `fragment <Name>`.
+    /// # Example
+    /// ```no_run
+    /// use shopify_api::*;
+    /// use shopify_api::utils::ReadJsonTreeSteps;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let shopify = Shopify::new("myshop", "myapikey", ShopifyAPIVersion::V2026_10, None);
+    ///     shopify
+    ///         .register_fragment("fragment ProductFields on Product { id title handle }")
+    ///         .unwrap();
+    ///
+    ///     let json_finder = vec![ReadJsonTreeSteps::Key("data"), ReadJsonTreeSteps::Key("product")];
+    ///     let product: serde_json::Value = shopify
+    ///         .graphql_query(
+    ///             "query($id: ID!) { product(id: $id) { ...ProductFields } }",
+    ///             &serde_json::json!({ "id": "gid://shopify/Product/1" }),
+    ///             &json_finder,
+    ///         )
+    ///         .await
+    ///         .unwrap();
+    /// }
+    /// ```
+    pub fn register_fragment(&self, definition: &str) -> Result<(), ShopifyAPIError> {
+        let name = definition
+            .trim_start()
+            .strip_prefix("fragment")
+            .filter(|rest| rest.starts_with(char::is_whitespace))
+            .map(|rest| identifier(rest.trim_start()))
+            .filter(|name| !name.is_empty())
+            .ok_or_else(|| {
+                ShopifyAPIError::InvalidQuery("expected a fragment definition".to_string())
+            })?;
+
+        self.operations
+            .fragments
+            .write()
+            .unwrap()
+            .insert(name.to_string(), definition.to_string());
+        Ok(())
+    }
+
+    /// Register a query or a mutation under a name, to run it with [`Shopify::graphql_operation`]
+    pub fn register_operation(&self, name: &str, graphql_query: &str) {
+        self.operations
+            .operations
+            .write()
+            .unwrap()
+            .insert(name.to_string(), graphql_query.to_string());
+    }
+
+    /// Run an operation registered with [`Shopify::register_operation`]
+    ///
+    /// This fails with [`ShopifyAPIError::InvalidQuery`] if no operation has this name.
+    /// # Example
+    /// ```no_run
+    /// use shopify_api::*;
+    /// use shopify_api::utils::ReadJsonTreeSteps;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let shopify = Shopify::new("myshop", "myapikey", ShopifyAPIVersion::V2026_10, None);
+    ///     shopify.register_operation("ShopName", "{ shop { name } }");
+    ///
+    ///     let json_finder = vec![ReadJsonTreeSteps::Key("data"), ReadJsonTreeSteps::Key("shop")];
+    ///     let shop: serde_json::Value = shopify
+    ///         .graphql_operation("ShopName", &serde_json::json!({}), &json_finder)
+    ///         .await
+    ///         .unwrap();
+    /// }
+    /// ```
+    pub async fn graphql_operation<ReturnType, VariablesType>(
+        &self,
+        name: &str,
+        variables: &VariablesType,
+        json_finder: &Vec<ReadJsonTreeSteps<'_>>,
+    ) -> Result<ReturnType, ShopifyAPIError>
+    where
+        ReturnType: serde::de::DeserializeOwned,
+        VariablesType: serde::Serialize,
+    {
+        let graphql_query = self
+            .operations
+            .operations
+            .read()
+            .unwrap()
+            .get(name)
+            .cloned()
+            .ok_or_else(|| {
+                ShopifyAPIError::InvalidQuery(format!("unknown operation `{}`", name))
+            })?;
+
+        self.graphql_query(&graphql_query, variables, json_finder)
+            .await
+    }
+}
+
+/// Names of the fragments spread in a query, e.g. `ProductFields` for `...ProductFields`
+fn fragment_spreads(graphql_query: &str) -> Vec<&str> {
+    graphql_query
+        .match_indices("...")
+        .map(|(index, _)| identifier(graphql_query[index + 3..].trim_start()))
+        // `... on Type` and `... @include(...)` are inline fragments
+        .filter(|name| !name.is_empty() && *name != "on")
+        .collect()
+}
+
+/// Names of the fragments defined in a query
+fn fragment_definitions(graphql_query: &str) -> HashSet<String> {
+    graphql_query
+        .match_indices("fragment")
+        .filter(|(index, _)| {
+            !graphql_query[..*index].ends_with(|c: char| c.is_ascii_alphanumeric() || c == '_')
+        })
+        .map(|(index, keyword)| &graphql_query[index + keyword.len()..])
+        .filter(|rest| rest.starts_with(char::is_whitespace))
+        .map(|rest| identifier(rest.trim_start()).to_string())
+        .filter(|name| !name.is_empty())
+        .collect()
+}
+
+/// Read the GraphQL name at the start of the text
+fn identifier(text: &str) -> &str {
+    let end = text
+        .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+        .unwrap_or(text.len());
+    &text[..end]
+}
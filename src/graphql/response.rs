@@ -0,0 +1,93 @@
+use serde::{Deserialize, Serialize};
+
+use super::{GraphQLError, GraphQLOutput};
+use crate::{rate_limit::GraphQLCost, RequestOptions, Shopify, ShopifyAPIError};
+
+/// Whole response of a GraphQL query, returned by [`Shopify::graphql_response`]
+///
+/// Unlike [`Shopify::graphql_query`], the errors are returned with the data, which may be partial
+/// when only some fields failed.
+/// # Example
+/// ```
+/// use shopify_api::graphql::GraphQLResponse;
+///
+/// let response: GraphQLResponse<serde_json::Value> = serde_json::from_value(serde_json::json!({
+///     "data": { "shop": { "name": "Rust api" } },
+///     "extensions": {
+///         "cost": {
+///             "requestedQueryCost": 1.0,
+///             "actualQueryCost": 1.0,
+///             "throttleStatus": { "maximumAvailable": 2000.0, "currentlyAvailable": 1999.0, "restoreRate": 100.0 }
+///         }
+///     }
+/// })).unwrap();
+///
+/// assert!(response.errors.is_empty());
+/// assert_eq!(response.data.unwrap()["shop"]["name"], "Rust api");
+/// assert_eq!(response.extensions.unwrap().cost.unwrap().actual_query_cost, Some(1.0));
+/// ```
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct GraphQLResponse<DataType> {
+    /// Data of the query, missing if the query could not run
+    pub data: Option<DataType>,
+    /// Errors of the query, with the fields that failed in their `path`
+    #[serde(default)]
+    pub errors: Vec<GraphQLError>,
+    pub extensions: Option<Extensions>,
+}
+
+/// `extensions` of a GraphQL response
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Extensions {
+    /// Cost of the query and state of the GraphQL bucket after it
+    pub cost: Option<GraphQLCost>,
+}
+
+impl Shopify {
+    /// Query graphql shopify api, returning the whole response with its errors and extensions
+    ///
+    /// The query only fails for errors preventing the response from being read. `THROTTLED` queries
+    /// are still sent again as with [`Shopify::graphql_query`].
+    /// # Example
+    /// ```no_run
+    /// use shopify_api::*;
+    /// use shopify_api::graphql::GraphQLResponse;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let shopify = Shopify::new("myshop", "myapikey", ShopifyAPIVersion::V2026_10, None);
+    ///     let query = "{ shop { name } orders(first: 5) { nodes { id } } }";
+    ///
+    ///     let response: GraphQLResponse<serde_json::Value> = shopify
+    ///         .graphql_response(query, &serde_json::json!({}), &RequestOptions::new())
+    ///         .await
+    ///         .unwrap();
+    ///
+    ///     // The shop name is returned even without access to the orders
+    ///     for error in &response.errors {
+    ///         println!("{} at {:?}", error, error.path);
+    ///     }
+    ///     if let Some(cost) = response.extensions.and_then(|extensions| extensions.cost) {
+    ///         println!("Cost: {}", cost.requested_query_cost);
+    ///     }
+    /// }
+    /// ```
+    pub async fn graphql_response<DataType, VariablesType>(
+        &self,
+        graphql_query: &str,
+        variables: &VariablesType,
+        options: &RequestOptions,
+    ) -> Result<GraphQLResponse<DataType>, ShopifyAPIError>
+    where
+        DataType: serde::de::DeserializeOwned,
+        VariablesType: serde::Serialize,
+    {
+        let req_body = self.graphql_body(graphql_query, variables)?;
+
+        let (response, _) = self
+            .send_graphql(req_body, GraphQLOutput::Response, options)
+            .await?;
+
+        Ok(response)
+    }
+}
@@ -0,0 +1,290 @@
+use std::collections::{HashMap, HashSet};
+
+use graphql_parser::{
+    query::{self, Definition as QueryDefinition, OperationDefinition, Selection, TypeCondition},
+    schema::{self, Definition as SchemaDefinition, TypeDefinition, TypeExtension},
+    Pos,
+};
+
+use crate::{Shopify, ShopifyAPIError};
+
+/// Admin API schema, used to validate queries before sending them
+///
+/// The schema is loaded from the SDL of the API version used by the client, e.g. the
+/// `schema.graphql` file downloaded by the Shopify CLI. Queries are checked for unknown types,
+/// fields and arguments, and for fields missing or having a selection. Available with the
+/// `schema-validation` feature.
+/// # Example
+/// ```
+/// use shopify_api::graphql::Schema;
+///
+/// let schema = Schema::from_sdl(r#"
+///     schema { query: QueryRoot }
+///     type QueryRoot { product(id: ID!): Product }
+///     type Product { id: ID! title: String! }
+/// "#).unwrap();
+///
+/// assert!(schema.validate("query($id: ID!) { product(id: $id) { id title } }").is_ok());
+///
+/// let error = schema.validate("{ product(id: \"1\") { titel } }").unwrap_err();
+/// assert_eq!(
+///     error.to_string(),
+///     "Invalid GraphQL query: unknown field `titel` on `Product` (line 1, column 22)"
+/// );
+/// ```
+#[derive(Clone, Debug)]
+pub struct Schema {
+    types: HashMap<String, SchemaType>,
+    query_type: String,
+    mutation_type: String,
+}
+
+#[derive(Clone, Debug, Default)]
+struct SchemaType {
+    /// Objects, interfaces and unions have a selection
+    composite: bool,
+    fields: HashMap<String, SchemaField>,
+}
+
+#[derive(Clone, Debug)]
+struct SchemaField {
+    type_name: String,
+    arguments: HashSet<String>,
+}
+
+impl Schema {
+    /// Load a schema from its SDL, failing with [`ShopifyAPIError::InvalidConfiguration`] if it cannot be parsed
+    pub fn from_sdl(sdl: &str) -> Result<Schema, ShopifyAPIError> {
+        let document = schema::parse_schema::<String>(sdl).map_err(|error| {
+            ShopifyAPIError::InvalidConfiguration(format!("invalid GraphQL schema: {}", error))
+        })?;
+
+        let mut schema = Schema {
+            types: HashMap::new(),
+            query_type: "Query".to_string(),
+            mutation_type: "Mutation".to_string(),
+        };
+
+        for definition in document.definitions {
+            match definition {
+                SchemaDefinition::SchemaDefinition(definition) => {
+                    if let Some(query_type) = definition.query {
+                        schema.query_type = query_type;
+                    }
+                    if let Some(mutation_type) = definition.mutation {
+                        schema.mutation_type = mutation_type;
+                    }
+                }
+                SchemaDefinition::TypeDefinition(definition) => match definition {
+                    TypeDefinition::Object(object) => schema.add_fields(object.name, object.fields),
+                    TypeDefinition::Interface(interface) => {
+                        schema.add_fields(interface.name, interface.fields)
+                    }
+                    TypeDefinition::Union(union) => schema.add_fields(union.name, Vec::new()),
+                    TypeDefinition::Scalar(scalar) => schema.add_leaf(scalar.name),
+                    TypeDefinition::Enum(enumeration) => schema.add_leaf(enumeration.name),
+                    TypeDefinition::InputObject(input) => schema.add_leaf(input.name),
+                },
+                SchemaDefinition::TypeExtension(extension) => match extension {
+                    TypeExtension::Object(object) => schema.add_fields(object.name, object.fields),
+                    TypeExtension::Interface(interface) => {
+                        schema.add_fields(interface.name, interface.fields)
+                    }
+                    _ => {}
+                },
+                SchemaDefinition::DirectiveDefinition(_) => {}
+            }
+        }
+
+        // Built-in scalars are not declared in the SDL
+        for scalar in ["Int", "Float", "String", "Boolean", "ID"] {
+            schema.add_leaf(scalar.to_string());
+        }
+
+        Ok(schema)
+    }
+
+    fn add_fields(&mut self, name: String, fields: Vec<schema::Field<'_, String>>) {
+        let schema_type = self.types.entry(name).or_default();
+        schema_type.composite = true;
+        for field in fields {
+            schema_type.fields.insert(
+                field.name,
+                SchemaField {
+                    type_name: named_type(&field.field_type).to_string(),
+                    arguments: field
+                        .arguments
+                        .into_iter()
+                        .map(|argument| argument.name)
+                        .collect(),
+                },
+            );
+        }
+    }
+
+    fn add_leaf(&mut self, name: String) {
+        self.types.entry(name).or_default();
+    }
+
+    /// Validate a query against the schema, failing with [`ShopifyAPIError::InvalidQuery`] listing
+    /// every problem found
+    pub fn validate(&self, graphql_query: &str) -> Result<(), ShopifyAPIError> {
+        let document = query::parse_query::<String>(graphql_query)
+            .map_err(|error| ShopifyAPIError::InvalidQuery(error.to_string()))?;
+
+        let mut problems = Vec::new();
+        for definition in &document.definitions {
+            let (type_name, selection_set) = match definition {
+                QueryDefinition::Operation(OperationDefinition::SelectionSet(selection_set)) => {
+                    (&self.query_type, selection_set)
+                }
+                QueryDefinition::Operation(OperationDefinition::Query(query)) => {
+                    (&self.query_type, &query.selection_set)
+                }
+                QueryDefinition::Operation(OperationDefinition::Mutation(mutation)) => {
+                    (&self.mutation_type, &mutation.selection_set)
+                }
+                QueryDefinition::Operation(OperationDefinition::Subscription(subscription)) => {
+                    problems.push(format!(
+                        "subscriptions are not supported ({})",
+                        position(subscription.position)
+                    ));
+                    continue;
+                }
+                QueryDefinition::Fragment(fragment) => {
+                    let TypeCondition::On(type_name) = &fragment.type_condition;
+                    if !self.types.contains_key(type_name) {
+                        problems.push(format!(
+                            "unknown type `{}` ({})",
+                            type_name,
+                            position(fragment.position)
+                        ));
+                        continue;
+                    }
+                    (type_name, &fragment.selection_set)
+                }
+            };
+
+            self.validate_selection(type_name, selection_set, &mut problems);
+        }
+
+        match problems.is_empty() {
+            true => Ok(()),
+            false => Err(ShopifyAPIError::InvalidQuery(problems.join("; "))),
+        }
+    }
+
+    /// Validate the selection of an object, fragments spreads being validated with their definition
+    fn validate_selection(
+        &self,
+        type_name: &str,
+        selection_set: &query::SelectionSet<'_, String>,
+        problems: &mut Vec<String>,
+    ) {
+        let Some(schema_type) = self.types.get(type_name) else {
+            return;
+        };
+
+        for selection in &selection_set.items {
+            match selection {
+                Selection::Field(field) => {
+                    if field.name == "__typename" {
+                        continue;
+                    }
+                    let Some(schema_field) = schema_type.fields.get(&field.name) else {
+                        problems.push(format!(
+                            "unknown field `{}` on `{}` ({})",
+                            field.name,
+                            type_name,
+                            position(field.position)
+                        ));
+                        continue;
+                    };
+
+                    for (argument, _) in &field.arguments {
+                        if !schema_field.arguments.contains(argument) {
+                            problems.push(format!(
+                                "unknown argument `{}` on `{}.{}` ({})",
+                                argument,
+                                type_name,
+                                field.name,
+                                position(field.position)
+                            ));
+                        }
+                    }
+
+                    let composite = self
+                        .types
+                        .get(&schema_field.type_name)
+                        .is_some_and(|field_type| field_type.composite);
+                    match (composite, field.selection_set.items.is_empty()) {
+                        (true, true) => problems.push(format!(
+                            "field `{}` of type `{}` needs a selection ({})",
+                            field.name,
+                            schema_field.type_name,
+                            position(field.position)
+                        )),
+                        (false, false) => problems.push(format!(
+                            "field `{}` of type `{}` has no fields to select ({})",
+                            field.name,
+                            schema_field.type_name,
+                            position(field.position)
+                        )),
+                        _ => self.validate_selection(
+                            &schema_field.type_name,
+                            &field.selection_set,
+                            problems,
+                        ),
+                    }
+                }
+                Selection::InlineFragment(fragment) => match &fragment.type_condition {
+                    Some(TypeCondition::On(condition)) if !self.types.contains_key(condition) => {
+                        problems.push(format!(
+                            "unknown type `{}` ({})",
+                            condition,
+                            position(fragment.position)
+                        ));
+                    }
+                    Some(TypeCondition::On(condition)) => {
+                        self.validate_selection(condition, &fragment.selection_set, problems)
+                    }
+                    None => self.validate_selection(type_name, &fragment.selection_set, problems),
+                },
+                Selection::FragmentSpread(_) => {}
+            }
+        }
+    }
+}
+
+impl Shopify {
+    /// Validate every GraphQL query against the schema before sending it
+    ///
+    /// Invalid queries fail with [`ShopifyAPIError::InvalidQuery`] without spending an API call.
+    /// The schema must be the one of the API version of the client. Available with the
+    /// `schema-validation` feature.
+    /// # Example
+    /// ```no_run
+    /// use shopify_api::*;
+    /// use shopify_api::graphql::Schema;
+    ///
+    /// let sdl = std::fs::read_to_string("schema/2026-10.graphql").unwrap();
+    /// let shopify = Shopify::new("myshop", "myapikey", ShopifyAPIVersion::V2026_10, None)
+    ///     .with_schema(Schema::from_sdl(&sdl).unwrap());
+    /// ```
+    pub fn with_schema(mut self, schema: Schema) -> Shopify {
+        self.schema = Some(std::sync::Arc::new(schema));
+        self
+    }
+}
+
+/// Get the name of a type without its list and non-null wrappers
+fn named_type<'a>(field_type: &'a schema::Type<'_, String>) -> &'a str {
+    match field_type {
+        schema::Type::NamedType(name) => name,
+        schema::Type::ListType(inner) | schema::Type::NonNullType(inner) => named_type(inner),
+    }
+}
+
+fn position(pos: Pos) -> String {
+    format!("line {}, column {}", pos.line, pos.column)
+}
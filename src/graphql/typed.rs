@@ -0,0 +1,63 @@
+use graphql_client::GraphQLQuery;
+
+use super::GraphQLOutput;
+use crate::{utils::ReadJsonTreeSteps, RequestOptions, Shopify, ShopifyAPIError};
+
+impl Shopify {
+    /// Run a query generated by `graphql_client`, available with the `graphql-client` feature
+    ///
+    /// The request body is built by the generated operation, and its `data` is deserialized into
+    /// `Q::ResponseData`. The query goes through the retry policy and the rate limits of the client,
+    /// and fails with [`ShopifyAPIError::GraphQL`] if the response has errors.
+    /// # Example
+    /// ```ignore
+    /// use graphql_client::GraphQLQuery;
+    /// use shopify_api::*;
+    ///
+    /// #[derive(GraphQLQuery)]
+    /// #[graphql(schema_path = "shopify_schema.json", query_path = "queries/product.graphql")]
+    /// struct ProductQuery;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let shopify = Shopify::new("myshop", "myapikey", ShopifyAPIVersion::V2026_10, None);
+    ///     let variables = product_query::Variables {
+    ///         id: "gid://shopify/Product/1".to_string(),
+    ///     };
+    ///
+    ///     let data = shopify.graphql_query_typed::<ProductQuery>(variables).await.unwrap();
+    ///     println!("{:?}", data.product);
+    /// }
+    /// ```
+    pub async fn graphql_query_typed<Q>(
+        &self,
+        variables: Q::Variables,
+    ) -> Result<Q::ResponseData, ShopifyAPIError>
+    where
+        Q: GraphQLQuery,
+    {
+        self.graphql_query_typed_with_options::<Q>(variables, &RequestOptions::default())
+            .await
+    }
+
+    /// Run a query generated by `graphql_client` with options applied to this request only
+    ///
+    /// See [`Shopify::graphql_query_typed`].
+    pub async fn graphql_query_typed_with_options<Q>(
+        &self,
+        variables: Q::Variables,
+        options: &RequestOptions,
+    ) -> Result<Q::ResponseData, ShopifyAPIError>
+    where
+        Q: GraphQLQuery,
+    {
+        let req_body = serde_json::to_string(&Q::build_query(variables))?;
+        let json_finder = vec![ReadJsonTreeSteps::Key("data")];
+
+        let (data, _) = self
+            .send_graphql(req_body, GraphQLOutput::Data(&json_finder), options)
+            .await?;
+
+        Ok(data)
+    }
+}
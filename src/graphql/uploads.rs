@@ -0,0 +1,292 @@
+//! Staged uploads, the way to send files to Shopify before referencing them in a mutation
+//!
+//! `stagedUploadsCreate` returns a signed URL of Shopify's storage for each file, with the
+//! parameters to send along. Once uploaded, a file is referenced by the `resourceUrl` of its
+//! target, e.g. as the `originalSource` of `fileCreate` or `productCreateMedia`.
+//!
+//! [`Shopify::staged_upload`] and [`Shopify::staged_upload_file`] do both steps for one file.
+//! The contents are kept in memory while uploading.
+
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+use crate::{utils::ReadJsonTreeSteps, Shopify, ShopifyAPIError};
+
+/// Kind of resource a staged upload is for
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum StagedUploadResource {
+    BulkMutationVariables,
+    CollectionImage,
+    DisputeFileUpload,
+    File,
+    Image,
+    #[serde(rename = "MODEL_3D")]
+    Model3d,
+    ProductImage,
+    ReturnLabel,
+    ShopImage,
+    UrlRedirectImport,
+    Video,
+}
+
+/// HTTP method used to upload the file to its target
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum StagedUploadHttpMethod {
+    /// Multipart form with the parameters of the target and the file
+    #[default]
+    #[serde(rename = "POST")]
+    Post,
+    /// File as the body, with the parameters of the target as headers
+    #[serde(rename = "PUT")]
+    Put,
+}
+
+/// File to stage with [`Shopify::staged_uploads_create`]
+///
+/// # Example
+/// ```
+/// use shopify_api::graphql::uploads::{StagedUploadInput, StagedUploadResource};
+///
+/// let input = StagedUploadInput::new(StagedUploadResource::Image, "shirt.png", "image/png")
+///     .file_size(2048);
+///
+/// assert_eq!(
+///     serde_json::to_value(&input).unwrap(),
+///     serde_json::json!({
+///         "resource": "IMAGE",
+///         "filename": "shirt.png",
+///         "mimeType": "image/png",
+///         "httpMethod": "POST",
+///         "fileSize": "2048"
+///     })
+/// );
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StagedUploadInput {
+    resource: StagedUploadResource,
+    filename: String,
+    mime_type: String,
+    http_method: StagedUploadHttpMethod,
+    /// Required for videos and 3D models, sent as a string like every `UnsignedInt64`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    file_size: Option<String>,
+}
+
+impl StagedUploadInput {
+    pub fn new(resource: StagedUploadResource, filename: &str, mime_type: &str) -> Self {
+        StagedUploadInput {
+            resource,
+            filename: filename.to_string(),
+            mime_type: mime_type.to_string(),
+            http_method: StagedUploadHttpMethod::default(),
+            file_size: None,
+        }
+    }
+
+    /// Set the size of the file in bytes, required for videos and 3D models
+    pub fn file_size(mut self, file_size: u64) -> Self {
+        self.file_size = Some(file_size.to_string());
+        self
+    }
+
+    /// Set the HTTP method of the upload, POST by default
+    pub fn http_method(mut self, http_method: StagedUploadHttpMethod) -> Self {
+        self.http_method = http_method;
+        self
+    }
+}
+
+/// Parameter to send with a staged upload
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StagedUploadParameter {
+    pub name: String,
+    pub value: String,
+}
+
+/// Target of a staged upload, returned by `stagedUploadsCreate`
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StagedUploadTarget {
+    /// Signed URL the file is uploaded to
+    pub url: String,
+    /// URL referencing the uploaded file in the mutations
+    pub resource_url: Option<String>,
+    pub parameters: Vec<StagedUploadParameter>,
+}
+
+impl StagedUploadTarget {
+    /// Get the value of a parameter, e.g. `key` which is the path of the bulk mutation variables
+    pub fn parameter(&self, name: &str) -> Option<&str> {
+        self.parameters
+            .iter()
+            .find(|parameter| parameter.name == name)
+            .map(|parameter| parameter.value.as_str())
+    }
+}
+
+impl Shopify {
+    /// Request staged upload targets with `stagedUploadsCreate`, one per input in the same order
+    pub async fn staged_uploads_create(
+        &self,
+        inputs: &[StagedUploadInput],
+    ) -> Result<Vec<StagedUploadTarget>, ShopifyAPIError> {
+        let mutation = r#"
+            mutation($input: [StagedUploadInput!]!) {
+                stagedUploadsCreate(input: $input) {
+                    stagedTargets { url resourceUrl parameters { name value } }
+                    userErrors { field message }
+                }
+            }"#;
+
+        self.graphql_mutation(
+            mutation,
+            &json!({ "input": inputs }),
+            &vec![
+                ReadJsonTreeSteps::Key("data"),
+                ReadJsonTreeSteps::Key("stagedUploadsCreate"),
+                ReadJsonTreeSteps::Key("stagedTargets"),
+            ],
+        )
+        .await
+    }
+
+    /// Upload the contents of a file to a staged upload target
+    ///
+    /// The method, file name and MIME type must be the ones of the input the target was created for.
+    /// The request goes to Shopify's storage, without the access token of the client.
+    pub async fn upload_to_staged_target(
+        &self,
+        target: &StagedUploadTarget,
+        input: &StagedUploadInput,
+        contents: Vec<u8>,
+    ) -> Result<(), ShopifyAPIError> {
+        let request = match input.http_method {
+            StagedUploadHttpMethod::Post => {
+                let boundary = uuid::Uuid::new_v4().simple().to_string();
+                let mut body = Vec::with_capacity(contents.len() + 1024);
+                for parameter in &target.parameters {
+                    body.extend_from_slice(
+                        format!(
+                            "--{boundary}\r\nContent-Disposition: form-data; name=\"{}\"\r\n\r\n{}\r\n",
+                            parameter.name, parameter.value
+                        )
+                        .as_bytes(),
+                    );
+                }
+                body.extend_from_slice(
+                    format!(
+                        "--{boundary}\r\nContent-Disposition: form-data; name=\"file\"; filename=\"{}\"\r\n",
+                        input.filename.replace('"', "")
+                    )
+                    .as_bytes(),
+                );
+                body.extend_from_slice(
+                    format!("Content-Type: {}\r\n\r\n", input.mime_type).as_bytes(),
+                );
+                body.extend_from_slice(&contents);
+                body.extend_from_slice(format!("\r\n--{boundary}--\r\n").as_bytes());
+
+                self.client
+                    .post(&target.url)
+                    .header(
+                        reqwest::header::CONTENT_TYPE,
+                        format!("multipart/form-data; boundary={boundary}"),
+                    )
+                    .body(body)
+            }
+            StagedUploadHttpMethod::Put => {
+                let mut request = self.client.put(&target.url);
+                for parameter in &target.parameters {
+                    request = request.header(parameter.name.as_str(), parameter.value.as_str());
+                }
+                request.body(contents)
+            }
+        };
+
+        request.send().await?.error_for_status()?;
+
+        Ok(())
+    }
+
+    /// Stage a file and upload its contents, returning its target
+    ///
+    /// The uploaded file is referenced by the [`StagedUploadTarget::resource_url`] of the target.
+    /// # Example
+    /// ```no_run
+    /// use shopify_api::*;
+    /// use shopify_api::graphql::uploads::{StagedUploadInput, StagedUploadResource};
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let shopify = Shopify::new("myshop", "myapikey", ShopifyAPIVersion::V2026_10, None);
+    ///     let input = StagedUploadInput::new(StagedUploadResource::File, "terms.txt", "text/plain");
+    ///
+    ///     let target = shopify
+    ///         .staged_upload(&input, b"Terms of service".to_vec())
+    ///         .await
+    ///         .unwrap();
+    ///     println!("{:?}", target.resource_url);
+    /// }
+    /// ```
+    pub async fn staged_upload(
+        &self,
+        input: &StagedUploadInput,
+        contents: Vec<u8>,
+    ) -> Result<StagedUploadTarget, ShopifyAPIError> {
+        let target = self
+            .staged_uploads_create(std::slice::from_ref(input))
+            .await?
+            .into_iter()
+            .next()
+            .ok_or_else(|| {
+                ShopifyAPIError::NotWantedJsonFormat("no staged upload target".to_string())
+            })?;
+
+        self.upload_to_staged_target(&target, input, contents)
+            .await?;
+
+        Ok(target)
+    }
+
+    /// Stage a local file and upload it, returning its target
+    ///
+    /// The file name and size are read from the file, which is read whole with `std::fs::read`.
+    /// # Example
+    /// ```no_run
+    /// use shopify_api::*;
+    /// use shopify_api::graphql::uploads::StagedUploadResource;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let shopify = Shopify::new("myshop", "myapikey", ShopifyAPIVersion::V2026_10, None);
+    ///     let target = shopify
+    ///         .staged_upload_file(StagedUploadResource::Image, "images/shirt.png", "image/png")
+    ///         .await
+    ///         .unwrap();
+    ///     println!("{:?}", target.resource_url);
+    /// }
+    /// ```
+    pub async fn staged_upload_file(
+        &self,
+        resource: StagedUploadResource,
+        path: impl AsRef<Path>,
+        mime_type: &str,
+    ) -> Result<StagedUploadTarget, ShopifyAPIError> {
+        let path = path.as_ref();
+        let contents = std::fs::read(path).map_err(|error| {
+            ShopifyAPIError::InvalidInput(format!("cannot read {}: {}", path.display(), error))
+        })?;
+        let filename = path
+            .file_name()
+            .map(|filename| filename.to_string_lossy().into_owned())
+            .unwrap_or_default();
+
+        let input =
+            StagedUploadInput::new(resource, &filename, mime_type).file_size(contents.len() as u64);
+        self.staged_upload(&input, contents).await
+    }
+}
@@ -0,0 +1,94 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use crate::ShopifyAPIError;
+
+/// Error returned by a mutation in its `userErrors` field
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct UserError {
+    /// Path of the invalid input field
+    #[serde(default)]
+    pub field: Option<Vec<String>>,
+    pub message: String,
+    /// Error code, only returned by the mutations with typed user errors
+    #[serde(default)]
+    pub code: Option<String>,
+}
+
+impl UserError {
+    /// Collect the user errors of every mutation of a GraphQL `data` object
+    ///
+    /// Both `userErrors` and the typed variants (`customerUserErrors`, `mediaUserErrors`...) are read.
+    /// This fails with [`ShopifyAPIError::NotWantedJsonFormat`] if they cannot be read, e.g. when the
+    /// query doesn't select their `message`, rather than hiding the errors.
+    /// # Example
+    /// ```
+    /// use shopify_api::graphql::UserError;
+    ///
+    /// let data = serde_json::json!({
+    ///     "productCreate": {
+    ///         "product": null,
+    ///         "userErrors": [{"field": ["title"], "message": "Title can't be blank"}]
+    ///     }
+    /// });
+    ///
+    /// let errors = UserError::from_data(&data).unwrap();
+    /// assert_eq!(errors.len(), 1);
+    /// assert_eq!(errors[0].to_string(), "title: Title can't be blank");
+    /// ```
+    pub fn from_data(data: &serde_json::Value) -> Result<Vec<UserError>, ShopifyAPIError> {
+        let Some(mutations) = data.as_object() else {
+            return Ok(Vec::new());
+        };
+
+        let mut user_errors = Vec::new();
+        let fields = mutations
+            .values()
+            .filter_map(serde_json::Value::as_object)
+            .flat_map(|payload| payload.iter())
+            .filter(|(key, errors)| {
+                (*key == "userErrors" || key.ends_with("UserErrors")) && !errors.is_null()
+            });
+        for (key, errors) in fields {
+            let errors: Vec<UserError> =
+                serde_json::from_value(errors.clone()).map_err(|error| {
+                    ShopifyAPIError::NotWantedJsonFormat(format!(
+                        "cannot read `{}`: {}",
+                        key, error
+                    ))
+                })?;
+            user_errors.extend(errors);
+        }
+
+        Ok(user_errors)
+    }
+}
+
+impl fmt::Display for UserError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.field {
+            Some(field) if !field.is_empty() => write!(f, "{}: {}", field.join("."), self.message),
+            _ => f.write_str(&self.message),
+        }
+    }
+}
+
+/// Fail with [`ShopifyAPIError::UserErrors`] if a mutation of the `data` object returned user errors
+pub fn check_user_errors(data: &serde_json::Value) -> Result<(), ShopifyAPIError> {
+    let errors = UserError::from_data(data)?;
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(ShopifyAPIError::UserErrors(errors))
+    }
+}
+
+/// Join user errors for the error message
+pub(crate) fn format_user_errors(errors: &[UserError]) -> String {
+    errors
+        .iter()
+        .map(UserError::to_string)
+        .collect::<Vec<_>>()
+        .join("; ")
+}
@@ -0,0 +1,291 @@
+//! Product variants, created and updated in batches with the `productVariantsBulkCreate` and
+//! `productVariantsBulkUpdate` mutations
+//!
+//! These mutations replace the deprecated REST variant endpoints. The variants are described with
+//! [`ProductVariantsBulkInput`], which assembles the nested GraphQL input objects.
+
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+use crate::{utils::ReadJsonTreeSteps, Gid, Shopify, ShopifyAPIError};
+
+const VARIANT_FIELDS: &str =
+    "id title sku price compareAtPrice barcode inventoryPolicy selectedOptions { name value }";
+
+/// Whether a variant can be sold when it is out of stock
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum InventoryPolicy {
+    #[serde(rename = "DENY")]
+    Deny,
+    #[serde(rename = "CONTINUE")]
+    Continue,
+}
+
+/// Variant to create or update
+///
+/// Prices are decimal strings, e.g. `"19.99"`.
+/// # Example
+/// ```
+/// use shopify_api::Gid;
+/// use shopify_api::graphql::variants::{InventoryPolicy, ProductVariantsBulkInput};
+///
+/// let variant = ProductVariantsBulkInput::new()
+///     .option("Color", "Red")
+///     .price("19.99")
+///     .sku("SHIRT-RED")
+///     .inventory_policy(InventoryPolicy::Continue)
+///     .inventory_quantity(&Gid::location(1), 10);
+///
+/// assert_eq!(
+///     serde_json::to_value(&variant).unwrap(),
+///     serde_json::json!({
+///         "price": "19.99",
+///         "inventoryPolicy": "CONTINUE",
+///         "optionValues": [{ "optionName": "Color", "name": "Red" }],
+///         "inventoryItem": { "sku": "SHIRT-RED" },
+///         "inventoryQuantities": [{ "locationId": "gid://shopify/Location/1", "availableQuantity": 10 }]
+///     })
+/// );
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProductVariantsBulkInput {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    id: Option<Gid>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    price: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    compare_at_price: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    barcode: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    taxable: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    inventory_policy: Option<InventoryPolicy>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    option_values: Vec<VariantOptionValue>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    inventory_item: Option<InventoryItemInput>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    inventory_quantities: Vec<InventoryLevelInput>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct VariantOptionValue {
+    option_name: String,
+    name: String,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct InventoryItemInput {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    sku: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tracked: Option<bool>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct InventoryLevelInput {
+    location_id: Gid,
+    available_quantity: i64,
+}
+
+impl ProductVariantsBulkInput {
+    /// Describe a new variant, for [`Shopify::product_variants_bulk_create`]
+    pub fn new() -> ProductVariantsBulkInput {
+        ProductVariantsBulkInput::default()
+    }
+
+    /// Describe the changes of an existing variant, for [`Shopify::product_variants_bulk_update`]
+    pub fn update(id: &Gid) -> ProductVariantsBulkInput {
+        ProductVariantsBulkInput {
+            id: Some(id.clone()),
+            ..ProductVariantsBulkInput::default()
+        }
+    }
+
+    pub fn price(mut self, price: &str) -> Self {
+        self.price = Some(price.to_string());
+        self
+    }
+
+    pub fn compare_at_price(mut self, compare_at_price: &str) -> Self {
+        self.compare_at_price = Some(compare_at_price.to_string());
+        self
+    }
+
+    pub fn barcode(mut self, barcode: &str) -> Self {
+        self.barcode = Some(barcode.to_string());
+        self
+    }
+
+    pub fn taxable(mut self, taxable: bool) -> Self {
+        self.taxable = Some(taxable);
+        self
+    }
+
+    pub fn inventory_policy(mut self, inventory_policy: InventoryPolicy) -> Self {
+        self.inventory_policy = Some(inventory_policy);
+        self
+    }
+
+    /// Set the value of an option of the product, e.g. `Color` and `Red`
+    pub fn option(mut self, option_name: &str, value: &str) -> Self {
+        self.option_values.push(VariantOptionValue {
+            option_name: option_name.to_string(),
+            name: value.to_string(),
+        });
+        self
+    }
+
+    /// Set the SKU of the inventory item of the variant
+    pub fn sku(mut self, sku: &str) -> Self {
+        self.inventory_item.get_or_insert_with(Default::default).sku = Some(sku.to_string());
+        self
+    }
+
+    /// Set whether the inventory of the variant is tracked
+    pub fn tracked(mut self, tracked: bool) -> Self {
+        self.inventory_item
+            .get_or_insert_with(Default::default)
+            .tracked = Some(tracked);
+        self
+    }
+
+    /// Set the initial quantity available at a location, only used when creating the variant
+    pub fn inventory_quantity(mut self, location: &Gid, available_quantity: i64) -> Self {
+        self.inventory_quantities.push(InventoryLevelInput {
+            location_id: location.clone(),
+            available_quantity,
+        });
+        self
+    }
+}
+
+/// Option value of a variant, e.g. `Color` and `Red`
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SelectedOption {
+    pub name: String,
+    pub value: String,
+}
+
+/// Variant returned by the bulk mutations
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProductVariant {
+    pub id: Gid,
+    pub title: String,
+    pub sku: Option<String>,
+    pub price: String,
+    pub compare_at_price: Option<String>,
+    pub barcode: Option<String>,
+    pub inventory_policy: InventoryPolicy,
+    #[serde(default)]
+    pub selected_options: Vec<SelectedOption>,
+}
+
+impl Shopify {
+    /// Create variants of a product with `productVariantsBulkCreate`
+    ///
+    /// This fails with [`ShopifyAPIError::UserErrors`] if a variant is invalid, in which case
+    /// no variant is created.
+    /// # Example
+    /// ```no_run
+    /// use shopify_api::*;
+    /// use shopify_api::graphql::variants::ProductVariantsBulkInput;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let shopify = Shopify::new("myshop", "myapikey", ShopifyAPIVersion::V2026_10, None);
+    ///     let variants = vec![
+    ///         ProductVariantsBulkInput::new().option("Size", "S").price("19.99").sku("SHIRT-S"),
+    ///         ProductVariantsBulkInput::new().option("Size", "M").price("19.99").sku("SHIRT-M"),
+    ///     ];
+    ///
+    ///     let created = shopify
+    ///         .product_variants_bulk_create(&Gid::product(1), &variants)
+    ///         .await
+    ///         .unwrap();
+    ///     println!("{} variants created", created.len());
+    /// }
+    /// ```
+    pub async fn product_variants_bulk_create(
+        &self,
+        product_id: &Gid,
+        variants: &[ProductVariantsBulkInput],
+    ) -> Result<Vec<ProductVariant>, ShopifyAPIError> {
+        let mutation = format!(
+            r#"
+            mutation($productId: ID!, $variants: [ProductVariantsBulkInput!]!) {{
+                productVariantsBulkCreate(productId: $productId, variants: $variants) {{
+                    productVariants {{ {VARIANT_FIELDS} }}
+                    userErrors {{ field message code }}
+                }}
+            }}"#
+        );
+
+        self.graphql_mutation(
+            &mutation,
+            &json!({ "productId": product_id, "variants": variants }),
+            &vec![
+                ReadJsonTreeSteps::Key("data"),
+                ReadJsonTreeSteps::Key("productVariantsBulkCreate"),
+                ReadJsonTreeSteps::Key("productVariants"),
+            ],
+        )
+        .await
+    }
+
+    /// Update variants of a product with `productVariantsBulkUpdate`
+    ///
+    /// Only the fields set on each [`ProductVariantsBulkInput::update`] are changed. This fails with
+    /// [`ShopifyAPIError::UserErrors`] if a variant is invalid, in which case no variant is updated.
+    /// # Example
+    /// ```no_run
+    /// use shopify_api::*;
+    /// use shopify_api::graphql::variants::ProductVariantsBulkInput;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let shopify = Shopify::new("myshop", "myapikey", ShopifyAPIVersion::V2026_10, None);
+    ///     let variants = vec![
+    ///         ProductVariantsBulkInput::update(&Gid::product_variant(10)).price("14.99"),
+    ///         ProductVariantsBulkInput::update(&Gid::product_variant(11)).price("14.99"),
+    ///     ];
+    ///
+    ///     shopify
+    ///         .product_variants_bulk_update(&Gid::product(1), &variants)
+    ///         .await
+    ///         .unwrap();
+    /// }
+    /// ```
+    pub async fn product_variants_bulk_update(
+        &self,
+        product_id: &Gid,
+        variants: &[ProductVariantsBulkInput],
+    ) -> Result<Vec<ProductVariant>, ShopifyAPIError> {
+        let mutation = format!(
+            r#"
+            mutation($productId: ID!, $variants: [ProductVariantsBulkInput!]!) {{
+                productVariantsBulkUpdate(productId: $productId, variants: $variants) {{
+                    productVariants {{ {VARIANT_FIELDS} }}
+                    userErrors {{ field message code }}
+                }}
+            }}"#
+        );
+
+        self.graphql_mutation(
+            &mutation,
+            &json!({ "productId": product_id, "variants": variants }),
+            &vec![
+                ReadJsonTreeSteps::Key("data"),
+                ReadJsonTreeSteps::Key("productVariantsBulkUpdate"),
+                ReadJsonTreeSteps::Key("productVariants"),
+            ],
+        )
+        .await
+    }
+}
@@ -0,0 +1,185 @@
+//! Web pixel of the app, managed with the `webPixelCreate`, `webPixelUpdate` and `webPixelDelete`
+//! mutations
+//!
+//! A web pixel extension of the app subscribes to the customer events of the storefront and the
+//! checkout. Its settings, declared in the `shopify.extension.toml` of the extension, are given as
+//! any serializable type and sent as the JSON string expected by the mutations.
+
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use serde_json::json;
+
+use crate::{utils::ReadJsonTreeSteps, Gid, Shopify, ShopifyAPIError};
+
+/// Web pixel of the app, with its typed settings
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct WebPixel<Settings> {
+    pub id: Gid,
+    pub settings: Settings,
+}
+
+#[derive(Deserialize)]
+struct RawWebPixel {
+    id: Gid,
+    settings: serde_json::Value,
+}
+
+impl RawWebPixel {
+    /// Read the settings, returned as a JSON string or as an object
+    fn into_web_pixel<Settings>(self) -> Result<WebPixel<Settings>, ShopifyAPIError>
+    where
+        Settings: DeserializeOwned,
+    {
+        let settings = match self.settings {
+            serde_json::Value::String(settings) => serde_json::from_str(&settings)?,
+            settings => serde_json::from_value(settings)?,
+        };
+
+        Ok(WebPixel {
+            id: self.id,
+            settings,
+        })
+    }
+}
+
+/// Render the `WebPixelInput` of the mutations
+fn web_pixel_input<Settings: Serialize>(
+    settings: &Settings,
+) -> Result<serde_json::Value, ShopifyAPIError> {
+    Ok(json!({ "settings": serde_json::to_string(settings)? }))
+}
+
+impl Shopify {
+    /// Get the web pixel of the app with `webPixel`
+    pub async fn web_pixel<Settings>(&self) -> Result<WebPixel<Settings>, ShopifyAPIError>
+    where
+        Settings: DeserializeOwned,
+    {
+        let query = r#"
+            query {
+                webPixel { id settings }
+            }"#;
+
+        let raw: RawWebPixel = self
+            .graphql_query(
+                query,
+                &json!({}),
+                &vec![
+                    ReadJsonTreeSteps::Key("data"),
+                    ReadJsonTreeSteps::Key("webPixel"),
+                ],
+            )
+            .await?;
+
+        raw.into_web_pixel()
+    }
+
+    /// Activate the web pixel of the app with `webPixelCreate`
+    ///
+    /// This fails with [`ShopifyAPIError::UserErrors`] if the settings don't match the ones
+    /// declared by the extension, or if the pixel is already activated.
+    /// # Example
+    /// ```no_run
+    /// use serde::{Deserialize, Serialize};
+    /// use shopify_api::*;
+    ///
+    /// #[derive(Debug, Serialize, Deserialize)]
+    /// struct PixelSettings {
+    ///     #[serde(rename = "accountID")]
+    ///     account_id: String,
+    /// }
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let shopify = Shopify::new("myshop", "myapikey", ShopifyAPIVersion::V2026_10, None);
+    ///     let settings = PixelSettings {
+    ///         account_id: "234".to_string(),
+    ///     };
+    ///
+    ///     let pixel = shopify.web_pixel_create(&settings).await.unwrap();
+    ///     println!("{} {:?}", pixel.id, pixel.settings);
+    /// }
+    /// ```
+    pub async fn web_pixel_create<Settings>(
+        &self,
+        settings: &Settings,
+    ) -> Result<WebPixel<Settings>, ShopifyAPIError>
+    where
+        Settings: Serialize + DeserializeOwned,
+    {
+        let mutation = r#"
+            mutation($webPixel: WebPixelInput!) {
+                webPixelCreate(webPixel: $webPixel) {
+                    webPixel { id settings }
+                    userErrors { field message code }
+                }
+            }"#;
+
+        let raw: RawWebPixel = self
+            .graphql_mutation(
+                mutation,
+                &json!({ "webPixel": web_pixel_input(settings)? }),
+                &vec![
+                    ReadJsonTreeSteps::Key("data"),
+                    ReadJsonTreeSteps::Key("webPixelCreate"),
+                    ReadJsonTreeSteps::Key("webPixel"),
+                ],
+            )
+            .await?;
+
+        raw.into_web_pixel()
+    }
+
+    /// Replace the settings of the web pixel of the app with `webPixelUpdate`
+    pub async fn web_pixel_update<Settings>(
+        &self,
+        id: &Gid,
+        settings: &Settings,
+    ) -> Result<WebPixel<Settings>, ShopifyAPIError>
+    where
+        Settings: Serialize + DeserializeOwned,
+    {
+        let mutation = r#"
+            mutation($id: ID!, $webPixel: WebPixelInput!) {
+                webPixelUpdate(id: $id, webPixel: $webPixel) {
+                    webPixel { id settings }
+                    userErrors { field message code }
+                }
+            }"#;
+
+        let raw: RawWebPixel = self
+            .graphql_mutation(
+                mutation,
+                &json!({ "id": id, "webPixel": web_pixel_input(settings)? }),
+                &vec![
+                    ReadJsonTreeSteps::Key("data"),
+                    ReadJsonTreeSteps::Key("webPixelUpdate"),
+                    ReadJsonTreeSteps::Key("webPixel"),
+                ],
+            )
+            .await?;
+
+        raw.into_web_pixel()
+    }
+
+    /// Deactivate the web pixel of the app with `webPixelDelete`, returning its ID
+    pub async fn web_pixel_delete(&self, id: &Gid) -> Result<Gid, ShopifyAPIError> {
+        let mutation = r#"
+            mutation($id: ID!) {
+                webPixelDelete(id: $id) {
+                    deletedWebPixelId
+                    userErrors { field message code }
+                }
+            }"#;
+
+        self.graphql_mutation(
+            mutation,
+            &json!({ "id": id }),
+            &vec![
+                ReadJsonTreeSteps::Key("data"),
+                ReadJsonTreeSteps::Key("webPixelDelete"),
+                ReadJsonTreeSteps::Key("deletedWebPixelId"),
+            ],
+        )
+        .await
+    }
+}
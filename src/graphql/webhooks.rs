@@ -0,0 +1,728 @@
+//! Webhook subscriptions, managed with the `webhookSubscriptionCreate`, `webhookSubscriptionUpdate`
+//! and `webhookSubscriptionDelete` mutations
+//!
+//! A subscription sends the events of a [`WebhookTopic`] to a [`WebhookEndpoint`]: an HTTPS callback
+//! URL, an Amazon EventBridge event source ARN or a Google Cloud Pub/Sub topic. The endpoint is given
+//! as the `uri` of the subscription, available since the `2025-04` API version.
+
+use std::{fmt, str::FromStr};
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::json;
+
+use crate::{utils::ReadJsonTreeSteps, Shopify, ShopifyAPIError};
+
+const SUBSCRIPTION_FIELDS: &str = "id topic uri format includeFields filter";
+
+/// Topic of a webhook subscription, e.g. `ORDERS_CREATE`
+///
+/// Topics without a dedicated variant are kept in [`WebhookTopic::Other`].
+/// # Example
+/// ```
+/// use shopify_api::graphql::webhooks::WebhookTopic;
+///
+/// assert_eq!(WebhookTopic::OrdersCreate.as_str(), "ORDERS_CREATE");
+/// assert_eq!("PRODUCTS_UPDATE".parse::<WebhookTopic>().unwrap(), WebhookTopic::ProductsUpdate);
+/// assert_eq!(
+///     "MARKETS_CREATE".parse::<WebhookTopic>().unwrap(),
+///     WebhookTopic::Other("MARKETS_CREATE".to_string())
+/// );
+///
+/// // REST names, as used by the REST webhooks and the `X-Shopify-Topic` header
+/// assert_eq!(WebhookTopic::OrdersPartiallyFulfilled.rest_name(), "orders/partially_fulfilled");
+/// assert_eq!(WebhookTopic::from_rest_name("app/uninstalled"), WebhookTopic::AppUninstalled);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum WebhookTopic {
+    AppUninstalled,
+    AppPurchasesOneTimeUpdate,
+    AppSubscriptionsApproachingCappedAmount,
+    AppSubscriptionsUpdate,
+    BulkOperationsFinish,
+    CartsCreate,
+    CartsUpdate,
+    CheckoutsCreate,
+    CheckoutsUpdate,
+    CheckoutsDelete,
+    CollectionListingsAdd,
+    CollectionListingsRemove,
+    CollectionListingsUpdate,
+    CollectionsCreate,
+    CollectionsUpdate,
+    CollectionsDelete,
+    CustomersCreate,
+    CustomersUpdate,
+    CustomersDelete,
+    CustomersDisable,
+    CustomersEnable,
+    CustomersEmailMarketingConsentUpdate,
+    CustomersMarketingConsentUpdate,
+    DisputesCreate,
+    DisputesUpdate,
+    DomainsCreate,
+    DomainsUpdate,
+    DomainsDestroy,
+    DraftOrdersCreate,
+    DraftOrdersUpdate,
+    DraftOrdersDelete,
+    FulfillmentEventsCreate,
+    FulfillmentEventsDelete,
+    FulfillmentOrdersCancellationRequestAccepted,
+    FulfillmentOrdersCancellationRequestRejected,
+    FulfillmentOrdersCancellationRequestSubmitted,
+    FulfillmentOrdersCancelled,
+    FulfillmentOrdersFulfillmentRequestAccepted,
+    FulfillmentOrdersFulfillmentRequestRejected,
+    FulfillmentOrdersFulfillmentRequestSubmitted,
+    FulfillmentOrdersHoldReleased,
+    FulfillmentOrdersMoved,
+    FulfillmentOrdersOrderRoutingComplete,
+    FulfillmentOrdersPlacedOnHold,
+    FulfillmentOrdersReadyToFulfill,
+    FulfillmentOrdersRescheduled,
+    FulfillmentsCreate,
+    FulfillmentsUpdate,
+    InventoryItemsCreate,
+    InventoryItemsUpdate,
+    InventoryItemsDelete,
+    InventoryLevelsConnect,
+    InventoryLevelsUpdate,
+    InventoryLevelsDisconnect,
+    LocalesCreate,
+    LocalesUpdate,
+    LocationsActivate,
+    LocationsCreate,
+    LocationsDeactivate,
+    LocationsDelete,
+    LocationsUpdate,
+    OrderTransactionsCreate,
+    OrdersCreate,
+    OrdersUpdated,
+    OrdersPaid,
+    OrdersCancelled,
+    OrdersEdited,
+    OrdersFulfilled,
+    OrdersPartiallyFulfilled,
+    OrdersDelete,
+    ProductListingsAdd,
+    ProductListingsRemove,
+    ProductListingsUpdate,
+    ProductsCreate,
+    ProductsUpdate,
+    ProductsDelete,
+    RefundsCreate,
+    SellingPlanGroupsCreate,
+    SellingPlanGroupsUpdate,
+    SellingPlanGroupsDelete,
+    ShopUpdate,
+    SubscriptionContractsCreate,
+    SubscriptionContractsUpdate,
+    TenderTransactionsCreate,
+    ThemesCreate,
+    ThemesPublish,
+    ThemesUpdate,
+    ThemesDelete,
+
+    /// Any other topic, by its GraphQL name
+    Other(String),
+}
+
+impl WebhookTopic {
+    /// Get the GraphQL name of the topic
+    pub fn as_str(&self) -> &str {
+        match self {
+            WebhookTopic::AppUninstalled => "APP_UNINSTALLED",
+            WebhookTopic::AppPurchasesOneTimeUpdate => "APP_PURCHASES_ONE_TIME_UPDATE",
+            WebhookTopic::AppSubscriptionsApproachingCappedAmount => {
+                "APP_SUBSCRIPTIONS_APPROACHING_CAPPED_AMOUNT"
+            }
+            WebhookTopic::AppSubscriptionsUpdate => "APP_SUBSCRIPTIONS_UPDATE",
+            WebhookTopic::BulkOperationsFinish => "BULK_OPERATIONS_FINISH",
+            WebhookTopic::CartsCreate => "CARTS_CREATE",
+            WebhookTopic::CartsUpdate => "CARTS_UPDATE",
+            WebhookTopic::CheckoutsCreate => "CHECKOUTS_CREATE",
+            WebhookTopic::CheckoutsUpdate => "CHECKOUTS_UPDATE",
+            WebhookTopic::CheckoutsDelete => "CHECKOUTS_DELETE",
+            WebhookTopic::CollectionListingsAdd => "COLLECTION_LISTINGS_ADD",
+            WebhookTopic::CollectionListingsRemove => "COLLECTION_LISTINGS_REMOVE",
+            WebhookTopic::CollectionListingsUpdate => "COLLECTION_LISTINGS_UPDATE",
+            WebhookTopic::CollectionsCreate => "COLLECTIONS_CREATE",
+            WebhookTopic::CollectionsUpdate => "COLLECTIONS_UPDATE",
+            WebhookTopic::CollectionsDelete => "COLLECTIONS_DELETE",
+            WebhookTopic::CustomersCreate => "CUSTOMERS_CREATE",
+            WebhookTopic::CustomersUpdate => "CUSTOMERS_UPDATE",
+            WebhookTopic::CustomersDelete => "CUSTOMERS_DELETE",
+            WebhookTopic::CustomersDisable => "CUSTOMERS_DISABLE",
+            WebhookTopic::CustomersEnable => "CUSTOMERS_ENABLE",
+            WebhookTopic::CustomersEmailMarketingConsentUpdate => {
+                "CUSTOMERS_EMAIL_MARKETING_CONSENT_UPDATE"
+            }
+            WebhookTopic::CustomersMarketingConsentUpdate => "CUSTOMERS_MARKETING_CONSENT_UPDATE",
+            WebhookTopic::DisputesCreate => "DISPUTES_CREATE",
+            WebhookTopic::DisputesUpdate => "DISPUTES_UPDATE",
+            WebhookTopic::DomainsCreate => "DOMAINS_CREATE",
+            WebhookTopic::DomainsUpdate => "DOMAINS_UPDATE",
+            WebhookTopic::DomainsDestroy => "DOMAINS_DESTROY",
+            WebhookTopic::DraftOrdersCreate => "DRAFT_ORDERS_CREATE",
+            WebhookTopic::DraftOrdersUpdate => "DRAFT_ORDERS_UPDATE",
+            WebhookTopic::DraftOrdersDelete => "DRAFT_ORDERS_DELETE",
+            WebhookTopic::FulfillmentEventsCreate => "FULFILLMENT_EVENTS_CREATE",
+            WebhookTopic::FulfillmentEventsDelete => "FULFILLMENT_EVENTS_DELETE",
+            WebhookTopic::FulfillmentOrdersCancellationRequestAccepted => {
+                "FULFILLMENT_ORDERS_CANCELLATION_REQUEST_ACCEPTED"
+            }
+            WebhookTopic::FulfillmentOrdersCancellationRequestRejected => {
+                "FULFILLMENT_ORDERS_CANCELLATION_REQUEST_REJECTED"
+            }
+            WebhookTopic::FulfillmentOrdersCancellationRequestSubmitted => {
+                "FULFILLMENT_ORDERS_CANCELLATION_REQUEST_SUBMITTED"
+            }
+            WebhookTopic::FulfillmentOrdersCancelled => "FULFILLMENT_ORDERS_CANCELLED",
+            WebhookTopic::FulfillmentOrdersFulfillmentRequestAccepted => {
+                "FULFILLMENT_ORDERS_FULFILLMENT_REQUEST_ACCEPTED"
+            }
+            WebhookTopic::FulfillmentOrdersFulfillmentRequestRejected => {
+                "FULFILLMENT_ORDERS_FULFILLMENT_REQUEST_REJECTED"
+            }
+            WebhookTopic::FulfillmentOrdersFulfillmentRequestSubmitted => {
+                "FULFILLMENT_ORDERS_FULFILLMENT_REQUEST_SUBMITTED"
+            }
+            WebhookTopic::FulfillmentOrdersHoldReleased => "FULFILLMENT_ORDERS_HOLD_RELEASED",
+            WebhookTopic::FulfillmentOrdersMoved => "FULFILLMENT_ORDERS_MOVED",
+            WebhookTopic::FulfillmentOrdersOrderRoutingComplete => {
+                "FULFILLMENT_ORDERS_ORDER_ROUTING_COMPLETE"
+            }
+            WebhookTopic::FulfillmentOrdersPlacedOnHold => "FULFILLMENT_ORDERS_PLACED_ON_HOLD",
+            WebhookTopic::FulfillmentOrdersReadyToFulfill => "FULFILLMENT_ORDERS_READY_TO_FULFILL",
+            WebhookTopic::FulfillmentOrdersRescheduled => "FULFILLMENT_ORDERS_RESCHEDULED",
+            WebhookTopic::FulfillmentsCreate => "FULFILLMENTS_CREATE",
+            WebhookTopic::FulfillmentsUpdate => "FULFILLMENTS_UPDATE",
+            WebhookTopic::InventoryItemsCreate => "INVENTORY_ITEMS_CREATE",
+            WebhookTopic::InventoryItemsUpdate => "INVENTORY_ITEMS_UPDATE",
+            WebhookTopic::InventoryItemsDelete => "INVENTORY_ITEMS_DELETE",
+            WebhookTopic::InventoryLevelsConnect => "INVENTORY_LEVELS_CONNECT",
+            WebhookTopic::InventoryLevelsUpdate => "INVENTORY_LEVELS_UPDATE",
+            WebhookTopic::InventoryLevelsDisconnect => "INVENTORY_LEVELS_DISCONNECT",
+            WebhookTopic::LocalesCreate => "LOCALES_CREATE",
+            WebhookTopic::LocalesUpdate => "LOCALES_UPDATE",
+            WebhookTopic::LocationsActivate => "LOCATIONS_ACTIVATE",
+            WebhookTopic::LocationsCreate => "LOCATIONS_CREATE",
+            WebhookTopic::LocationsDeactivate => "LOCATIONS_DEACTIVATE",
+            WebhookTopic::LocationsDelete => "LOCATIONS_DELETE",
+            WebhookTopic::LocationsUpdate => "LOCATIONS_UPDATE",
+            WebhookTopic::OrderTransactionsCreate => "ORDER_TRANSACTIONS_CREATE",
+            WebhookTopic::OrdersCreate => "ORDERS_CREATE",
+            WebhookTopic::OrdersUpdated => "ORDERS_UPDATED",
+            WebhookTopic::OrdersPaid => "ORDERS_PAID",
+            WebhookTopic::OrdersCancelled => "ORDERS_CANCELLED",
+            WebhookTopic::OrdersEdited => "ORDERS_EDITED",
+            WebhookTopic::OrdersFulfilled => "ORDERS_FULFILLED",
+            WebhookTopic::OrdersPartiallyFulfilled => "ORDERS_PARTIALLY_FULFILLED",
+            WebhookTopic::OrdersDelete => "ORDERS_DELETE",
+            WebhookTopic::ProductListingsAdd => "PRODUCT_LISTINGS_ADD",
+            WebhookTopic::ProductListingsRemove => "PRODUCT_LISTINGS_REMOVE",
+            WebhookTopic::ProductListingsUpdate => "PRODUCT_LISTINGS_UPDATE",
+            WebhookTopic::ProductsCreate => "PRODUCTS_CREATE",
+            WebhookTopic::ProductsUpdate => "PRODUCTS_UPDATE",
+            WebhookTopic::ProductsDelete => "PRODUCTS_DELETE",
+            WebhookTopic::RefundsCreate => "REFUNDS_CREATE",
+            WebhookTopic::SellingPlanGroupsCreate => "SELLING_PLAN_GROUPS_CREATE",
+            WebhookTopic::SellingPlanGroupsUpdate => "SELLING_PLAN_GROUPS_UPDATE",
+            WebhookTopic::SellingPlanGroupsDelete => "SELLING_PLAN_GROUPS_DELETE",
+            WebhookTopic::ShopUpdate => "SHOP_UPDATE",
+            WebhookTopic::SubscriptionContractsCreate => "SUBSCRIPTION_CONTRACTS_CREATE",
+            WebhookTopic::SubscriptionContractsUpdate => "SUBSCRIPTION_CONTRACTS_UPDATE",
+            WebhookTopic::TenderTransactionsCreate => "TENDER_TRANSACTIONS_CREATE",
+            WebhookTopic::ThemesCreate => "THEMES_CREATE",
+            WebhookTopic::ThemesPublish => "THEMES_PUBLISH",
+            WebhookTopic::ThemesUpdate => "THEMES_UPDATE",
+            WebhookTopic::ThemesDelete => "THEMES_DELETE",
+            WebhookTopic::Other(topic) => topic,
+        }
+    }
+
+    /// Get the REST name of the topic, e.g. `orders/create`, as used by the REST `webhooks.json`
+    /// endpoints and the `X-Shopify-Topic` header of the webhooks
+    ///
+    /// The REST name of an [`WebhookTopic::Other`] topic is derived from its GraphQL name, the
+    /// action being the last word, e.g. `markets/create` for `MARKETS_CREATE`.
+    pub fn rest_name(&self) -> String {
+        let name = match self {
+            WebhookTopic::AppUninstalled => "app/uninstalled",
+            WebhookTopic::AppPurchasesOneTimeUpdate => "app_purchases_one_time/update",
+            WebhookTopic::AppSubscriptionsApproachingCappedAmount => {
+                "app_subscriptions/approaching_capped_amount"
+            }
+            WebhookTopic::AppSubscriptionsUpdate => "app_subscriptions/update",
+            WebhookTopic::BulkOperationsFinish => "bulk_operations/finish",
+            WebhookTopic::CartsCreate => "carts/create",
+            WebhookTopic::CartsUpdate => "carts/update",
+            WebhookTopic::CheckoutsCreate => "checkouts/create",
+            WebhookTopic::CheckoutsUpdate => "checkouts/update",
+            WebhookTopic::CheckoutsDelete => "checkouts/delete",
+            WebhookTopic::CollectionListingsAdd => "collection_listings/add",
+            WebhookTopic::CollectionListingsRemove => "collection_listings/remove",
+            WebhookTopic::CollectionListingsUpdate => "collection_listings/update",
+            WebhookTopic::CollectionsCreate => "collections/create",
+            WebhookTopic::CollectionsUpdate => "collections/update",
+            WebhookTopic::CollectionsDelete => "collections/delete",
+            WebhookTopic::CustomersCreate => "customers/create",
+            WebhookTopic::CustomersUpdate => "customers/update",
+            WebhookTopic::CustomersDelete => "customers/delete",
+            WebhookTopic::CustomersDisable => "customers/disable",
+            WebhookTopic::CustomersEnable => "customers/enable",
+            WebhookTopic::CustomersEmailMarketingConsentUpdate => {
+                "customers_email_marketing_consent/update"
+            }
+            WebhookTopic::CustomersMarketingConsentUpdate => "customers_marketing_consent/update",
+            WebhookTopic::DisputesCreate => "disputes/create",
+            WebhookTopic::DisputesUpdate => "disputes/update",
+            WebhookTopic::DomainsCreate => "domains/create",
+            WebhookTopic::DomainsUpdate => "domains/update",
+            WebhookTopic::DomainsDestroy => "domains/destroy",
+            WebhookTopic::DraftOrdersCreate => "draft_orders/create",
+            WebhookTopic::DraftOrdersUpdate => "draft_orders/update",
+            WebhookTopic::DraftOrdersDelete => "draft_orders/delete",
+            WebhookTopic::FulfillmentEventsCreate => "fulfillment_events/create",
+            WebhookTopic::FulfillmentEventsDelete => "fulfillment_events/delete",
+            WebhookTopic::FulfillmentOrdersCancellationRequestAccepted => {
+                "fulfillment_orders/cancellation_request_accepted"
+            }
+            WebhookTopic::FulfillmentOrdersCancellationRequestRejected => {
+                "fulfillment_orders/cancellation_request_rejected"
+            }
+            WebhookTopic::FulfillmentOrdersCancellationRequestSubmitted => {
+                "fulfillment_orders/cancellation_request_submitted"
+            }
+            WebhookTopic::FulfillmentOrdersCancelled => "fulfillment_orders/cancelled",
+            WebhookTopic::FulfillmentOrdersFulfillmentRequestAccepted => {
+                "fulfillment_orders/fulfillment_request_accepted"
+            }
+            WebhookTopic::FulfillmentOrdersFulfillmentRequestRejected => {
+                "fulfillment_orders/fulfillment_request_rejected"
+            }
+            WebhookTopic::FulfillmentOrdersFulfillmentRequestSubmitted => {
+                "fulfillment_orders/fulfillment_request_submitted"
+            }
+            WebhookTopic::FulfillmentOrdersHoldReleased => "fulfillment_orders/hold_released",
+            WebhookTopic::FulfillmentOrdersMoved => "fulfillment_orders/moved",
+            WebhookTopic::FulfillmentOrdersOrderRoutingComplete => {
+                "fulfillment_orders/order_routing_complete"
+            }
+            WebhookTopic::FulfillmentOrdersPlacedOnHold => "fulfillment_orders/placed_on_hold",
+            WebhookTopic::FulfillmentOrdersReadyToFulfill => "fulfillment_orders/ready_to_fulfill",
+            WebhookTopic::FulfillmentOrdersRescheduled => "fulfillment_orders/rescheduled",
+            WebhookTopic::FulfillmentsCreate => "fulfillments/create",
+            WebhookTopic::FulfillmentsUpdate => "fulfillments/update",
+            WebhookTopic::InventoryItemsCreate => "inventory_items/create",
+            WebhookTopic::InventoryItemsUpdate => "inventory_items/update",
+            WebhookTopic::InventoryItemsDelete => "inventory_items/delete",
+            WebhookTopic::InventoryLevelsConnect => "inventory_levels/connect",
+            WebhookTopic::InventoryLevelsUpdate => "inventory_levels/update",
+            WebhookTopic::InventoryLevelsDisconnect => "inventory_levels/disconnect",
+            WebhookTopic::LocalesCreate => "locales/create",
+            WebhookTopic::LocalesUpdate => "locales/update",
+            WebhookTopic::LocationsActivate => "locations/activate",
+            WebhookTopic::LocationsCreate => "locations/create",
+            WebhookTopic::LocationsDeactivate => "locations/deactivate",
+            WebhookTopic::LocationsDelete => "locations/delete",
+            WebhookTopic::LocationsUpdate => "locations/update",
+            WebhookTopic::OrderTransactionsCreate => "order_transactions/create",
+            WebhookTopic::OrdersCreate => "orders/create",
+            WebhookTopic::OrdersUpdated => "orders/updated",
+            WebhookTopic::OrdersPaid => "orders/paid",
+            WebhookTopic::OrdersCancelled => "orders/cancelled",
+            WebhookTopic::OrdersEdited => "orders/edited",
+            WebhookTopic::OrdersFulfilled => "orders/fulfilled",
+            WebhookTopic::OrdersPartiallyFulfilled => "orders/partially_fulfilled",
+            WebhookTopic::OrdersDelete => "orders/delete",
+            WebhookTopic::ProductListingsAdd => "product_listings/add",
+            WebhookTopic::ProductListingsRemove => "product_listings/remove",
+            WebhookTopic::ProductListingsUpdate => "product_listings/update",
+            WebhookTopic::ProductsCreate => "products/create",
+            WebhookTopic::ProductsUpdate => "products/update",
+            WebhookTopic::ProductsDelete => "products/delete",
+            WebhookTopic::RefundsCreate => "refunds/create",
+            WebhookTopic::SellingPlanGroupsCreate => "selling_plan_groups/create",
+            WebhookTopic::SellingPlanGroupsUpdate => "selling_plan_groups/update",
+            WebhookTopic::SellingPlanGroupsDelete => "selling_plan_groups/delete",
+            WebhookTopic::ShopUpdate => "shop/update",
+            WebhookTopic::SubscriptionContractsCreate => "subscription_contracts/create",
+            WebhookTopic::SubscriptionContractsUpdate => "subscription_contracts/update",
+            WebhookTopic::TenderTransactionsCreate => "tender_transactions/create",
+            WebhookTopic::ThemesCreate => "themes/create",
+            WebhookTopic::ThemesPublish => "themes/publish",
+            WebhookTopic::ThemesUpdate => "themes/update",
+            WebhookTopic::ThemesDelete => "themes/delete",
+            WebhookTopic::Other(topic) => {
+                let topic = topic.to_lowercase();
+                return match topic.rsplit_once('_') {
+                    Some((resource, action)) => format!("{}/{}", resource, action),
+                    None => topic,
+                };
+            }
+        };
+        name.to_string()
+    }
+
+    /// Parse the REST name of a topic, e.g. `orders/create`
+    pub fn from_rest_name(topic: &str) -> WebhookTopic {
+        topic
+            .to_uppercase()
+            .replace('/', "_")
+            .parse()
+            .unwrap_or_else(|never| match never {})
+    }
+}
+
+impl FromStr for WebhookTopic {
+    type Err = std::convert::Infallible;
+
+    fn from_str(topic: &str) -> Result<Self, Self::Err> {
+        Ok(match topic {
+            "APP_UNINSTALLED" => WebhookTopic::AppUninstalled,
+            "APP_PURCHASES_ONE_TIME_UPDATE" => WebhookTopic::AppPurchasesOneTimeUpdate,
+            "APP_SUBSCRIPTIONS_APPROACHING_CAPPED_AMOUNT" => {
+                WebhookTopic::AppSubscriptionsApproachingCappedAmount
+            }
+            "APP_SUBSCRIPTIONS_UPDATE" => WebhookTopic::AppSubscriptionsUpdate,
+            "BULK_OPERATIONS_FINISH" => WebhookTopic::BulkOperationsFinish,
+            "CARTS_CREATE" => WebhookTopic::CartsCreate,
+            "CARTS_UPDATE" => WebhookTopic::CartsUpdate,
+            "CHECKOUTS_CREATE" => WebhookTopic::CheckoutsCreate,
+            "CHECKOUTS_UPDATE" => WebhookTopic::CheckoutsUpdate,
+            "CHECKOUTS_DELETE" => WebhookTopic::CheckoutsDelete,
+            "COLLECTION_LISTINGS_ADD" => WebhookTopic::CollectionListingsAdd,
+            "COLLECTION_LISTINGS_REMOVE" => WebhookTopic::CollectionListingsRemove,
+            "COLLECTION_LISTINGS_UPDATE" => WebhookTopic::CollectionListingsUpdate,
+            "COLLECTIONS_CREATE" => WebhookTopic::CollectionsCreate,
+            "COLLECTIONS_UPDATE" => WebhookTopic::CollectionsUpdate,
+            "COLLECTIONS_DELETE" => WebhookTopic::CollectionsDelete,
+            "CUSTOMERS_CREATE" => WebhookTopic::CustomersCreate,
+            "CUSTOMERS_UPDATE" => WebhookTopic::CustomersUpdate,
+            "CUSTOMERS_DELETE" => WebhookTopic::CustomersDelete,
+            "CUSTOMERS_DISABLE" => WebhookTopic::CustomersDisable,
+            "CUSTOMERS_ENABLE" => WebhookTopic::CustomersEnable,
+            "CUSTOMERS_EMAIL_MARKETING_CONSENT_UPDATE" => {
+                WebhookTopic::CustomersEmailMarketingConsentUpdate
+            }
+            "CUSTOMERS_MARKETING_CONSENT_UPDATE" => WebhookTopic::CustomersMarketingConsentUpdate,
+            "DISPUTES_CREATE" => WebhookTopic::DisputesCreate,
+            "DISPUTES_UPDATE" => WebhookTopic::DisputesUpdate,
+            "DOMAINS_CREATE" => WebhookTopic::DomainsCreate,
+            "DOMAINS_UPDATE" => WebhookTopic::DomainsUpdate,
+            "DOMAINS_DESTROY" => WebhookTopic::DomainsDestroy,
+            "DRAFT_ORDERS_CREATE" => WebhookTopic::DraftOrdersCreate,
+            "DRAFT_ORDERS_UPDATE" => WebhookTopic::DraftOrdersUpdate,
+            "DRAFT_ORDERS_DELETE" => WebhookTopic::DraftOrdersDelete,
+            "FULFILLMENT_EVENTS_CREATE" => WebhookTopic::FulfillmentEventsCreate,
+            "FULFILLMENT_EVENTS_DELETE" => WebhookTopic::FulfillmentEventsDelete,
+            "FULFILLMENT_ORDERS_CANCELLATION_REQUEST_ACCEPTED" => {
+                WebhookTopic::FulfillmentOrdersCancellationRequestAccepted
+            }
+            "FULFILLMENT_ORDERS_CANCELLATION_REQUEST_REJECTED" => {
+                WebhookTopic::FulfillmentOrdersCancellationRequestRejected
+            }
+            "FULFILLMENT_ORDERS_CANCELLATION_REQUEST_SUBMITTED" => {
+                WebhookTopic::FulfillmentOrdersCancellationRequestSubmitted
+            }
+            "FULFILLMENT_ORDERS_CANCELLED" => WebhookTopic::FulfillmentOrdersCancelled,
+            "FULFILLMENT_ORDERS_FULFILLMENT_REQUEST_ACCEPTED" => {
+                WebhookTopic::FulfillmentOrdersFulfillmentRequestAccepted
+            }
+            "FULFILLMENT_ORDERS_FULFILLMENT_REQUEST_REJECTED" => {
+                WebhookTopic::FulfillmentOrdersFulfillmentRequestRejected
+            }
+            "FULFILLMENT_ORDERS_FULFILLMENT_REQUEST_SUBMITTED" => {
+                WebhookTopic::FulfillmentOrdersFulfillmentRequestSubmitted
+            }
+            "FULFILLMENT_ORDERS_HOLD_RELEASED" => WebhookTopic::FulfillmentOrdersHoldReleased,
+            "FULFILLMENT_ORDERS_MOVED" => WebhookTopic::FulfillmentOrdersMoved,
+            "FULFILLMENT_ORDERS_ORDER_ROUTING_COMPLETE" => {
+                WebhookTopic::FulfillmentOrdersOrderRoutingComplete
+            }
+            "FULFILLMENT_ORDERS_PLACED_ON_HOLD" => WebhookTopic::FulfillmentOrdersPlacedOnHold,
+            "FULFILLMENT_ORDERS_READY_TO_FULFILL" => WebhookTopic::FulfillmentOrdersReadyToFulfill,
+            "FULFILLMENT_ORDERS_RESCHEDULED" => WebhookTopic::FulfillmentOrdersRescheduled,
+            "FULFILLMENTS_CREATE" => WebhookTopic::FulfillmentsCreate,
+            "FULFILLMENTS_UPDATE" => WebhookTopic::FulfillmentsUpdate,
+            "INVENTORY_ITEMS_CREATE" => WebhookTopic::InventoryItemsCreate,
+            "INVENTORY_ITEMS_UPDATE" => WebhookTopic::InventoryItemsUpdate,
+            "INVENTORY_ITEMS_DELETE" => WebhookTopic::InventoryItemsDelete,
+            "INVENTORY_LEVELS_CONNECT" => WebhookTopic::InventoryLevelsConnect,
+            "INVENTORY_LEVELS_UPDATE" => WebhookTopic::InventoryLevelsUpdate,
+            "INVENTORY_LEVELS_DISCONNECT" => WebhookTopic::InventoryLevelsDisconnect,
+            "LOCALES_CREATE" => WebhookTopic::LocalesCreate,
+            "LOCALES_UPDATE" => WebhookTopic::LocalesUpdate,
+            "LOCATIONS_ACTIVATE" => WebhookTopic::LocationsActivate,
+            "LOCATIONS_CREATE" => WebhookTopic::LocationsCreate,
+            "LOCATIONS_DEACTIVATE" => WebhookTopic::LocationsDeactivate,
+            "LOCATIONS_DELETE" => WebhookTopic::LocationsDelete,
+            "LOCATIONS_UPDATE" => WebhookTopic::LocationsUpdate,
+            "ORDER_TRANSACTIONS_CREATE" => WebhookTopic::OrderTransactionsCreate,
+            "ORDERS_CREATE" => WebhookTopic::OrdersCreate,
+            "ORDERS_UPDATED" => WebhookTopic::OrdersUpdated,
+            "ORDERS_PAID" => WebhookTopic::OrdersPaid,
+            "ORDERS_CANCELLED" => WebhookTopic::OrdersCancelled,
+            "ORDERS_EDITED" => WebhookTopic::OrdersEdited,
+            "ORDERS_FULFILLED" => WebhookTopic::OrdersFulfilled,
+            "ORDERS_PARTIALLY_FULFILLED" => WebhookTopic::OrdersPartiallyFulfilled,
+            "ORDERS_DELETE" => WebhookTopic::OrdersDelete,
+            "PRODUCT_LISTINGS_ADD" => WebhookTopic::ProductListingsAdd,
+            "PRODUCT_LISTINGS_REMOVE" => WebhookTopic::ProductListingsRemove,
+            "PRODUCT_LISTINGS_UPDATE" => WebhookTopic::ProductListingsUpdate,
+            "PRODUCTS_CREATE" => WebhookTopic::ProductsCreate,
+            "PRODUCTS_UPDATE" => WebhookTopic::ProductsUpdate,
+            "PRODUCTS_DELETE" => WebhookTopic::ProductsDelete,
+            "REFUNDS_CREATE" => WebhookTopic::RefundsCreate,
+            "SELLING_PLAN_GROUPS_CREATE" => WebhookTopic::SellingPlanGroupsCreate,
+            "SELLING_PLAN_GROUPS_UPDATE" => WebhookTopic::SellingPlanGroupsUpdate,
+            "SELLING_PLAN_GROUPS_DELETE" => WebhookTopic::SellingPlanGroupsDelete,
+            "SHOP_UPDATE" => WebhookTopic::ShopUpdate,
+            "SUBSCRIPTION_CONTRACTS_CREATE" => WebhookTopic::SubscriptionContractsCreate,
+            "SUBSCRIPTION_CONTRACTS_UPDATE" => WebhookTopic::SubscriptionContractsUpdate,
+            "TENDER_TRANSACTIONS_CREATE" => WebhookTopic::TenderTransactionsCreate,
+            "THEMES_CREATE" => WebhookTopic::ThemesCreate,
+            "THEMES_PUBLISH" => WebhookTopic::ThemesPublish,
+            "THEMES_UPDATE" => WebhookTopic::ThemesUpdate,
+            "THEMES_DELETE" => WebhookTopic::ThemesDelete,
+            other => WebhookTopic::Other(other.to_string()),
+        })
+    }
+}
+
+impl fmt::Display for WebhookTopic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl Serialize for WebhookTopic {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for WebhookTopic {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let topic = String::deserialize(deserializer)?;
+        Ok(topic.parse().unwrap_or_else(|never| match never {}))
+    }
+}
+
+/// Destination of the events of a subscription
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum WebhookEndpoint {
+    /// HTTPS callback URL
+    Http(String),
+    /// ARN of an Amazon EventBridge partner event source
+    EventBridge(String),
+    /// Google Cloud Pub/Sub topic
+    PubSub { project: String, topic: String },
+}
+
+impl WebhookEndpoint {
+    /// Get the `uri` of the endpoint, as given to the subscription
+    ///
+    /// # Example
+    /// ```
+    /// use shopify_api::graphql::webhooks::WebhookEndpoint;
+    ///
+    /// let endpoint = WebhookEndpoint::PubSub {
+    ///     project: "my-project".to_string(),
+    ///     topic: "shopify".to_string(),
+    /// };
+    /// assert_eq!(endpoint.uri(), "pubsub://my-project:shopify");
+    /// ```
+    pub fn uri(&self) -> String {
+        match self {
+            WebhookEndpoint::Http(url) => url.clone(),
+            WebhookEndpoint::EventBridge(arn) => arn.clone(),
+            WebhookEndpoint::PubSub { project, topic } => format!("pubsub://{}:{}", project, topic),
+        }
+    }
+}
+
+/// Format of the webhook payloads
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum WebhookFormat {
+    #[default]
+    #[serde(rename = "JSON")]
+    Json,
+    #[serde(rename = "XML")]
+    Xml,
+}
+
+/// Input of [`Shopify::webhook_subscription_create`] and [`Shopify::webhook_subscription_update`]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct WebhookSubscriptionInput {
+    endpoint: WebhookEndpoint,
+    format: WebhookFormat,
+    include_fields: Vec<String>,
+    filter: Option<String>,
+}
+
+impl WebhookSubscriptionInput {
+    pub fn new(endpoint: WebhookEndpoint) -> WebhookSubscriptionInput {
+        WebhookSubscriptionInput {
+            endpoint,
+            format: WebhookFormat::default(),
+            include_fields: Vec::new(),
+            filter: None,
+        }
+    }
+
+    /// Set the format of the payloads, JSON by default
+    pub fn format(mut self, format: WebhookFormat) -> Self {
+        self.format = format;
+        self
+    }
+
+    /// Only send these fields of the payloads, e.g. `id` and `updated_at`
+    pub fn include_fields<Field>(mut self, fields: impl IntoIterator<Item = Field>) -> Self
+    where
+        Field: Into<String>,
+    {
+        self.include_fields
+            .extend(fields.into_iter().map(Into::into));
+        self
+    }
+
+    /// Only send the events matching a filter in Shopify's search syntax, e.g. `vendor:Acme`
+    pub fn filter(mut self, filter: &str) -> Self {
+        self.filter = Some(filter.to_string());
+        self
+    }
+
+    /// Render the `WebhookSubscriptionInput` of the mutations
+    fn to_variable(&self) -> serde_json::Value {
+        let mut input = json!({
+            "uri": self.endpoint.uri(),
+            "format": self.format,
+        });
+        if !self.include_fields.is_empty() {
+            input["includeFields"] = json!(self.include_fields);
+        }
+        if let Some(filter) = &self.filter {
+            input["filter"] = json!(filter);
+        }
+        input
+    }
+}
+
+/// Webhook subscription returned by the mutations
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WebhookSubscription {
+    pub id: String,
+    pub topic: WebhookTopic,
+    pub uri: String,
+    pub format: WebhookFormat,
+    #[serde(default)]
+    pub include_fields: Vec<String>,
+    #[serde(default)]
+    pub filter: Option<String>,
+}
+
+impl Shopify {
+    /// Subscribe to a topic with `webhookSubscriptionCreate`
+    ///
+    /// This fails with [`ShopifyAPIError::UserErrors`] if the subscription is rejected, e.g. when
+    /// the endpoint is already subscribed to the topic.
+    /// # Example
+    /// ```no_run
+    /// use shopify_api::*;
+    /// use shopify_api::graphql::webhooks::{WebhookEndpoint, WebhookSubscriptionInput, WebhookTopic};
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let shopify = Shopify::new("myshop", "myapikey", ShopifyAPIVersion::V2026_10, None);
+    ///     let input = WebhookSubscriptionInput::new(WebhookEndpoint::Http(
+    ///         "https://example.com/webhooks".to_string(),
+    ///     ))
+    ///     .include_fields(["id", "updated_at"]);
+    ///
+    ///     let subscription = shopify
+    ///         .webhook_subscription_create(WebhookTopic::OrdersCreate, &input)
+    ///         .await
+    ///         .unwrap();
+    ///     println!("Subscribed: {}", subscription.id);
+    /// }
+    /// ```
+    pub async fn webhook_subscription_create(
+        &self,
+        topic: WebhookTopic,
+        input: &WebhookSubscriptionInput,
+    ) -> Result<WebhookSubscription, ShopifyAPIError> {
+        let mutation = format!(
+            r#"
+            mutation($topic: WebhookSubscriptionTopic!, $webhookSubscription: WebhookSubscriptionInput!) {{
+                webhookSubscriptionCreate(topic: $topic, webhookSubscription: $webhookSubscription) {{
+                    webhookSubscription {{ {SUBSCRIPTION_FIELDS} }}
+                    userErrors {{ field message }}
+                }}
+            }}"#
+        );
+
+        self.graphql_mutation(
+            &mutation,
+            &json!({ "topic": topic, "webhookSubscription": input.to_variable() }),
+            &vec![
+                ReadJsonTreeSteps::Key("data"),
+                ReadJsonTreeSteps::Key("webhookSubscriptionCreate"),
+                ReadJsonTreeSteps::Key("webhookSubscription"),
+            ],
+        )
+        .await
+    }
+
+    /// Replace the endpoint, format, fields and filter of a subscription with `webhookSubscriptionUpdate`
+    pub async fn webhook_subscription_update(
+        &self,
+        id: &str,
+        input: &WebhookSubscriptionInput,
+    ) -> Result<WebhookSubscription, ShopifyAPIError> {
+        let mutation = format!(
+            r#"
+            mutation($id: ID!, $webhookSubscription: WebhookSubscriptionInput!) {{
+                webhookSubscriptionUpdate(id: $id, webhookSubscription: $webhookSubscription) {{
+                    webhookSubscription {{ {SUBSCRIPTION_FIELDS} }}
+                    userErrors {{ field message }}
+                }}
+            }}"#
+        );
+
+        self.graphql_mutation(
+            &mutation,
+            &json!({ "id": id, "webhookSubscription": input.to_variable() }),
+            &vec![
+                ReadJsonTreeSteps::Key("data"),
+                ReadJsonTreeSteps::Key("webhookSubscriptionUpdate"),
+                ReadJsonTreeSteps::Key("webhookSubscription"),
+            ],
+        )
+        .await
+    }
+
+    /// Delete a subscription with `webhookSubscriptionDelete`, returning the ID of the deleted subscription
+    pub async fn webhook_subscription_delete(&self, id: &str) -> Result<String, ShopifyAPIError> {
+        let mutation = r#"
+            mutation($id: ID!) {
+                webhookSubscriptionDelete(id: $id) {
+                    deletedWebhookSubscriptionId
+                    userErrors { field message }
+                }
+            }"#;
+
+        self.graphql_mutation(
+            mutation,
+            &json!({ "id": id }),
+            &vec![
+                ReadJsonTreeSteps::Key("data"),
+                ReadJsonTreeSteps::Key("webhookSubscriptionDelete"),
+                ReadJsonTreeSteps::Key("deletedWebhookSubscriptionId"),
+            ],
+        )
+        .await
+    }
+}
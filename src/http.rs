@@ -0,0 +1,548 @@
+use std::time::Duration;
+
+use reqwest::{header, Method, RequestBuilder, StatusCode};
+
+use crate::{
+    api_version_to_string, middleware::ShopifyResponse, rate_limit::ShopifyPlan,
+    rest::ShopifyRestErrors, retry::RetryPolicy, utils, RequestOptions, Shopify, ShopifyAPIError,
+};
+
+impl Shopify {
+    /// Get the retry policy of the request
+    pub(crate) fn retry_policy<'a>(&'a self, options: &'a RequestOptions) -> &'a RetryPolicy {
+        options.get_retry_policy().unwrap_or(&self.retry_policy)
+    }
+
+    /// Get the url of a REST endpoint for the API version of the request
+    ///
+    /// Endpoints starting with `/` are unversioned paths of the shop, e.g.
+    /// `/admin/oauth/access_scopes.json`.
+    pub(crate) fn endpoint_url(&self, endpoint: &str, options: &RequestOptions) -> String {
+        if endpoint.starts_with('/') {
+            return format!("{}{}", self.base_url, endpoint);
+        }
+
+        match options.get_api_version() {
+            Some(api_version) => format!(
+                "{}/admin/api/{}/{}",
+                self.base_url,
+                api_version_to_string(api_version),
+                endpoint
+            ),
+            None => self.get_api_endpoint(endpoint),
+        }
+    }
+
+    /// Get the GraphQL url for the API version of the request
+    pub(crate) fn graphql_url(&self, options: &RequestOptions) -> String {
+        match options.get_api_version() {
+            Some(api_version) => format!(
+                "{}/admin/api/{}/graphql.json",
+                self.base_url,
+                api_version_to_string(api_version)
+            ),
+            None => self.get_query_url().to_string(),
+        }
+    }
+
+    /// Headers sent with every request: authentication, user agent and the request headers
+    fn headers(&self, options: &RequestOptions) -> Result<header::HeaderMap, ShopifyAPIError> {
+        let mut headers = header::HeaderMap::new();
+        headers.insert(
+            header::CONTENT_TYPE,
+            header::HeaderValue::from_static("application/json"),
+        );
+        let access_token = header::HeaderValue::from_str(&self.api_key).map_err(|_| {
+            ShopifyAPIError::InvalidCredentials(
+                "The access token contains invalid header characters".to_string(),
+            )
+        })?;
+        headers.insert("X-Shopify-Access-Token", access_token);
+        let user_agent = header::HeaderValue::from_str(&self.user_agent)
+            .map_err(|_| ShopifyAPIError::InvalidConfiguration("Invalid user agent".to_string()))?;
+        headers.insert(header::USER_AGENT, user_agent);
+        for (name, value) in options.get_headers() {
+            headers.insert(name, value.clone());
+        }
+
+        Ok(headers)
+    }
+
+    /// Prepare a request on the shared client with the authentication headers and the timeout
+    pub(crate) fn request(
+        &self,
+        method: Method,
+        url: &str,
+        options: &RequestOptions,
+    ) -> Result<RequestBuilder, ShopifyAPIError> {
+        let req = self
+            .client
+            .request(method, url)
+            .headers(self.headers(options)?);
+
+        // The browser fetch API used on wasm32 has no request timeout
+        #[cfg(not(target_arch = "wasm32"))]
+        let req = match options.get_timeout().or(self.timeout) {
+            Some(timeout) => req.timeout(timeout),
+            None => req,
+        };
+
+        Ok(req)
+    }
+
+    /// Send a request through the middleware chain and read the whole response
+    pub(crate) async fn send(
+        &self,
+        req: RequestBuilder,
+        options: &RequestOptions,
+    ) -> Result<ShopifyResponse, ShopifyAPIError> {
+        let mut request = req.build()?;
+        let _permit = self.concurrency.acquire(options.get_priority()).await;
+
+        for middleware in &self.middlewares.0 {
+            middleware.before_send(&mut request).await?;
+        }
+
+        self.stats.record_request();
+        let sent_at = utils::now();
+
+        // Connection Response
+        let res = self.client.execute(request).await?;
+        let status = res.status();
+        let headers = res.headers().clone();
+        self.check_deprecation(res.url(), &headers);
+
+        // Connection data
+        let body = res.text().await.map_err(body_error)?;
+        self.received(sent_at, status);
+
+        let mut response = ShopifyResponse {
+            status,
+            headers,
+            body,
+        };
+
+        self.rate_limit.record(&response);
+
+        for middleware in self.middlewares.0.iter().rev() {
+            middleware.after_receive(&mut response).await?;
+        }
+
+        check_status(&response, self.plan)?;
+
+        Ok(response)
+    }
+
+    /// Send a request on the blocking client through the middleware chain and read the whole response
+    ///
+    /// The request is prepared with [`Shopify::request`], so it gets the same headers and timeout
+    /// as the async requests. The middlewares are run on the calling thread.
+    #[cfg(feature = "blocking")]
+    pub(crate) fn send_blocking(
+        &self,
+        req: RequestBuilder,
+        options: &RequestOptions,
+    ) -> Result<ShopifyResponse, ShopifyAPIError> {
+        let client = self.blocking_client.get()?;
+        let mut request = req.build()?;
+        let _permit = self.concurrency.acquire_blocking(options.get_priority());
+
+        for middleware in &self.middlewares.0 {
+            block_on(middleware.before_send(&mut request))?;
+        }
+
+        self.stats.record_request();
+        let sent_at = utils::now();
+
+        let res = client.execute(into_blocking(request)?)?;
+        let status = res.status();
+        let headers = res.headers().clone();
+        self.check_deprecation(res.url(), &headers);
+        let body = res.text().map_err(body_error)?;
+        self.received(sent_at, status);
+
+        let mut response = ShopifyResponse {
+            status,
+            headers,
+            body,
+        };
+
+        self.rate_limit.record(&response);
+
+        for middleware in self.middlewares.0.iter().rev() {
+            block_on(middleware.after_receive(&mut response))?;
+        }
+
+        check_status(&response, self.plan)?;
+
+        Ok(response)
+    }
+}
+
+/// Client of the blocking queries, built on the first blocking query
+///
+/// The client is built with the same connect timeout and proxy as the async client. A custom
+/// async client cannot be mirrored, so blocking queries then require a custom blocking client.
+#[cfg(feature = "blocking")]
+#[derive(Clone, Debug)]
+pub(crate) struct BlockingClient {
+    client: std::sync::Arc<std::sync::OnceLock<reqwest::blocking::Client>>,
+    options: Option<BlockingClientOptions>,
+}
+
+/// Client level options of the blocking client, mirroring the ones of the async client
+#[cfg(feature = "blocking")]
+#[derive(Clone, Debug, Default)]
+struct BlockingClientOptions {
+    connect_timeout: Option<Duration>,
+    proxy: Option<reqwest::Proxy>,
+}
+
+#[cfg(feature = "blocking")]
+impl Default for BlockingClient {
+    fn default() -> Self {
+        BlockingClient::new(None, None)
+    }
+}
+
+#[cfg(feature = "blocking")]
+impl BlockingClient {
+    /// Blocking client built with the given options
+    pub(crate) fn new(connect_timeout: Option<Duration>, proxy: Option<reqwest::Proxy>) -> Self {
+        BlockingClient {
+            client: Default::default(),
+            options: Some(BlockingClientOptions {
+                connect_timeout,
+                proxy,
+            }),
+        }
+    }
+
+    /// Custom blocking client
+    pub(crate) fn custom(client: reqwest::blocking::Client) -> Self {
+        BlockingClient {
+            client: std::sync::Arc::new(std::sync::OnceLock::from(client)),
+            options: None,
+        }
+    }
+
+    /// Blocking client of an instance the async client of which was replaced by a custom one
+    pub(crate) fn for_custom_client(self) -> Self {
+        match self.options {
+            Some(_) => BlockingClient {
+                client: Default::default(),
+                options: None,
+            },
+            None => self,
+        }
+    }
+
+    /// Get the client, building it on the first call
+    fn get(&self) -> Result<&reqwest::blocking::Client, ShopifyAPIError> {
+        if let Some(client) = self.client.get() {
+            return Ok(client);
+        }
+
+        let options = self.options.as_ref().ok_or_else(|| {
+            ShopifyAPIError::InvalidConfiguration(
+                "Blocking queries cannot use a custom client, set a custom blocking client too"
+                    .to_string(),
+            )
+        })?;
+        // The blocking client times out after 30s by default, unlike the async one: the timeout
+        // of the builder is set on each request instead
+        let mut client = reqwest::blocking::Client::builder().timeout(None);
+        if let Some(connect_timeout) = options.connect_timeout {
+            client = client.connect_timeout(connect_timeout);
+        }
+        if let Some(proxy) = &options.proxy {
+            client = client.proxy(proxy.clone());
+        }
+        let client = client.build()?;
+
+        Ok(self.client.get_or_init(|| client))
+    }
+}
+
+/// Turn a request prepared for the async client into a request of the blocking client
+#[cfg(feature = "blocking")]
+fn into_blocking(request: reqwest::Request) -> Result<reqwest::blocking::Request, ShopifyAPIError> {
+    let mut blocking =
+        reqwest::blocking::Request::new(request.method().clone(), request.url().clone());
+    *blocking.headers_mut() = request.headers().clone();
+    *blocking.timeout_mut() = request.timeout().copied();
+    if let Some(body) = request.body() {
+        let body = body.as_bytes().ok_or_else(|| {
+            ShopifyAPIError::Other("Streamed bodies cannot be sent by blocking queries".to_string())
+        })?;
+        *blocking.body_mut() = Some(body.to_vec().into());
+    }
+
+    Ok(blocking)
+}
+
+/// Run a future, e.g. a middleware hook, to completion on the current thread
+#[cfg(feature = "blocking")]
+fn block_on<F: std::future::Future>(future: F) -> F::Output {
+    struct ThreadWaker(std::thread::Thread);
+
+    impl std::task::Wake for ThreadWaker {
+        fn wake(self: std::sync::Arc<Self>) {
+            self.0.unpark();
+        }
+    }
+
+    let waker = std::task::Waker::from(std::sync::Arc::new(ThreadWaker(std::thread::current())));
+    let mut context = std::task::Context::from_waker(&waker);
+    let mut future = std::pin::pin!(future);
+
+    loop {
+        match future.as_mut().poll(&mut context) {
+            std::task::Poll::Ready(output) => return output,
+            std::task::Poll::Pending => std::thread::park(),
+        }
+    }
+}
+
+impl Shopify {
+    /// Record the latency and the status of a response read in full
+    fn received(&self, sent_at: Option<f64>, status: StatusCode) {
+        if let (Some(sent_at), Some(now)) = (sent_at, utils::now()) {
+            self.stats
+                .record_latency(Duration::from_secs_f64(now - sent_at));
+        }
+        if status == StatusCode::TOO_MANY_REQUESTS {
+            self.stats.record_rate_limited();
+        }
+    }
+}
+
+/// Map an error raised while reading the body, keeping the connection errors as source
+fn body_error(error: reqwest::Error) -> ShopifyAPIError {
+    if error.is_decode() {
+        ShopifyAPIError::ResponseBroken
+    } else {
+        ShopifyAPIError::from(error)
+    }
+}
+
+/// Turn the error statuses of a response into errors
+///
+/// Without a `Retry-After` header, a 429 response is retried after the leak delay of the plan, if known.
+fn check_status(
+    response: &ShopifyResponse,
+    plan: Option<ShopifyPlan>,
+) -> Result<(), ShopifyAPIError> {
+    if response.status == StatusCode::TOO_MANY_REQUESTS {
+        return Err(ShopifyAPIError::RateLimited {
+            retry_after: parse_retry_after(&response.headers)
+                .or_else(|| plan.map(|plan| plan.rest_leak_delay())),
+            call_limit: parse_call_limit(&response.headers),
+            request_id: response.request_id(),
+        });
+    }
+
+    if response.status.is_success() {
+        return Ok(());
+    }
+
+    let body = response.body.clone();
+    let errors = ShopifyRestErrors::parse(&response.body);
+    let request_id = response.request_id();
+
+    Err(match response.status {
+        StatusCode::UNAUTHORIZED => ShopifyAPIError::Unauthorized {
+            body,
+            errors,
+            request_id,
+        },
+        StatusCode::FORBIDDEN => ShopifyAPIError::Forbidden {
+            body,
+            errors,
+            request_id,
+        },
+        StatusCode::NOT_FOUND => ShopifyAPIError::NotFound {
+            body,
+            errors,
+            request_id,
+        },
+        StatusCode::UNPROCESSABLE_ENTITY => ShopifyAPIError::UnprocessableEntity {
+            body,
+            errors,
+            request_id,
+        },
+        status => ShopifyAPIError::Http {
+            status,
+            headers: Box::new(response.headers.clone()),
+            body,
+            errors,
+            request_id,
+        },
+    })
+}
+
+/// Parse the `Retry-After` header, sent by Shopify as a number of seconds (e.g. `2.0`)
+fn parse_retry_after(headers: &header::HeaderMap) -> Option<Duration> {
+    let seconds: f64 = headers
+        .get(header::RETRY_AFTER)?
+        .to_str()
+        .ok()?
+        .trim()
+        .parse()
+        .ok()?;
+
+    Duration::try_from_secs_f64(seconds).ok()
+}
+
+/// Parse the `X-Shopify-Shop-Api-Call-Limit` header (`used/capacity`, e.g. `40/40`)
+pub(crate) fn parse_call_limit(headers: &header::HeaderMap) -> Option<(u32, u32)> {
+    let (used, capacity) = headers
+        .get("X-Shopify-Shop-Api-Call-Limit")?
+        .to_str()
+        .ok()?
+        .split_once('/')?;
+
+    Some((used.trim().parse().ok()?, capacity.trim().parse().ok()?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn response(status: StatusCode, headers: &[(&'static str, &str)]) -> ShopifyResponse {
+        let mut header_map = header::HeaderMap::new();
+        for (name, value) in headers {
+            header_map.insert(*name, value.parse().unwrap());
+        }
+        ShopifyResponse {
+            status,
+            headers: header_map,
+            body: r#"{"errors":"Not Found"}"#.to_string(),
+        }
+    }
+
+    fn retry_after(
+        headers: &[(&'static str, &str)],
+        plan: Option<ShopifyPlan>,
+    ) -> Option<Duration> {
+        match check_status(&response(StatusCode::TOO_MANY_REQUESTS, headers), plan) {
+            Err(ShopifyAPIError::RateLimited { retry_after, .. }) => retry_after,
+            result => panic!("expected RateLimited, got {:?}", result),
+        }
+    }
+
+    #[test]
+    fn rate_limited_reads_fractional_retry_after() {
+        let headers = [("Retry-After", "2.0")];
+        assert_eq!(retry_after(&headers, None), Some(Duration::from_secs(2)));
+        let headers = [("Retry-After", " 0.5 ")];
+        assert_eq!(
+            retry_after(&headers, Some(ShopifyPlan::Standard)),
+            Some(Duration::from_millis(500))
+        );
+    }
+
+    #[test]
+    fn rate_limited_without_retry_after_falls_back_to_the_plan() {
+        assert_eq!(retry_after(&[], None), None);
+        assert_eq!(
+            retry_after(&[], Some(ShopifyPlan::Standard)),
+            Some(Duration::from_millis(500))
+        );
+        assert_eq!(
+            retry_after(&[], Some(ShopifyPlan::Plus)),
+            Some(Duration::from_millis(50))
+        );
+    }
+
+    #[test]
+    fn rate_limited_ignores_invalid_retry_after() {
+        for value in ["soon", "-1", "NaN", "inf"] {
+            let headers = [("Retry-After", value)];
+            assert_eq!(retry_after(&headers, None), None, "{}", value);
+            assert_eq!(
+                retry_after(&headers, Some(ShopifyPlan::Standard)),
+                Some(Duration::from_millis(500)),
+                "{}",
+                value
+            );
+        }
+    }
+
+    #[test]
+    fn rate_limited_keeps_the_call_limit_and_request_id() {
+        let response = response(
+            StatusCode::TOO_MANY_REQUESTS,
+            &[
+                ("X-Shopify-Shop-Api-Call-Limit", "40/40"),
+                ("X-Request-Id", "abc-123"),
+            ],
+        );
+        match check_status(&response, None) {
+            Err(ShopifyAPIError::RateLimited {
+                call_limit,
+                request_id,
+                ..
+            }) => {
+                assert_eq!(call_limit, Some((40, 40)));
+                assert_eq!(request_id.as_deref(), Some("abc-123"));
+            }
+            result => panic!("expected RateLimited, got {:?}", result),
+        }
+    }
+
+    #[test]
+    fn call_limit_is_parsed() {
+        let parse = |value: &str| {
+            parse_call_limit(
+                &response(StatusCode::OK, &[("X-Shopify-Shop-Api-Call-Limit", value)]).headers,
+            )
+        };
+        assert_eq!(parse("40/40"), Some((40, 40)));
+        assert_eq!(parse(" 3 / 80 "), Some((3, 80)));
+        assert_eq!(parse("40"), None);
+        assert_eq!(parse("a/40"), None);
+        assert_eq!(parse_call_limit(&header::HeaderMap::new()), None);
+    }
+
+    #[test]
+    fn success_statuses_are_ok() {
+        for status in [StatusCode::OK, StatusCode::CREATED, StatusCode::NO_CONTENT] {
+            assert!(check_status(&response(status, &[]), None).is_ok());
+        }
+    }
+
+    #[test]
+    fn error_statuses_are_mapped_to_their_variant() {
+        let check = |status| check_status(&response(status, &[("X-Request-Id", "abc-123")]), None);
+
+        assert!(matches!(
+            check(StatusCode::UNAUTHORIZED),
+            Err(ShopifyAPIError::Unauthorized { .. })
+        ));
+        assert!(matches!(
+            check(StatusCode::FORBIDDEN),
+            Err(ShopifyAPIError::Forbidden { .. })
+        ));
+        assert!(matches!(
+            check(StatusCode::NOT_FOUND),
+            Err(ShopifyAPIError::NotFound { .. })
+        ));
+        assert!(matches!(
+            check(StatusCode::UNPROCESSABLE_ENTITY),
+            Err(ShopifyAPIError::UnprocessableEntity { .. })
+        ));
+        match check(StatusCode::INTERNAL_SERVER_ERROR) {
+            Err(ShopifyAPIError::Http {
+                status, request_id, ..
+            }) => {
+                assert_eq!(status, StatusCode::INTERNAL_SERVER_ERROR);
+                assert_eq!(request_id.as_deref(), Some("abc-123"));
+            }
+            result => panic!("expected Http, got {:?}", result),
+        }
+
+        let error = check(StatusCode::NOT_FOUND).unwrap_err();
+        assert_eq!(error.request_id(), Some("abc-123"));
+    }
+}
@@ -1,20 +1,99 @@
+use std::time::Duration;
+
 use chrono::TimeZone;
 use thiserror::Error;
 
+#[cfg(all(feature = "blocking", target_arch = "wasm32"))]
+compile_error!("The `blocking` feature is not supported on wasm32");
+
+#[cfg(all(
+    not(target_arch = "wasm32"),
+    not(any(feature = "tokio", feature = "async-std", feature = "smol"))
+))]
+compile_error!("One of the `tokio`, `async-std` or `smol` features is required");
+
+pub mod builder;
+pub mod cancel;
+mod concurrency;
+mod deprecation;
+mod gid;
 pub mod graphql;
+mod http;
+pub mod middleware;
+pub mod options;
+pub mod pagination;
+pub mod pool;
+pub mod rate_limit;
+mod report;
+mod response;
 pub mod rest;
+pub mod retry;
+mod stats;
 pub mod utils;
 
+pub use builder::ShopifyBuilder;
+pub use deprecation::DeprecationWarning;
+pub use gid::Gid;
+pub use options::RequestOptions;
+pub use pool::ShopifyPool;
+pub use report::ErrorReport;
+pub use response::ResponseMetadata;
+pub use stats::ShopifyStats;
+
+/// `User-Agent` header sent when the app does not identify itself
+pub const DEFAULT_USER_AGENT: &str = concat!("shopify_api-rs/", env!("CARGO_PKG_VERSION"));
+
 #[derive(Clone, Debug)]
 pub struct Shopify {
     api_version: ShopifyAPIVersion,
     shared_secret: Option<String>,
     api_key: String,
+    base_url: String,
     query_url: String,
     rest_url: String,
     shop: String,
+    client: reqwest::Client,
+    timeout: Option<Duration>,
+    retry_policy: retry::RetryPolicy,
+    graphql_throttle_retry: retry::GraphQLThrottleRetry,
+    user_agent: String,
+    middlewares: middleware::Middlewares,
+    error_hook: report::ErrorHook,
+    throttle_hook: rate_limit::ThrottleHook,
+    deprecation_hook: deprecation::DeprecationHook,
+    deprecations: std::sync::Arc<deprecation::DeprecationLog>,
+    rate_limit: std::sync::Arc<rate_limit::RateLimitTracker>,
+    rate_limiter: Option<rate_limit::RateLimiter>,
+    plan: Option<rate_limit::ShopifyPlan>,
+    concurrency: std::sync::Arc<concurrency::ConcurrencyLimit>,
+    stats: std::sync::Arc<stats::StatsCounters>,
+    operations: std::sync::Arc<graphql::OperationRegistry>,
+    shop_cache: std::sync::Arc<rest::shop::ShopCache>,
+    shop_cache_ttl: Duration,
+    #[cfg(feature = "schema-validation")]
+    schema: Option<std::sync::Arc<graphql::Schema>>,
+    #[cfg(feature = "blocking")]
+    blocking_client: http::BlockingClient,
 }
 
+/// Version of the Shopify Admin API
+///
+/// Versions can be parsed from and displayed as their url name, and compared by release date.
+/// # Example
+/// ```
+/// use shopify_api::ShopifyAPIVersion;
+///
+/// let version: ShopifyAPIVersion = "2024-01".parse().unwrap();
+/// assert_eq!(version, ShopifyAPIVersion::V2024_01);
+/// assert_eq!(version.to_string(), "2024-01");
+/// assert!(version >= ShopifyAPIVersion::V2023_10);
+///
+/// // Versions released after this crate can still be used
+/// let custom: ShopifyAPIVersion = "2027-04".parse().unwrap();
+/// assert_eq!(custom, ShopifyAPIVersion::Custom("2027-04".to_string()));
+/// assert!(custom > ShopifyAPIVersion::Latest);
+/// assert!(custom < ShopifyAPIVersion::Unstable);
+/// ```
 #[derive(Clone, Debug)]
 pub enum ShopifyAPIVersion {
     /// Deprecated
@@ -23,23 +102,206 @@ pub enum ShopifyAPIVersion {
     V2022_04,
     V2022_07,
     V2022_10,
-
-    /// Will be deprecated soon
     V2023_01,
     V2023_04,
+    V2023_07,
+    V2023_10,
+    V2024_01,
+    V2024_04,
+    V2024_07,
+    V2024_10,
+    V2025_01,
+    V2025_04,
+    V2025_07,
+
+    /// Will be deprecated soon
+    V2025_10,
+    V2026_01,
+    V2026_04,
+    V2026_07,
 
     /// Latest stable version
-    V2023_07,
+    V2026_10,
 
-    /// Release candidate
-    V2023_10,
+    /// Latest stable version known by this crate, see [`LATEST_API_VERSION`]
+    Latest,
     Unstable,
+
+    /// Any version name (`YYYY-MM`), for versions released after this crate
+    Custom(String),
+}
+
+/// Version used by [`ShopifyAPIVersion::Latest`]
+pub const LATEST_API_VERSION: ShopifyAPIVersion = ShopifyAPIVersion::V2026_10;
+
+/// Versions with a dedicated variant, from the oldest to the newest
+const KNOWN_API_VERSIONS: [ShopifyAPIVersion; 21] = [
+    ShopifyAPIVersion::V2021_10,
+    ShopifyAPIVersion::V2022_01,
+    ShopifyAPIVersion::V2022_04,
+    ShopifyAPIVersion::V2022_07,
+    ShopifyAPIVersion::V2022_10,
+    ShopifyAPIVersion::V2023_01,
+    ShopifyAPIVersion::V2023_04,
+    ShopifyAPIVersion::V2023_07,
+    ShopifyAPIVersion::V2023_10,
+    ShopifyAPIVersion::V2024_01,
+    ShopifyAPIVersion::V2024_04,
+    ShopifyAPIVersion::V2024_07,
+    ShopifyAPIVersion::V2024_10,
+    ShopifyAPIVersion::V2025_01,
+    ShopifyAPIVersion::V2025_04,
+    ShopifyAPIVersion::V2025_07,
+    ShopifyAPIVersion::V2025_10,
+    ShopifyAPIVersion::V2026_01,
+    ShopifyAPIVersion::V2026_04,
+    ShopifyAPIVersion::V2026_07,
+    ShopifyAPIVersion::V2026_10,
+];
+
+impl ShopifyAPIVersion {
+    /// Get the name of the version as used in the API urls
+    /// # Example
+    /// ```
+    /// use shopify_api::ShopifyAPIVersion;
+    /// assert_eq!(ShopifyAPIVersion::V2023_10.as_str(), "2023-10");
+    /// assert_eq!(ShopifyAPIVersion::Unstable.as_str(), "unstable");
+    /// ```
+    pub fn as_str(&self) -> &str {
+        match self {
+            ShopifyAPIVersion::V2021_10 => "2021-10",
+            ShopifyAPIVersion::V2022_01 => "2022-01",
+            ShopifyAPIVersion::V2022_04 => "2022-04",
+            ShopifyAPIVersion::V2022_07 => "2022-07",
+            ShopifyAPIVersion::V2022_10 => "2022-10",
+            ShopifyAPIVersion::V2023_01 => "2023-01",
+            ShopifyAPIVersion::V2023_04 => "2023-04",
+            ShopifyAPIVersion::V2023_07 => "2023-07",
+            ShopifyAPIVersion::V2023_10 => "2023-10",
+            ShopifyAPIVersion::V2024_01 => "2024-01",
+            ShopifyAPIVersion::V2024_04 => "2024-04",
+            ShopifyAPIVersion::V2024_07 => "2024-07",
+            ShopifyAPIVersion::V2024_10 => "2024-10",
+            ShopifyAPIVersion::V2025_01 => "2025-01",
+            ShopifyAPIVersion::V2025_04 => "2025-04",
+            ShopifyAPIVersion::V2025_07 => "2025-07",
+            ShopifyAPIVersion::V2025_10 => "2025-10",
+            ShopifyAPIVersion::V2026_01 => "2026-01",
+            ShopifyAPIVersion::V2026_04 => "2026-04",
+            ShopifyAPIVersion::V2026_07 => "2026-07",
+            ShopifyAPIVersion::V2026_10 => "2026-10",
+            ShopifyAPIVersion::Latest => LATEST_API_VERSION.as_str(),
+            ShopifyAPIVersion::Unstable => "unstable",
+            ShopifyAPIVersion::Custom(version) => version,
+        }
+    }
+
+    /// Get the release year and month of the version, `None` for unstable
+    fn release_date(&self) -> Option<(i32, u32)> {
+        parse_release_date(self.as_str())
+    }
+}
+
+/// Parse a `YYYY-MM` version name
+fn parse_release_date(version: &str) -> Option<(i32, u32)> {
+    let (year, month) = version.split_once('-')?;
+    if year.len() != 4 || month.len() != 2 {
+        return None;
+    }
+
+    let year: i32 = year.parse().ok()?;
+    let month: u32 = month.parse().ok()?;
+    if !(1..=12).contains(&month) {
+        return None;
+    }
+
+    Some((year, month))
+}
+
+impl std::fmt::Display for ShopifyAPIVersion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl std::str::FromStr for ShopifyAPIVersion {
+    type Err = ShopifyAPIError;
+
+    fn from_str(version: &str) -> Result<Self, Self::Err> {
+        match version {
+            "latest" => return Ok(ShopifyAPIVersion::Latest),
+            "unstable" => return Ok(ShopifyAPIVersion::Unstable),
+            _ => {}
+        }
+
+        if let Some(known) = KNOWN_API_VERSIONS
+            .iter()
+            .find(|known| known.as_str() == version)
+        {
+            return Ok(known.clone());
+        }
+
+        match parse_release_date(version) {
+            Some(_) => Ok(ShopifyAPIVersion::Custom(version.to_string())),
+            None => Err(ShopifyAPIError::InvalidConfiguration(format!(
+                "Invalid API version: {version}"
+            ))),
+        }
+    }
+}
+
+impl PartialEq for ShopifyAPIVersion {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl Eq for ShopifyAPIVersion {}
+
+impl std::hash::Hash for ShopifyAPIVersion {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state);
+    }
+}
+
+impl PartialOrd for ShopifyAPIVersion {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for ShopifyAPIVersion {
+    /// Dated versions are ordered by release date and come before unstable
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        match (self.release_date(), other.release_date()) {
+            (Some(date), Some(other_date)) => date.cmp(&other_date),
+            (Some(_), None) => std::cmp::Ordering::Less,
+            (None, Some(_)) => std::cmp::Ordering::Greater,
+            (None, None) => self.as_str().cmp(other.as_str()),
+        }
+    }
 }
 
+/// Error returned by the queries
+///
+/// The underlying `reqwest` and `serde_json` errors are kept as the [`std::error::Error::source`]
+/// of the error, so error reporters such as `anyhow` show the whole chain of causes.
+/// # Example
+/// ```
+/// use std::error::Error;
+/// use shopify_api::ShopifyAPIError;
+///
+/// let error = ShopifyAPIError::from(serde_json::from_str::<u64>("not json").unwrap_err());
+/// assert!(error.source().is_some());
+/// ```
 #[derive(Debug, Error)]
+#[non_exhaustive]
 pub enum ShopifyAPIError {
     #[error("Connection failed")]
-    ConnectionFailed(#[from] reqwest::Error),
+    ConnectionFailed(#[source] reqwest::Error),
+
+    #[error("Request timed out")]
+    Timeout,
 
     #[error("Response broken")]
     ResponseBroken,
@@ -53,14 +315,245 @@ pub enum ShopifyAPIError {
     #[error("Throttled")]
     Throttled,
 
+    #[error("Query cancelled")]
+    Cancelled,
+
+    /// The request was rejected with a 429 status
+    ///
+    /// `call_limit` is the REST bucket usage (`used`, `capacity`) read from the `X-Shopify-Shop-Api-Call-Limit` header.
+    #[error("Rate limited, retry after {retry_after:?}")]
+    RateLimited {
+        retry_after: Option<Duration>,
+        call_limit: Option<(u32, u32)>,
+        request_id: Option<String>,
+    },
+
+    /// 401: the access token is invalid or was revoked
+    #[error("Unauthorized: {body}")]
+    Unauthorized {
+        body: String,
+        errors: Option<rest::ShopifyRestErrors>,
+        request_id: Option<String>,
+    },
+
+    /// 403: the app is missing an access scope
+    #[error("Forbidden: {body}")]
+    Forbidden {
+        body: String,
+        errors: Option<rest::ShopifyRestErrors>,
+        request_id: Option<String>,
+    },
+
+    /// 404: the resource or the endpoint does not exist
+    #[error("Not found: {body}")]
+    NotFound {
+        body: String,
+        errors: Option<rest::ShopifyRestErrors>,
+        request_id: Option<String>,
+    },
+
+    /// 422: the request body failed validation, see `errors` for the invalid fields
+    #[error("Unprocessable entity: {body}")]
+    UnprocessableEntity {
+        body: String,
+        errors: Option<rest::ShopifyRestErrors>,
+        request_id: Option<String>,
+    },
+
+    /// Any other unsuccessful status
+    #[error("HTTP error {status}: {body}")]
+    Http {
+        status: reqwest::StatusCode,
+        headers: Box<reqwest::header::HeaderMap>,
+        body: String,
+        errors: Option<rest::ShopifyRestErrors>,
+        request_id: Option<String>,
+    },
+
+    /// Top-level `errors` of a GraphQL response
+    #[error("GraphQL errors: {}", graphql::format_graphql_errors(errors))]
+    GraphQL {
+        errors: Vec<graphql::GraphQLError>,
+        request_id: Option<String>,
+    },
+
+    #[error("User errors: {}", graphql::format_user_errors(.0))]
+    UserErrors(Vec<graphql::UserError>),
+
     #[error("JSON parsing error: {0}")]
     JsonParseError(#[from] serde_json::Error),
 
+    #[error("Invalid configuration: {0}")]
+    InvalidConfiguration(String),
+
+    #[error("Invalid credentials: {0}")]
+    InvalidCredentials(String),
+
+    /// A GraphQL query that could not be parsed
+    #[error("Invalid GraphQL query: {0}")]
+    InvalidQuery(String),
+
+    /// An input rejected before being sent, e.g. a metafield with an unknown type
+    #[error("Invalid input: {0}")]
+    InvalidInput(String),
+
+    /// A paginated query returned more items than the given maximum
+    #[error("Too many items: more than {0}")]
+    TooManyItems(usize),
+
     #[error("Other error: {0}")]
     Other(String),
 }
 
+impl ShopifyAPIError {
+    /// Get the HTTP status of the response that caused the error, if any
+    /// # Example
+    /// ```
+    /// use shopify_api::ShopifyAPIError;
+    ///
+    /// let error = ShopifyAPIError::NotFound {
+    ///     body: r#"{"errors":"Not Found"}"#.to_string(),
+    ///     errors: None,
+    ///     request_id: None,
+    /// };
+    /// assert_eq!(error.status(), Some(reqwest::StatusCode::NOT_FOUND));
+    /// assert_eq!(ShopifyAPIError::Timeout.status(), None);
+    /// ```
+    pub fn status(&self) -> Option<reqwest::StatusCode> {
+        match self {
+            ShopifyAPIError::Http { status, .. } => Some(*status),
+            ShopifyAPIError::Unauthorized { .. } => Some(reqwest::StatusCode::UNAUTHORIZED),
+            ShopifyAPIError::Forbidden { .. } => Some(reqwest::StatusCode::FORBIDDEN),
+            ShopifyAPIError::NotFound { .. } => Some(reqwest::StatusCode::NOT_FOUND),
+            ShopifyAPIError::UnprocessableEntity { .. } => {
+                Some(reqwest::StatusCode::UNPROCESSABLE_ENTITY)
+            }
+            ShopifyAPIError::RateLimited { .. } => Some(reqwest::StatusCode::TOO_MANY_REQUESTS),
+            ShopifyAPIError::ConnectionFailed(error) => error.status(),
+            _ => None,
+        }
+    }
+
+    /// Check if the query may succeed when sent again
+    ///
+    /// Connection failures, timeouts, rate limits and server errors are retryable,
+    /// while client errors (401, 403, 404, 422...) and invalid responses are not.
+    /// This is the default predicate of [`retry::RetryPolicy`].
+    /// # Example
+    /// ```
+    /// use shopify_api::ShopifyAPIError;
+    ///
+    /// let error = ShopifyAPIError::UnprocessableEntity {
+    ///     body: String::new(),
+    ///     errors: None,
+    ///     request_id: None,
+    /// };
+    /// assert!(!error.is_retryable());
+    /// assert!(ShopifyAPIError::Throttled.is_retryable());
+    /// ```
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            ShopifyAPIError::ConnectionFailed(_)
+            | ShopifyAPIError::Timeout
+            | ShopifyAPIError::ResponseBroken
+            | ShopifyAPIError::Throttled
+            | ShopifyAPIError::RateLimited { .. } => true,
+            ShopifyAPIError::Http { status, .. } => {
+                status.is_server_error() || *status == reqwest::StatusCode::REQUEST_TIMEOUT
+            }
+            ShopifyAPIError::GraphQL { errors, .. } => errors
+                .iter()
+                .any(|error| error.code().is_some_and(|code| code.is_retryable())),
+            _ => false,
+        }
+    }
+
+    /// Check if the query was rejected because of the API rate limits
+    pub fn is_rate_limited(&self) -> bool {
+        matches!(
+            self,
+            ShopifyAPIError::Throttled | ShopifyAPIError::RateLimited { .. }
+        )
+    }
+
+    /// Check if the query was rejected because of the access token or its scopes
+    pub fn is_auth_error(&self) -> bool {
+        match self {
+            ShopifyAPIError::Unauthorized { .. } | ShopifyAPIError::Forbidden { .. } => true,
+            ShopifyAPIError::GraphQL { errors, .. } => errors
+                .iter()
+                .any(|error| error.code() == Some(graphql::GraphQLErrorCode::AccessDenied)),
+            _ => false,
+        }
+    }
+
+    /// Check if the requested resource does not exist
+    pub fn is_not_found(&self) -> bool {
+        matches!(self, ShopifyAPIError::NotFound { .. })
+    }
+
+    /// Get the `X-Request-Id` of the response that caused the error, to give to Shopify support
+    pub fn request_id(&self) -> Option<&str> {
+        match self {
+            ShopifyAPIError::Http { request_id, .. }
+            | ShopifyAPIError::Unauthorized { request_id, .. }
+            | ShopifyAPIError::Forbidden { request_id, .. }
+            | ShopifyAPIError::NotFound { request_id, .. }
+            | ShopifyAPIError::UnprocessableEntity { request_id, .. }
+            | ShopifyAPIError::RateLimited { request_id, .. }
+            | ShopifyAPIError::GraphQL { request_id, .. } => request_id.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// Get the parsed `errors` payload of a failed REST request, if any
+    pub fn rest_errors(&self) -> Option<&rest::ShopifyRestErrors> {
+        match self {
+            ShopifyAPIError::Http { errors, .. }
+            | ShopifyAPIError::Unauthorized { errors, .. }
+            | ShopifyAPIError::Forbidden { errors, .. }
+            | ShopifyAPIError::NotFound { errors, .. }
+            | ShopifyAPIError::UnprocessableEntity { errors, .. } => errors.as_ref(),
+            _ => None,
+        }
+    }
+
+    /// Get the delay requested by Shopify before retrying, if any
+    /// # Example
+    /// ```
+    /// use std::time::Duration;
+    /// use shopify_api::ShopifyAPIError;
+    ///
+    /// let error = ShopifyAPIError::RateLimited {
+    ///     retry_after: Some(Duration::from_secs(2)),
+    ///     call_limit: Some((40, 40)),
+    ///     request_id: None,
+    /// };
+    /// assert_eq!(error.retry_after(), Some(Duration::from_secs(2)));
+    /// assert_eq!(ShopifyAPIError::Throttled.retry_after(), None);
+    /// ```
+    pub fn retry_after(&self) -> Option<Duration> {
+        match self {
+            ShopifyAPIError::RateLimited { retry_after, .. } => *retry_after,
+            _ => None,
+        }
+    }
+}
+
+impl From<reqwest::Error> for ShopifyAPIError {
+    fn from(error: reqwest::Error) -> Self {
+        if error.is_timeout() {
+            ShopifyAPIError::Timeout
+        } else {
+            ShopifyAPIError::ConnectionFailed(error)
+        }
+    }
+}
+
 /// Get the end of support date for a given API version
+///
+/// Shopify supports each stable version for 12 months after its release, so the support ends at
+/// the end of the month before its first anniversary.
 /// # Example
 ///
 /// ```
@@ -69,21 +562,25 @@ pub enum ShopifyAPIError {
 ///
 /// assert_eq!(
 ///     get_end_of_support_date(&ShopifyAPIVersion::V2023_01),
-///     chrono::Utc.ymd(2023, 1, 31).and_hms(23, 59, 59)
+///     chrono::Utc.with_ymd_and_hms(2023, 12, 31, 23, 59, 59).unwrap()
+/// );
+/// assert_eq!(
+///     get_end_of_support_date(&ShopifyAPIVersion::Custom("2027-04".to_string())),
+///     chrono::Utc.with_ymd_and_hms(2028, 3, 31, 23, 59, 59).unwrap()
 /// );
 /// ```
 pub fn get_end_of_support_date(api_version: &ShopifyAPIVersion) -> chrono::DateTime<chrono::Utc> {
-    match api_version {
-        ShopifyAPIVersion::V2021_10 => chrono::Utc.ymd(2021, 10, 31).and_hms(23, 59, 59),
-        ShopifyAPIVersion::V2022_01 => chrono::Utc.ymd(2022, 1, 31).and_hms(23, 59, 59),
-        ShopifyAPIVersion::V2022_04 => chrono::Utc.ymd(2022, 4, 30).and_hms(23, 59, 59),
-        ShopifyAPIVersion::V2022_07 => chrono::Utc.ymd(2022, 7, 31).and_hms(23, 59, 59),
-        ShopifyAPIVersion::V2022_10 => chrono::Utc.ymd(2022, 10, 31).and_hms(23, 59, 59),
-        ShopifyAPIVersion::V2023_01 => chrono::Utc.ymd(2023, 1, 31).and_hms(23, 59, 59),
-        ShopifyAPIVersion::V2023_04 => chrono::Utc.ymd(2023, 4, 30).and_hms(23, 59, 59),
-        ShopifyAPIVersion::V2023_07 => chrono::Utc.ymd(2023, 7, 31).and_hms(23, 59, 59),
-        ShopifyAPIVersion::V2023_10 => chrono::Utc.ymd(2023, 10, 31).and_hms(23, 59, 59),
-        ShopifyAPIVersion::Unstable => chrono::Utc.ymd(9999, 12, 31).and_hms(23, 59, 59),
+    match api_version.release_date() {
+        Some((year, month)) => {
+            chrono::Utc
+                .with_ymd_and_hms(year + 1, month, 1, 0, 0, 0)
+                .unwrap()
+                - chrono::Duration::seconds(1)
+        }
+        // Unstable, and custom versions without a release date
+        None => chrono::Utc
+            .with_ymd_and_hms(9999, 12, 31, 23, 59, 59)
+            .unwrap(),
     }
 }
 
@@ -92,7 +589,8 @@ pub fn get_end_of_support_date(api_version: &ShopifyAPIVersion) -> chrono::DateT
 /// ```
 /// use shopify_api::{ is_deprecated, ShopifyAPIVersion };
 /// assert_eq!(is_deprecated(&ShopifyAPIVersion::V2021_10), true);
-/// assert_eq!(is_deprecated(&ShopifyAPIVersion::V2023_10), false);
+/// assert_eq!(is_deprecated(&ShopifyAPIVersion::V2023_10), true);
+/// assert_eq!(is_deprecated(&ShopifyAPIVersion::Unstable), false);
 /// ```
 pub fn is_deprecated(api_version: &ShopifyAPIVersion) -> bool {
     let max_date = get_end_of_support_date(api_version);
@@ -101,31 +599,39 @@ pub fn is_deprecated(api_version: &ShopifyAPIVersion) -> bool {
 }
 
 /// Transform the enum type of the API version to a string
+///
+/// Supported versions are sent as given. The versions with a dedicated variant are replaced by
+/// `unstable` once they are not supported anymore, with a warning logged with `log::warn!`, while
+/// [`ShopifyAPIVersion::Latest`] and [`ShopifyAPIVersion::Custom`] are always sent as given.
 /// # Example
 /// ```
-/// use shopify_api::{ api_version_to_string, ShopifyAPIVersion };
+/// use shopify_api::{ api_version_to_string, is_deprecated, ShopifyAPIVersion, LATEST_API_VERSION };
 /// assert_eq!(api_version_to_string(&ShopifyAPIVersion::V2021_10), "unstable"); // Deprecated
-/// assert_eq!(api_version_to_string(&ShopifyAPIVersion::V2023_10), "2023-10");
+/// assert_eq!(api_version_to_string(&ShopifyAPIVersion::V2023_10), "unstable"); // Deprecated
+/// assert_eq!(api_version_to_string(&ShopifyAPIVersion::Latest), LATEST_API_VERSION.as_str());
+///
+/// // Custom versions are sent as given, even once they are not supported anymore
+/// assert_eq!(api_version_to_string(&ShopifyAPIVersion::Custom("2025-01".to_string())), "2025-01");
+///
+/// // Supported versions are sent as given
+/// if !is_deprecated(&LATEST_API_VERSION) {
+///     assert_eq!(api_version_to_string(&LATEST_API_VERSION), LATEST_API_VERSION.as_str());
+/// }
 /// ```
 pub fn api_version_to_string(api_version: &ShopifyAPIVersion) -> String {
-    if is_deprecated(api_version) {
-        println!("Warning: You are using a deprecated API version");
-        println!("Warning: unstable API version will be used");
+    let sent_as_given = matches!(
+        api_version,
+        ShopifyAPIVersion::Latest | ShopifyAPIVersion::Custom(_)
+    );
+    if !sent_as_given && is_deprecated(api_version) {
+        log::warn!(
+            "shopify API version {} is not supported anymore, unstable will be used",
+            api_version
+        );
         return "unstable".to_string();
     }
 
-    match api_version {
-        ShopifyAPIVersion::V2021_10 => "unstable".to_string(),
-        ShopifyAPIVersion::V2022_01 => "unstable".to_string(),
-        ShopifyAPIVersion::V2022_04 => "unstable".to_string(),
-        ShopifyAPIVersion::V2022_07 => "unstable".to_string(),
-        ShopifyAPIVersion::V2022_10 => "unstable".to_string(),
-        ShopifyAPIVersion::V2023_01 => "2023-01".to_string(),
-        ShopifyAPIVersion::V2023_04 => "2023-04".to_string(),
-        ShopifyAPIVersion::V2023_07 => "2023-07".to_string(),
-        ShopifyAPIVersion::V2023_10 => "2023-10".to_string(),
-        ShopifyAPIVersion::Unstable => "unstable".to_string(),
-    }
+    api_version.as_str().to_string()
 }
 
 impl Shopify {
@@ -151,25 +657,102 @@ impl Shopify {
             shop_domain
         };
 
-        let query_url = format!(
-            "https://{}/admin/api/{}/graphql.json",
-            shop_domain,
-            api_version_to_string(&api_version)
-        );
-        let rest_url = format!(
-            "https://{}/admin/api/{}/",
-            shop_domain,
-            api_version_to_string(&api_version)
-        );
-
-        Shopify {
+        let mut shopify = Shopify {
             api_version,
             shared_secret: shared_secret.map(|secret| secret.to_string()),
             api_key: api_key.to_string(),
-            query_url,
-            rest_url,
+            base_url: format!("https://{}", shop_domain),
+            query_url: String::new(),
+            rest_url: String::new(),
             shop: shop.to_string(),
+            client: reqwest::Client::new(),
+            timeout: None,
+            retry_policy: retry::RetryPolicy::default(),
+            graphql_throttle_retry: retry::GraphQLThrottleRetry::default(),
+            user_agent: DEFAULT_USER_AGENT.to_string(),
+            middlewares: middleware::Middlewares::default(),
+            error_hook: report::ErrorHook::default(),
+            throttle_hook: rate_limit::ThrottleHook::default(),
+            deprecation_hook: deprecation::DeprecationHook::default(),
+            deprecations: Default::default(),
+            rate_limit: Default::default(),
+            rate_limiter: None,
+            plan: None,
+            concurrency: Default::default(),
+            stats: Default::default(),
+            operations: Default::default(),
+            shop_cache: Default::default(),
+            shop_cache_ttl: rest::shop::DEFAULT_SHOP_CACHE_TTL,
+            #[cfg(feature = "schema-validation")]
+            schema: None,
+            #[cfg(feature = "blocking")]
+            blocking_client: Default::default(),
+        };
+        shopify.update_urls();
+
+        shopify
+    }
+
+    /// Send every request to the given base url instead of `https://<shop>.myshopify.com`
+    ///
+    /// This is mostly useful to run integration tests against a mock server.
+    /// # Example
+    /// ```
+    /// use shopify_api::*;
+    /// let shopify = Shopify::new("myshop", "myapikey", ShopifyAPIVersion::Unstable, None)
+    ///     .with_base_url("http://localhost:8080/");
+    ///
+    /// assert_eq!(shopify.get_api_endpoint("products.json"), "http://localhost:8080/admin/api/unstable/products.json");
+    /// assert_eq!(shopify.get_query_url(), "http://localhost:8080/admin/api/unstable/graphql.json");
+    /// ```
+    pub fn with_base_url(mut self, base_url: &str) -> Shopify {
+        self.base_url = base_url.trim_end_matches('/').to_string();
+        self.update_urls();
+        self
+    }
+
+    /// Compute the REST and GraphQL urls from the base url and the API version
+    fn update_urls(&mut self) {
+        let api_version = api_version_to_string(&self.api_version);
+        self.query_url = format!("{}/admin/api/{}/graphql.json", self.base_url, api_version);
+        self.rest_url = format!("{}/admin/api/{}/", self.base_url, api_version);
+    }
+
+    /// Create a new [`ShopifyBuilder`]
+    /// # Example
+    /// ```
+    /// use shopify_api::*;
+    /// let shopify = Shopify::builder()
+    ///     .shop_name("myshop")
+    ///     .access_token("myapikey")
+    ///     .api_version(ShopifyAPIVersion::V2023_10)
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    pub fn builder() -> ShopifyBuilder {
+        ShopifyBuilder::new()
+    }
+
+    /// Use a custom `reqwest::Client` for every request made by this instance
+    ///
+    /// This allows configuring connection pools, proxies or TLS once and reusing
+    /// connections across calls.
+    /// # Example
+    /// ```
+    /// use shopify_api::*;
+    /// let client = reqwest::Client::builder().pool_max_idle_per_host(4).build().unwrap();
+    /// let shopify = Shopify::new("myshop", "myapikey", ShopifyAPIVersion::V2023_10, None).with_client(client);
+    /// ```
+    ///
+    /// With the `blocking` feature, the blocking queries then require a custom blocking client,
+    /// see [`Shopify::with_blocking_client`].
+    pub fn with_client(mut self, client: reqwest::Client) -> Shopify {
+        self.client = client;
+        #[cfg(feature = "blocking")]
+        {
+            self.blocking_client = self.blocking_client.for_custom_client();
         }
+        self
     }
 
     /// Get the shop name
@@ -201,11 +784,86 @@ impl Shopify {
         Ok(self)
     }
 
+    /// Register a middleware run around every REST and GraphQL request
+    ///
+    /// See [`middleware::RequestMiddleware`] for an example.
+    pub fn with_middleware<M>(mut self, middleware: M) -> Shopify
+    where
+        M: middleware::RequestMiddleware + 'static,
+    {
+        self.middlewares.0.push(std::sync::Arc::new(middleware));
+        self
+    }
+
+    /// Call the given hook with the report of every query that failed, after its retries
+    /// # Example
+    /// ```
+    /// use shopify_api::*;
+    ///
+    /// let shopify = Shopify::new("myshop", "myapikey", ShopifyAPIVersion::V2026_10, None)
+    ///     .with_error_hook(|report| {
+    ///         log::error!("{}", serde_json::to_string(report).unwrap());
+    ///     });
+    /// ```
+    pub fn with_error_hook<F>(mut self, hook: F) -> Shopify
+    where
+        F: Fn(&ErrorReport) + Send + Sync + 'static,
+    {
+        self.error_hook = report::ErrorHook::new(hook);
+        self
+    }
+
+    /// Use a custom `reqwest::blocking::Client` for the blocking queries of this instance
+    ///
+    /// A blocking client with the options of the async client is created on the first blocking
+    /// query otherwise, which fails if a custom async client was set with [`Shopify::with_client`].
+    #[cfg(feature = "blocking")]
+    pub fn with_blocking_client(mut self, client: reqwest::blocking::Client) -> Shopify {
+        self.blocking_client = http::BlockingClient::custom(client);
+        self
+    }
+
+    /// Set the retry policy applied to every query
+    pub fn with_retry_policy(mut self, retry_policy: retry::RetryPolicy) -> Shopify {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// Set how THROTTLED GraphQL queries are sent again before the retry policy takes over
+    pub fn with_graphql_throttle_retry(
+        mut self,
+        graphql_throttle_retry: retry::GraphQLThrottleRetry,
+    ) -> Shopify {
+        self.graphql_throttle_retry = graphql_throttle_retry;
+        self
+    }
+
+    /// Get the HTTP client used by this instance
+    pub fn get_client(&self) -> &reqwest::Client {
+        &self.client
+    }
+
+    /// Get the `User-Agent` header sent with every request
+    /// # Example
+    /// ```
+    /// use shopify_api::*;
+    /// let shopify = Shopify::new("myshop", "myapikey", ShopifyAPIVersion::V2023_10, None);
+    /// assert_eq!(shopify.get_user_agent(), DEFAULT_USER_AGENT);
+    /// ```
+    pub fn get_user_agent(&self) -> &str {
+        self.user_agent.as_ref()
+    }
+
     /// Get the query url
     pub fn get_query_url(&self) -> &str {
         self.query_url.as_ref()
     }
 
+    /// Get the base url of the shop
+    pub fn get_base_url(&self) -> &str {
+        self.base_url.as_ref()
+    }
+
     /// Get the rest url
     pub fn rest_url(&self) -> &str {
         self.rest_url.as_ref()
@@ -215,7 +873,8 @@ impl Shopify {
     /// # Example
     /// ```
     /// use shopify_api::*;
-    /// let shopify = Shopify::new("myshop", "myapikey", ShopifyAPIVersion::V2023_10, Some("mysharedsecret"));
+    /// let version = ShopifyAPIVersion::Custom("2023-10".to_string());
+    /// let shopify = Shopify::new("myshop", "myapikey", version, Some("mysharedsecret"));
     ///
     /// assert_eq!(shopify.get_api_endpoint("products.json"), "https://myshop.myshopify.com/admin/api/2023-10/products.json");
     /// ```
@@ -0,0 +1,64 @@
+use std::{fmt, sync::Arc};
+
+use async_trait::async_trait;
+
+use crate::ShopifyAPIError;
+
+/// Response received from Shopify, before it is parsed
+#[derive(Clone, Debug)]
+pub struct ShopifyResponse {
+    pub status: reqwest::StatusCode,
+    pub headers: reqwest::header::HeaderMap,
+    pub body: String,
+}
+
+/// Hooks run around every REST and GraphQL request
+///
+/// Middlewares run `before_send` in the order they were registered and `after_receive`
+/// in the reverse order, so the first registered middleware sees the final response.
+/// Returning an error from a hook aborts the attempt with that error.
+/// # Example
+/// ```
+/// use async_trait::async_trait;
+/// use shopify_api::*;
+/// use shopify_api::middleware::{RequestMiddleware, ShopifyResponse};
+///
+/// struct Tracing;
+///
+/// #[async_trait]
+/// impl RequestMiddleware for Tracing {
+///     async fn before_send(&self, request: &mut reqwest::Request) -> Result<(), ShopifyAPIError> {
+///         request.headers_mut().insert("X-Trace-Id", "my-trace-id".parse().unwrap());
+///         Ok(())
+///     }
+///
+///     async fn after_receive(&self, response: &mut ShopifyResponse) -> Result<(), ShopifyAPIError> {
+///         println!("Shopify answered with {}", response.status);
+///         Ok(())
+///     }
+/// }
+///
+/// let shopify = Shopify::new("myshop", "myapikey", ShopifyAPIVersion::V2023_10, None).with_middleware(Tracing);
+/// ```
+#[async_trait]
+pub trait RequestMiddleware: Send + Sync {
+    /// Called before the request is sent
+    async fn before_send(&self, _request: &mut reqwest::Request) -> Result<(), ShopifyAPIError> {
+        Ok(())
+    }
+
+    /// Called after the response is received and before it is parsed
+    async fn after_receive(&self, _response: &mut ShopifyResponse) -> Result<(), ShopifyAPIError> {
+        Ok(())
+    }
+}
+
+/// Middlewares registered on a client
+#[derive(Clone, Default)]
+pub(crate) struct Middlewares(pub(crate) Vec<Arc<dyn RequestMiddleware>>);
+
+impl fmt::Debug for Middlewares {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Middlewares({})", self.0.len())
+    }
+}
@@ -0,0 +1,201 @@
+use std::time::Duration;
+
+use reqwest::header::{HeaderMap, HeaderValue, IntoHeaderName};
+
+use crate::{cancel::CancellationToken, retry::RetryPolicy, ShopifyAPIVersion};
+
+/// Options applied to a single REST or GraphQL query
+///
+/// Every option left unset falls back to the configuration of the [`crate::Shopify`] client.
+/// # Example
+/// ```
+/// use std::time::Duration;
+/// use shopify_api::RequestOptions;
+///
+/// let options = RequestOptions::new()
+///     .timeout(Duration::from_secs(5))
+///     .header("Accept-Language", "fr".parse().unwrap());
+///
+/// assert_eq!(options.get_timeout(), Some(Duration::from_secs(5)));
+/// assert_eq!(options.get_headers()["accept-language"], "fr");
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct RequestOptions {
+    timeout: Option<Duration>,
+    headers: HeaderMap,
+    api_version: Option<ShopifyAPIVersion>,
+    retry_policy: Option<RetryPolicy>,
+    cancellation_token: Option<CancellationToken>,
+    idempotency_key: Option<IdempotencyKey>,
+    priority: Priority,
+}
+
+/// Priority of a query waiting for the rate limiter or the concurrency limit
+///
+/// Queries waiting with a higher priority are sent first. Queries that do not need to wait
+/// are sent right away whatever their priority.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Priority {
+    /// Background work, e.g. a catalog sync
+    Low,
+    #[default]
+    Normal,
+    /// Interactive work, e.g. handling a webhook or a user action
+    High,
+}
+
+/// Idempotency key sent with POST and PUT REST requests
+#[derive(Clone, Debug)]
+enum IdempotencyKey {
+    Fixed(String),
+    Generated,
+}
+
+impl RequestOptions {
+    /// Create options that use the client configuration
+    pub fn new() -> RequestOptions {
+        RequestOptions::default()
+    }
+
+    /// Set the total timeout of the request, overriding the client timeout
+    pub fn timeout(mut self, timeout: Duration) -> RequestOptions {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Get the timeout of the request
+    pub fn get_timeout(&self) -> Option<Duration> {
+        self.timeout
+    }
+
+    /// Add a header to the request, replacing the default header with the same name
+    pub fn header<K>(mut self, name: K, value: HeaderValue) -> RequestOptions
+    where
+        K: IntoHeaderName,
+    {
+        self.headers.insert(name, value);
+        self
+    }
+
+    /// Get the extra headers of the request
+    pub fn get_headers(&self) -> &HeaderMap {
+        &self.headers
+    }
+
+    /// Use another API version for this request only
+    /// # Example
+    /// ```no_run
+    /// use shopify_api::*;
+    /// use shopify_api::utils::ReadJsonTreeSteps;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let shopify = Shopify::new("myshop", "myapikey", ShopifyAPIVersion::V2026_10, None);
+    ///     let options = RequestOptions::new().api_version(ShopifyAPIVersion::Unstable);
+    ///     let json_finder = vec![ReadJsonTreeSteps::Key("data")];
+    ///
+    ///     let data: serde_json::Value = shopify
+    ///         .graphql_query_with_options("{ shop { name } }", &serde_json::json!({}), &json_finder, &options)
+    ///         .await
+    ///         .unwrap();
+    /// }
+    /// ```
+    pub fn api_version(mut self, api_version: ShopifyAPIVersion) -> RequestOptions {
+        self.api_version = Some(api_version);
+        self
+    }
+
+    /// Get the API version override of the request
+    pub fn get_api_version(&self) -> Option<&ShopifyAPIVersion> {
+        self.api_version.as_ref()
+    }
+
+    /// Use another retry policy for this request only
+    pub fn retry_policy(mut self, retry_policy: RetryPolicy) -> RequestOptions {
+        self.retry_policy = Some(retry_policy);
+        self
+    }
+
+    /// Get the retry policy override of the request
+    pub fn get_retry_policy(&self) -> Option<&RetryPolicy> {
+        self.retry_policy.as_ref()
+    }
+
+    /// Abort the request, including its retries and backoff, when the token is cancelled
+    ///
+    /// See [`CancellationToken`].
+    pub fn cancellation_token(mut self, token: CancellationToken) -> RequestOptions {
+        self.cancellation_token = Some(token);
+        self
+    }
+
+    /// Get the cancellation token of the request
+    pub fn get_cancellation_token(&self) -> Option<&CancellationToken> {
+        self.cancellation_token.as_ref()
+    }
+
+    /// Send the given `Idempotency-Key` header with POST and PUT REST requests
+    ///
+    /// Every attempt of the request sends the same key, so a write retried after a network failure
+    /// is not applied twice by Shopify.
+    pub fn idempotency_key(mut self, key: &str) -> RequestOptions {
+        self.idempotency_key = Some(IdempotencyKey::Fixed(key.to_string()));
+        self
+    }
+
+    /// Send a random `Idempotency-Key` header with POST and PUT REST requests
+    ///
+    /// A new UUID is generated for each query and reused by all of its retries,
+    /// so the options can be shared between queries.
+    /// # Example
+    /// ```no_run
+    /// use std::collections::HashMap;
+    /// use shopify_api::*;
+    /// use shopify_api::rest::ShopifyAPIRestType;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let shopify = Shopify::new("myshop", "myapikey", ShopifyAPIVersion::V2026_10, None);
+    ///     let options = RequestOptions::new().generate_idempotency_key();
+    ///     let order = serde_json::json!({"order": {"line_items": [{"variant_id": 447654529, "quantity": 1}]}});
+    ///
+    ///     let created: serde_json::Value = shopify
+    ///         .rest_query_with_options(&ShopifyAPIRestType::Post("orders.json", &HashMap::new(), &order), &None, &options)
+    ///         .await
+    ///         .unwrap();
+    /// }
+    /// ```
+    pub fn generate_idempotency_key(mut self) -> RequestOptions {
+        self.idempotency_key = Some(IdempotencyKey::Generated);
+        self
+    }
+
+    /// Set the priority of the request when it waits for the rate limiter or the concurrency limit
+    /// # Example
+    /// ```
+    /// use shopify_api::RequestOptions;
+    /// use shopify_api::options::Priority;
+    ///
+    /// let options = RequestOptions::new().priority(Priority::High);
+    ///
+    /// assert_eq!(options.get_priority(), Priority::High);
+    /// assert_eq!(RequestOptions::new().get_priority(), Priority::Normal);
+    /// ```
+    pub fn priority(mut self, priority: Priority) -> RequestOptions {
+        self.priority = priority;
+        self
+    }
+
+    /// Get the priority of the request
+    pub fn get_priority(&self) -> Priority {
+        self.priority
+    }
+
+    /// Get the idempotency key of a new query, generating it if needed
+    pub(crate) fn new_idempotency_key(&self) -> Option<String> {
+        match self.idempotency_key.as_ref()? {
+            IdempotencyKey::Fixed(key) => Some(key.clone()),
+            IdempotencyKey::Generated => Some(uuid::Uuid::new_v4().to_string()),
+        }
+    }
+}
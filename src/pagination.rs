@@ -0,0 +1,186 @@
+use std::{
+    collections::VecDeque,
+    fmt,
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use futures_util::Stream;
+use serde::{Deserialize, Serialize};
+
+use crate::ShopifyAPIError;
+
+/// Page of items returned by the paged queries
+///
+/// Returned by [`crate::Shopify::rest_page`] and [`crate::Shopify::graphql_page`]. The cursors
+/// can be stored to query the pages around this one later, e.g. after a restart.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Page<T> {
+    pub items: Vec<T>,
+    /// Cursor of the next page, missing on the last page
+    pub next: Option<PageCursor>,
+    /// Cursor of the previous page, missing on the first page
+    pub prev: Option<PageCursor>,
+    /// `X-Request-Id` header of the response
+    pub request_id: Option<String>,
+}
+
+impl<T> Page<T> {
+    /// Check if there is a page after this one
+    pub fn has_next(&self) -> bool {
+        self.next.is_some()
+    }
+}
+
+/// Opaque position of a page: the `page_info` parameter of a REST page, or the cursor of a
+/// GraphQL connection
+///
+/// Cursors are serialized as plain strings, so a sync can checkpoint its position in a database
+/// and resume from it after a crash.
+/// # Example
+/// ```
+/// use shopify_api::pagination::PageCursor;
+///
+/// let cursor = PageCursor::new("eyJsYXN0X2lkIjo0MjB9");
+/// assert_eq!(cursor.as_str(), "eyJsYXN0X2lkIjo0MjB9");
+/// assert_eq!(cursor.to_string(), "eyJsYXN0X2lkIjo0MjB9");
+///
+/// let checkpoint = serde_json::to_string(&Some(cursor.clone())).unwrap();
+/// assert_eq!(checkpoint, r#""eyJsYXN0X2lkIjo0MjB9""#);
+/// let resumed: Option<PageCursor> = serde_json::from_str(&checkpoint).unwrap();
+/// assert_eq!(resumed, Some(cursor));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct PageCursor(String);
+
+impl PageCursor {
+    /// Create a cursor from a stored value
+    pub fn new(cursor: impl Into<String>) -> PageCursor {
+        PageCursor(cursor.into())
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for PageCursor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+/// Stream of the items of consecutive pages, requesting the next page while the current one is consumed
+///
+/// `fetch` queries the page at a cursor, the first page being queried without one. Each page holds
+/// the cursor of the next one, so a single request is in flight at a time, and it only progresses
+/// while the stream is polled. The received pages are buffered, up to `prefetch` pages ahead of the
+/// current one.
+pub(crate) struct PageStream<ItemType, FetchType, FutureType> {
+    fetch: FetchType,
+    prefetch: usize,
+    /// Items of the current page not yielded yet
+    items: std::vec::IntoIter<ItemType>,
+    /// Pages received ahead of the current one
+    pages: VecDeque<Vec<ItemType>>,
+    /// Request of the next page
+    in_flight: Option<Pin<Box<FutureType>>>,
+    /// Error yielded after the received pages
+    error: Option<ShopifyAPIError>,
+    /// Cursor of the next page to query (`Some(None)` for the first page), `None` after the last page
+    next: Option<Option<PageCursor>>,
+}
+
+impl<ItemType, FetchType, FutureType> PageStream<ItemType, FetchType, FutureType>
+where
+    FetchType: FnMut(Option<PageCursor>) -> FutureType,
+    FutureType: Future<Output = Result<Page<ItemType>, ShopifyAPIError>>,
+{
+    pub(crate) fn new(fetch: FetchType, prefetch: usize) -> Self {
+        PageStream {
+            fetch,
+            prefetch,
+            items: Vec::new().into_iter(),
+            pages: VecDeque::new(),
+            in_flight: None,
+            error: None,
+            next: Some(None),
+        }
+    }
+
+    /// Start and poll the requests of the next pages while there is room for them
+    fn poll_fetch(&mut self, cx: &mut Context<'_>) {
+        loop {
+            let in_flight = match &mut self.in_flight {
+                Some(in_flight) => in_flight,
+                None => {
+                    let needed = self.items.len() == 0 && self.pages.is_empty();
+                    if self.error.is_some() || !(needed || self.pages.len() < self.prefetch) {
+                        return;
+                    }
+                    let Some(cursor) = self.next.take() else {
+                        return;
+                    };
+                    self.in_flight.insert(Box::pin((self.fetch)(cursor)))
+                }
+            };
+
+            match in_flight.as_mut().poll(cx) {
+                Poll::Ready(Ok(page)) => {
+                    self.in_flight = None;
+                    self.next = page.next.map(Some);
+                    if !page.items.is_empty() {
+                        self.pages.push_back(page.items);
+                    }
+                }
+                Poll::Ready(Err(error)) => {
+                    self.in_flight = None;
+                    self.error = Some(error);
+                    return;
+                }
+                Poll::Pending => return,
+            }
+        }
+    }
+}
+
+// The request future is boxed, no field is structurally pinned
+impl<ItemType, FetchType, FutureType> Unpin for PageStream<ItemType, FetchType, FutureType> {}
+
+impl<ItemType, FetchType, FutureType> Stream for PageStream<ItemType, FetchType, FutureType>
+where
+    FetchType: FnMut(Option<PageCursor>) -> FutureType,
+    FutureType: Future<Output = Result<Page<ItemType>, ShopifyAPIError>>,
+{
+    type Item = Result<ItemType, ShopifyAPIError>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+
+        loop {
+            if this.items.len() > 0 {
+                this.poll_fetch(cx);
+                return Poll::Ready(this.items.next().map(Ok));
+            }
+            if let Some(items) = this.pages.pop_front() {
+                this.items = items.into_iter();
+                continue;
+            }
+            if let Some(error) = this.error.take() {
+                this.next = None;
+                return Poll::Ready(Some(Err(error)));
+            }
+
+            this.poll_fetch(cx);
+            if this.items.len() > 0 || !this.pages.is_empty() || this.error.is_some() {
+                continue;
+            }
+            return match this.in_flight {
+                Some(_) => Poll::Pending,
+                None => Poll::Ready(None),
+            };
+        }
+    }
+}
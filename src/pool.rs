@@ -0,0 +1,181 @@
+use std::{
+    collections::HashMap,
+    fmt,
+    sync::{Arc, RwLock},
+};
+
+use async_trait::async_trait;
+
+use crate::{Shopify, ShopifyAPIError, ShopifyBuilder};
+
+/// Source of the access tokens of the shops of a [`ShopifyPool`]
+///
+/// It is implemented for closures returning the token of a shop, or `None` if the app is not installed.
+#[async_trait]
+pub trait TokenStore: Send + Sync {
+    /// Get the access token of a shop (`myshop`)
+    async fn access_token(&self, shop: &str) -> Result<Option<String>, ShopifyAPIError>;
+}
+
+#[async_trait]
+impl<F> TokenStore for F
+where
+    F: Fn(&str) -> Option<String> + Send + Sync,
+{
+    async fn access_token(&self, shop: &str) -> Result<Option<String>, ShopifyAPIError> {
+        Ok(self(shop))
+    }
+}
+
+/// Clients of many shops sharing the same configuration and the same `reqwest::Client`
+///
+/// Every client is built from a template [`ShopifyBuilder`] without shop name nor access token.
+/// Clients are added with [`ShopifyPool::insert`], or built on first use from a [`TokenStore`].
+/// # Example
+/// ```
+/// use std::time::Duration;
+/// use shopify_api::*;
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let template = ShopifyBuilder::new()
+///         .api_version(ShopifyAPIVersion::V2026_10)
+///         .timeout(Duration::from_secs(30));
+///
+///     let pool = ShopifyPool::new(template)
+///         .unwrap()
+///         .token_store(|shop: &str| (shop == "other-shop").then(|| "other-token".to_string()));
+///
+///     pool.insert("my-shop.myshopify.com", "my-token").unwrap();
+///
+///     let shopify = pool.get("my-shop").await.unwrap();
+///     assert_eq!(shopify.get_shop(), "my-shop");
+///
+///     let other = pool.get("other-shop").await.unwrap();
+///     assert_eq!(other.get_shop(), "other-shop");
+///     assert!(pool.get("unknown-shop").await.is_err());
+/// }
+/// ```
+#[derive(Clone)]
+pub struct ShopifyPool {
+    template: ShopifyBuilder,
+    token_store: Option<Arc<dyn TokenStore>>,
+    clients: Arc<RwLock<HashMap<String, Arc<Shopify>>>>,
+}
+
+impl ShopifyPool {
+    /// Create an empty pool
+    /// # Errors
+    /// This function returns an error if the client level options of the template are invalid
+    pub fn new(template: ShopifyBuilder) -> Result<ShopifyPool, ShopifyAPIError> {
+        Ok(ShopifyPool {
+            template: template.share_client()?,
+            token_store: None,
+            clients: Default::default(),
+        })
+    }
+
+    /// Build the missing clients from the tokens of the given store
+    pub fn token_store<T>(mut self, token_store: T) -> ShopifyPool
+    where
+        T: TokenStore + 'static,
+    {
+        self.token_store = Some(Arc::new(token_store));
+        self
+    }
+
+    /// Build the client of a shop and add it to the pool, replacing the previous one
+    /// # Errors
+    /// This function returns an error if the template is incomplete or the access token is empty
+    pub fn insert(&self, shop: &str, access_token: &str) -> Result<Arc<Shopify>, ShopifyAPIError> {
+        let shop = shop_name(shop);
+        let shopify = self.build(shop, access_token)?;
+
+        self.clients
+            .write()
+            .unwrap()
+            .insert(shop.to_string(), shopify.clone());
+
+        Ok(shopify)
+    }
+
+    /// Get the client of a shop (`myshop` or `myshop.myshopify.com`)
+    ///
+    /// The client is built from the token store if it is not in the pool yet.
+    /// # Errors
+    /// This function returns an error if the shop is unknown or the token store fails
+    pub async fn get(&self, shop: &str) -> Result<Arc<Shopify>, ShopifyAPIError> {
+        let shop = shop_name(shop);
+        if let Some(shopify) = self.clients.read().unwrap().get(shop) {
+            return Ok(shopify.clone());
+        }
+
+        let access_token = match &self.token_store {
+            Some(token_store) => token_store.access_token(shop).await?,
+            None => None,
+        };
+
+        let Some(access_token) = access_token else {
+            return Err(ShopifyAPIError::InvalidConfiguration(format!(
+                "No access token for shop {shop}"
+            )));
+        };
+        let shopify = self.build(shop, &access_token)?;
+
+        // Another call may have added the shop while the token was fetched, its client is kept so
+        // that every caller shares the same rate limiter
+        Ok(self
+            .clients
+            .write()
+            .unwrap()
+            .entry(shop.to_string())
+            .or_insert(shopify)
+            .clone())
+    }
+
+    /// Build the client of a shop from the template
+    fn build(&self, shop: &str, access_token: &str) -> Result<Arc<Shopify>, ShopifyAPIError> {
+        Ok(Arc::new(
+            self.template
+                .clone()
+                .unshare_rate_limiter()
+                .shop_name(shop)
+                .access_token(access_token)
+                .build()?,
+        ))
+    }
+
+    /// Remove the client of a shop, e.g. when the app is uninstalled
+    pub fn remove(&self, shop: &str) -> Option<Arc<Shopify>> {
+        self.clients.write().unwrap().remove(shop_name(shop))
+    }
+
+    /// Get the shops with a client in the pool
+    pub fn shops(&self) -> Vec<String> {
+        self.clients.read().unwrap().keys().cloned().collect()
+    }
+
+    /// Get the number of clients in the pool
+    pub fn len(&self) -> usize {
+        self.clients.read().unwrap().len()
+    }
+
+    /// Check if the pool has no client
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl fmt::Debug for ShopifyPool {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ShopifyPool")
+            .field("template", &self.template)
+            .field("shops", &self.shops())
+            .finish_non_exhaustive()
+    }
+}
+
+/// Get the shop name used as key of the pool
+fn shop_name(shop: &str) -> &str {
+    shop.trim_end_matches(".myshopify.com")
+}
@@ -0,0 +1,620 @@
+use std::{
+    collections::HashMap,
+    fmt,
+    sync::{Arc, Mutex, OnceLock, Weak},
+    time::Duration,
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{http, middleware::ShopifyResponse, options::Priority, utils, Shopify};
+
+/// Usage of the REST API leaky bucket, from the `X-Shopify-Shop-Api-Call-Limit` header
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RestCallLimit {
+    /// Number of calls in the bucket
+    pub used: u32,
+    /// Size of the bucket (40, or 400 for Shopify Plus)
+    pub capacity: u32,
+}
+
+impl RestCallLimit {
+    /// Number of calls that can be made before the bucket is full
+    pub fn remaining(&self) -> u32 {
+        self.capacity.saturating_sub(self.used)
+    }
+}
+
+/// State of the GraphQL cost bucket, from `extensions.cost.throttleStatus`
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GraphQLThrottleStatus {
+    /// Size of the bucket, in cost points
+    pub maximum_available: f64,
+    /// Points available when the response was sent
+    pub currently_available: f64,
+    /// Points restored every second
+    pub restore_rate: f64,
+}
+
+/// Cost of a GraphQL query, from the `extensions.cost` of its response
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GraphQLCost {
+    /// Cost estimated by Shopify before running the query
+    pub requested_query_cost: f64,
+    /// Cost of the query once run, missing if it was throttled
+    pub actual_query_cost: Option<f64>,
+    pub throttle_status: GraphQLThrottleStatus,
+}
+
+/// Rate limit usage of a shop, as reported by the last responses
+/// # Example
+/// ```
+/// use shopify_api::*;
+///
+/// let shopify = Shopify::new("myshop", "myapikey", ShopifyAPIVersion::V2026_10, None);
+///
+/// // Nothing is known until a first response is received
+/// assert_eq!(shopify.rate_limit_state().rest, None);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct RateLimitState {
+    /// REST bucket usage after the last REST response
+    pub rest: Option<RestCallLimit>,
+    /// Cost of the last GraphQL query and state of the GraphQL bucket after it
+    pub graphql: Option<GraphQLCost>,
+}
+
+/// Rate limit state shared by the clones of a client
+#[derive(Debug, Default)]
+pub(crate) struct RateLimitTracker {
+    state: Mutex<RateLimitState>,
+    graphql_budget: Mutex<Option<Bucket>>,
+}
+
+/// Leaky bucket estimated on the client, including the amounts reserved by queries in flight
+#[derive(Debug)]
+struct Bucket {
+    available: f64,
+    maximum: f64,
+    restore_rate: f64,
+    updated_at: Option<f64>,
+}
+
+impl Bucket {
+    fn new(available: f64, maximum: f64, restore_rate: f64) -> Bucket {
+        Bucket {
+            available,
+            maximum,
+            restore_rate,
+            updated_at: utils::now(),
+        }
+    }
+
+    /// Reserve the given amount, and get the delay to wait until it is restored
+    ///
+    /// No delay is returned if the amount exceeds the bucket size, or without a clock.
+    fn take(&mut self, amount: f64) -> Option<Duration> {
+        self.refill()?;
+
+        if amount > self.maximum || self.restore_rate <= 0.0 {
+            return None;
+        }
+
+        let missing = amount - self.available;
+        self.available -= amount;
+        if missing <= 0.0 {
+            return None;
+        }
+
+        Some(Duration::from_secs_f64(missing / self.restore_rate))
+    }
+
+    /// Add the amount restored since the last update, failing without a clock
+    fn refill(&mut self) -> Option<()> {
+        let now = utils::now()?;
+        let elapsed = now - self.updated_at.unwrap_or(now);
+        self.available = (self.available + elapsed * self.restore_rate).min(self.maximum);
+        self.updated_at = Some(now);
+
+        Some(())
+    }
+}
+
+impl RateLimitTracker {
+    /// Update the state from the headers of a response
+    pub(crate) fn record(&self, response: &ShopifyResponse) {
+        if let Some((used, capacity)) = http::parse_call_limit(&response.headers) {
+            self.state.lock().unwrap().rest = Some(RestCallLimit { used, capacity });
+        }
+    }
+
+    /// Update the state from the `extensions.cost` of a GraphQL response
+    pub(crate) fn record_graphql_cost(&self, cost: &serde_json::Value) {
+        let Ok(cost) = serde_json::from_value::<GraphQLCost>(cost.clone()) else {
+            return;
+        };
+
+        let status = cost.throttle_status;
+        *self.graphql_budget.lock().unwrap() = Some(Bucket::new(
+            status.currently_available,
+            status.maximum_available,
+            status.restore_rate,
+        ));
+        self.state.lock().unwrap().graphql = Some(cost);
+    }
+
+    /// Reserve the points of the next GraphQL query, and get the delay to wait before sending it
+    ///
+    /// The cost of the last query is used as the expected cost. No delay is returned if the
+    /// bucket state is unknown, or if the query cost exceeds the bucket size.
+    pub(crate) fn reserve_graphql(&self) -> Option<Duration> {
+        let cost = self.state.lock().unwrap().graphql?.requested_query_cost;
+
+        self.graphql_budget.lock().unwrap().as_mut()?.take(cost)
+    }
+
+    pub(crate) fn state(&self) -> RateLimitState {
+        self.state.lock().unwrap().clone()
+    }
+}
+
+/// Shopify plan of a shop, setting the size of its rate limit buckets
+///
+/// See [`Shopify::with_plan`].
+/// # Example
+/// ```
+/// use shopify_api::rate_limit::ShopifyPlan;
+///
+/// assert_eq!(ShopifyPlan::Plus.rest_bucket_size(), 400);
+/// assert_eq!(ShopifyPlan::Standard.graphql_restore_rate(), 100.0);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ShopifyPlan {
+    /// Basic, Shopify and Grow plans
+    Standard,
+    Advanced,
+    Plus,
+    /// Commerce Components
+    Enterprise,
+}
+
+impl ShopifyPlan {
+    /// Number of REST requests leaking from the bucket every second
+    pub fn rest_requests_per_second(&self) -> f64 {
+        match self {
+            ShopifyPlan::Standard => 2.0,
+            ShopifyPlan::Advanced => 4.0,
+            ShopifyPlan::Plus => 20.0,
+            ShopifyPlan::Enterprise => 40.0,
+        }
+    }
+
+    /// Number of REST requests the bucket can hold
+    pub fn rest_bucket_size(&self) -> u32 {
+        match self {
+            ShopifyPlan::Standard => 40,
+            ShopifyPlan::Advanced => 80,
+            ShopifyPlan::Plus => 400,
+            ShopifyPlan::Enterprise => 800,
+        }
+    }
+
+    /// Number of GraphQL cost points restored every second
+    pub fn graphql_restore_rate(&self) -> f64 {
+        match self {
+            ShopifyPlan::Standard => 100.0,
+            ShopifyPlan::Advanced => 200.0,
+            ShopifyPlan::Plus => 1000.0,
+            ShopifyPlan::Enterprise => 2000.0,
+        }
+    }
+
+    /// Number of GraphQL cost points the bucket can hold
+    pub fn graphql_bucket_size(&self) -> f64 {
+        self.graphql_restore_rate() * 20.0
+    }
+
+    /// Maximum cost of a single GraphQL query, the same for every plan
+    pub fn graphql_max_query_cost(&self) -> f64 {
+        1000.0
+    }
+
+    /// Token bucket matching the REST limits of the plan
+    pub fn rate_limiter(&self) -> RateLimiter {
+        RateLimiter::new(self.rest_requests_per_second(), self.rest_bucket_size())
+    }
+
+    /// Time for one request to leak from a full REST bucket
+    pub(crate) fn rest_leak_delay(&self) -> Duration {
+        Duration::from_secs_f64(1.0 / self.rest_requests_per_second())
+    }
+}
+
+/// Client side token bucket limiting the rate of the REST requests
+///
+/// Requests wait for a token before being sent, so concurrent tasks sharing a client stay
+/// under the Shopify limits instead of being rejected with a 429 status.
+/// Clones share the same bucket. Rate limiting is not available on wasm32.
+/// # Example
+/// ```
+/// use shopify_api::*;
+/// use shopify_api::rate_limit::RateLimiter;
+///
+/// let shopify = ShopifyBuilder::new()
+///     .shop_name("myshop")
+///     .access_token("myapikey")
+///     .api_version(ShopifyAPIVersion::V2026_10)
+///     .rate_limiter(RateLimiter::standard())
+///     .build()
+///     .unwrap();
+/// ```
+#[derive(Clone, Debug)]
+pub struct RateLimiter {
+    requests_per_second: f64,
+    burst: u32,
+    bucket: Arc<Mutex<LimiterBucket>>,
+}
+
+#[derive(Debug)]
+struct LimiterBucket {
+    bucket: Bucket,
+    /// Number of requests waiting for a token, by priority
+    waiting: [usize; 3],
+}
+
+impl LimiterBucket {
+    /// Take a token, or get the delay to wait before trying again
+    ///
+    /// The tokens restored while requests of a higher priority are waiting are left to them.
+    fn try_take(&mut self, priority: Priority) -> Option<Duration> {
+        if self.bucket.restore_rate <= 0.0 {
+            return None;
+        }
+        self.bucket.refill()?;
+
+        let ahead: usize = self.waiting[priority as usize + 1..].iter().sum();
+        let needed = 1.0 + ahead as f64;
+        if self.bucket.available >= needed {
+            self.bucket.available -= 1.0;
+            return None;
+        }
+
+        Some(Duration::from_secs_f64(
+            (needed - self.bucket.available) / self.bucket.restore_rate,
+        ))
+    }
+}
+
+/// Request counted as waiting for a token until dropped
+struct Waiting<'a> {
+    limiter: &'a RateLimiter,
+    priority: Priority,
+}
+
+impl<'a> Waiting<'a> {
+    fn new(limiter: &'a RateLimiter, priority: Priority) -> Waiting<'a> {
+        limiter.bucket.lock().unwrap().waiting[priority as usize] += 1;
+        Waiting { limiter, priority }
+    }
+}
+
+impl Drop for Waiting<'_> {
+    fn drop(&mut self) {
+        self.limiter.bucket.lock().unwrap().waiting[self.priority as usize] -= 1;
+    }
+}
+
+impl RateLimiter {
+    /// Allow `requests_per_second` requests every second on average, and bursts of `burst` requests
+    pub fn new(requests_per_second: f64, burst: u32) -> RateLimiter {
+        let burst = burst.max(1);
+        RateLimiter {
+            requests_per_second,
+            burst,
+            bucket: Arc::new(Mutex::new(LimiterBucket {
+                bucket: Bucket::new(burst as f64, burst as f64, requests_per_second),
+                waiting: [0; 3],
+            })),
+        }
+    }
+
+    /// Limits of a standard shop: 2 requests per second, bursts of 40 requests
+    pub fn standard() -> RateLimiter {
+        ShopifyPlan::Standard.rate_limiter()
+    }
+
+    /// Limits of a Shopify Plus shop: 20 requests per second, bursts of 400 requests
+    ///
+    /// See [`ShopifyPlan::rate_limiter`] for the limits of the other plans.
+    /// # Example
+    /// ```
+    /// use shopify_api::rate_limit::{RateLimiter, ShopifyPlan};
+    ///
+    /// let limiter = RateLimiter::plus();
+    /// assert_eq!(limiter.get_requests_per_second(), 20.0);
+    /// assert_eq!(limiter.get_burst(), ShopifyPlan::Plus.rest_bucket_size());
+    /// ```
+    pub fn plus() -> RateLimiter {
+        ShopifyPlan::Plus.rate_limiter()
+    }
+
+    /// Get the average number of requests allowed every second
+    pub fn get_requests_per_second(&self) -> f64 {
+        self.requests_per_second
+    }
+
+    /// Get the number of requests allowed in a burst
+    pub fn get_burst(&self) -> u32 {
+        self.burst
+    }
+
+    /// Create a limiter with the same limits and its own bucket
+    pub(crate) fn unshared(&self) -> RateLimiter {
+        RateLimiter::new(self.requests_per_second, self.burst)
+    }
+
+    fn downgrade(&self) -> WeakRateLimiter {
+        WeakRateLimiter {
+            requests_per_second: self.requests_per_second,
+            burst: self.burst,
+            bucket: Arc::downgrade(&self.bucket),
+        }
+    }
+
+    /// Wait until a request can be sent
+    pub async fn acquire(&self) {
+        self.acquire_with_priority(Priority::Normal).await;
+    }
+
+    /// Wait until a request of the given priority can be sent
+    ///
+    /// Waiting requests of a higher priority get the next tokens.
+    pub async fn acquire_with_priority(&self, priority: Priority) {
+        self.wait(priority, |_| ()).await;
+    }
+
+    /// Wait for a token, calling `on_delay` with the first delay if the request has to wait
+    pub(crate) async fn wait<F>(&self, priority: Priority, on_delay: F)
+    where
+        F: FnOnce(Duration),
+    {
+        let mut on_delay = Some(on_delay);
+        let mut waiting = None;
+
+        loop {
+            let delay = self.bucket.lock().unwrap().try_take(priority);
+            let Some(delay) = delay else {
+                return;
+            };
+            if let Some(on_delay) = on_delay.take() {
+                on_delay(delay);
+            }
+            waiting.get_or_insert_with(|| Waiting::new(self, priority));
+            utils::sleep(delay).await;
+        }
+    }
+
+    /// Block the current thread until a request of the given priority can be sent
+    #[cfg(feature = "blocking")]
+    pub(crate) fn wait_blocking<F>(&self, priority: Priority, on_delay: F)
+    where
+        F: FnOnce(Duration),
+    {
+        let mut on_delay = Some(on_delay);
+        let mut waiting = None;
+
+        loop {
+            let delay = self.bucket.lock().unwrap().try_take(priority);
+            let Some(delay) = delay else {
+                return;
+            };
+            if let Some(on_delay) = on_delay.take() {
+                on_delay(delay);
+            }
+            waiting.get_or_insert_with(|| Waiting::new(self, priority));
+            std::thread::sleep(delay);
+        }
+    }
+}
+
+/// Cause of a delay due to rate limiting
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ThrottleReason {
+    /// A REST request waited for the [`RateLimiter`]
+    RateLimiter,
+    /// A GraphQL query waited for the estimated bucket to have enough points for its cost
+    GraphQLBudget,
+    /// A REST request was rejected with a 429 status and will be retried
+    RateLimited,
+    /// A GraphQL query was rejected with a `THROTTLED` error and will be sent again
+    Throttled,
+}
+
+/// Delay due to rate limiting, given to the hook set with [`Shopify::with_throttle_hook`]
+#[derive(Clone, Debug)]
+pub struct ThrottleInfo {
+    pub reason: ThrottleReason,
+    /// Time waited before sending the request
+    pub delay: Duration,
+    /// Rate limit usage reported by the last responses
+    pub rate_limit: RateLimitState,
+}
+
+type ThrottleHookFn = Arc<dyn Fn(&ThrottleInfo) + Send + Sync>;
+
+/// Hook called every time a request is delayed due to rate limiting
+#[derive(Clone, Default)]
+pub(crate) struct ThrottleHook(Option<ThrottleHookFn>);
+
+impl ThrottleHook {
+    pub(crate) fn new<F>(hook: F) -> ThrottleHook
+    where
+        F: Fn(&ThrottleInfo) + Send + Sync + 'static,
+    {
+        ThrottleHook(Some(Arc::new(hook)))
+    }
+}
+
+impl fmt::Debug for ThrottleHook {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            Some(_) => f.write_str("ThrottleHook(Some(..))"),
+            None => f.write_str("ThrottleHook(None)"),
+        }
+    }
+}
+
+/// Limiter registered for a shop, not keeping its bucket alive
+#[derive(Debug)]
+struct WeakRateLimiter {
+    requests_per_second: f64,
+    burst: u32,
+    bucket: Weak<Mutex<LimiterBucket>>,
+}
+
+impl WeakRateLimiter {
+    fn upgrade(&self) -> Option<RateLimiter> {
+        Some(RateLimiter {
+            requests_per_second: self.requests_per_second,
+            burst: self.burst,
+            bucket: self.bucket.upgrade()?,
+        })
+    }
+}
+
+/// Rate limit state registered for a shop with [`Shopify::with_shared_rate_limits`]
+#[derive(Debug, Default)]
+struct SharedRateLimits {
+    tracker: Weak<RateLimitTracker>,
+    limiter: Option<WeakRateLimiter>,
+}
+
+/// Registry of the rate limit state of every shop, keyed by shop domain
+fn registry() -> &'static Mutex<HashMap<String, SharedRateLimits>> {
+    static REGISTRY: OnceLock<Mutex<HashMap<String, SharedRateLimits>>> = OnceLock::new();
+    REGISTRY.get_or_init(Default::default)
+}
+
+impl Shopify {
+    /// Get the rate limit usage reported by the last responses
+    ///
+    /// The state is shared by the clones of this client, so it can be used to throttle
+    /// concurrent tasks proactively before Shopify rejects their requests.
+    /// GraphQL queries are already delayed automatically when the points available in the bucket
+    /// are lower than the cost of the last query.
+    pub fn rate_limit_state(&self) -> RateLimitState {
+        self.rate_limit.state()
+    }
+
+    /// Limit the rate of the REST requests with a client side token bucket
+    ///
+    /// See [`RateLimiter`].
+    pub fn with_rate_limiter(mut self, rate_limiter: RateLimiter) -> Shopify {
+        self.rate_limiter = Some(rate_limiter);
+        self
+    }
+
+    /// Report a request delayed due to rate limiting to the throttle hook
+    pub(crate) fn throttled(&self, reason: ThrottleReason, delay: Duration) {
+        log::debug!("shopify request delayed by {delay:?} ({reason:?})");
+
+        if let Some(hook) = &self.throttle_hook.0 {
+            hook(&ThrottleInfo {
+                reason,
+                delay,
+                rate_limit: self.rate_limit_state(),
+            });
+        }
+    }
+
+    /// Call the given hook every time a request is delayed or retried due to rate limiting
+    ///
+    /// This makes it possible to emit metrics, or to alert when the app is consistently
+    /// saturating the limits of a shop.
+    /// # Example
+    /// ```
+    /// use shopify_api::*;
+    ///
+    /// let shopify = Shopify::new("myshop", "myapikey", ShopifyAPIVersion::V2026_10, None)
+    ///     .with_throttle_hook(|info| {
+    ///         log::warn!("request delayed by {:?} ({:?})", info.delay, info.reason);
+    ///     });
+    /// ```
+    pub fn with_throttle_hook<F>(mut self, hook: F) -> Shopify
+    where
+        F: Fn(&ThrottleInfo) + Send + Sync + 'static,
+    {
+        self.throttle_hook = ThrottleHook::new(hook);
+        self
+    }
+
+    /// Configure the rate limits for the plan of the shop
+    ///
+    /// REST requests are limited with the [`RateLimiter`] of the plan. The delays before retrying
+    /// a 429 response without a `Retry-After` header, or a THROTTLED GraphQL query without its cost,
+    /// are computed from the rates of the plan.
+    /// # Example
+    /// ```
+    /// use shopify_api::*;
+    /// use shopify_api::rate_limit::ShopifyPlan;
+    ///
+    /// let shopify = Shopify::new("myshop", "myapikey", ShopifyAPIVersion::V2026_10, None)
+    ///     .with_plan(ShopifyPlan::Plus);
+    ///
+    /// assert_eq!(shopify.get_plan(), Some(ShopifyPlan::Plus));
+    /// ```
+    pub fn with_plan(mut self, plan: ShopifyPlan) -> Shopify {
+        self.plan = Some(plan);
+        self.rate_limiter = Some(plan.rate_limiter());
+        self
+    }
+
+    /// Get the plan set with [`Shopify::with_plan`]
+    pub fn get_plan(&self) -> Option<ShopifyPlan> {
+        self.plan
+    }
+
+    /// Share the rate limit state with the other clients of the same shop in this process
+    ///
+    /// Clones of a client always share their state. This shares it with the clients created
+    /// separately for the same shop, e.g. by independent parts of an app: the reported usage,
+    /// the GraphQL bucket estimate and the [`RateLimiter`] bucket are then common to all of them.
+    /// The limiter of the first client registered for the shop is used, so this should be called
+    /// after configuring the rate limiter. The state of a shop is freed with its last client.
+    /// # Example
+    /// ```
+    /// use shopify_api::*;
+    /// use shopify_api::rate_limit::ShopifyPlan;
+    ///
+    /// let sync = Shopify::new("myshop", "myapikey", ShopifyAPIVersion::V2026_10, None)
+    ///     .with_plan(ShopifyPlan::Standard)
+    ///     .with_shared_rate_limits();
+    /// let webhooks = Shopify::new("myshop", "myapikey", ShopifyAPIVersion::V2026_10, None)
+    ///     .with_plan(ShopifyPlan::Standard)
+    ///     .with_shared_rate_limits();
+    ///
+    /// assert_eq!(sync.rate_limit_state(), webhooks.rate_limit_state());
+    /// ```
+    pub fn with_shared_rate_limits(mut self) -> Shopify {
+        let mut registry = registry().lock().unwrap();
+        registry.retain(|_, shared| shared.tracker.strong_count() > 0);
+        let shared = registry.entry(self.base_url.clone()).or_default();
+
+        match shared.tracker.upgrade() {
+            Some(tracker) => self.rate_limit = tracker,
+            None => shared.tracker = Arc::downgrade(&self.rate_limit),
+        }
+
+        if let Some(rate_limiter) = &self.rate_limiter {
+            match shared.limiter.as_ref().and_then(WeakRateLimiter::upgrade) {
+                Some(shared_limiter) => self.rate_limiter = Some(shared_limiter),
+                None => shared.limiter = Some(rate_limiter.downgrade()),
+            }
+        }
+
+        self
+    }
+}
@@ -0,0 +1,122 @@
+use std::{fmt, sync::Arc};
+
+use serde::Serialize;
+
+use crate::{rest::ShopifyRestErrors, retry::Failed, Shopify, ShopifyAPIError};
+
+/// Summary of a failed query, serializable for structured logging
+/// # Example
+/// ```
+/// use shopify_api::*;
+///
+/// let error = ShopifyAPIError::NotFound {
+///     body: r#"{"errors":"Not Found"}"#.to_string(),
+///     errors: None,
+///     request_id: Some("a1b2c3".to_string()),
+/// };
+///
+/// let report = error.report();
+/// assert_eq!(report.kind, "not_found");
+/// assert_eq!(report.status, Some(404));
+/// assert_eq!(
+///     serde_json::to_value(&report).unwrap()["request_id"],
+///     "a1b2c3"
+/// );
+/// ```
+#[derive(Clone, Debug, Serialize)]
+pub struct ErrorReport {
+    /// Name of the error variant, in snake case
+    pub kind: &'static str,
+    pub message: String,
+    pub status: Option<u16>,
+    pub request_id: Option<String>,
+    /// Url of the failed query, when reported by a query
+    pub endpoint: Option<String>,
+    /// Number of attempts made before giving up, when reported by a query
+    pub attempts: Option<u64>,
+    pub retryable: bool,
+    /// Parsed `errors` payload of a failed REST request
+    pub errors: Option<ShopifyRestErrors>,
+}
+
+impl ShopifyAPIError {
+    /// Get a serializable summary of the error
+    pub fn report(&self) -> ErrorReport {
+        ErrorReport {
+            kind: self.kind(),
+            message: self.to_string(),
+            status: self.status().map(|status| status.as_u16()),
+            request_id: self.request_id().map(str::to_string),
+            endpoint: None,
+            attempts: None,
+            retryable: self.is_retryable(),
+            errors: self.rest_errors().cloned(),
+        }
+    }
+
+    /// Name of the error variant, in snake case
+    fn kind(&self) -> &'static str {
+        match self {
+            ShopifyAPIError::ConnectionFailed(_) => "connection_failed",
+            ShopifyAPIError::Timeout => "timeout",
+            ShopifyAPIError::ResponseBroken => "response_broken",
+            ShopifyAPIError::NotJson(_) => "not_json",
+            ShopifyAPIError::NotWantedJsonFormat(_) => "not_wanted_json_format",
+            ShopifyAPIError::Throttled => "throttled",
+            ShopifyAPIError::Cancelled => "cancelled",
+            ShopifyAPIError::RateLimited { .. } => "rate_limited",
+            ShopifyAPIError::Unauthorized { .. } => "unauthorized",
+            ShopifyAPIError::Forbidden { .. } => "forbidden",
+            ShopifyAPIError::NotFound { .. } => "not_found",
+            ShopifyAPIError::UnprocessableEntity { .. } => "unprocessable_entity",
+            ShopifyAPIError::Http { .. } => "http",
+            ShopifyAPIError::GraphQL { .. } => "graphql",
+            ShopifyAPIError::UserErrors(_) => "user_errors",
+            ShopifyAPIError::JsonParseError(_) => "json_parse_error",
+            ShopifyAPIError::InvalidConfiguration(_) => "invalid_configuration",
+            ShopifyAPIError::InvalidCredentials(_) => "invalid_credentials",
+            ShopifyAPIError::InvalidQuery(_) => "invalid_query",
+            ShopifyAPIError::InvalidInput(_) => "invalid_input",
+            ShopifyAPIError::TooManyItems(_) => "too_many_items",
+            ShopifyAPIError::Other(_) => "other",
+        }
+    }
+}
+
+type ErrorHookFn = Arc<dyn Fn(&ErrorReport) + Send + Sync>;
+
+/// Hook called with the report of every failed query
+#[derive(Clone, Default)]
+pub(crate) struct ErrorHook(pub(crate) Option<ErrorHookFn>);
+
+impl ErrorHook {
+    pub(crate) fn new<F>(hook: F) -> ErrorHook
+    where
+        F: Fn(&ErrorReport) + Send + Sync + 'static,
+    {
+        ErrorHook(Some(Arc::new(hook)))
+    }
+}
+
+impl fmt::Debug for ErrorHook {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            Some(_) => f.write_str("ErrorHook(Some(..))"),
+            None => f.write_str("ErrorHook(None)"),
+        }
+    }
+}
+
+impl Shopify {
+    /// Report a query the retry loop gave up on to the error hook, and get its error
+    pub(crate) fn failed(&self, failed: Failed, endpoint: &str) -> ShopifyAPIError {
+        if let Some(hook) = &self.error_hook.0 {
+            let mut report = failed.error.report();
+            report.endpoint = Some(endpoint.to_string());
+            report.attempts = Some(failed.attempts);
+            hook(&report);
+        }
+
+        failed.error
+    }
+}
@@ -0,0 +1,41 @@
+use reqwest::{header::HeaderMap, StatusCode};
+
+use crate::{http, middleware::ShopifyResponse};
+
+/// Information about the response of a successful query
+///
+/// Returned by [`crate::Shopify::rest_query_with_metadata`] and [`crate::Shopify::graphql_query_with_metadata`].
+#[derive(Clone, Debug)]
+pub struct ResponseMetadata {
+    pub status: StatusCode,
+    /// `X-Request-Id` header, to give to Shopify support when debugging a request
+    pub request_id: Option<String>,
+    /// REST bucket usage (`used`, `capacity`) from the `X-Shopify-Shop-Api-Call-Limit` header
+    pub call_limit: Option<(u32, u32)>,
+    /// Version that served the request, from the `X-Shopify-API-Version` header
+    pub api_version: Option<String>,
+    pub headers: HeaderMap,
+}
+
+impl ShopifyResponse {
+    /// Get the `X-Request-Id` header of the response
+    pub fn request_id(&self) -> Option<String> {
+        header_string(&self.headers, "X-Request-Id")
+    }
+
+    /// Get the metadata of the response
+    pub fn metadata(&self) -> ResponseMetadata {
+        ResponseMetadata {
+            status: self.status,
+            request_id: self.request_id(),
+            call_limit: http::parse_call_limit(&self.headers),
+            api_version: header_string(&self.headers, "X-Shopify-API-Version"),
+            headers: self.headers.clone(),
+        }
+    }
+}
+
+/// Read a header as a string
+pub(crate) fn header_string(headers: &HeaderMap, name: &str) -> Option<String> {
+    Some(headers.get(name)?.to_str().ok()?.to_string())
+}
@@ -0,0 +1,294 @@
+//! Abandoned checkouts, through the REST `checkouts.json` endpoints
+//!
+//! A checkout is abandoned when the customer entered their contact details but didn't complete
+//! the payment. Cart-recovery apps send the customer back to its `abandoned_checkout_url`, which
+//! restores their cart.
+
+use futures_util::Stream;
+use serde::{Deserialize, Serialize};
+
+use super::{
+    customers::Customer,
+    orders::{Address, LineItemProperty, ShippingLine, TaxLine},
+    resource::Params,
+};
+use crate::{
+    pagination::{Page, PageCursor},
+    Shopify, ShopifyAPIError,
+};
+
+/// Status of an abandoned checkout
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AbandonedCheckoutStatus {
+    /// Not completed
+    Open,
+    /// Completed, or closed by the merchant
+    Closed,
+}
+
+impl AbandonedCheckoutStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AbandonedCheckoutStatus::Open => "open",
+            AbandonedCheckoutStatus::Closed => "closed",
+        }
+    }
+}
+
+/// Product left in an abandoned checkout
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct CheckoutLineItem {
+    /// Key of the line item in the cart
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub key: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub product_id: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub variant_id: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub variant_title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sku: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vendor: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub quantity: Option<u32>,
+    /// Price of one item
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub price: Option<String>,
+    /// Price of the items, times the quantity
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub line_price: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub compare_at_price: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub grams: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub requires_shipping: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub taxable: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gift_card: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fulfillment_service: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tax_lines: Vec<TaxLine>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub properties: Vec<LineItemProperty>,
+}
+
+/// Discount code entered in an abandoned checkout
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CheckoutDiscountCode {
+    pub code: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amount: Option<String>,
+    /// Kind of the discount, e.g. `percentage`
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub discount_type: Option<String>,
+}
+
+/// Checkout the customer left before paying
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct AbandonedCheckout {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub token: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cart_token: Option<String>,
+    /// Page restoring the cart of the customer, to send them back to
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub abandoned_checkout_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub phone: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub customer: Option<Customer>,
+    /// Whether the customer agreed to receive marketing emails
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub buyer_accepts_marketing: Option<bool>,
+    /// Language of the customer, e.g. `fr`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub customer_locale: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub currency: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub presentment_currency: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total_price: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subtotal_price: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total_tax: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total_discounts: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total_line_items_price: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub taxes_included: Option<bool>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub line_items: Vec<CheckoutLineItem>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub discount_codes: Vec<CheckoutDiscountCode>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub shipping_lines: Vec<ShippingLine>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tax_lines: Vec<TaxLine>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub billing_address: Option<Address>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shipping_address: Option<Address>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub note: Option<String>,
+    /// Where the checkout was started, e.g. `web`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub landing_site: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub referring_site: Option<String>,
+    /// Set once the checkout is completed into an order
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub completed_at: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub closed_at: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<String>,
+}
+
+/// Filters of [`AbandonedCheckouts::list`], [`AbandonedCheckouts::stream`] and
+/// [`AbandonedCheckouts::count`]
+///
+/// # Example
+/// ```
+/// use chrono::{Duration, Utc};
+/// use shopify_api::rest::abandoned_checkouts::{AbandonedCheckoutListParams, AbandonedCheckoutStatus};
+///
+/// // Checkouts abandoned in the last day
+/// let params = AbandonedCheckoutListParams::new()
+///     .status(AbandonedCheckoutStatus::Open)
+///     .created_at_min(Utc::now() - Duration::days(1));
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct AbandonedCheckoutListParams {
+    params: Params,
+}
+
+impl AbandonedCheckoutListParams {
+    pub fn new() -> AbandonedCheckoutListParams {
+        AbandonedCheckoutListParams::default()
+    }
+
+    /// Set the number of checkouts of each page, 50 by default and at most 250
+    pub fn limit(mut self, limit: u32) -> Self {
+        self.params.set("limit", limit);
+        self
+    }
+
+    /// Only list the checkouts with an ID greater than `since_id`
+    pub fn since_id(mut self, since_id: u64) -> Self {
+        self.params.set("since_id", since_id);
+        self
+    }
+
+    /// Set the status of the checkouts, [`AbandonedCheckoutStatus::Open`] by default
+    pub fn status(mut self, status: AbandonedCheckoutStatus) -> Self {
+        self.params.set("status", status.as_str());
+        self
+    }
+
+    pub fn created_at_min(mut self, created_at_min: chrono::DateTime<chrono::Utc>) -> Self {
+        self.params.set_time("created_at_min", created_at_min);
+        self
+    }
+
+    pub fn created_at_max(mut self, created_at_max: chrono::DateTime<chrono::Utc>) -> Self {
+        self.params.set_time("created_at_max", created_at_max);
+        self
+    }
+
+    pub fn updated_at_min(mut self, updated_at_min: chrono::DateTime<chrono::Utc>) -> Self {
+        self.params.set_time("updated_at_min", updated_at_min);
+        self
+    }
+
+    pub fn updated_at_max(mut self, updated_at_max: chrono::DateTime<chrono::Utc>) -> Self {
+        self.params.set_time("updated_at_max", updated_at_max);
+        self
+    }
+}
+
+/// Abandoned checkouts of the shop, returned by [`Shopify::abandoned_checkouts`]
+#[derive(Clone, Copy, Debug)]
+pub struct AbandonedCheckouts<'a> {
+    shopify: &'a Shopify,
+}
+
+impl Shopify {
+    /// Query the abandoned checkouts of the shop
+    ///
+    /// # Example
+    /// ```no_run
+    /// use chrono::{Duration, Utc};
+    /// use futures_util::StreamExt;
+    /// use shopify_api::*;
+    /// use shopify_api::rest::abandoned_checkouts::AbandonedCheckoutListParams;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let shopify = Shopify::new("myshop", "myapikey", ShopifyAPIVersion::V2026_10, None);
+    ///     let params = AbandonedCheckoutListParams::new()
+    ///         .created_at_min(Utc::now() - Duration::days(1));
+    ///
+    ///     let mut checkouts = shopify.abandoned_checkouts().stream(&params);
+    ///     while let Some(checkout) = checkouts.next().await {
+    ///         let checkout = checkout.unwrap();
+    ///         if let (Some(email), Some(url)) = (checkout.email, checkout.abandoned_checkout_url) {
+    ///             println!("Remind {} of {}", email, url);
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub fn abandoned_checkouts(&self) -> AbandonedCheckouts<'_> {
+        AbandonedCheckouts { shopify: self }
+    }
+}
+
+impl<'a> AbandonedCheckouts<'a> {
+    /// Query a page of checkouts, starting from the first page without a cursor
+    pub async fn list(
+        &self,
+        params: &AbandonedCheckoutListParams,
+        cursor: Option<&PageCursor>,
+    ) -> Result<Page<AbandonedCheckout>, ShopifyAPIError> {
+        self.shopify
+            .rest_list("checkouts.json", &params.params, cursor)
+            .await
+    }
+
+    /// Stream every checkout matching the filters, following the pages
+    pub fn stream(
+        &self,
+        params: &AbandonedCheckoutListParams,
+    ) -> impl Stream<Item = Result<AbandonedCheckout, ShopifyAPIError>> + Unpin + 'a {
+        self.shopify
+            .rest_list_stream("checkouts.json".to_string(), params.params.clone())
+    }
+
+    /// Count the checkouts matching the filters
+    pub async fn count(
+        &self,
+        params: &AbandonedCheckoutListParams,
+    ) -> Result<u64, ShopifyAPIError> {
+        self.shopify
+            .rest_count("checkouts/count.json", &params.params)
+            .await
+    }
+}
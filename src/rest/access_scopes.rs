@@ -0,0 +1,94 @@
+//! Access scopes granted to the app, through the REST `oauth/access_scopes.json` endpoint
+//!
+//! The scopes granted by the merchant may differ from the ones the app asks for, e.g. after the
+//! app added a scope the merchant didn't approve yet. [`Shopify::missing_access_scopes`] checks
+//! the scopes a feature requires before using it.
+
+use serde::{Deserialize, Serialize};
+
+use super::resource::Params;
+use crate::{Shopify, ShopifyAPIError};
+
+/// Access scope granted to the app, e.g. `read_products`
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AccessScope {
+    pub handle: String,
+    /// Description of the scope, only returned by GraphQL
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+}
+
+impl AccessScope {
+    /// Check if the scope grants `scope`, a `write_` scope granting the matching `read_` scope
+    ///
+    /// # Example
+    /// ```
+    /// use shopify_api::rest::access_scopes::AccessScope;
+    ///
+    /// let scope = AccessScope {
+    ///     handle: "write_products".to_string(),
+    ///     description: None,
+    /// };
+    ///
+    /// assert!(scope.grants("write_products"));
+    /// assert!(scope.grants("read_products"));
+    /// assert!(!scope.grants("read_orders"));
+    /// ```
+    pub fn grants(&self, scope: &str) -> bool {
+        if self.handle == scope {
+            return true;
+        }
+
+        match (
+            self.handle.strip_prefix("write_"),
+            scope.strip_prefix("read_"),
+        ) {
+            (Some(granted), Some(required)) => granted == required,
+            _ => false,
+        }
+    }
+}
+
+impl Shopify {
+    /// Get the access scopes granted to the app by the shop
+    pub async fn access_scopes(&self) -> Result<Vec<AccessScope>, ShopifyAPIError> {
+        self.rest_get_item(
+            "/admin/oauth/access_scopes.json",
+            &Params::default(),
+            "access_scopes",
+        )
+        .await
+    }
+
+    /// Get the scopes of `required` not granted to the app, empty when every scope is granted
+    ///
+    /// # Example
+    /// ```no_run
+    /// use shopify_api::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let shopify = Shopify::new("myshop", "myapikey", ShopifyAPIVersion::V2026_10, None);
+    ///
+    ///     let missing = shopify
+    ///         .missing_access_scopes(&["read_orders", "write_products"])
+    ///         .await
+    ///         .unwrap();
+    ///     if !missing.is_empty() {
+    ///         println!("Ask the merchant to grant {}", missing.join(", "));
+    ///     }
+    /// }
+    /// ```
+    pub async fn missing_access_scopes(
+        &self,
+        required: &[&str],
+    ) -> Result<Vec<String>, ShopifyAPIError> {
+        let granted = self.access_scopes().await?;
+
+        Ok(required
+            .iter()
+            .filter(|scope| !granted.iter().any(|granted| granted.grants(scope)))
+            .map(|scope| scope.to_string())
+            .collect())
+    }
+}
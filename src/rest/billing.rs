@@ -0,0 +1,656 @@
+//! Billing of the app, through the REST `application_charges.json`,
+//! `recurring_application_charges.json` and `application_credits.json` endpoints
+//!
+//! A charge is created with a `return_url`, then the merchant is sent to its
+//! `confirmation_url` to approve it, and comes back to the `return_url` with a `charge_id`
+//! parameter. Shopify activates the approved charges itself, see
+//! [`RecurringApplicationCharges::activate`]. Test charges, made with `test(true)`, are not
+//! billed, e.g. for development stores.
+
+use serde::{Deserialize, Deserializer, Serialize};
+
+use super::{resource::Params, ShopifyAPIRestType};
+use crate::{utils::ReadJsonTreeSteps, Shopify, ShopifyAPIError};
+
+/// Status of a charge
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ChargeStatus {
+    /// Waiting for the approval of the merchant
+    Pending,
+    /// Approved by the merchant, with API versions older than 2021-01
+    Accepted,
+    Active,
+    /// Declined by the merchant
+    Declined,
+    /// Not approved within two days
+    Expired,
+    /// Suspended while the shop is frozen, e.g. for an unpaid Shopify bill
+    Frozen,
+    Cancelled,
+    #[serde(other)]
+    Unknown,
+}
+
+impl ChargeStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ChargeStatus::Pending => "pending",
+            ChargeStatus::Accepted => "accepted",
+            ChargeStatus::Active => "active",
+            ChargeStatus::Declined => "declined",
+            ChargeStatus::Expired => "expired",
+            ChargeStatus::Frozen => "frozen",
+            ChargeStatus::Cancelled => "cancelled",
+            ChargeStatus::Unknown => "unknown",
+        }
+    }
+}
+
+/// Read an amount sent either as a string or as a number, e.g. `"10.00"` or `10.0`
+fn deserialize_amount<'de, D>(deserializer: D) -> Result<Option<String>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let amount = Option::<serde_json::Value>::deserialize(deserializer)?;
+
+    Ok(match amount {
+        Some(serde_json::Value::String(amount)) => Some(amount),
+        Some(serde_json::Value::Number(amount)) => Some(amount.to_string()),
+        _ => None,
+    })
+}
+
+/// One-time charge of the app, e.g. for a feature bought once
+///
+/// # Example
+/// ```
+/// use shopify_api::rest::billing::ApplicationCharge;
+///
+/// let charge = ApplicationCharge::new("Premium theme", "49.00", "https://example.com/billing")
+///     .test(true);
+///
+/// assert_eq!(
+///     serde_json::to_value(&charge).unwrap(),
+///     serde_json::json!({
+///         "name": "Premium theme",
+///         "price": "49.00",
+///         "return_url": "https://example.com/billing",
+///         "test": true,
+///     })
+/// );
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ApplicationCharge {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(
+        default,
+        deserialize_with = "deserialize_amount",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub price: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub currency: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<ChargeStatus>,
+    /// Whether the charge is a test, not billed to the merchant
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub test: Option<bool>,
+    /// URL the merchant is sent back to after approving or declining the charge
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub return_url: Option<String>,
+    /// URL the merchant approves the charge at
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confirmation_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<String>,
+}
+
+impl ApplicationCharge {
+    pub fn new(name: &str, price: &str, return_url: &str) -> ApplicationCharge {
+        ApplicationCharge {
+            name: Some(name.to_string()),
+            price: Some(price.to_string()),
+            return_url: Some(return_url.to_string()),
+            ..ApplicationCharge::default()
+        }
+    }
+
+    /// Set whether the charge is a test, not billed to the merchant
+    pub fn test(mut self, test: bool) -> Self {
+        self.test = Some(test);
+        self
+    }
+}
+
+/// Subscription of the app, billed every 30 days
+///
+/// # Example
+/// ```
+/// use shopify_api::rest::billing::RecurringApplicationCharge;
+///
+/// let charge = RecurringApplicationCharge::new("Pro", "19.00", "https://example.com/billing")
+///     .trial_days(7)
+///     .capped_amount("100.00", "$0.10 for each order");
+///
+/// assert_eq!(
+///     serde_json::to_value(&charge).unwrap(),
+///     serde_json::json!({
+///         "name": "Pro",
+///         "price": "19.00",
+///         "return_url": "https://example.com/billing",
+///         "trial_days": 7,
+///         "capped_amount": "100.00",
+///         "terms": "$0.10 for each order",
+///     })
+/// );
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RecurringApplicationCharge {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// Price billed every 30 days
+    #[serde(
+        default,
+        deserialize_with = "deserialize_amount",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub price: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub currency: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<ChargeStatus>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub test: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub return_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confirmation_url: Option<String>,
+    /// URL the merchant approves an increase of the capped amount at
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub update_capped_amount_url: Option<String>,
+    /// Days before the first bill
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trial_days: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trial_ends_on: Option<String>,
+    /// Date of the next bill
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub billing_on: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub activated_on: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cancelled_on: Option<String>,
+    /// Maximum of the usage charges of each 30 days, required to create usage charges
+    #[serde(
+        default,
+        deserialize_with = "deserialize_amount",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub capped_amount: Option<String>,
+    /// Terms of the usage charges shown to the merchant
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub terms: Option<String>,
+    /// Usage charges of the current 30 days
+    #[serde(
+        default,
+        deserialize_with = "deserialize_amount",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub balance_used: Option<String>,
+    #[serde(
+        default,
+        deserialize_with = "deserialize_amount",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub balance_remaining: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<String>,
+}
+
+impl RecurringApplicationCharge {
+    pub fn new(name: &str, price: &str, return_url: &str) -> RecurringApplicationCharge {
+        RecurringApplicationCharge {
+            name: Some(name.to_string()),
+            price: Some(price.to_string()),
+            return_url: Some(return_url.to_string()),
+            ..RecurringApplicationCharge::default()
+        }
+    }
+
+    pub fn test(mut self, test: bool) -> Self {
+        self.test = Some(test);
+        self
+    }
+
+    pub fn trial_days(mut self, trial_days: u32) -> Self {
+        self.trial_days = Some(trial_days);
+        self
+    }
+
+    /// Allow usage charges up to `capped_amount` every 30 days, on the given terms
+    pub fn capped_amount(mut self, capped_amount: &str, terms: &str) -> Self {
+        self.capped_amount = Some(capped_amount.to_string());
+        self.terms = Some(terms.to_string());
+        self
+    }
+}
+
+/// Charge added to a subscription for its usage, e.g. for each order processed
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UsageCharge {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<u64>,
+    /// Description shown to the merchant on their bill
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(
+        default,
+        deserialize_with = "deserialize_amount",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub price: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub currency: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recurring_application_charge_id: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub billing_on: Option<String>,
+    #[serde(
+        default,
+        deserialize_with = "deserialize_amount",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub balance_used: Option<String>,
+    #[serde(
+        default,
+        deserialize_with = "deserialize_amount",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub balance_remaining: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<String>,
+}
+
+impl UsageCharge {
+    pub fn new(description: &str, price: &str) -> UsageCharge {
+        UsageCharge {
+            description: Some(description.to_string()),
+            price: Some(price.to_string()),
+            ..UsageCharge::default()
+        }
+    }
+}
+
+/// Credit of the app, deducted from the next bills of the merchant, e.g. as a refund
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ApplicationCredit {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(
+        default,
+        deserialize_with = "deserialize_amount",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub amount: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub currency: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub test: Option<bool>,
+}
+
+impl ApplicationCredit {
+    pub fn new(description: &str, amount: &str) -> ApplicationCredit {
+        ApplicationCredit {
+            description: Some(description.to_string()),
+            amount: Some(amount.to_string()),
+            ..ApplicationCredit::default()
+        }
+    }
+
+    pub fn test(mut self, test: bool) -> Self {
+        self.test = Some(test);
+        self
+    }
+}
+
+/// Filters of the lists of charges and credits
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ChargeListParams {
+    params: Params,
+}
+
+impl ChargeListParams {
+    pub fn new() -> ChargeListParams {
+        ChargeListParams::default()
+    }
+
+    /// Only list the charges with an ID greater than `since_id`
+    pub fn since_id(mut self, since_id: u64) -> Self {
+        self.params.set("since_id", since_id);
+        self
+    }
+
+    /// Only return the given fields of each charge
+    pub fn fields(mut self, fields: &[&str]) -> Self {
+        self.params.set_list("fields", fields);
+        self
+    }
+}
+
+/// One-time charges of the app, returned by [`Shopify::application_charges`]
+#[derive(Clone, Copy, Debug)]
+pub struct ApplicationCharges<'a> {
+    shopify: &'a Shopify,
+}
+
+/// Subscriptions of the app, returned by [`Shopify::recurring_application_charges`]
+#[derive(Clone, Copy, Debug)]
+pub struct RecurringApplicationCharges<'a> {
+    shopify: &'a Shopify,
+}
+
+/// Usage charges of a subscription, returned by [`RecurringApplicationCharges::usage_charges`]
+#[derive(Clone, Copy, Debug)]
+pub struct UsageCharges<'a> {
+    shopify: &'a Shopify,
+    recurring_application_charge_id: u64,
+}
+
+/// Credits of the app, returned by [`Shopify::application_credits`]
+#[derive(Clone, Copy, Debug)]
+pub struct ApplicationCredits<'a> {
+    shopify: &'a Shopify,
+}
+
+impl Shopify {
+    /// Bill the merchant once
+    ///
+    /// # Example
+    /// ```no_run
+    /// use shopify_api::*;
+    /// use shopify_api::rest::billing::ApplicationCharge;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let shopify = Shopify::new("myshop", "myapikey", ShopifyAPIVersion::V2026_10, None);
+    ///     let charge = ApplicationCharge::new("Premium theme", "49.00", "https://example.com/billing");
+    ///
+    ///     let charge = shopify.application_charges().create(&charge).await.unwrap();
+    ///     println!("Approve at {:?}", charge.confirmation_url);
+    /// }
+    /// ```
+    pub fn application_charges(&self) -> ApplicationCharges<'_> {
+        ApplicationCharges { shopify: self }
+    }
+
+    /// Manage the subscriptions of the merchant to the app, and their usage charges
+    ///
+    /// # Example
+    /// ```no_run
+    /// use shopify_api::*;
+    /// use shopify_api::rest::billing::{RecurringApplicationCharge, UsageCharge};
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let shopify = Shopify::new("myshop", "myapikey", ShopifyAPIVersion::V2026_10, None);
+    ///     let charge = RecurringApplicationCharge::new("Pro", "19.00", "https://example.com/billing")
+    ///         .capped_amount("100.00", "$0.10 for each order");
+    ///     let charge = shopify
+    ///         .recurring_application_charges()
+    ///         .create(&charge)
+    ///         .await
+    ///         .unwrap();
+    ///
+    ///     // Once the merchant is back on the return URL
+    ///     let charge = shopify
+    ///         .recurring_application_charges()
+    ///         .activate(charge.id.unwrap())
+    ///         .await
+    ///         .unwrap();
+    ///     shopify
+    ///         .recurring_application_charges()
+    ///         .usage_charges(charge.id.unwrap())
+    ///         .create(&UsageCharge::new("10 orders", "1.00"))
+    ///         .await
+    ///         .unwrap();
+    /// }
+    /// ```
+    pub fn recurring_application_charges(&self) -> RecurringApplicationCharges<'_> {
+        RecurringApplicationCharges { shopify: self }
+    }
+
+    /// Give credits to the merchant, deducted from their next bills
+    pub fn application_credits(&self) -> ApplicationCredits<'_> {
+        ApplicationCredits { shopify: self }
+    }
+}
+
+impl<'a> ApplicationCharges<'a> {
+    pub async fn list(
+        &self,
+        params: &ChargeListParams,
+    ) -> Result<Vec<ApplicationCharge>, ShopifyAPIError> {
+        self.shopify
+            .rest_get_item(
+                "application_charges.json",
+                &params.params,
+                "application_charges",
+            )
+            .await
+    }
+
+    pub async fn get(&self, id: u64) -> Result<ApplicationCharge, ShopifyAPIError> {
+        self.shopify
+            .rest_get_item(
+                &format!("application_charges/{}.json", id),
+                &Params::default(),
+                "application_charge",
+            )
+            .await
+    }
+
+    /// Create a pending charge, to be approved by the merchant at its `confirmation_url`
+    pub async fn create(
+        &self,
+        charge: &ApplicationCharge,
+    ) -> Result<ApplicationCharge, ShopifyAPIError> {
+        self.shopify
+            .rest_post_item("application_charges.json", "application_charge", charge)
+            .await
+    }
+}
+
+impl<'a> RecurringApplicationCharges<'a> {
+    pub async fn list(
+        &self,
+        params: &ChargeListParams,
+    ) -> Result<Vec<RecurringApplicationCharge>, ShopifyAPIError> {
+        self.shopify
+            .rest_get_item(
+                "recurring_application_charges.json",
+                &params.params,
+                "recurring_application_charges",
+            )
+            .await
+    }
+
+    pub async fn get(&self, id: u64) -> Result<RecurringApplicationCharge, ShopifyAPIError> {
+        self.shopify
+            .rest_get_item(
+                &format!("recurring_application_charges/{}.json", id),
+                &Params::default(),
+                "recurring_application_charge",
+            )
+            .await
+    }
+
+    /// Get the active subscription of the merchant, if any
+    pub async fn current(&self) -> Result<Option<RecurringApplicationCharge>, ShopifyAPIError> {
+        let charges = self.list(&ChargeListParams::new()).await?;
+
+        Ok(charges
+            .into_iter()
+            .find(|charge| charge.status == Some(ChargeStatus::Active)))
+    }
+
+    /// Create a pending subscription, to be approved by the merchant at its `confirmation_url`,
+    /// replacing the current one once approved
+    pub async fn create(
+        &self,
+        charge: &RecurringApplicationCharge,
+    ) -> Result<RecurringApplicationCharge, ShopifyAPIError> {
+        self.shopify
+            .rest_post_item(
+                "recurring_application_charges.json",
+                "recurring_application_charge",
+                charge,
+            )
+            .await
+    }
+
+    /// Check that the merchant approved a subscription, once back on the return URL
+    ///
+    /// Since API version 2021-01, Shopify activates the approved charges itself, so this gets
+    /// the charge and fails with [`ShopifyAPIError::Other`] when it is not active, e.g. when the
+    /// merchant declined it.
+    pub async fn activate(&self, id: u64) -> Result<RecurringApplicationCharge, ShopifyAPIError> {
+        let charge = self.get(id).await?;
+
+        match charge.status {
+            Some(ChargeStatus::Active) => Ok(charge),
+            status => Err(ShopifyAPIError::Other(format!(
+                "Recurring application charge {} is {}",
+                id,
+                status.unwrap_or(ChargeStatus::Unknown).as_str()
+            ))),
+        }
+    }
+
+    /// Update the capped amount of a subscription, the merchant approving the increase at the
+    /// returned `update_capped_amount_url`
+    pub async fn customize(
+        &self,
+        id: u64,
+        capped_amount: &str,
+    ) -> Result<RecurringApplicationCharge, ShopifyAPIError> {
+        let mut params = Params::default();
+        params.set("recurring_application_charge[capped_amount]", capped_amount);
+
+        self.shopify
+            .rest_query(
+                &ShopifyAPIRestType::Put(
+                    &format!("recurring_application_charges/{}/customize.json", id),
+                    &params.to_map(),
+                    &serde_json::json!({}),
+                ),
+                &Some(vec![ReadJsonTreeSteps::Key("recurring_application_charge")]),
+            )
+            .await
+    }
+
+    /// Cancel a subscription, the merchant no longer being billed
+    pub async fn cancel(&self, id: u64) -> Result<(), ShopifyAPIError> {
+        self.shopify
+            .rest_delete_item(&format!("recurring_application_charges/{}.json", id))
+            .await
+    }
+
+    /// Query and create the usage charges of a subscription
+    pub fn usage_charges(&self, recurring_application_charge_id: u64) -> UsageCharges<'a> {
+        UsageCharges {
+            shopify: self.shopify,
+            recurring_application_charge_id,
+        }
+    }
+}
+
+impl<'a> UsageCharges<'a> {
+    pub async fn list(
+        &self,
+        params: &ChargeListParams,
+    ) -> Result<Vec<UsageCharge>, ShopifyAPIError> {
+        self.shopify
+            .rest_get_item(
+                &format!(
+                    "recurring_application_charges/{}/usage_charges.json",
+                    self.recurring_application_charge_id
+                ),
+                &params.params,
+                "usage_charges",
+            )
+            .await
+    }
+
+    pub async fn get(&self, id: u64) -> Result<UsageCharge, ShopifyAPIError> {
+        self.shopify
+            .rest_get_item(
+                &format!(
+                    "recurring_application_charges/{}/usage_charges/{}.json",
+                    self.recurring_application_charge_id, id
+                ),
+                &Params::default(),
+                "usage_charge",
+            )
+            .await
+    }
+
+    /// Bill the merchant for their usage, within the capped amount of the subscription
+    pub async fn create(&self, charge: &UsageCharge) -> Result<UsageCharge, ShopifyAPIError> {
+        self.shopify
+            .rest_post_item(
+                &format!(
+                    "recurring_application_charges/{}/usage_charges.json",
+                    self.recurring_application_charge_id
+                ),
+                "usage_charge",
+                charge,
+            )
+            .await
+    }
+}
+
+impl<'a> ApplicationCredits<'a> {
+    pub async fn list(
+        &self,
+        params: &ChargeListParams,
+    ) -> Result<Vec<ApplicationCredit>, ShopifyAPIError> {
+        self.shopify
+            .rest_get_item(
+                "application_credits.json",
+                &params.params,
+                "application_credits",
+            )
+            .await
+    }
+
+    pub async fn get(&self, id: u64) -> Result<ApplicationCredit, ShopifyAPIError> {
+        self.shopify
+            .rest_get_item(
+                &format!("application_credits/{}.json", id),
+                &Params::default(),
+                "application_credit",
+            )
+            .await
+    }
+
+    pub async fn create(
+        &self,
+        credit: &ApplicationCredit,
+    ) -> Result<ApplicationCredit, ShopifyAPIError> {
+        self.shopify
+            .rest_post_item("application_credits.json", "application_credit", credit)
+            .await
+    }
+}
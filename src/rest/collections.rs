@@ -0,0 +1,642 @@
+//! Smart and custom collections, through the REST `smart_collections.json`,
+//! `custom_collections.json` and `collects.json` endpoints
+//!
+//! The products of a smart collection are selected by its [`CollectionRule`]s, while the
+//! products of a custom collection are added one by one, each addition being a [`Collect`].
+
+use futures_util::Stream;
+use serde::{Deserialize, Serialize};
+
+use super::{resource::Params, ShopifyAPIRestType};
+use crate::{
+    pagination::{Page, PageCursor},
+    Shopify, ShopifyAPIError,
+};
+
+/// Order of the products of a collection
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum CollectionSortOrder {
+    AlphaAsc,
+    AlphaDesc,
+    BestSelling,
+    Created,
+    CreatedDesc,
+    /// Order set by the merchant, or with [`SmartCollections::order`]
+    Manual,
+    PriceAsc,
+    PriceDesc,
+}
+
+impl CollectionSortOrder {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CollectionSortOrder::AlphaAsc => "alpha-asc",
+            CollectionSortOrder::AlphaDesc => "alpha-desc",
+            CollectionSortOrder::BestSelling => "best-selling",
+            CollectionSortOrder::Created => "created",
+            CollectionSortOrder::CreatedDesc => "created-desc",
+            CollectionSortOrder::Manual => "manual",
+            CollectionSortOrder::PriceAsc => "price-asc",
+            CollectionSortOrder::PriceDesc => "price-desc",
+        }
+    }
+}
+
+/// Product property a [`CollectionRule`] is checked against
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RuleColumn {
+    Title,
+    #[serde(rename = "type")]
+    ProductType,
+    Vendor,
+    Tag,
+    VariantTitle,
+    VariantPrice,
+    VariantCompareAtPrice,
+    VariantWeight,
+    VariantInventory,
+    /// Whether the compare at price of a variant is greater than its price
+    IsPriceReduced,
+    #[serde(other)]
+    Unknown,
+}
+
+/// Comparison of a [`CollectionRule`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RuleRelation {
+    Equals,
+    NotEquals,
+    GreaterThan,
+    LessThan,
+    StartsWith,
+    EndsWith,
+    Contains,
+    NotContains,
+    #[serde(other)]
+    Unknown,
+}
+
+/// Condition selecting the products of a smart collection
+///
+/// # Example
+/// ```
+/// use shopify_api::rest::collections::{CollectionRule, RuleColumn, RuleRelation};
+///
+/// let rule = CollectionRule::new(RuleColumn::VariantPrice, RuleRelation::LessThan, "20");
+///
+/// assert_eq!(
+///     serde_json::to_value(&rule).unwrap(),
+///     serde_json::json!({ "column": "variant_price", "relation": "less_than", "condition": "20" })
+/// );
+/// assert_eq!(CollectionRule::tag("sale").column, RuleColumn::Tag);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CollectionRule {
+    pub column: RuleColumn,
+    pub relation: RuleRelation,
+    pub condition: String,
+}
+
+impl CollectionRule {
+    pub fn new(column: RuleColumn, relation: RuleRelation, condition: &str) -> CollectionRule {
+        CollectionRule {
+            column,
+            relation,
+            condition: condition.to_string(),
+        }
+    }
+
+    /// Products with the given tag
+    pub fn tag(tag: &str) -> CollectionRule {
+        CollectionRule::new(RuleColumn::Tag, RuleRelation::Equals, tag)
+    }
+
+    /// Products of the given vendor
+    pub fn vendor(vendor: &str) -> CollectionRule {
+        CollectionRule::new(RuleColumn::Vendor, RuleRelation::Equals, vendor)
+    }
+
+    /// Products of the given type
+    pub fn product_type(product_type: &str) -> CollectionRule {
+        CollectionRule::new(RuleColumn::ProductType, RuleRelation::Equals, product_type)
+    }
+
+    /// Products whose title contains `text`
+    pub fn title_contains(text: &str) -> CollectionRule {
+        CollectionRule::new(RuleColumn::Title, RuleRelation::Contains, text)
+    }
+}
+
+/// Image of a collection, created from the URL of its `src` or from its contents encoded in
+/// base64 in `attachment`
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CollectionImage {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub src: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub alt: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub width: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub height: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attachment: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<String>,
+}
+
+/// Collection whose products are selected by rules
+///
+/// # Example
+/// ```
+/// use shopify_api::rest::collections::{CollectionRule, CollectionSortOrder, SmartCollection};
+///
+/// // Products tagged `sale` or made by Acme, the best sellers first
+/// let collection = SmartCollection::new("Deals")
+///     .rule(CollectionRule::tag("sale"))
+///     .rule(CollectionRule::vendor("Acme"))
+///     .disjunctive(true)
+///     .sort_order(CollectionSortOrder::BestSelling);
+///
+/// assert_eq!(collection.rules.len(), 2);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SmartCollection {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub handle: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub body_html: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub rules: Vec<CollectionRule>,
+    /// Whether a product matching any rule is selected, rather than every rule
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disjunctive: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sort_order: Option<CollectionSortOrder>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub image: Option<CollectionImage>,
+    /// Whether the collection is visible, only used when creating or updating it
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub published: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub published_at: Option<String>,
+    /// `web` or `global` to also publish to the point of sale
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub published_scope: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub template_suffix: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub admin_graphql_api_id: Option<String>,
+}
+
+impl SmartCollection {
+    /// Smart collection to create
+    pub fn new(title: &str) -> SmartCollection {
+        SmartCollection {
+            title: Some(title.to_string()),
+            ..SmartCollection::default()
+        }
+    }
+
+    /// Add a rule selecting products
+    pub fn rule(mut self, rule: CollectionRule) -> Self {
+        self.rules.push(rule);
+        self
+    }
+
+    /// Select the products matching any rule, rather than every rule
+    pub fn disjunctive(mut self, disjunctive: bool) -> Self {
+        self.disjunctive = Some(disjunctive);
+        self
+    }
+
+    pub fn sort_order(mut self, sort_order: CollectionSortOrder) -> Self {
+        self.sort_order = Some(sort_order);
+        self
+    }
+}
+
+/// Collection whose products are added one by one
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CustomCollection {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub handle: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub body_html: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sort_order: Option<CollectionSortOrder>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub image: Option<CollectionImage>,
+    /// Products to add when creating the collection
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub collects: Vec<Collect>,
+    /// Whether the collection is visible, only used when creating or updating it
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub published: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub published_at: Option<String>,
+    /// `web` or `global` to also publish to the point of sale
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub published_scope: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub template_suffix: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub admin_graphql_api_id: Option<String>,
+}
+
+/// Product added to a custom collection
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Collect {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub collection_id: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub product_id: Option<u64>,
+    /// Position of the product in a manually sorted collection
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub position: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sort_value: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<String>,
+}
+
+/// Filters of the `list`, `stream` and `count` queries of [`SmartCollections`] and
+/// [`CustomCollections`]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct CollectionListParams {
+    params: Params,
+}
+
+impl CollectionListParams {
+    pub fn new() -> CollectionListParams {
+        CollectionListParams::default()
+    }
+
+    /// Set the number of collections of each page, 50 by default and at most 250
+    pub fn limit(mut self, limit: u32) -> Self {
+        self.params.set("limit", limit);
+        self
+    }
+
+    pub fn ids(mut self, ids: &[u64]) -> Self {
+        self.params.set_list("ids", ids);
+        self
+    }
+
+    /// Only list the collections with an ID greater than `since_id`
+    pub fn since_id(mut self, since_id: u64) -> Self {
+        self.params.set("since_id", since_id);
+        self
+    }
+
+    pub fn title(mut self, title: &str) -> Self {
+        self.params.set("title", title);
+        self
+    }
+
+    pub fn handle(mut self, handle: &str) -> Self {
+        self.params.set("handle", handle);
+        self
+    }
+
+    /// Only list the collections containing the given product
+    pub fn product_id(mut self, product_id: u64) -> Self {
+        self.params.set("product_id", product_id);
+        self
+    }
+
+    /// `published`, `unpublished` or `any` (the default)
+    pub fn published_status(mut self, published_status: &str) -> Self {
+        self.params.set("published_status", published_status);
+        self
+    }
+
+    pub fn updated_at_min(mut self, updated_at_min: chrono::DateTime<chrono::Utc>) -> Self {
+        self.params.set_time("updated_at_min", updated_at_min);
+        self
+    }
+
+    pub fn updated_at_max(mut self, updated_at_max: chrono::DateTime<chrono::Utc>) -> Self {
+        self.params.set_time("updated_at_max", updated_at_max);
+        self
+    }
+
+    /// Only return the given fields of each collection
+    pub fn fields(mut self, fields: &[&str]) -> Self {
+        self.params.set_list("fields", fields);
+        self
+    }
+}
+
+/// Smart collections of a shop, returned by [`Shopify::smart_collections`]
+#[derive(Clone, Copy, Debug)]
+pub struct SmartCollections<'a> {
+    shopify: &'a Shopify,
+}
+
+/// Custom collections of a shop and their products, returned by [`Shopify::custom_collections`]
+#[derive(Clone, Copy, Debug)]
+pub struct CustomCollections<'a> {
+    shopify: &'a Shopify,
+}
+
+impl Shopify {
+    /// Query and manage the smart collections of the shop
+    ///
+    /// # Example
+    /// ```no_run
+    /// use shopify_api::*;
+    /// use shopify_api::rest::collections::{CollectionRule, CollectionSortOrder, SmartCollection};
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let shopify = Shopify::new("myshop", "myapikey", ShopifyAPIVersion::V2026_10, None);
+    ///     let collection = SmartCollection::new("Sale").rule(CollectionRule::tag("sale"));
+    ///
+    ///     let collection = shopify.smart_collections().create(&collection).await.unwrap();
+    ///
+    ///     // Pin two products first, the others following
+    ///     shopify
+    ///         .smart_collections()
+    ///         .order(collection.id.unwrap(), Some(CollectionSortOrder::Manual), &[921728736, 457924702])
+    ///         .await
+    ///         .unwrap();
+    /// }
+    /// ```
+    pub fn smart_collections(&self) -> SmartCollections<'_> {
+        SmartCollections { shopify: self }
+    }
+
+    /// Query and manage the custom collections of the shop, and their products
+    ///
+    /// # Example
+    /// ```no_run
+    /// use shopify_api::*;
+    /// use shopify_api::rest::collections::CustomCollection;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let shopify = Shopify::new("myshop", "myapikey", ShopifyAPIVersion::V2026_10, None);
+    ///     let collection = CustomCollection {
+    ///         title: Some("Staff picks".to_string()),
+    ///         ..CustomCollection::default()
+    ///     };
+    ///
+    ///     let collection = shopify.custom_collections().create(&collection).await.unwrap();
+    ///     shopify
+    ///         .custom_collections()
+    ///         .add_product(collection.id.unwrap(), 632910392)
+    ///         .await
+    ///         .unwrap();
+    /// }
+    /// ```
+    pub fn custom_collections(&self) -> CustomCollections<'_> {
+        CustomCollections { shopify: self }
+    }
+}
+
+impl<'a> SmartCollections<'a> {
+    /// Query a page of smart collections, starting from the first page without a cursor
+    pub async fn list(
+        &self,
+        params: &CollectionListParams,
+        cursor: Option<&PageCursor>,
+    ) -> Result<Page<SmartCollection>, ShopifyAPIError> {
+        self.shopify
+            .rest_list("smart_collections.json", &params.params, cursor)
+            .await
+    }
+
+    /// Stream every smart collection matching the filters, following the pages
+    pub fn stream(
+        &self,
+        params: &CollectionListParams,
+    ) -> impl Stream<Item = Result<SmartCollection, ShopifyAPIError>> + Unpin + 'a {
+        self.shopify
+            .rest_list_stream("smart_collections.json".to_string(), params.params.clone())
+    }
+
+    pub async fn get(&self, id: u64) -> Result<SmartCollection, ShopifyAPIError> {
+        self.shopify
+            .rest_get_item(
+                &format!("smart_collections/{}.json", id),
+                &Params::default(),
+                "smart_collection",
+            )
+            .await
+    }
+
+    /// Count the smart collections matching the filters, `limit`, `ids` and `fields` being
+    /// ignored
+    pub async fn count(&self, params: &CollectionListParams) -> Result<u64, ShopifyAPIError> {
+        self.shopify
+            .rest_count("smart_collections/count.json", &params.params)
+            .await
+    }
+
+    pub async fn create(
+        &self,
+        collection: &SmartCollection,
+    ) -> Result<SmartCollection, ShopifyAPIError> {
+        self.shopify
+            .rest_post_item("smart_collections.json", "smart_collection", collection)
+            .await
+    }
+
+    /// Update the fields set on `collection`, the rules being replaced when set
+    pub async fn update(
+        &self,
+        id: u64,
+        collection: &SmartCollection,
+    ) -> Result<SmartCollection, ShopifyAPIError> {
+        self.shopify
+            .rest_put_item(
+                &format!("smart_collections/{}.json", id),
+                "smart_collection",
+                collection,
+            )
+            .await
+    }
+
+    pub async fn delete(&self, id: u64) -> Result<(), ShopifyAPIError> {
+        self.shopify
+            .rest_delete_item(&format!("smart_collections/{}.json", id))
+            .await
+    }
+
+    /// Change the sort order of a smart collection, putting `product_ids` first in this order
+    /// when it is manual
+    pub async fn order(
+        &self,
+        id: u64,
+        sort_order: Option<CollectionSortOrder>,
+        product_ids: &[u64],
+    ) -> Result<(), ShopifyAPIError> {
+        // Shopify reads the products from a repeated `products[]` parameter
+        let products: Vec<String> = product_ids
+            .iter()
+            .map(|product_id| format!("products[]={}", product_id))
+            .collect();
+        let mut endpoint = format!("smart_collections/{}/order.json", id);
+        if !products.is_empty() {
+            endpoint = format!("{}?{}", endpoint, products.join("&"));
+        }
+
+        let mut params = Params::default();
+        if let Some(sort_order) = sort_order {
+            params.set("sort_order", sort_order.as_str());
+        }
+
+        self.shopify
+            .rest_query::<serde_json::Value>(
+                &ShopifyAPIRestType::Put(&endpoint, &params.to_map(), &serde_json::json!({})),
+                &None,
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+impl<'a> CustomCollections<'a> {
+    /// Query a page of custom collections, starting from the first page without a cursor
+    pub async fn list(
+        &self,
+        params: &CollectionListParams,
+        cursor: Option<&PageCursor>,
+    ) -> Result<Page<CustomCollection>, ShopifyAPIError> {
+        self.shopify
+            .rest_list("custom_collections.json", &params.params, cursor)
+            .await
+    }
+
+    /// Stream every custom collection matching the filters, following the pages
+    pub fn stream(
+        &self,
+        params: &CollectionListParams,
+    ) -> impl Stream<Item = Result<CustomCollection, ShopifyAPIError>> + Unpin + 'a {
+        self.shopify
+            .rest_list_stream("custom_collections.json".to_string(), params.params.clone())
+    }
+
+    pub async fn get(&self, id: u64) -> Result<CustomCollection, ShopifyAPIError> {
+        self.shopify
+            .rest_get_item(
+                &format!("custom_collections/{}.json", id),
+                &Params::default(),
+                "custom_collection",
+            )
+            .await
+    }
+
+    /// Count the custom collections matching the filters, `limit`, `ids` and `fields` being
+    /// ignored
+    pub async fn count(&self, params: &CollectionListParams) -> Result<u64, ShopifyAPIError> {
+        self.shopify
+            .rest_count("custom_collections/count.json", &params.params)
+            .await
+    }
+
+    /// Create a custom collection, with the products of its `collects`
+    pub async fn create(
+        &self,
+        collection: &CustomCollection,
+    ) -> Result<CustomCollection, ShopifyAPIError> {
+        self.shopify
+            .rest_post_item("custom_collections.json", "custom_collection", collection)
+            .await
+    }
+
+    /// Update the fields set on `collection`, e.g. its `sort_order`
+    pub async fn update(
+        &self,
+        id: u64,
+        collection: &CustomCollection,
+    ) -> Result<CustomCollection, ShopifyAPIError> {
+        self.shopify
+            .rest_put_item(
+                &format!("custom_collections/{}.json", id),
+                "custom_collection",
+                collection,
+            )
+            .await
+    }
+
+    pub async fn delete(&self, id: u64) -> Result<(), ShopifyAPIError> {
+        self.shopify
+            .rest_delete_item(&format!("custom_collections/{}.json", id))
+            .await
+    }
+
+    /// Change the sort order of a custom collection
+    pub async fn set_sort_order(
+        &self,
+        id: u64,
+        sort_order: CollectionSortOrder,
+    ) -> Result<CustomCollection, ShopifyAPIError> {
+        let collection = CustomCollection {
+            sort_order: Some(sort_order),
+            ..CustomCollection::default()
+        };
+
+        self.update(id, &collection).await
+    }
+
+    /// Query a page of the products of a custom collection, starting from the first page
+    /// without a cursor
+    pub async fn collects(
+        &self,
+        collection_id: u64,
+        cursor: Option<&PageCursor>,
+    ) -> Result<Page<Collect>, ShopifyAPIError> {
+        let mut params = Params::default();
+        params.set("collection_id", collection_id);
+
+        self.shopify
+            .rest_list("collects.json", &params, cursor)
+            .await
+    }
+
+    /// Add a product to a custom collection
+    pub async fn add_product(
+        &self,
+        collection_id: u64,
+        product_id: u64,
+    ) -> Result<Collect, ShopifyAPIError> {
+        let collect = Collect {
+            collection_id: Some(collection_id),
+            product_id: Some(product_id),
+            ..Collect::default()
+        };
+
+        self.shopify
+            .rest_post_item("collects.json", "collect", &collect)
+            .await
+    }
+
+    /// Remove a product from a custom collection, by the ID of its [`Collect`]
+    pub async fn remove_collect(&self, collect_id: u64) -> Result<(), ShopifyAPIError> {
+        self.shopify
+            .rest_delete_item(&format!("collects/{}.json", collect_id))
+            .await
+    }
+}
@@ -0,0 +1,249 @@
+//! Countries and provinces the shop ships to, through the REST `countries.json` endpoints
+//!
+//! Each country and province holds its sales tax rate. Countries are added to the shop with their
+//! ISO 3166-1 alpha-2 code, their provinces being created with them.
+
+use serde::{Deserialize, Serialize};
+
+use super::resource::Params;
+use crate::{Shopify, ShopifyAPIError};
+
+/// How the tax of a province is combined with the tax of its country
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ProvinceTaxType {
+    /// Charged instead of the tax of the country
+    Normal,
+    /// Charged on top of the price including the tax of the country
+    Compounded,
+    /// Combined with the tax of the country into a single tax, e.g. the Canadian HST
+    Harmonized,
+    #[serde(other)]
+    Unknown,
+}
+
+/// Province, state or region of a country
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct Province {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub country_id: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// Code of the province, e.g. `QC`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub code: Option<String>,
+    /// Sales tax rate, e.g. `0.09975` for 9.975%
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tax: Option<f64>,
+    /// Name of the tax, e.g. `QST`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tax_name: Option<String>,
+    /// Sales tax rate as a percentage, e.g. `9.975`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tax_percentage: Option<f64>,
+    /// Unset when the tax of the country is charged alone
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tax_type: Option<ProvinceTaxType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shipping_zone_id: Option<u64>,
+}
+
+/// Country of the shop, with its provinces
+///
+/// # Example
+/// ```
+/// use shopify_api::rest::countries::Country;
+///
+/// let country = Country::new("FR").tax(0.2);
+///
+/// assert_eq!(
+///     serde_json::to_value(&country).unwrap(),
+///     serde_json::json!({ "code": "FR", "tax": 0.2 })
+/// );
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct Country {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// ISO 3166-1 alpha-2 code, e.g. `CA`, or `*` for the rest of the world
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub code: Option<String>,
+    /// Sales tax rate, e.g. `0.05` for 5%
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tax: Option<f64>,
+    /// Name of the tax, e.g. `GST`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tax_name: Option<String>,
+    /// Set for the countries of a shipping zone
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shipping_zone_id: Option<u64>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub provinces: Vec<Province>,
+}
+
+impl Country {
+    /// Country of the given ISO 3166-1 alpha-2 code, e.g. `FR`
+    pub fn new(code: &str) -> Country {
+        Country {
+            code: Some(code.to_string()),
+            ..Country::default()
+        }
+    }
+
+    /// Set the sales tax rate, e.g. `0.2` for 20%
+    pub fn tax(mut self, tax: f64) -> Self {
+        self.tax = Some(tax);
+        self
+    }
+
+    /// Get a province of the country by its code, e.g. `QC`
+    pub fn province(&self, code: &str) -> Option<&Province> {
+        self.provinces
+            .iter()
+            .find(|province| province.code.as_deref() == Some(code))
+    }
+}
+
+/// Countries of a shop, returned by [`Shopify::countries`]
+#[derive(Clone, Copy, Debug)]
+pub struct Countries<'a> {
+    shopify: &'a Shopify,
+}
+
+/// Provinces of a country, returned by [`Countries::provinces`]
+#[derive(Clone, Copy, Debug)]
+pub struct CountryProvinces<'a> {
+    shopify: &'a Shopify,
+    country_id: u64,
+}
+
+impl Shopify {
+    /// Query and manage the countries of the shop and their tax rates
+    ///
+    /// # Example
+    /// ```no_run
+    /// use shopify_api::*;
+    /// use shopify_api::rest::countries::{Country, Province};
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let shopify = Shopify::new("myshop", "myapikey", ShopifyAPIVersion::V2026_10, None);
+    ///     let canada = shopify.countries().create(&Country::new("CA")).await.unwrap();
+    ///
+    ///     let quebec = canada.province("QC").unwrap();
+    ///     let province = Province {
+    ///         tax: Some(0.09975),
+    ///         ..Province::default()
+    ///     };
+    ///     shopify
+    ///         .countries()
+    ///         .provinces(canada.id.unwrap())
+    ///         .update(quebec.id.unwrap(), &province)
+    ///         .await
+    ///         .unwrap();
+    /// }
+    /// ```
+    pub fn countries(&self) -> Countries<'_> {
+        Countries { shopify: self }
+    }
+}
+
+impl<'a> Countries<'a> {
+    /// Get every country of the shop, with their provinces
+    pub async fn list(&self) -> Result<Vec<Country>, ShopifyAPIError> {
+        self.shopify
+            .rest_get_item("countries.json", &Params::default(), "countries")
+            .await
+    }
+
+    pub async fn get(&self, id: u64) -> Result<Country, ShopifyAPIError> {
+        self.shopify
+            .rest_get_item(
+                &format!("countries/{}.json", id),
+                &Params::default(),
+                "country",
+            )
+            .await
+    }
+
+    pub async fn count(&self) -> Result<u64, ShopifyAPIError> {
+        self.shopify
+            .rest_count("countries/count.json", &Params::default())
+            .await
+    }
+
+    /// Add a country to the shop from its code, with the default tax rate when `tax` is not set
+    pub async fn create(&self, country: &Country) -> Result<Country, ShopifyAPIError> {
+        self.shopify
+            .rest_post_item("countries.json", "country", country)
+            .await
+    }
+
+    /// Update the tax rate of a country
+    pub async fn update(&self, id: u64, country: &Country) -> Result<Country, ShopifyAPIError> {
+        self.shopify
+            .rest_put_item(&format!("countries/{}.json", id), "country", country)
+            .await
+    }
+
+    pub async fn delete(&self, id: u64) -> Result<(), ShopifyAPIError> {
+        self.shopify
+            .rest_delete_item(&format!("countries/{}.json", id))
+            .await
+    }
+
+    /// Query and update the provinces of a country
+    pub fn provinces(&self, country_id: u64) -> CountryProvinces<'a> {
+        CountryProvinces {
+            shopify: self.shopify,
+            country_id,
+        }
+    }
+}
+
+impl<'a> CountryProvinces<'a> {
+    /// Get every province of the country
+    pub async fn list(&self) -> Result<Vec<Province>, ShopifyAPIError> {
+        self.shopify
+            .rest_get_item(
+                &format!("countries/{}/provinces.json", self.country_id),
+                &Params::default(),
+                "provinces",
+            )
+            .await
+    }
+
+    pub async fn get(&self, id: u64) -> Result<Province, ShopifyAPIError> {
+        self.shopify
+            .rest_get_item(
+                &format!("countries/{}/provinces/{}.json", self.country_id, id),
+                &Params::default(),
+                "province",
+            )
+            .await
+    }
+
+    pub async fn count(&self) -> Result<u64, ShopifyAPIError> {
+        self.shopify
+            .rest_count(
+                &format!("countries/{}/provinces/count.json", self.country_id),
+                &Params::default(),
+            )
+            .await
+    }
+
+    /// Update the tax rate of a province, provinces being neither created nor deleted
+    pub async fn update(&self, id: u64, province: &Province) -> Result<Province, ShopifyAPIError> {
+        self.shopify
+            .rest_put_item(
+                &format!("countries/{}/provinces/{}.json", self.country_id, id),
+                "province",
+                province,
+            )
+            .await
+    }
+}
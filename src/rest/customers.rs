@@ -0,0 +1,390 @@
+//! Customers and their addresses, through the REST `customers.json` endpoints
+
+use futures_util::Stream;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+use super::{
+    orders::{Order, OrderListParams},
+    resource::Params,
+};
+use crate::{
+    pagination::{Page, PageCursor},
+    Shopify, ShopifyAPIError,
+};
+
+/// State of the account of a customer
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CustomerState {
+    /// The customer has no account
+    Disabled,
+    /// An invitation to create an account was sent
+    Invited,
+    Enabled,
+    /// The customer declined the invitation
+    Declined,
+    #[serde(other)]
+    Unknown,
+}
+
+/// Customer of the shop
+///
+/// # Example
+/// ```
+/// use shopify_api::rest::customers::{Customer, CustomerAddress};
+///
+/// let customer = Customer {
+///     first_name: Some("Steve".to_string()),
+///     email: Some("steve.lastnameson@example.com".to_string()),
+///     addresses: vec![CustomerAddress {
+///         city: Some("Ottawa".to_string()),
+///         country_code: Some("CA".to_string()),
+///         ..CustomerAddress::default()
+///     }],
+///     ..Customer::default()
+/// };
+///
+/// assert_eq!(
+///     serde_json::to_value(&customer).unwrap(),
+///     serde_json::json!({
+///         "first_name": "Steve",
+///         "email": "steve.lastnameson@example.com",
+///         "addresses": [{ "city": "Ottawa", "country_code": "CA" }]
+///     })
+/// );
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct Customer {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub first_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_name: Option<String>,
+    /// Phone number in the E.164 format, e.g. `+16135551111`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub phone: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub state: Option<CustomerState>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub verified_email: Option<bool>,
+    /// Comma-separated tags
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tags: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub note: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tax_exempt: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub currency: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_order_id: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_order_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email_marketing_consent: Option<MarketingConsent>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sms_marketing_consent: Option<MarketingConsent>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub addresses: Vec<CustomerAddress>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_address: Option<CustomerAddress>,
+    /// Password set when creating or updating the customer, never returned
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub password: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub password_confirmation: Option<String>,
+    /// Send an account invitation when creating the customer
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub send_email_invite: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub admin_graphql_api_id: Option<String>,
+}
+
+/// Consent of a customer to receive marketing emails or SMS
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MarketingConsent {
+    /// e.g. `subscribed`, `not_subscribed` or `unsubscribed`
+    pub state: String,
+    /// e.g. `single_opt_in` or `confirmed_opt_in`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub opt_in_level: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub consent_updated_at: Option<String>,
+}
+
+/// Address of a customer
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CustomerAddress {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub customer_id: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub first_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub company: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address1: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address2: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub city: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub province: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub province_code: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub country: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub country_code: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub country_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub zip: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub phone: Option<String>,
+    /// Whether this is the default address of the customer
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default: Option<bool>,
+}
+
+/// Account invitation sent with [`Customers::send_invite`], the shop's template being used for
+/// the fields not set
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CustomerInvite {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub to: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub from: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub bcc: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subject: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_message: Option<String>,
+}
+
+/// Filters of [`Customers::list`], [`Customers::stream`] and [`Customers::count`]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct CustomerListParams {
+    params: Params,
+}
+
+impl CustomerListParams {
+    pub fn new() -> CustomerListParams {
+        CustomerListParams::default()
+    }
+
+    /// Set the number of customers of each page, 50 by default and at most 250
+    pub fn limit(mut self, limit: u32) -> Self {
+        self.params.set("limit", limit);
+        self
+    }
+
+    pub fn ids(mut self, ids: &[u64]) -> Self {
+        self.params.set_list("ids", ids);
+        self
+    }
+
+    /// Only list the customers with an ID greater than `since_id`
+    pub fn since_id(mut self, since_id: u64) -> Self {
+        self.params.set("since_id", since_id);
+        self
+    }
+
+    pub fn created_at_min(mut self, created_at_min: chrono::DateTime<chrono::Utc>) -> Self {
+        self.params.set_time("created_at_min", created_at_min);
+        self
+    }
+
+    pub fn created_at_max(mut self, created_at_max: chrono::DateTime<chrono::Utc>) -> Self {
+        self.params.set_time("created_at_max", created_at_max);
+        self
+    }
+
+    pub fn updated_at_min(mut self, updated_at_min: chrono::DateTime<chrono::Utc>) -> Self {
+        self.params.set_time("updated_at_min", updated_at_min);
+        self
+    }
+
+    pub fn updated_at_max(mut self, updated_at_max: chrono::DateTime<chrono::Utc>) -> Self {
+        self.params.set_time("updated_at_max", updated_at_max);
+        self
+    }
+
+    /// Only return the given fields of each customer
+    pub fn fields(mut self, fields: &[&str]) -> Self {
+        self.params.set_list("fields", fields);
+        self
+    }
+}
+
+/// Customers of a shop, returned by [`Shopify::customers`]
+#[derive(Clone, Copy, Debug)]
+pub struct Customers<'a> {
+    shopify: &'a Shopify,
+}
+
+impl Shopify {
+    /// Query the customers of the shop
+    ///
+    /// # Example
+    /// ```no_run
+    /// use shopify_api::*;
+    /// use shopify_api::rest::customers::{Customer, CustomerInvite};
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let shopify = Shopify::new("myshop", "myapikey", ShopifyAPIVersion::V2026_10, None);
+    ///
+    ///     let customers = shopify
+    ///         .customers()
+    ///         .search("email:bob@example.com", None)
+    ///         .await
+    ///         .unwrap();
+    ///
+    ///     for customer in customers.items {
+    ///         let id = customer.id.unwrap();
+    ///         let invite = CustomerInvite {
+    ///             subject: Some("Welcome to my new shop".to_string()),
+    ///             ..CustomerInvite::default()
+    ///         };
+    ///         shopify.customers().send_invite(id, &invite).await.unwrap();
+    ///     }
+    /// }
+    /// ```
+    pub fn customers(&self) -> Customers<'_> {
+        Customers { shopify: self }
+    }
+}
+
+impl<'a> Customers<'a> {
+    /// Query a page of customers, starting from the first page without a cursor
+    pub async fn list(
+        &self,
+        params: &CustomerListParams,
+        cursor: Option<&PageCursor>,
+    ) -> Result<Page<Customer>, ShopifyAPIError> {
+        self.shopify
+            .rest_list("customers.json", &params.params, cursor)
+            .await
+    }
+
+    /// Stream every customer matching the filters, following the pages
+    pub fn stream(
+        &self,
+        params: &CustomerListParams,
+    ) -> impl Stream<Item = Result<Customer, ShopifyAPIError>> + Unpin + 'a {
+        self.shopify
+            .rest_list_stream("customers.json".to_string(), params.params.clone())
+    }
+
+    /// Query a page of the customers matching a search query, e.g. `country:Canada` or
+    /// `email:bob@example.com`, starting from the first page without a cursor
+    pub async fn search(
+        &self,
+        query: &str,
+        cursor: Option<&PageCursor>,
+    ) -> Result<Page<Customer>, ShopifyAPIError> {
+        let mut params = Params::default();
+        params.set("query", query);
+
+        self.shopify
+            .rest_list("customers/search.json", &params, cursor)
+            .await
+    }
+
+    pub async fn get(&self, id: u64) -> Result<Customer, ShopifyAPIError> {
+        self.shopify
+            .rest_get_item(
+                &format!("customers/{}.json", id),
+                &Params::default(),
+                "customer",
+            )
+            .await
+    }
+
+    /// Count the customers matching the filters, `limit`, `since_id` and `fields` being ignored
+    pub async fn count(&self, params: &CustomerListParams) -> Result<u64, ShopifyAPIError> {
+        self.shopify
+            .rest_count("customers/count.json", &params.params)
+            .await
+    }
+
+    /// Create a customer, with their addresses
+    pub async fn create(&self, customer: &Customer) -> Result<Customer, ShopifyAPIError> {
+        self.shopify
+            .rest_post_item("customers.json", "customer", customer)
+            .await
+    }
+
+    /// Update the fields set on `customer`
+    pub async fn update(&self, id: u64, customer: &Customer) -> Result<Customer, ShopifyAPIError> {
+        self.shopify
+            .rest_put_item(&format!("customers/{}.json", id), "customer", customer)
+            .await
+    }
+
+    /// Delete a customer, which fails if the customer has orders
+    pub async fn delete(&self, id: u64) -> Result<(), ShopifyAPIError> {
+        self.shopify
+            .rest_delete_item(&format!("customers/{}.json", id))
+            .await
+    }
+
+    /// Query a page of the orders of a customer, open orders only unless a status is set
+    pub async fn orders_for_customer(
+        &self,
+        id: u64,
+        params: &OrderListParams,
+        cursor: Option<&PageCursor>,
+    ) -> Result<Page<Order>, ShopifyAPIError> {
+        self.shopify
+            .rest_list(
+                &format!("customers/{}/orders.json", id),
+                &params.params,
+                cursor,
+            )
+            .await
+    }
+
+    /// Send an account invitation to a customer, returning the invitation sent
+    pub async fn send_invite(
+        &self,
+        id: u64,
+        invite: &CustomerInvite,
+    ) -> Result<CustomerInvite, ShopifyAPIError> {
+        self.shopify
+            .rest_post_item(
+                &format!("customers/{}/send_invite.json", id),
+                "customer_invite",
+                invite,
+            )
+            .await
+    }
+
+    /// Generate a single-use URL for a customer to activate their account, without sending it
+    ///
+    /// The URL expires after 30 days, generating a new one invalidates the previous one.
+    pub async fn account_activation_url(&self, id: u64) -> Result<String, ShopifyAPIError> {
+        self.shopify
+            .rest_post_action(
+                &format!("customers/{}/account_activation_url.json", id),
+                &json!({}),
+                "account_activation_url",
+            )
+            .await
+    }
+}
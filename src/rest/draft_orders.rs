@@ -0,0 +1,445 @@
+//! Draft orders, through the REST `draft_orders.json` endpoints
+//!
+//! Draft orders are created by the merchant or an app for quotes, wholesale or phone orders: the
+//! customer pays them through the invoice sent with [`DraftOrders::send_invoice`], or they are
+//! completed into an order with [`DraftOrders::complete`].
+
+use futures_util::Stream;
+use serde::{Deserialize, Serialize};
+
+use super::{
+    customers::Customer,
+    orders::{Address, LineItemProperty, ShippingLine, TaxLine},
+    resource::Params,
+    ShopifyAPIRestType,
+};
+use crate::{
+    pagination::{Page, PageCursor},
+    utils::ReadJsonTreeSteps,
+    Shopify, ShopifyAPIError,
+};
+
+/// Status of a draft order
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DraftOrderStatus {
+    Open,
+    InvoiceSent,
+    Completed,
+}
+
+impl DraftOrderStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DraftOrderStatus::Open => "open",
+            DraftOrderStatus::InvoiceSent => "invoice_sent",
+            DraftOrderStatus::Completed => "completed",
+        }
+    }
+}
+
+/// How the value of an [`AppliedDiscount`] is applied
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DiscountValueType {
+    FixedAmount,
+    Percentage,
+}
+
+/// Custom discount of a draft order or of one of its line items
+///
+/// # Example
+/// ```
+/// use shopify_api::rest::draft_orders::AppliedDiscount;
+///
+/// let discount = AppliedDiscount::percentage("Wholesale", "15.0");
+///
+/// assert_eq!(
+///     serde_json::to_value(&discount).unwrap(),
+///     serde_json::json!({ "title": "Wholesale", "value": "15.0", "value_type": "percentage" })
+/// );
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AppliedDiscount {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Amount or percentage, as a decimal string
+    pub value: String,
+    pub value_type: DiscountValueType,
+    /// Amount of the discount, computed by Shopify
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amount: Option<String>,
+}
+
+impl AppliedDiscount {
+    /// Discount of `percentage` percent, e.g. `"15.0"`
+    pub fn percentage(title: &str, percentage: &str) -> AppliedDiscount {
+        AppliedDiscount {
+            title: Some(title.to_string()),
+            description: None,
+            value: percentage.to_string(),
+            value_type: DiscountValueType::Percentage,
+            amount: None,
+        }
+    }
+
+    /// Discount of a fixed `amount` in the currency of the shop, e.g. `"10.00"`
+    pub fn fixed_amount(title: &str, amount: &str) -> AppliedDiscount {
+        AppliedDiscount {
+            title: Some(title.to_string()),
+            description: None,
+            value: amount.to_string(),
+            value_type: DiscountValueType::FixedAmount,
+            amount: None,
+        }
+    }
+}
+
+/// Line item of a draft order, either a variant or a custom item with a title and a price
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct DraftOrderLineItem {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub variant_id: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub product_id: Option<u64>,
+    /// Title of a custom item, the title of the product otherwise
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub variant_title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sku: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vendor: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub quantity: Option<u32>,
+    /// Price of one item before discounts, required for a custom item
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub price: Option<String>,
+    /// Whether the line item is a custom item rather than a variant
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub applied_discount: Option<AppliedDiscount>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub requires_shipping: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub taxable: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gift_card: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub grams: Option<u64>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tax_lines: Vec<TaxLine>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub properties: Vec<LineItemProperty>,
+}
+
+/// Draft order of the shop
+///
+/// # Example
+/// ```
+/// use shopify_api::rest::draft_orders::{AppliedDiscount, DraftOrder, DraftOrderLineItem};
+///
+/// // A quote for 100 items of a variant, and a custom engraving
+/// let draft_order = DraftOrder {
+///     email: Some("bob@example.com".to_string()),
+///     line_items: vec![
+///         DraftOrderLineItem {
+///             variant_id: Some(447654529),
+///             quantity: Some(100),
+///             applied_discount: Some(AppliedDiscount::percentage("Wholesale", "15.0")),
+///             ..DraftOrderLineItem::default()
+///         },
+///         DraftOrderLineItem {
+///             title: Some("Engraving".to_string()),
+///             price: Some("20.00".to_string()),
+///             quantity: Some(1),
+///             ..DraftOrderLineItem::default()
+///         },
+///     ],
+///     ..DraftOrder::default()
+/// };
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct DraftOrder {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<u64>,
+    /// Name of the draft order, e.g. `#D1`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<DraftOrderStatus>,
+    /// Order created when the draft order was completed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order_id: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub customer: Option<Customer>,
+    /// Whether the addresses of the customer are used when not set
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_customer_default_address: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub currency: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total_price: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subtotal_price: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total_tax: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub taxes_included: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tax_exempt: Option<bool>,
+    /// Comma-separated tags
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tags: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub note: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub line_items: Vec<DraftOrderLineItem>,
+    /// Discount applied to the whole draft order
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub applied_discount: Option<AppliedDiscount>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shipping_line: Option<ShippingLine>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tax_lines: Vec<TaxLine>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub billing_address: Option<Address>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shipping_address: Option<Address>,
+    /// Checkout page where the customer pays the draft order
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub invoice_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub invoice_sent_at: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub completed_at: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub admin_graphql_api_id: Option<String>,
+}
+
+/// Invoice sent with [`DraftOrders::send_invoice`], the shop's template being used for the
+/// fields not set
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DraftOrderInvoice {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub to: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub from: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub bcc: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subject: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_message: Option<String>,
+}
+
+/// Filters of [`DraftOrders::list`], [`DraftOrders::stream`] and [`DraftOrders::count`]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct DraftOrderListParams {
+    params: Params,
+}
+
+impl DraftOrderListParams {
+    pub fn new() -> DraftOrderListParams {
+        DraftOrderListParams::default()
+    }
+
+    /// Set the number of draft orders of each page, 50 by default and at most 250
+    pub fn limit(mut self, limit: u32) -> Self {
+        self.params.set("limit", limit);
+        self
+    }
+
+    pub fn ids(mut self, ids: &[u64]) -> Self {
+        self.params.set_list("ids", ids);
+        self
+    }
+
+    /// Only list the draft orders with an ID greater than `since_id`
+    pub fn since_id(mut self, since_id: u64) -> Self {
+        self.params.set("since_id", since_id);
+        self
+    }
+
+    pub fn status(mut self, status: DraftOrderStatus) -> Self {
+        self.params.set("status", status.as_str());
+        self
+    }
+
+    pub fn updated_at_min(mut self, updated_at_min: chrono::DateTime<chrono::Utc>) -> Self {
+        self.params.set_time("updated_at_min", updated_at_min);
+        self
+    }
+
+    pub fn updated_at_max(mut self, updated_at_max: chrono::DateTime<chrono::Utc>) -> Self {
+        self.params.set_time("updated_at_max", updated_at_max);
+        self
+    }
+
+    /// Only return the given fields of each draft order
+    pub fn fields(mut self, fields: &[&str]) -> Self {
+        self.params.set_list("fields", fields);
+        self
+    }
+}
+
+/// Draft orders of a shop, returned by [`Shopify::draft_orders`]
+#[derive(Clone, Copy, Debug)]
+pub struct DraftOrders<'a> {
+    shopify: &'a Shopify,
+}
+
+impl Shopify {
+    /// Query and manage the draft orders of the shop
+    ///
+    /// # Example
+    /// ```no_run
+    /// use shopify_api::*;
+    /// use shopify_api::rest::draft_orders::{DraftOrder, DraftOrderInvoice, DraftOrderLineItem};
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let shopify = Shopify::new("myshop", "myapikey", ShopifyAPIVersion::V2026_10, None);
+    ///     let draft_order = DraftOrder {
+    ///         email: Some("bob@example.com".to_string()),
+    ///         line_items: vec![DraftOrderLineItem {
+    ///             variant_id: Some(447654529),
+    ///             quantity: Some(100),
+    ///             ..DraftOrderLineItem::default()
+    ///         }],
+    ///         ..DraftOrder::default()
+    ///     };
+    ///
+    ///     let draft_order = shopify.draft_orders().create(&draft_order).await.unwrap();
+    ///     let invoice = DraftOrderInvoice {
+    ///         subject: Some("Your quote".to_string()),
+    ///         ..DraftOrderInvoice::default()
+    ///     };
+    ///     shopify
+    ///         .draft_orders()
+    ///         .send_invoice(draft_order.id.unwrap(), &invoice)
+    ///         .await
+    ///         .unwrap();
+    /// }
+    /// ```
+    pub fn draft_orders(&self) -> DraftOrders<'_> {
+        DraftOrders { shopify: self }
+    }
+}
+
+impl<'a> DraftOrders<'a> {
+    /// Query a page of draft orders, starting from the first page without a cursor
+    pub async fn list(
+        &self,
+        params: &DraftOrderListParams,
+        cursor: Option<&PageCursor>,
+    ) -> Result<Page<DraftOrder>, ShopifyAPIError> {
+        self.shopify
+            .rest_list("draft_orders.json", &params.params, cursor)
+            .await
+    }
+
+    /// Stream every draft order matching the filters, following the pages
+    pub fn stream(
+        &self,
+        params: &DraftOrderListParams,
+    ) -> impl Stream<Item = Result<DraftOrder, ShopifyAPIError>> + Unpin + 'a {
+        self.shopify
+            .rest_list_stream("draft_orders.json".to_string(), params.params.clone())
+    }
+
+    pub async fn get(&self, id: u64) -> Result<DraftOrder, ShopifyAPIError> {
+        self.shopify
+            .rest_get_item(
+                &format!("draft_orders/{}.json", id),
+                &Params::default(),
+                "draft_order",
+            )
+            .await
+    }
+
+    /// Count the draft orders matching the filters, `limit`, `ids` and `fields` being ignored
+    pub async fn count(&self, params: &DraftOrderListParams) -> Result<u64, ShopifyAPIError> {
+        self.shopify
+            .rest_count("draft_orders/count.json", &params.params)
+            .await
+    }
+
+    pub async fn create(&self, draft_order: &DraftOrder) -> Result<DraftOrder, ShopifyAPIError> {
+        self.shopify
+            .rest_post_item("draft_orders.json", "draft_order", draft_order)
+            .await
+    }
+
+    /// Update the fields set on `draft_order`, the line items being replaced when set
+    pub async fn update(
+        &self,
+        id: u64,
+        draft_order: &DraftOrder,
+    ) -> Result<DraftOrder, ShopifyAPIError> {
+        self.shopify
+            .rest_put_item(
+                &format!("draft_orders/{}.json", id),
+                "draft_order",
+                draft_order,
+            )
+            .await
+    }
+
+    /// Delete a draft order, which fails once completed
+    pub async fn delete(&self, id: u64) -> Result<(), ShopifyAPIError> {
+        self.shopify
+            .rest_delete_item(&format!("draft_orders/{}.json", id))
+            .await
+    }
+
+    /// Turn a draft order into an order, read from [`DraftOrder::order_id`]
+    ///
+    /// The order is marked as paid, or as pending with `payment_pending`, e.g. for net terms.
+    pub async fn complete(
+        &self,
+        id: u64,
+        payment_pending: bool,
+    ) -> Result<DraftOrder, ShopifyAPIError> {
+        let mut params = Params::default();
+        params.set("payment_pending", payment_pending);
+
+        self.shopify
+            .rest_query(
+                &ShopifyAPIRestType::Put(
+                    &format!("draft_orders/{}/complete.json", id),
+                    &params.to_map(),
+                    &serde_json::json!({}),
+                ),
+                &Some(vec![ReadJsonTreeSteps::Key("draft_order")]),
+            )
+            .await
+    }
+
+    /// Email the invoice of a draft order to the customer
+    pub async fn send_invoice(
+        &self,
+        id: u64,
+        invoice: &DraftOrderInvoice,
+    ) -> Result<DraftOrderInvoice, ShopifyAPIError> {
+        self.shopify
+            .rest_post_item(
+                &format!("draft_orders/{}/send_invoice.json", id),
+                "draft_order_invoice",
+                invoice,
+            )
+            .await
+    }
+}
@@ -0,0 +1,107 @@
+use std::{collections::BTreeMap, fmt};
+
+use serde::Serialize;
+
+/// `errors` payload of a failed REST request
+///
+/// Shopify sends either a message (`{"errors": "Not Found"}`), a list of messages,
+/// or the messages of each invalid field (`{"errors": {"title": ["can't be blank"]}}`).
+/// # Example
+/// ```
+/// use shopify_api::rest::ShopifyRestErrors;
+///
+/// let errors = ShopifyRestErrors::parse(r#"{"errors": {"title": ["can't be blank"]}}"#).unwrap();
+/// assert_eq!(errors.field("title"), Some(&["can't be blank".to_string()][..]));
+/// assert_eq!(errors.messages(), vec!["title can't be blank"]);
+///
+/// let errors = ShopifyRestErrors::parse(r#"{"errors": "Not Found"}"#).unwrap();
+/// assert_eq!(errors, ShopifyRestErrors::Message("Not Found".to_string()));
+/// ```
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(untagged)]
+pub enum ShopifyRestErrors {
+    /// A single message
+    Message(String),
+
+    /// A list of messages
+    Messages(Vec<String>),
+
+    /// The messages of each invalid field, `base` being used for the whole resource
+    Fields(BTreeMap<String, Vec<String>>),
+}
+
+impl ShopifyRestErrors {
+    /// Parse the `errors` (or `error`) key of a response body
+    ///
+    /// Returns `None` if the body is not JSON or has no errors.
+    pub fn parse(body: &str) -> Option<ShopifyRestErrors> {
+        let json: serde_json::Value = serde_json::from_str(body).ok()?;
+        let errors = json.get("errors").or_else(|| json.get("error"))?;
+
+        ShopifyRestErrors::from_json(errors)
+    }
+
+    /// Read an `errors` value
+    fn from_json(errors: &serde_json::Value) -> Option<ShopifyRestErrors> {
+        match errors {
+            serde_json::Value::String(message) => Some(ShopifyRestErrors::Message(message.clone())),
+            serde_json::Value::Array(messages) => Some(ShopifyRestErrors::Messages(
+                messages.iter().map(json_message).collect(),
+            )),
+            serde_json::Value::Object(fields) => Some(ShopifyRestErrors::Fields(
+                fields
+                    .iter()
+                    .map(|(field, messages)| {
+                        let messages = match messages {
+                            serde_json::Value::Array(messages) => {
+                                messages.iter().map(json_message).collect()
+                            }
+                            message => vec![json_message(message)],
+                        };
+                        (field.clone(), messages)
+                    })
+                    .collect(),
+            )),
+            _ => None,
+        }
+    }
+
+    /// Get the messages of a field
+    pub fn field(&self, field: &str) -> Option<&[String]> {
+        match self {
+            ShopifyRestErrors::Fields(fields) => fields.get(field).map(Vec::as_slice),
+            _ => None,
+        }
+    }
+
+    /// Get every message, prefixed by its field name (except for `base`)
+    pub fn messages(&self) -> Vec<String> {
+        match self {
+            ShopifyRestErrors::Message(message) => vec![message.clone()],
+            ShopifyRestErrors::Messages(messages) => messages.clone(),
+            ShopifyRestErrors::Fields(fields) => fields
+                .iter()
+                .flat_map(|(field, messages)| {
+                    messages.iter().map(move |message| match field.as_str() {
+                        "base" => message.clone(),
+                        field => format!("{field} {message}"),
+                    })
+                })
+                .collect(),
+        }
+    }
+}
+
+impl fmt::Display for ShopifyRestErrors {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.messages().join("; "))
+    }
+}
+
+/// Get a message that may not be a JSON string
+fn json_message(message: &serde_json::Value) -> String {
+    match message {
+        serde_json::Value::String(message) => message.clone(),
+        message => message.to_string(),
+    }
+}
@@ -0,0 +1,373 @@
+//! Events of the shop, through the REST `events.json` endpoints
+//!
+//! Shopify records an event whenever a product, an order or another resource is created, updated
+//! or deleted, from the admin or through the API: listing them gives an audit log of the shop.
+//! The events of a single resource are listed with [`Events::resource`].
+
+use std::{fmt, str::FromStr};
+
+use futures_util::Stream;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use super::resource::Params;
+use crate::{
+    pagination::{Page, PageCursor},
+    Shopify, ShopifyAPIError,
+};
+
+/// Type of the resource an event is about
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum EventSubjectType {
+    Article,
+    Blog,
+    /// Smart or custom collection
+    Collection,
+    /// Comment of a blog article
+    Comment,
+    Order,
+    Page,
+    PriceRule,
+    Product,
+    /// Access scopes granted or revoked for an app
+    ApiPermission,
+
+    /// Any other type, by its REST name
+    Other(String),
+}
+
+impl EventSubjectType {
+    /// Get the REST name of the type, e.g. `PriceRule`
+    pub fn as_str(&self) -> &str {
+        match self {
+            EventSubjectType::Article => "Article",
+            EventSubjectType::Blog => "Blog",
+            EventSubjectType::Collection => "Collection",
+            EventSubjectType::Comment => "Comment",
+            EventSubjectType::Order => "Order",
+            EventSubjectType::Page => "Page",
+            EventSubjectType::PriceRule => "PriceRule",
+            EventSubjectType::Product => "Product",
+            EventSubjectType::ApiPermission => "ApiPermission",
+            EventSubjectType::Other(subject_type) => subject_type,
+        }
+    }
+}
+
+impl FromStr for EventSubjectType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(subject_type: &str) -> Result<Self, Self::Err> {
+        Ok(match subject_type {
+            "Article" => EventSubjectType::Article,
+            "Blog" => EventSubjectType::Blog,
+            "Collection" => EventSubjectType::Collection,
+            "Comment" => EventSubjectType::Comment,
+            "Order" => EventSubjectType::Order,
+            "Page" => EventSubjectType::Page,
+            "PriceRule" => EventSubjectType::PriceRule,
+            "Product" => EventSubjectType::Product,
+            "ApiPermission" => EventSubjectType::ApiPermission,
+            other => EventSubjectType::Other(other.to_string()),
+        })
+    }
+}
+
+impl fmt::Display for EventSubjectType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl Serialize for EventSubjectType {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for EventSubjectType {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let subject_type = String::deserialize(deserializer)?;
+        Ok(subject_type.parse().unwrap_or_else(|never| match never {}))
+    }
+}
+
+/// Event of the shop
+///
+/// # Example
+/// ```
+/// use shopify_api::rest::events::{Event, EventSubjectType};
+///
+/// let event: Event = serde_json::from_value(serde_json::json!({
+///     "id": 1,
+///     "subject_id": 632910392,
+///     "subject_type": "Product",
+///     "verb": "destroy",
+///     "author": "Shopify",
+///     "arguments": ["IPod Nano - 8GB"],
+/// }))
+/// .unwrap();
+///
+/// assert_eq!(event.subject_type, Some(EventSubjectType::Product));
+/// assert_eq!(event.verb.as_deref(), Some("destroy"));
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Event {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<u64>,
+    /// ID of the resource the event is about
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subject_id: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subject_type: Option<EventSubjectType>,
+    /// Action of the event, e.g. `create`, `update`, `destroy` or `published`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub verb: Option<String>,
+    /// Values referenced by the message, e.g. the title of the product
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub arguments: Vec<serde_json::Value>,
+    /// Text of the event, if any, e.g. a comment left on an order
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub body: Option<String>,
+    /// Message of the event, as HTML
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    /// Name of whoever triggered the event, e.g. a staff member or an app
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub author: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Admin path of the resource, e.g. `/admin/products/632910392`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<String>,
+}
+
+/// Resource listing its own events, see [`Events::resource`]
+///
+/// # Example
+/// ```
+/// use shopify_api::rest::events::EventResource;
+///
+/// assert_eq!(EventResource::Order(450789469).endpoint(), "orders/450789469/events.json");
+/// assert_eq!(
+///     EventResource::Resource("gift_cards".to_string(), 4).endpoint(),
+///     "gift_cards/4/events.json"
+/// );
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum EventResource {
+    Article(u64),
+    Blog(u64),
+    Comment(u64),
+    CustomCollection(u64),
+    SmartCollection(u64),
+    Order(u64),
+    Page(u64),
+    PriceRule(u64),
+    Product(u64),
+    /// Any other resource, by the path of its endpoints and its ID
+    Resource(String, u64),
+}
+
+impl EventResource {
+    /// Get the endpoint listing the events of the resource
+    pub fn endpoint(&self) -> String {
+        let (resource, id) = match self {
+            EventResource::Article(id) => ("articles", id),
+            EventResource::Blog(id) => ("blogs", id),
+            EventResource::Comment(id) => ("comments", id),
+            EventResource::CustomCollection(id) => ("custom_collections", id),
+            EventResource::SmartCollection(id) => ("smart_collections", id),
+            EventResource::Order(id) => ("orders", id),
+            EventResource::Page(id) => ("pages", id),
+            EventResource::PriceRule(id) => ("price_rules", id),
+            EventResource::Product(id) => ("products", id),
+            EventResource::Resource(resource, id) => (resource.as_str(), id),
+        };
+
+        format!("{}/{}/events.json", resource, id)
+    }
+}
+
+/// Filters of the event queries
+///
+/// # Example
+/// ```
+/// use chrono::{Duration, Utc};
+/// use shopify_api::rest::events::{EventListParams, EventSubjectType};
+///
+/// // Products and collections deleted in the last week
+/// let params = EventListParams::new()
+///     .subject_types(&[EventSubjectType::Product, EventSubjectType::Collection])
+///     .verb("destroy")
+///     .created_at_min(Utc::now() - Duration::weeks(1));
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct EventListParams {
+    params: Params,
+}
+
+impl EventListParams {
+    pub fn new() -> EventListParams {
+        EventListParams::default()
+    }
+
+    /// Set the number of events of each page, 50 by default and at most 250
+    pub fn limit(mut self, limit: u32) -> Self {
+        self.params.set("limit", limit);
+        self
+    }
+
+    /// Only list the events with an ID greater than `since_id`
+    pub fn since_id(mut self, since_id: u64) -> Self {
+        self.params.set("since_id", since_id);
+        self
+    }
+
+    pub fn created_at_min(mut self, created_at_min: chrono::DateTime<chrono::Utc>) -> Self {
+        self.params.set_time("created_at_min", created_at_min);
+        self
+    }
+
+    pub fn created_at_max(mut self, created_at_max: chrono::DateTime<chrono::Utc>) -> Self {
+        self.params.set_time("created_at_max", created_at_max);
+        self
+    }
+
+    /// Only list the events about resources of the given types
+    pub fn subject_types(mut self, subject_types: &[EventSubjectType]) -> Self {
+        self.params.set_list("filter", subject_types);
+        self
+    }
+
+    /// Only list the events of an action, e.g. `create` or `destroy`
+    pub fn verb(mut self, verb: &str) -> Self {
+        self.params.set("verb", verb);
+        self
+    }
+
+    /// Only return the given fields of each event
+    pub fn fields(mut self, fields: &[&str]) -> Self {
+        self.params.set_list("fields", fields);
+        self
+    }
+}
+
+/// Events of the shop, returned by [`Shopify::events`]
+#[derive(Clone, Copy, Debug)]
+pub struct Events<'a> {
+    shopify: &'a Shopify,
+}
+
+/// Events of a single resource, returned by [`Events::resource`]
+#[derive(Clone, Debug)]
+pub struct ResourceEvents<'a> {
+    shopify: &'a Shopify,
+    resource: EventResource,
+}
+
+impl Shopify {
+    /// Query the events of the shop
+    ///
+    /// # Example
+    /// ```no_run
+    /// use chrono::{Duration, Utc};
+    /// use futures_util::StreamExt;
+    /// use shopify_api::*;
+    /// use shopify_api::rest::events::{EventListParams, EventResource, EventSubjectType};
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let shopify = Shopify::new("myshop", "myapikey", ShopifyAPIVersion::V2026_10, None);
+    ///     let params = EventListParams::new()
+    ///         .subject_types(&[EventSubjectType::Product])
+    ///         .created_at_min(Utc::now() - Duration::days(1));
+    ///
+    ///     let mut events = shopify.events().stream(&params);
+    ///     while let Some(event) = events.next().await {
+    ///         let event = event.unwrap();
+    ///         println!("{:?} {:?} {:?}", event.author, event.verb, event.subject_id);
+    ///     }
+    ///
+    ///     // History of an order
+    ///     let page = shopify
+    ///         .events()
+    ///         .resource(EventResource::Order(450789469))
+    ///         .list(&EventListParams::new(), None)
+    ///         .await
+    ///         .unwrap();
+    ///     for event in page.items {
+    ///         println!("{:?}", event.message);
+    ///     }
+    /// }
+    /// ```
+    pub fn events(&self) -> Events<'_> {
+        Events { shopify: self }
+    }
+}
+
+impl<'a> Events<'a> {
+    /// Query a page of events, starting from the first page without a cursor
+    pub async fn list(
+        &self,
+        params: &EventListParams,
+        cursor: Option<&PageCursor>,
+    ) -> Result<Page<Event>, ShopifyAPIError> {
+        self.shopify
+            .rest_list("events.json", &params.params, cursor)
+            .await
+    }
+
+    /// Stream every event matching the filters, following the pages
+    pub fn stream(
+        &self,
+        params: &EventListParams,
+    ) -> impl Stream<Item = Result<Event, ShopifyAPIError>> + Unpin + 'a {
+        self.shopify
+            .rest_list_stream("events.json".to_string(), params.params.clone())
+    }
+
+    pub async fn get(&self, id: u64) -> Result<Event, ShopifyAPIError> {
+        self.shopify
+            .rest_get_item(&format!("events/{}.json", id), &Params::default(), "event")
+            .await
+    }
+
+    /// Count the events matching the filters, Shopify only applying the `created_at` ones
+    pub async fn count(&self, params: &EventListParams) -> Result<u64, ShopifyAPIError> {
+        self.shopify
+            .rest_count("events/count.json", &params.params)
+            .await
+    }
+
+    /// Query the events of a single resource
+    pub fn resource(&self, resource: EventResource) -> ResourceEvents<'a> {
+        ResourceEvents {
+            shopify: self.shopify,
+            resource,
+        }
+    }
+}
+
+impl<'a> ResourceEvents<'a> {
+    /// Query a page of the events of the resource, starting from the first page without a cursor
+    pub async fn list(
+        &self,
+        params: &EventListParams,
+        cursor: Option<&PageCursor>,
+    ) -> Result<Page<Event>, ShopifyAPIError> {
+        self.shopify
+            .rest_list(&self.resource.endpoint(), &params.params, cursor)
+            .await
+    }
+
+    /// Stream every event of the resource matching the filters, following the pages
+    pub fn stream(
+        &self,
+        params: &EventListParams,
+    ) -> impl Stream<Item = Result<Event, ShopifyAPIError>> + Unpin + 'a {
+        self.shopify
+            .rest_list_stream(self.resource.endpoint(), params.params.clone())
+    }
+}
@@ -0,0 +1,225 @@
+//! Fulfillment services, through the REST `fulfillment_services.json` endpoints
+//!
+//! A fulfillment service, e.g. a third-party warehouse, registers itself with the shop and gets a
+//! location of its own. The orders assigned to that location are sent to its `callback_url` as
+//! fulfillment requests, see [`crate::rest::fulfillments`].
+
+use serde::{Deserialize, Serialize};
+
+use super::resource::Params;
+use crate::{Shopify, ShopifyAPIError};
+
+/// Fulfillment service of the shop
+///
+/// # Example
+/// ```
+/// use shopify_api::rest::fulfillment_services::FulfillmentService;
+///
+/// let fulfillment_service = FulfillmentService::new("Acme Warehouse", "https://example.com")
+///     .inventory_management(true)
+///     .tracking_support(true);
+///
+/// assert_eq!(
+///     serde_json::to_value(&fulfillment_service).unwrap(),
+///     serde_json::json!({
+///         "name": "Acme Warehouse",
+///         "callback_url": "https://example.com",
+///         "inventory_management": true,
+///         "tracking_support": true,
+///         "format": "json",
+///     })
+/// );
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FulfillmentService {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<u64>,
+    /// Name shown to the merchant, also naming the location of the service
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// Handle of the service, e.g. `acme-warehouse`, set on the variants it fulfills
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub handle: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+    /// Base URL receiving the fulfillment requests and the stock and tracking queries
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub callback_url: Option<String>,
+    /// Whether Shopify queries `callback_url/fetch_stock` for the inventory levels
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub inventory_management: Option<bool>,
+    /// Whether Shopify queries `callback_url/fetch_tracking_numbers` for the tracking numbers
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tracking_support: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub requires_shipping_method: Option<bool>,
+    /// Whether the merchant can stock the same SKU at the service and at other locations
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub permits_sku_sharing: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fulfillment_orders_opt_in: Option<bool>,
+    /// Format of the requests, `json`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub format: Option<String>,
+    /// Location created for the service
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub location_id: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub provider_id: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub service_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub admin_graphql_api_id: Option<String>,
+}
+
+impl FulfillmentService {
+    /// Fulfillment service sending its requests as JSON to `callback_url`
+    pub fn new(name: &str, callback_url: &str) -> FulfillmentService {
+        FulfillmentService {
+            name: Some(name.to_string()),
+            callback_url: Some(callback_url.to_string()),
+            format: Some("json".to_string()),
+            ..FulfillmentService::default()
+        }
+    }
+
+    /// Set whether the service reports its inventory levels
+    pub fn inventory_management(mut self, inventory_management: bool) -> Self {
+        self.inventory_management = Some(inventory_management);
+        self
+    }
+
+    /// Set whether the service reports the tracking numbers of its fulfillments
+    pub fn tracking_support(mut self, tracking_support: bool) -> Self {
+        self.tracking_support = Some(tracking_support);
+        self
+    }
+
+    pub fn requires_shipping_method(mut self, requires_shipping_method: bool) -> Self {
+        self.requires_shipping_method = Some(requires_shipping_method);
+        self
+    }
+
+    pub fn permits_sku_sharing(mut self, permits_sku_sharing: bool) -> Self {
+        self.permits_sku_sharing = Some(permits_sku_sharing);
+        self
+    }
+
+    pub fn email(mut self, email: &str) -> Self {
+        self.email = Some(email.to_string());
+        self
+    }
+}
+
+/// Fulfillment services listed by [`FulfillmentServices::list`]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum FulfillmentServiceScope {
+    /// Only the services of the app
+    #[default]
+    CurrentClient,
+    /// Every service of the shop
+    All,
+}
+
+impl FulfillmentServiceScope {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            FulfillmentServiceScope::CurrentClient => "current_client",
+            FulfillmentServiceScope::All => "all",
+        }
+    }
+}
+
+/// Fulfillment services of the shop, returned by [`Shopify::fulfillment_services`]
+#[derive(Clone, Copy, Debug)]
+pub struct FulfillmentServices<'a> {
+    shopify: &'a Shopify,
+}
+
+impl Shopify {
+    /// Query and manage the fulfillment services of the shop
+    ///
+    /// # Example
+    /// ```no_run
+    /// use shopify_api::*;
+    /// use shopify_api::rest::fulfillment_services::FulfillmentService;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let shopify = Shopify::new("myshop", "myapikey", ShopifyAPIVersion::V2026_10, None);
+    ///     let fulfillment_service = FulfillmentService::new("Acme Warehouse", "https://example.com")
+    ///         .inventory_management(true);
+    ///
+    ///     let fulfillment_service = shopify
+    ///         .fulfillment_services()
+    ///         .create(&fulfillment_service)
+    ///         .await
+    ///         .unwrap();
+    ///     println!("Location: {:?}", fulfillment_service.location_id);
+    /// }
+    /// ```
+    pub fn fulfillment_services(&self) -> FulfillmentServices<'_> {
+        FulfillmentServices { shopify: self }
+    }
+}
+
+impl<'a> FulfillmentServices<'a> {
+    /// Get the fulfillment services of the app, or of the whole shop
+    pub async fn list(
+        &self,
+        scope: FulfillmentServiceScope,
+    ) -> Result<Vec<FulfillmentService>, ShopifyAPIError> {
+        let mut params = Params::default();
+        params.set("scope", scope.as_str());
+
+        self.shopify
+            .rest_get_item("fulfillment_services.json", &params, "fulfillment_services")
+            .await
+    }
+
+    pub async fn get(&self, id: u64) -> Result<FulfillmentService, ShopifyAPIError> {
+        self.shopify
+            .rest_get_item(
+                &format!("fulfillment_services/{}.json", id),
+                &Params::default(),
+                "fulfillment_service",
+            )
+            .await
+    }
+
+    /// Register a fulfillment service, creating its location
+    pub async fn create(
+        &self,
+        fulfillment_service: &FulfillmentService,
+    ) -> Result<FulfillmentService, ShopifyAPIError> {
+        self.shopify
+            .rest_post_item(
+                "fulfillment_services.json",
+                "fulfillment_service",
+                fulfillment_service,
+            )
+            .await
+    }
+
+    /// Update the fields set on `fulfillment_service`
+    pub async fn update(
+        &self,
+        id: u64,
+        fulfillment_service: &FulfillmentService,
+    ) -> Result<FulfillmentService, ShopifyAPIError> {
+        self.shopify
+            .rest_put_item(
+                &format!("fulfillment_services/{}.json", id),
+                "fulfillment_service",
+                fulfillment_service,
+            )
+            .await
+    }
+
+    /// Delete a fulfillment service, which fails while its location stocks inventory
+    pub async fn delete(&self, id: u64) -> Result<(), ShopifyAPIError> {
+        self.shopify
+            .rest_delete_item(&format!("fulfillment_services/{}.json", id))
+            .await
+    }
+}
@@ -0,0 +1,507 @@
+//! Fulfillment orders and fulfillments, through the REST `fulfillment_orders.json` and
+//! `fulfillments.json` endpoints
+//!
+//! Shopify splits every order into fulfillment orders, one for each location the items are
+//! fulfilled from. A fulfillment service first accepts or rejects the fulfillment requests sent to
+//! it, then fulfills the line items of the fulfillment orders by creating fulfillments.
+
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+use super::{
+    orders::{Address, LineItem},
+    resource::Params,
+};
+use crate::{
+    pagination::{Page, PageCursor},
+    Shopify, ShopifyAPIError,
+};
+
+/// Status of a fulfillment order
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FulfillmentOrderStatus {
+    Open,
+    InProgress,
+    Cancelled,
+    Incomplete,
+    Closed,
+    Scheduled,
+    OnHold,
+    #[serde(other)]
+    Unknown,
+}
+
+/// Status of the fulfillment request of a fulfillment order, sent to a fulfillment service
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FulfillmentRequestStatus {
+    Unsubmitted,
+    Submitted,
+    Accepted,
+    Rejected,
+    CancellationRequested,
+    CancellationAccepted,
+    CancellationRejected,
+    Closed,
+    #[serde(other)]
+    Unknown,
+}
+
+/// Reason given by a fulfillment service to [`FulfillmentOrders::reject_request`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FulfillmentRejectionReason {
+    IncorrectAddress,
+    IneligibleProduct,
+    InventoryOutOfStock,
+    UndeliverableDestination,
+    Other,
+}
+
+/// Status of a fulfillment
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FulfillmentState {
+    Pending,
+    Open,
+    Success,
+    Cancelled,
+    Error,
+    Failure,
+    #[serde(other)]
+    Unknown,
+}
+
+/// Group of line items of an order fulfilled from the same location
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct FulfillmentOrder {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order_id: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub assigned_location_id: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<FulfillmentOrderStatus>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub request_status: Option<FulfillmentRequestStatus>,
+    /// Actions that can be run on the fulfillment order, e.g. `create_fulfillment` or `move`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub supported_actions: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub destination: Option<FulfillmentOrderDestination>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub line_items: Vec<FulfillmentOrderLineItem>,
+    /// When the fulfillment order is ready to be fulfilled, for scheduled fulfillment orders
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fulfill_at: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<String>,
+}
+
+/// Line item of an order to fulfill in a fulfillment order
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FulfillmentOrderLineItem {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fulfillment_order_id: Option<u64>,
+    /// Id of the line item of the order
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub line_item_id: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub inventory_item_id: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub variant_id: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub quantity: Option<u32>,
+    /// Quantity left to fulfill
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fulfillable_quantity: Option<u32>,
+}
+
+/// Address a fulfillment order is shipped to
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct FulfillmentOrderDestination {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+    #[serde(flatten)]
+    pub address: Address,
+}
+
+/// Shipment of line items of an order
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct Fulfillment {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order_id: Option<u64>,
+    /// Name of the fulfillment, e.g. `#1001.1`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<FulfillmentState>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub location_id: Option<u64>,
+    /// Service that fulfilled the items, e.g. `manual`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub service: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tracking_company: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tracking_number: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tracking_numbers: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tracking_url: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tracking_urls: Vec<String>,
+    /// Status of the shipment reported by the carrier, e.g. `in_transit` or `delivered`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shipment_status: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub line_items: Vec<LineItem>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub admin_graphql_api_id: Option<String>,
+}
+
+/// Tracking information of a fulfillment
+///
+/// Shopify generates the url from the number for the carriers it knows.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TrackingInfo {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub number: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    /// Name of the carrier, e.g. `UPS` or `Canada Post`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub company: Option<String>,
+}
+
+/// Answer of a fulfillment service to a fulfillment request
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+struct FulfillmentRequestAnswer<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    reason: Option<FulfillmentRejectionReason>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    message: Option<&'a str>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+struct FulfillmentOrderLineItems {
+    fulfillment_order_id: u64,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    fulfillment_order_line_items: Vec<FulfillmentOrderLineItemQuantity>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+struct FulfillmentOrderLineItemQuantity {
+    id: u64,
+    quantity: u32,
+}
+
+/// Fulfillment to create with [`Fulfillments::create`]
+///
+/// # Example
+/// ```
+/// use shopify_api::rest::fulfillments::{NewFulfillment, TrackingInfo};
+///
+/// // Every item of the first fulfillment order, and 2 items of the second one
+/// let fulfillment = NewFulfillment::new()
+///     .fulfillment_order(1046000778)
+///     .line_item(1046000779, 1025578633, 2)
+///     .tracking_info(TrackingInfo {
+///         number: Some("1Z001985YW99744790".to_string()),
+///         company: Some("UPS".to_string()),
+///         ..TrackingInfo::default()
+///     })
+///     .notify_customer(true);
+///
+/// assert_eq!(
+///     serde_json::to_value(&fulfillment).unwrap(),
+///     serde_json::json!({
+///         "line_items_by_fulfillment_order": [
+///             { "fulfillment_order_id": 1046000778 },
+///             {
+///                 "fulfillment_order_id": 1046000779,
+///                 "fulfillment_order_line_items": [{ "id": 1025578633, "quantity": 2 }]
+///             }
+///         ],
+///         "tracking_info": { "number": "1Z001985YW99744790", "company": "UPS" },
+///         "notify_customer": true
+///     })
+/// );
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize)]
+pub struct NewFulfillment {
+    line_items_by_fulfillment_order: Vec<FulfillmentOrderLineItems>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tracking_info: Option<TrackingInfo>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    notify_customer: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    location_id: Option<u64>,
+}
+
+impl NewFulfillment {
+    pub fn new() -> NewFulfillment {
+        NewFulfillment::default()
+    }
+
+    /// Fulfill every remaining item of a fulfillment order
+    pub fn fulfillment_order(mut self, fulfillment_order_id: u64) -> Self {
+        self.fulfillment_order_entry(fulfillment_order_id);
+        self
+    }
+
+    /// Fulfill `quantity` items of a line item of a fulfillment order
+    pub fn line_item(
+        mut self,
+        fulfillment_order_id: u64,
+        fulfillment_order_line_item_id: u64,
+        quantity: u32,
+    ) -> Self {
+        self.fulfillment_order_entry(fulfillment_order_id)
+            .fulfillment_order_line_items
+            .push(FulfillmentOrderLineItemQuantity {
+                id: fulfillment_order_line_item_id,
+                quantity,
+            });
+        self
+    }
+
+    pub fn tracking_info(mut self, tracking_info: TrackingInfo) -> Self {
+        self.tracking_info = Some(tracking_info);
+        self
+    }
+
+    /// Send a shipping confirmation to the customer
+    pub fn notify_customer(mut self, notify_customer: bool) -> Self {
+        self.notify_customer = Some(notify_customer);
+        self
+    }
+
+    /// Set the location the items are fulfilled from, the assigned location by default
+    pub fn location_id(mut self, location_id: u64) -> Self {
+        self.location_id = Some(location_id);
+        self
+    }
+
+    fn fulfillment_order_entry(
+        &mut self,
+        fulfillment_order_id: u64,
+    ) -> &mut FulfillmentOrderLineItems {
+        let lines = &mut self.line_items_by_fulfillment_order;
+        let index = match lines
+            .iter()
+            .position(|line| line.fulfillment_order_id == fulfillment_order_id)
+        {
+            Some(index) => index,
+            None => {
+                lines.push(FulfillmentOrderLineItems {
+                    fulfillment_order_id,
+                    fulfillment_order_line_items: Vec::new(),
+                });
+                lines.len() - 1
+            }
+        };
+
+        &mut lines[index]
+    }
+}
+
+/// Fulfillment orders of a shop, returned by [`Shopify::fulfillment_orders`]
+#[derive(Clone, Copy, Debug)]
+pub struct FulfillmentOrders<'a> {
+    shopify: &'a Shopify,
+}
+
+/// Fulfillments of a shop, returned by [`Shopify::fulfillments`]
+#[derive(Clone, Copy, Debug)]
+pub struct Fulfillments<'a> {
+    shopify: &'a Shopify,
+}
+
+impl Shopify {
+    /// Query the fulfillment orders of the shop and answer their fulfillment requests
+    ///
+    /// # Example
+    /// ```no_run
+    /// use shopify_api::*;
+    /// use shopify_api::rest::fulfillments::FulfillmentRequestStatus;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let shopify = Shopify::new("myshop", "myapikey", ShopifyAPIVersion::V2026_10, None);
+    ///
+    ///     for fulfillment_order in shopify.fulfillment_orders().list_for_order(450789469).await.unwrap() {
+    ///         if fulfillment_order.request_status == Some(FulfillmentRequestStatus::Submitted) {
+    ///             shopify
+    ///                 .fulfillment_orders()
+    ///                 .accept_request(fulfillment_order.id.unwrap(), Some("Ships tomorrow"))
+    ///                 .await
+    ///                 .unwrap();
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub fn fulfillment_orders(&self) -> FulfillmentOrders<'_> {
+        FulfillmentOrders { shopify: self }
+    }
+
+    /// Create, track and cancel the fulfillments of the shop
+    ///
+    /// # Example
+    /// ```no_run
+    /// use shopify_api::*;
+    /// use shopify_api::rest::fulfillments::{NewFulfillment, TrackingInfo};
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let shopify = Shopify::new("myshop", "myapikey", ShopifyAPIVersion::V2026_10, None);
+    ///
+    ///     let fulfillment = shopify
+    ///         .fulfillments()
+    ///         .create(&NewFulfillment::new().fulfillment_order(1046000778))
+    ///         .await
+    ///         .unwrap();
+    ///
+    ///     let tracking_info = TrackingInfo {
+    ///         number: Some("1Z001985YW99744790".to_string()),
+    ///         company: Some("UPS".to_string()),
+    ///         ..TrackingInfo::default()
+    ///     };
+    ///     shopify
+    ///         .fulfillments()
+    ///         .update_tracking(fulfillment.id.unwrap(), &tracking_info, true)
+    ///         .await
+    ///         .unwrap();
+    /// }
+    /// ```
+    pub fn fulfillments(&self) -> Fulfillments<'_> {
+        Fulfillments { shopify: self }
+    }
+}
+
+impl<'a> FulfillmentOrders<'a> {
+    /// Query every fulfillment order of an order, the endpoint not being paginated
+    pub async fn list_for_order(
+        &self,
+        order_id: u64,
+    ) -> Result<Vec<FulfillmentOrder>, ShopifyAPIError> {
+        self.shopify
+            .rest_get_item(
+                &format!("orders/{}/fulfillment_orders.json", order_id),
+                &Params::default(),
+                "fulfillment_orders",
+            )
+            .await
+    }
+
+    pub async fn get(&self, id: u64) -> Result<FulfillmentOrder, ShopifyAPIError> {
+        self.shopify
+            .rest_get_item(
+                &format!("fulfillment_orders/{}.json", id),
+                &Params::default(),
+                "fulfillment_order",
+            )
+            .await
+    }
+
+    /// Accept the fulfillment request of a fulfillment order, as its fulfillment service
+    pub async fn accept_request(
+        &self,
+        id: u64,
+        message: Option<&str>,
+    ) -> Result<FulfillmentOrder, ShopifyAPIError> {
+        self.shopify
+            .rest_post_action(
+                &format!("fulfillment_orders/{}/fulfillment_request/accept.json", id),
+                &json!({
+                    "fulfillment_request": FulfillmentRequestAnswer { reason: None, message },
+                }),
+                "fulfillment_order",
+            )
+            .await
+    }
+
+    /// Reject the fulfillment request of a fulfillment order, as its fulfillment service
+    pub async fn reject_request(
+        &self,
+        id: u64,
+        reason: Option<FulfillmentRejectionReason>,
+        message: Option<&str>,
+    ) -> Result<FulfillmentOrder, ShopifyAPIError> {
+        self.shopify
+            .rest_post_action(
+                &format!("fulfillment_orders/{}/fulfillment_request/reject.json", id),
+                &json!({ "fulfillment_request": FulfillmentRequestAnswer { reason, message } }),
+                "fulfillment_order",
+            )
+            .await
+    }
+}
+
+impl<'a> Fulfillments<'a> {
+    /// Query a page of the fulfillments of an order, starting from the first page without a
+    /// cursor
+    pub async fn list_for_order(
+        &self,
+        order_id: u64,
+        cursor: Option<&PageCursor>,
+    ) -> Result<Page<Fulfillment>, ShopifyAPIError> {
+        self.shopify
+            .rest_list(
+                &format!("orders/{}/fulfillments.json", order_id),
+                &Params::default(),
+                cursor,
+            )
+            .await
+    }
+
+    /// Fulfill line items of fulfillment orders
+    pub async fn create(
+        &self,
+        fulfillment: &NewFulfillment,
+    ) -> Result<Fulfillment, ShopifyAPIError> {
+        self.shopify
+            .rest_post_item("fulfillments.json", "fulfillment", fulfillment)
+            .await
+    }
+
+    /// Replace the tracking information of a fulfillment
+    pub async fn update_tracking(
+        &self,
+        id: u64,
+        tracking_info: &TrackingInfo,
+        notify_customer: bool,
+    ) -> Result<Fulfillment, ShopifyAPIError> {
+        self.shopify
+            .rest_post_item(
+                &format!("fulfillments/{}/update_tracking.json", id),
+                "fulfillment",
+                &json!({ "tracking_info": tracking_info, "notify_customer": notify_customer }),
+            )
+            .await
+    }
+
+    pub async fn cancel(&self, id: u64) -> Result<Fulfillment, ShopifyAPIError> {
+        self.shopify
+            .rest_post_action(
+                &format!("fulfillments/{}/cancel.json", id),
+                &json!({}),
+                "fulfillment",
+            )
+            .await
+    }
+}
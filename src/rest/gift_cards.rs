@@ -0,0 +1,460 @@
+//! Gift cards, through the REST `gift_cards.json` endpoints, only available to Shopify Plus shops
+//!
+//! The code of a gift card is only returned when it is created: it is held in a [`GiftCardCode`],
+//! whose `Debug` output only shows the last characters so that card numbers don't leak into logs.
+
+use std::fmt;
+
+use futures_util::Stream;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+use super::resource::Params;
+use crate::{
+    pagination::{Page, PageCursor},
+    Shopify, ShopifyAPIError,
+};
+
+/// Number of characters of a [`GiftCardCode`] shown by its `Debug` output
+const VISIBLE_CODE_CHARACTERS: usize = 4;
+
+/// Code of a gift card, redeemed by the customer at checkout
+///
+/// # Example
+/// ```
+/// use shopify_api::rest::gift_cards::GiftCardCode;
+///
+/// let code = GiftCardCode::new("ABCD1234EFGH5678");
+///
+/// assert_eq!(format!("{:?}", code), "GiftCardCode(\"************5678\")");
+/// assert_eq!(code.as_str(), "ABCD1234EFGH5678");
+/// ```
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct GiftCardCode(String);
+
+impl GiftCardCode {
+    pub fn new(code: &str) -> GiftCardCode {
+        GiftCardCode(code.to_string())
+    }
+
+    /// Full code, to be shown to the customer only
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Last characters of the code, as shown by the admin
+    pub fn last_characters(&self) -> &str {
+        let start = self
+            .0
+            .char_indices()
+            .rev()
+            .nth(VISIBLE_CODE_CHARACTERS - 1)
+            .map_or(0, |(index, _)| index);
+
+        &self.0[start..]
+    }
+}
+
+impl fmt::Debug for GiftCardCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let visible = self.last_characters();
+        let masked = "*".repeat(self.0.chars().count() - visible.chars().count());
+
+        f.debug_tuple("GiftCardCode")
+            .field(&format!("{}{}", masked, visible))
+            .finish()
+    }
+}
+
+/// Status filter of [`GiftCardListParams::status`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GiftCardStatus {
+    Enabled,
+    Disabled,
+}
+
+impl GiftCardStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            GiftCardStatus::Enabled => "enabled",
+            GiftCardStatus::Disabled => "disabled",
+        }
+    }
+}
+
+/// Gift card of the shop
+///
+/// # Example
+/// ```
+/// use shopify_api::rest::gift_cards::GiftCard;
+///
+/// let gift_card = GiftCard::new("25.00")
+///     .code("ABCD1234EFGH5678")
+///     .note("Apology for the delayed order");
+///
+/// assert!(!format!("{:?}", gift_card).contains("ABCD1234"));
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GiftCard {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<u64>,
+    /// Code of the gift card, only returned when created and generated by Shopify when not set
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub code: Option<GiftCardCode>,
+    /// Last four characters of the code
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_characters: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub initial_value: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub balance: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub currency: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub customer_id: Option<u64>,
+    /// Order through which the gift card was sold, if any
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order_id: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub line_item_id: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub api_client_id: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_id: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub note: Option<String>,
+    /// Date after which the gift card can't be used, e.g. `2027-01-31`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expires_on: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub template_suffix: Option<String>,
+    /// Set once the gift card is disabled, which can't be undone
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disabled_at: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub admin_graphql_api_id: Option<String>,
+}
+
+impl GiftCard {
+    /// New gift card of `initial_value` in the currency of the shop, e.g. `"25.00"`
+    pub fn new(initial_value: &str) -> GiftCard {
+        GiftCard {
+            initial_value: Some(initial_value.to_string()),
+            ..GiftCard::default()
+        }
+    }
+
+    /// Set the code of the gift card, from 8 to 20 alphanumeric characters
+    pub fn code(mut self, code: &str) -> Self {
+        self.code = Some(GiftCardCode::new(code));
+        self
+    }
+
+    pub fn note(mut self, note: &str) -> Self {
+        self.note = Some(note.to_string());
+        self
+    }
+
+    pub fn customer_id(mut self, customer_id: u64) -> Self {
+        self.customer_id = Some(customer_id);
+        self
+    }
+
+    /// Set the date after which the gift card can't be used, e.g. `2027-01-31`
+    pub fn expires_on(mut self, expires_on: &str) -> Self {
+        self.expires_on = Some(expires_on.to_string());
+        self
+    }
+
+    /// Whether the gift card was disabled
+    pub fn is_disabled(&self) -> bool {
+        self.disabled_at.is_some()
+    }
+}
+
+/// Credit or debit of the balance of a gift card
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GiftCardAdjustment {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gift_card_id: Option<u64>,
+    /// Amount added to the balance, negative for a debit
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amount: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub note: Option<String>,
+    /// Number of the adjustment, in the order of the adjustments of the gift card
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub number: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub api_client_id: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_id: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order_transaction_id: Option<u64>,
+    /// Reference of the adjustment in an external system, e.g. a point of sale
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub remote_transaction_ref: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub remote_transaction_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub processed_at: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<String>,
+}
+
+impl GiftCardAdjustment {
+    /// Adjustment of `amount`, e.g. `"10.00"` for a credit or `"-10.00"` for a debit
+    pub fn new(amount: &str) -> GiftCardAdjustment {
+        GiftCardAdjustment {
+            amount: Some(amount.to_string()),
+            ..GiftCardAdjustment::default()
+        }
+    }
+
+    pub fn note(mut self, note: &str) -> Self {
+        self.note = Some(note.to_string());
+        self
+    }
+}
+
+/// Filters of [`GiftCards::list`], [`GiftCards::stream`] and [`GiftCards::count`]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct GiftCardListParams {
+    params: Params,
+}
+
+impl GiftCardListParams {
+    pub fn new() -> GiftCardListParams {
+        GiftCardListParams::default()
+    }
+
+    /// Set the number of gift cards of each page, 50 by default and at most 250
+    pub fn limit(mut self, limit: u32) -> Self {
+        self.params.set("limit", limit);
+        self
+    }
+
+    /// Only list the gift cards with an ID greater than `since_id`
+    pub fn since_id(mut self, since_id: u64) -> Self {
+        self.params.set("since_id", since_id);
+        self
+    }
+
+    pub fn status(mut self, status: GiftCardStatus) -> Self {
+        self.params.set("status", status.as_str());
+        self
+    }
+
+    /// Only return the given fields of each gift card
+    pub fn fields(mut self, fields: &[&str]) -> Self {
+        self.params.set_list("fields", fields);
+        self
+    }
+}
+
+/// Query of [`GiftCards::search`]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct GiftCardSearchParams {
+    params: Params,
+}
+
+impl GiftCardSearchParams {
+    /// Search the gift cards matching `query`, e.g. `last_characters:5678` or `balance:>10`
+    pub fn new(query: &str) -> GiftCardSearchParams {
+        let mut params = Params::default();
+        params.set("query", query);
+
+        GiftCardSearchParams { params }
+    }
+
+    /// Set the number of gift cards of each page, 50 by default and at most 250
+    pub fn limit(mut self, limit: u32) -> Self {
+        self.params.set("limit", limit);
+        self
+    }
+
+    /// Sort the results, e.g. `balance DESC`, by `disabled_at DESC` by default
+    pub fn order(mut self, order: &str) -> Self {
+        self.params.set("order", order);
+        self
+    }
+
+    /// Only return the given fields of each gift card
+    pub fn fields(mut self, fields: &[&str]) -> Self {
+        self.params.set_list("fields", fields);
+        self
+    }
+}
+
+/// Gift cards of a shop, returned by [`Shopify::gift_cards`]
+#[derive(Clone, Copy, Debug)]
+pub struct GiftCards<'a> {
+    shopify: &'a Shopify,
+}
+
+impl Shopify {
+    /// Query and manage the gift cards of a Shopify Plus shop
+    ///
+    /// # Example
+    /// ```no_run
+    /// use shopify_api::*;
+    /// use shopify_api::rest::gift_cards::{GiftCard, GiftCardAdjustment, GiftCardSearchParams};
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let shopify = Shopify::new("myshop", "myapikey", ShopifyAPIVersion::V2026_10, None);
+    ///     let gift_card = shopify
+    ///         .gift_cards()
+    ///         .create(&GiftCard::new("25.00").customer_id(207119551))
+    ///         .await
+    ///         .unwrap();
+    ///     let gift_card_id = gift_card.id.unwrap();
+    ///
+    ///     // Send the code to the customer, the only time it can be read
+    ///     let code = gift_card.code.unwrap();
+    ///
+    ///     shopify
+    ///         .gift_cards()
+    ///         .create_adjustment(gift_card_id, &GiftCardAdjustment::new("-5.00").note("Store credit"))
+    ///         .await
+    ///         .unwrap();
+    ///
+    ///     let found = shopify
+    ///         .gift_cards()
+    ///         .search(&GiftCardSearchParams::new(&format!("last_characters:{}", code.last_characters())), None)
+    ///         .await
+    ///         .unwrap();
+    /// }
+    /// ```
+    pub fn gift_cards(&self) -> GiftCards<'_> {
+        GiftCards { shopify: self }
+    }
+}
+
+impl<'a> GiftCards<'a> {
+    /// Query a page of gift cards, starting from the first page without a cursor
+    pub async fn list(
+        &self,
+        params: &GiftCardListParams,
+        cursor: Option<&PageCursor>,
+    ) -> Result<Page<GiftCard>, ShopifyAPIError> {
+        self.shopify
+            .rest_list("gift_cards.json", &params.params, cursor)
+            .await
+    }
+
+    /// Stream every gift card matching the filters, following the pages
+    pub fn stream(
+        &self,
+        params: &GiftCardListParams,
+    ) -> impl Stream<Item = Result<GiftCard, ShopifyAPIError>> + Unpin + 'a {
+        self.shopify
+            .rest_list_stream("gift_cards.json".to_string(), params.params.clone())
+    }
+
+    pub async fn get(&self, id: u64) -> Result<GiftCard, ShopifyAPIError> {
+        self.shopify
+            .rest_get_item(
+                &format!("gift_cards/{}.json", id),
+                &Params::default(),
+                "gift_card",
+            )
+            .await
+    }
+
+    /// Count the gift cards matching the filters, only `status` being used
+    pub async fn count(&self, params: &GiftCardListParams) -> Result<u64, ShopifyAPIError> {
+        self.shopify
+            .rest_count("gift_cards/count.json", &params.params)
+            .await
+    }
+
+    /// Query a page of the gift cards matching a search, starting from the first page without a
+    /// cursor
+    pub async fn search(
+        &self,
+        params: &GiftCardSearchParams,
+        cursor: Option<&PageCursor>,
+    ) -> Result<Page<GiftCard>, ShopifyAPIError> {
+        self.shopify
+            .rest_list("gift_cards/search.json", &params.params, cursor)
+            .await
+    }
+
+    /// Create a gift card, its code being only returned in the response
+    pub async fn create(&self, gift_card: &GiftCard) -> Result<GiftCard, ShopifyAPIError> {
+        self.shopify
+            .rest_post_item("gift_cards.json", "gift_card", gift_card)
+            .await
+    }
+
+    /// Update the note, the expiry date, the template or the customer of a gift card
+    pub async fn update(&self, id: u64, gift_card: &GiftCard) -> Result<GiftCard, ShopifyAPIError> {
+        self.shopify
+            .rest_put_item(&format!("gift_cards/{}.json", id), "gift_card", gift_card)
+            .await
+    }
+
+    /// Disable a gift card for good, its balance being kept
+    pub async fn disable(&self, id: u64) -> Result<GiftCard, ShopifyAPIError> {
+        self.shopify
+            .rest_post_action(
+                &format!("gift_cards/{}/disable.json", id),
+                &json!({ "gift_card": { "id": id } }),
+                "gift_card",
+            )
+            .await
+    }
+
+    /// Get the adjustments of the balance of a gift card
+    pub async fn adjustments(
+        &self,
+        gift_card_id: u64,
+    ) -> Result<Vec<GiftCardAdjustment>, ShopifyAPIError> {
+        self.shopify
+            .rest_get_item(
+                &format!("gift_cards/{}/adjustments.json", gift_card_id),
+                &Params::default(),
+                "adjustments",
+            )
+            .await
+    }
+
+    pub async fn get_adjustment(
+        &self,
+        gift_card_id: u64,
+        id: u64,
+    ) -> Result<GiftCardAdjustment, ShopifyAPIError> {
+        self.shopify
+            .rest_get_item(
+                &format!("gift_cards/{}/adjustments/{}.json", gift_card_id, id),
+                &Params::default(),
+                "adjustment",
+            )
+            .await
+    }
+
+    /// Credit or debit the balance of a gift card, which fails on a disabled or expired one
+    pub async fn create_adjustment(
+        &self,
+        gift_card_id: u64,
+        adjustment: &GiftCardAdjustment,
+    ) -> Result<GiftCardAdjustment, ShopifyAPIError> {
+        self.shopify
+            .rest_post_item(
+                &format!("gift_cards/{}/adjustments.json", gift_card_id),
+                "adjustment",
+                adjustment,
+            )
+            .await
+    }
+}
@@ -0,0 +1,339 @@
+//! Inventory levels and inventory items, through the REST `inventory_levels.json` and
+//! `inventory_items.json` endpoints
+//!
+//! An inventory item is the stock-keeping side of a variant (its `inventory_item_id`), and an
+//! inventory level is the quantity of an item available at a location.
+
+use futures_util::Stream;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+use super::{resource::Params, ShopifyAPIRestType};
+use crate::{
+    pagination::{Page, PageCursor},
+    Shopify, ShopifyAPIError,
+};
+
+/// Quantity of an inventory item available at a location
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct InventoryLevel {
+    pub inventory_item_id: u64,
+    pub location_id: u64,
+    /// Available quantity, `None` when the item is not tracked
+    pub available: Option<i64>,
+    pub updated_at: Option<String>,
+    pub admin_graphql_api_id: Option<String>,
+}
+
+/// Inventory item of a variant
+///
+/// # Example
+/// ```
+/// use shopify_api::rest::inventory::InventoryItem;
+///
+/// let item = InventoryItem {
+///     cost: Some("25.00".to_string()),
+///     tracked: Some(true),
+///     ..InventoryItem::default()
+/// };
+///
+/// assert_eq!(
+///     serde_json::to_value(&item).unwrap(),
+///     serde_json::json!({ "cost": "25.00", "tracked": true })
+/// );
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct InventoryItem {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sku: Option<String>,
+    /// Unit cost as a decimal string, e.g. `"25.00"`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cost: Option<String>,
+    /// Whether Shopify tracks the quantities of the item
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tracked: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub requires_shipping: Option<bool>,
+    /// Country the item was made in, e.g. `CA`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub country_code_of_origin: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub province_code_of_origin: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub harmonized_system_code: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub admin_graphql_api_id: Option<String>,
+}
+
+/// Filters of [`InventoryLevels::list`] and [`InventoryLevels::stream`]
+///
+/// At least the inventory items or the locations must be set.
+/// # Example
+/// ```
+/// use shopify_api::rest::inventory::InventoryLevelListParams;
+///
+/// let params = InventoryLevelListParams::new()
+///     .inventory_item_ids(&[808950810, 39072856])
+///     .location_ids(&[655441491]);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct InventoryLevelListParams {
+    params: Params,
+}
+
+impl InventoryLevelListParams {
+    pub fn new() -> InventoryLevelListParams {
+        InventoryLevelListParams::default()
+    }
+
+    /// Set the number of levels of each page, 50 by default and at most 250
+    pub fn limit(mut self, limit: u32) -> Self {
+        self.params.set("limit", limit);
+        self
+    }
+
+    /// Only list the levels of the given inventory items, at most 50
+    pub fn inventory_item_ids(mut self, inventory_item_ids: &[u64]) -> Self {
+        self.params
+            .set_list("inventory_item_ids", inventory_item_ids);
+        self
+    }
+
+    /// Only list the levels at the given locations, at most 50
+    pub fn location_ids(mut self, location_ids: &[u64]) -> Self {
+        self.params.set_list("location_ids", location_ids);
+        self
+    }
+
+    pub fn updated_at_min(mut self, updated_at_min: chrono::DateTime<chrono::Utc>) -> Self {
+        self.params.set_time("updated_at_min", updated_at_min);
+        self
+    }
+}
+
+/// Inventory levels of a shop, returned by [`Shopify::inventory_levels`]
+#[derive(Clone, Copy, Debug)]
+pub struct InventoryLevels<'a> {
+    shopify: &'a Shopify,
+}
+
+/// Inventory items of a shop, returned by [`Shopify::inventory_items`]
+#[derive(Clone, Copy, Debug)]
+pub struct InventoryItems<'a> {
+    shopify: &'a Shopify,
+}
+
+impl Shopify {
+    /// Query and change the inventory levels of the shop
+    ///
+    /// # Example
+    /// ```no_run
+    /// use shopify_api::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let shopify = Shopify::new("myshop", "myapikey", ShopifyAPIVersion::V2026_10, None);
+    ///     let (inventory_item_id, location_id) = (808950810, 655441491);
+    ///
+    ///     // 5 items were sold in the physical store
+    ///     let level = shopify
+    ///         .inventory_levels()
+    ///         .adjust(inventory_item_id, location_id, -5)
+    ///         .await
+    ///         .unwrap();
+    ///     println!("{:?} available", level.available);
+    ///
+    ///     // After a stock count
+    ///     shopify
+    ///         .inventory_levels()
+    ///         .set(inventory_item_id, location_id, 42, false)
+    ///         .await
+    ///         .unwrap();
+    /// }
+    /// ```
+    pub fn inventory_levels(&self) -> InventoryLevels<'_> {
+        InventoryLevels { shopify: self }
+    }
+
+    /// Query and update the inventory items of the shop
+    ///
+    /// # Example
+    /// ```no_run
+    /// use shopify_api::*;
+    /// use shopify_api::rest::inventory::InventoryItem;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let shopify = Shopify::new("myshop", "myapikey", ShopifyAPIVersion::V2026_10, None);
+    ///     let item = InventoryItem {
+    ///         cost: Some("25.00".to_string()),
+    ///         tracked: Some(true),
+    ///         ..InventoryItem::default()
+    ///     };
+    ///
+    ///     shopify.inventory_items().update(808950810, &item).await.unwrap();
+    /// }
+    /// ```
+    pub fn inventory_items(&self) -> InventoryItems<'_> {
+        InventoryItems { shopify: self }
+    }
+}
+
+impl<'a> InventoryLevels<'a> {
+    /// Query a page of inventory levels, starting from the first page without a cursor
+    pub async fn list(
+        &self,
+        params: &InventoryLevelListParams,
+        cursor: Option<&PageCursor>,
+    ) -> Result<Page<InventoryLevel>, ShopifyAPIError> {
+        self.shopify
+            .rest_list("inventory_levels.json", &params.params, cursor)
+            .await
+    }
+
+    /// Stream every inventory level matching the filters, following the pages
+    pub fn stream(
+        &self,
+        params: &InventoryLevelListParams,
+    ) -> impl Stream<Item = Result<InventoryLevel, ShopifyAPIError>> + Unpin + 'a {
+        self.shopify
+            .rest_list_stream("inventory_levels.json".to_string(), params.params.clone())
+    }
+
+    /// Add `adjustment` (negative to remove) to the available quantity of an item at a location
+    pub async fn adjust(
+        &self,
+        inventory_item_id: u64,
+        location_id: u64,
+        adjustment: i64,
+    ) -> Result<InventoryLevel, ShopifyAPIError> {
+        self.shopify
+            .rest_post_action(
+                "inventory_levels/adjust.json",
+                &json!({
+                    "inventory_item_id": inventory_item_id,
+                    "location_id": location_id,
+                    "available_adjustment": adjustment,
+                }),
+                "inventory_level",
+            )
+            .await
+    }
+
+    /// Set the available quantity of an item at a location, connecting the item to the location
+    /// if needed
+    ///
+    /// With `disconnect_if_necessary`, the item is disconnected from a location that cannot stock
+    /// it with the location of a fulfillment service, instead of failing.
+    pub async fn set(
+        &self,
+        inventory_item_id: u64,
+        location_id: u64,
+        available: i64,
+        disconnect_if_necessary: bool,
+    ) -> Result<InventoryLevel, ShopifyAPIError> {
+        self.shopify
+            .rest_post_action(
+                "inventory_levels/set.json",
+                &json!({
+                    "inventory_item_id": inventory_item_id,
+                    "location_id": location_id,
+                    "available": available,
+                    "disconnect_if_necessary": disconnect_if_necessary,
+                }),
+                "inventory_level",
+            )
+            .await
+    }
+
+    /// Stock an item at a location
+    ///
+    /// With `relocate_if_necessary`, the item is moved from a location that cannot stock it with
+    /// this one, e.g. the location of a fulfillment service, instead of failing.
+    pub async fn connect(
+        &self,
+        inventory_item_id: u64,
+        location_id: u64,
+        relocate_if_necessary: bool,
+    ) -> Result<InventoryLevel, ShopifyAPIError> {
+        self.shopify
+            .rest_post_action(
+                "inventory_levels/connect.json",
+                &json!({
+                    "inventory_item_id": inventory_item_id,
+                    "location_id": location_id,
+                    "relocate_if_necessary": relocate_if_necessary,
+                }),
+                "inventory_level",
+            )
+            .await
+    }
+
+    /// Stop stocking an item at a location, which fails for its only location
+    pub async fn delete(
+        &self,
+        inventory_item_id: u64,
+        location_id: u64,
+    ) -> Result<(), ShopifyAPIError> {
+        let mut params = Params::default();
+        params.set("inventory_item_id", inventory_item_id);
+        params.set("location_id", location_id);
+
+        self.shopify
+            .rest_query::<serde_json::Value>(
+                &ShopifyAPIRestType::Delete("inventory_levels.json", &params.to_map()),
+                &None,
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+impl<'a> InventoryItems<'a> {
+    /// Query a page of the given inventory items, at most 100, starting from the first page
+    /// without a cursor
+    pub async fn list(
+        &self,
+        ids: &[u64],
+        cursor: Option<&PageCursor>,
+    ) -> Result<Page<InventoryItem>, ShopifyAPIError> {
+        let mut params = Params::default();
+        params.set_list("ids", ids);
+
+        self.shopify
+            .rest_list("inventory_items.json", &params, cursor)
+            .await
+    }
+
+    pub async fn get(&self, id: u64) -> Result<InventoryItem, ShopifyAPIError> {
+        self.shopify
+            .rest_get_item(
+                &format!("inventory_items/{}.json", id),
+                &Params::default(),
+                "inventory_item",
+            )
+            .await
+    }
+
+    /// Update the fields set on `item`, e.g. its cost or whether it is tracked
+    pub async fn update(
+        &self,
+        id: u64,
+        item: &InventoryItem,
+    ) -> Result<InventoryItem, ShopifyAPIError> {
+        self.shopify
+            .rest_put_item(
+                &format!("inventory_items/{}.json", id),
+                "inventory_item",
+                item,
+            )
+            .await
+    }
+}
@@ -0,0 +1,156 @@
+//! Locations, through the REST `locations.json` endpoints
+//!
+//! Locations are read-only with the REST API: they are managed in the Shopify admin.
+
+use serde::{Deserialize, Serialize};
+
+use super::{inventory::InventoryLevel, resource::Params};
+use crate::{
+    pagination::{Page, PageCursor},
+    Shopify, ShopifyAPIError,
+};
+
+/// Place where the shop stocks its inventory, sells or fulfills orders
+///
+/// # Example
+/// ```
+/// use shopify_api::rest::locations::Location;
+///
+/// let location: Location = serde_json::from_str(
+///     r#"{"id":655441491,"name":"Warehouse","city":"Ottawa","country_code":"CA","active":true,"legacy":false}"#,
+/// )
+/// .unwrap();
+///
+/// assert_eq!(location.city.as_deref(), Some("Ottawa"));
+/// assert!(location.is_active());
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Location {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address1: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address2: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub city: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub zip: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub province: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub province_code: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub country: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub country_code: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub country_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub localized_country_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub localized_province_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub phone: Option<String>,
+    /// Whether the location can stock inventory and fulfill orders
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub active: Option<bool>,
+    /// Whether the location is a fulfillment service, which only fulfills its own inventory
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub legacy: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub admin_graphql_api_id: Option<String>,
+}
+
+impl Location {
+    /// Whether the location is active, locations being active unless told otherwise
+    pub fn is_active(&self) -> bool {
+        self.active.unwrap_or(true)
+    }
+
+    /// Whether the location is the legacy location of a fulfillment service
+    pub fn is_legacy(&self) -> bool {
+        self.legacy.unwrap_or(false)
+    }
+}
+
+/// Locations of a shop, returned by [`Shopify::locations`]
+#[derive(Clone, Copy, Debug)]
+pub struct Locations<'a> {
+    shopify: &'a Shopify,
+}
+
+impl Shopify {
+    /// Query the locations of the shop
+    ///
+    /// # Example
+    /// ```no_run
+    /// use shopify_api::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let shopify = Shopify::new("myshop", "myapikey", ShopifyAPIVersion::V2026_10, None);
+    ///
+    ///     for location in shopify.locations().list().await.unwrap() {
+    ///         if location.is_active() {
+    ///             let levels = shopify
+    ///                 .locations()
+    ///                 .inventory_levels_for_location(location.id.unwrap(), None)
+    ///                 .await
+    ///                 .unwrap();
+    ///             println!("{:?}: {} levels", location.name, levels.items.len());
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub fn locations(&self) -> Locations<'_> {
+        Locations { shopify: self }
+    }
+}
+
+impl<'a> Locations<'a> {
+    /// Query every location of the shop, the endpoint not being paginated
+    pub async fn list(&self) -> Result<Vec<Location>, ShopifyAPIError> {
+        self.shopify
+            .rest_get_item("locations.json", &Params::default(), "locations")
+            .await
+    }
+
+    pub async fn get(&self, id: u64) -> Result<Location, ShopifyAPIError> {
+        self.shopify
+            .rest_get_item(
+                &format!("locations/{}.json", id),
+                &Params::default(),
+                "location",
+            )
+            .await
+    }
+
+    pub async fn count(&self) -> Result<u64, ShopifyAPIError> {
+        self.shopify
+            .rest_count("locations/count.json", &Params::default())
+            .await
+    }
+
+    /// Query a page of the inventory levels at a location, starting from the first page without
+    /// a cursor
+    pub async fn inventory_levels_for_location(
+        &self,
+        id: u64,
+        cursor: Option<&PageCursor>,
+    ) -> Result<Page<InventoryLevel>, ShopifyAPIError> {
+        self.shopify
+            .rest_list(
+                &format!("locations/{}/inventory_levels.json", id),
+                &Params::default(),
+                cursor,
+            )
+            .await
+    }
+}
@@ -0,0 +1,366 @@
+//! Metafields of any resource, through the REST `metafields.json` endpoints
+//!
+//! The endpoints are nested under the owner of the metafields, e.g. `products/1/metafields.json`,
+//! or at the root for the metafields of the shop: see [`MetafieldOwner`]. To set many metafields
+//! at once, see [`crate::graphql::metafields`].
+
+use futures_util::Stream;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+
+use super::resource::Params;
+use crate::{
+    graphql::metafields::is_valid_metafield_type,
+    pagination::{Page, PageCursor},
+    Shopify, ShopifyAPIError,
+};
+
+/// Resource owning metafields
+///
+/// # Example
+/// ```
+/// use shopify_api::rest::metafields::MetafieldOwner;
+///
+/// assert_eq!(MetafieldOwner::Shop.endpoint(), "metafields.json");
+/// assert_eq!(MetafieldOwner::Product(1).endpoint(), "products/1/metafields.json");
+/// assert_eq!(
+///     MetafieldOwner::Article { blog_id: 2, id: 3 }.endpoint(),
+///     "blogs/2/articles/3/metafields.json"
+/// );
+/// assert_eq!(
+///     MetafieldOwner::Resource("locations".to_string(), 4).endpoint(),
+///     "locations/4/metafields.json"
+/// );
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum MetafieldOwner {
+    Shop,
+    Product(u64),
+    Variant(u64),
+    Collection(u64),
+    Customer(u64),
+    Order(u64),
+    DraftOrder(u64),
+    Page(u64),
+    Blog(u64),
+    Article {
+        blog_id: u64,
+        id: u64,
+    },
+    /// Any other resource, by the path of its endpoints and its ID, e.g. `locations`
+    Resource(String, u64),
+}
+
+impl MetafieldOwner {
+    /// Get the path of the owner the metafield endpoints are nested under, empty for the shop
+    fn prefix(&self) -> String {
+        match self {
+            MetafieldOwner::Shop => String::new(),
+            MetafieldOwner::Product(id) => format!("products/{}/", id),
+            MetafieldOwner::Variant(id) => format!("variants/{}/", id),
+            MetafieldOwner::Collection(id) => format!("collections/{}/", id),
+            MetafieldOwner::Customer(id) => format!("customers/{}/", id),
+            MetafieldOwner::Order(id) => format!("orders/{}/", id),
+            MetafieldOwner::DraftOrder(id) => format!("draft_orders/{}/", id),
+            MetafieldOwner::Page(id) => format!("pages/{}/", id),
+            MetafieldOwner::Blog(id) => format!("blogs/{}/", id),
+            MetafieldOwner::Article { blog_id, id } => {
+                format!("blogs/{}/articles/{}/", blog_id, id)
+            }
+            MetafieldOwner::Resource(resource, id) => format!("{}/{}/", resource, id),
+        }
+    }
+
+    /// Get the endpoint listing the metafields of the owner
+    pub fn endpoint(&self) -> String {
+        format!("{}metafields.json", self.prefix())
+    }
+}
+
+/// Metafield of a resource
+///
+/// The value is returned by Shopify as a JSON string, number or boolean depending on its type,
+/// and can be read with [`Metafield::value_as`].
+/// # Example
+/// ```
+/// use serde::{Deserialize, Serialize};
+/// use shopify_api::rest::metafields::Metafield;
+///
+/// #[derive(Debug, PartialEq, Serialize, Deserialize)]
+/// struct Specs {
+///     weight: u32,
+/// }
+///
+/// let metafield = Metafield::json("custom", "specs", &Specs { weight: 2 }).unwrap();
+/// assert_eq!(metafield.metafield_type.as_deref(), Some("json"));
+/// assert_eq!(metafield.value_as::<Specs>().unwrap(), Specs { weight: 2 });
+///
+/// let metafield = Metafield::new("custom", "stock", "number_integer", 42);
+/// assert_eq!(metafield.value_as::<u32>().unwrap(), 42);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct Metafield {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub namespace: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub key: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<serde_json::Value>,
+    /// Type of the value, e.g. `single_line_text_field` or `list.product_reference`
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub metafield_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub owner_id: Option<u64>,
+    /// Type of the owner, e.g. `product`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub owner_resource: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub admin_graphql_api_id: Option<String>,
+}
+
+impl Metafield {
+    /// Metafield to create, the value being sent as a string, e.g. `42` or `"Wool"`
+    pub fn new(
+        namespace: &str,
+        key: &str,
+        metafield_type: &str,
+        value: impl ToString,
+    ) -> Metafield {
+        Metafield {
+            namespace: Some(namespace.to_string()),
+            key: Some(key.to_string()),
+            metafield_type: Some(metafield_type.to_string()),
+            value: Some(serde_json::Value::String(value.to_string())),
+            ..Metafield::default()
+        }
+    }
+
+    /// Metafield of type `json` to create, holding the serialized `value`
+    pub fn json<ValueType: Serialize>(
+        namespace: &str,
+        key: &str,
+        value: &ValueType,
+    ) -> Result<Metafield, ShopifyAPIError> {
+        Ok(Metafield::new(
+            namespace,
+            key,
+            "json",
+            serde_json::to_string(value)?,
+        ))
+    }
+
+    /// Read the value of the metafield as `ValueType`, parsing it when it is a string holding
+    /// JSON, e.g. for the `json`, `number_integer` or `list.*` types
+    pub fn value_as<ValueType: DeserializeOwned>(&self) -> Result<ValueType, ShopifyAPIError> {
+        let value = self.value.as_ref().ok_or_else(|| {
+            ShopifyAPIError::NotWantedJsonFormat(format!(
+                "metafield `{}` has no value",
+                self.key.as_deref().unwrap_or_default()
+            ))
+        })?;
+
+        if let serde_json::Value::String(text) = value {
+            if let Ok(parsed) = serde_json::from_str(text) {
+                return Ok(parsed);
+            }
+        }
+
+        Ok(serde_json::from_value(value.clone())?)
+    }
+}
+
+/// Filters of [`Metafields::list`], [`Metafields::stream`] and [`Metafields::count`]
+///
+/// # Example
+/// ```
+/// use shopify_api::rest::metafields::MetafieldListParams;
+///
+/// let params = MetafieldListParams::new().namespace("custom").key("specs");
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct MetafieldListParams {
+    params: Params,
+}
+
+impl MetafieldListParams {
+    pub fn new() -> MetafieldListParams {
+        MetafieldListParams::default()
+    }
+
+    /// Set the number of metafields of each page, 50 by default and at most 250
+    pub fn limit(mut self, limit: u32) -> Self {
+        self.params.set("limit", limit);
+        self
+    }
+
+    /// Only list the metafields with an ID greater than `since_id`
+    pub fn since_id(mut self, since_id: u64) -> Self {
+        self.params.set("since_id", since_id);
+        self
+    }
+
+    pub fn namespace(mut self, namespace: &str) -> Self {
+        self.params.set("namespace", namespace);
+        self
+    }
+
+    pub fn key(mut self, key: &str) -> Self {
+        self.params.set("key", key);
+        self
+    }
+
+    pub fn metafield_type(mut self, metafield_type: &str) -> Self {
+        self.params.set("type", metafield_type);
+        self
+    }
+
+    pub fn created_at_min(mut self, created_at_min: chrono::DateTime<chrono::Utc>) -> Self {
+        self.params.set_time("created_at_min", created_at_min);
+        self
+    }
+
+    pub fn created_at_max(mut self, created_at_max: chrono::DateTime<chrono::Utc>) -> Self {
+        self.params.set_time("created_at_max", created_at_max);
+        self
+    }
+
+    pub fn updated_at_min(mut self, updated_at_min: chrono::DateTime<chrono::Utc>) -> Self {
+        self.params.set_time("updated_at_min", updated_at_min);
+        self
+    }
+
+    pub fn updated_at_max(mut self, updated_at_max: chrono::DateTime<chrono::Utc>) -> Self {
+        self.params.set_time("updated_at_max", updated_at_max);
+        self
+    }
+
+    /// Only return the given fields of each metafield
+    pub fn fields(mut self, fields: &[&str]) -> Self {
+        self.params.set_list("fields", fields);
+        self
+    }
+}
+
+/// Metafields of a resource, returned by [`Shopify::metafields`]
+#[derive(Clone, Debug)]
+pub struct Metafields<'a> {
+    shopify: &'a Shopify,
+    owner: MetafieldOwner,
+}
+
+impl Shopify {
+    /// Query and manage the metafields of a resource, or of the shop
+    ///
+    /// # Example
+    /// ```no_run
+    /// use shopify_api::*;
+    /// use shopify_api::rest::metafields::{Metafield, MetafieldListParams, MetafieldOwner};
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let shopify = Shopify::new("myshop", "myapikey", ShopifyAPIVersion::V2026_10, None);
+    ///     let metafields = shopify.metafields(MetafieldOwner::Product(632910392));
+    ///
+    ///     let material = Metafield::new("custom", "material", "single_line_text_field", "Wool");
+    ///     metafields.create(&material).await.unwrap();
+    ///
+    ///     let page = metafields
+    ///         .list(&MetafieldListParams::new().namespace("custom"), None)
+    ///         .await
+    ///         .unwrap();
+    ///     for metafield in page.items {
+    ///         println!("{:?}: {:?}", metafield.key, metafield.value);
+    ///     }
+    /// }
+    /// ```
+    pub fn metafields(&self, owner: MetafieldOwner) -> Metafields<'_> {
+        Metafields {
+            shopify: self,
+            owner,
+        }
+    }
+}
+
+impl<'a> Metafields<'a> {
+    fn item_endpoint(&self, id: u64) -> String {
+        format!("{}metafields/{}.json", self.owner.prefix(), id)
+    }
+
+    /// Query a page of metafields, starting from the first page without a cursor
+    pub async fn list(
+        &self,
+        params: &MetafieldListParams,
+        cursor: Option<&PageCursor>,
+    ) -> Result<Page<Metafield>, ShopifyAPIError> {
+        self.shopify
+            .rest_list(&self.owner.endpoint(), &params.params, cursor)
+            .await
+    }
+
+    /// Stream every metafield matching the filters, following the pages
+    pub fn stream(
+        &self,
+        params: &MetafieldListParams,
+    ) -> impl Stream<Item = Result<Metafield, ShopifyAPIError>> + Unpin + 'a {
+        self.shopify
+            .rest_list_stream(self.owner.endpoint(), params.params.clone())
+    }
+
+    pub async fn get(&self, id: u64) -> Result<Metafield, ShopifyAPIError> {
+        self.shopify
+            .rest_get_item(&self.item_endpoint(id), &Params::default(), "metafield")
+            .await
+    }
+
+    /// Count the metafields matching the filters, `limit`, `since_id` and `fields` being ignored
+    pub async fn count(&self, params: &MetafieldListParams) -> Result<u64, ShopifyAPIError> {
+        self.shopify
+            .rest_count(
+                &format!("{}metafields/count.json", self.owner.prefix()),
+                &params.params,
+            )
+            .await
+    }
+
+    /// Create a metafield, replacing the metafield with the same namespace and key
+    ///
+    /// This fails with [`ShopifyAPIError::InvalidInput`] before sending the query if the type is
+    /// unknown, see [`is_valid_metafield_type`].
+    pub async fn create(&self, metafield: &Metafield) -> Result<Metafield, ShopifyAPIError> {
+        if let Some(metafield_type) = &metafield.metafield_type {
+            if !is_valid_metafield_type(metafield_type) {
+                return Err(ShopifyAPIError::InvalidInput(format!(
+                    "unknown metafield type `{}` for `{}`",
+                    metafield_type,
+                    metafield.key.as_deref().unwrap_or_default()
+                )));
+            }
+        }
+
+        self.shopify
+            .rest_post_item(&self.owner.endpoint(), "metafield", metafield)
+            .await
+    }
+
+    /// Update the fields set on `metafield`, usually its value
+    pub async fn update(
+        &self,
+        id: u64,
+        metafield: &Metafield,
+    ) -> Result<Metafield, ShopifyAPIError> {
+        self.shopify
+            .rest_put_item(&self.item_endpoint(id), "metafield", metafield)
+            .await
+    }
+
+    pub async fn delete(&self, id: u64) -> Result<(), ShopifyAPIError> {
+        self.shopify.rest_delete_item(&self.item_endpoint(id)).await
+    }
+}
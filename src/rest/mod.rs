@@ -1,10 +1,49 @@
+pub mod abandoned_checkouts;
+pub mod access_scopes;
+pub mod billing;
+pub mod collections;
+pub mod countries;
+pub mod customers;
+pub mod draft_orders;
+mod errors;
+pub mod events;
+pub mod fulfillment_services;
+pub mod fulfillments;
+pub mod gift_cards;
+pub mod inventory;
+pub mod locations;
+pub mod metafields;
+pub mod online_store;
+pub mod order_risks;
+pub mod orders;
+mod pagination;
+pub mod payments;
+pub mod price_rules;
+pub mod products;
+pub mod redirects;
+mod resource;
+pub mod script_tags;
+pub mod shipping;
+pub mod shop;
+pub mod tender_transactions;
+pub mod themes;
+pub mod transactions;
+pub mod users;
+pub mod webhooks;
+
 use std::collections::HashMap;
 
 use crate::{
+    middleware::ShopifyResponse,
+    rate_limit::ThrottleReason,
+    retry,
     utils::{self, ReadJsonTreeSteps},
-    Shopify, ShopifyAPIError,
+    RequestOptions, ResponseMetadata, Shopify, ShopifyAPIError,
 };
 
+pub use errors::ShopifyRestErrors;
+pub use pagination::{PageInfo, DEFAULT_MAX_ITEMS};
+
 pub enum ShopifyAPIRestType<'a> {
     Get(&'a str, &'a HashMap<&'a str, &'a str>),
     Post(
@@ -20,81 +59,143 @@ pub enum ShopifyAPIRestType<'a> {
     Delete(&'a str, &'a HashMap<&'a str, &'a str>),
 }
 
+const IDEMPOTENCY_KEY_HEADER: &str = "Idempotency-Key";
+
+/// A REST query whose url and body are built once and sent on every attempt
+struct PreparedRestQuery<'a> {
+    method: reqwest::Method,
+    url: String,
+    params: &'a HashMap<&'a str, &'a str>,
+    body: Option<String>,
+    idempotency_key: Option<String>,
+}
+
+impl<'a> ShopifyAPIRestType<'a> {
+    fn prepare(&self, shopify: &Shopify, options: &RequestOptions) -> PreparedRestQuery<'a> {
+        let (method, url, params, body) = match *self {
+            ShopifyAPIRestType::Get(url, params) => (reqwest::Method::GET, url, params, None),
+            ShopifyAPIRestType::Post(url, params, body) => {
+                (reqwest::Method::POST, url, params, Some(body))
+            }
+            ShopifyAPIRestType::Put(url, params, body) => {
+                (reqwest::Method::PUT, url, params, Some(body))
+            }
+            ShopifyAPIRestType::Delete(url, params) => (reqwest::Method::DELETE, url, params, None),
+        };
+
+        // The key is generated once so that every attempt sends the same one
+        let idempotency_key = match method {
+            reqwest::Method::POST | reqwest::Method::PUT => options.new_idempotency_key(),
+            _ => None,
+        };
+
+        PreparedRestQuery {
+            method,
+            url: shopify.endpoint_url(url, options),
+            params,
+            body: body.map(|body| body.to_string()),
+            idempotency_key,
+        }
+    }
+}
+
 async fn shopify_rest_query<ReturnType>(
-    (shopify, endpoint, json_finder): &(
+    (shopify, prepared, json_finder, options): &(
         &Shopify,
-        &ShopifyAPIRestType<'_>,
+        PreparedRestQuery<'_>,
         &Option<Vec<ReadJsonTreeSteps<'_>>>,
+        &RequestOptions,
     ),
-) -> Result<ReturnType, ShopifyAPIError>
+) -> Result<(ReturnType, ResponseMetadata), ShopifyAPIError>
 where
     ReturnType: serde::de::DeserializeOwned,
 {
-    // Prepare the client
-    let client = reqwest::Client::new();
-    let mut headers = reqwest::header::HeaderMap::new();
-    headers.insert("Content-Type", "application/json".parse().unwrap());
-    headers.insert("X-Shopify-Access-Token", shopify.api_key.parse().unwrap());
-
-    let req = match endpoint {
-        ShopifyAPIRestType::Get(url, params) => client
-            .get(shopify.get_api_endpoint(url))
-            .headers(headers)
-            .query(params),
-
-        ShopifyAPIRestType::Post(url, params, body) => client
-            .post(shopify.get_api_endpoint(url))
-            .headers(headers)
-            .query(params)
-            .body(body.to_string()),
-
-        ShopifyAPIRestType::Put(url, params, body) => client
-            .put(shopify.get_api_endpoint(url))
-            .headers(headers)
-            .query(params)
-            .body(body.to_string()),
-
-        ShopifyAPIRestType::Delete(url, params) => client
-            .delete(shopify.get_api_endpoint(url))
-            .headers(headers)
-            .query(params),
-    };
+    if let Some(rate_limiter) = &shopify.rate_limiter {
+        rate_limiter
+            .wait(options.get_priority(), |delay| {
+                shopify.throttled(ThrottleReason::RateLimiter, delay)
+            })
+            .await;
+    }
 
-    // Connection Response
-    let res = req.send().await?;
+    let mut req = shopify
+        .request(prepared.method.clone(), &prepared.url, options)?
+        .query(prepared.params);
 
-    // Connection data
-    let body = res.text().await;
-    if body.is_err() {
-        return Err(ShopifyAPIError::ResponseBroken);
+    if let Some(body) = &prepared.body {
+        req = req.body(body.clone());
     }
 
-    let body = body.unwrap();
+    if let Some(key) = &prepared.idempotency_key {
+        req = req.header(IDEMPOTENCY_KEY_HEADER, key);
+    }
+
+    let response = shopify.send(req, options).await?;
+    let json = parse_rest_response(&response, json_finder)?;
+
+    Ok((json, response.metadata()))
+}
 
+#[cfg(feature = "blocking")]
+fn shopify_rest_query_blocking<ReturnType>(
+    (shopify, prepared, json_finder, options): &(
+        &Shopify,
+        PreparedRestQuery<'_>,
+        &Option<Vec<ReadJsonTreeSteps<'_>>>,
+        &RequestOptions,
+    ),
+) -> Result<(ReturnType, ResponseMetadata), ShopifyAPIError>
+where
+    ReturnType: serde::de::DeserializeOwned,
+{
+    if let Some(rate_limiter) = &shopify.rate_limiter {
+        rate_limiter.wait_blocking(options.get_priority(), |delay| {
+            shopify.throttled(ThrottleReason::RateLimiter, delay)
+        });
+    }
+
+    let mut req = shopify
+        .request(prepared.method.clone(), &prepared.url, options)?
+        .query(prepared.params);
+
+    if let Some(body) = &prepared.body {
+        req = req.body(body.clone());
+    }
+
+    if let Some(key) = &prepared.idempotency_key {
+        req = req.header(IDEMPOTENCY_KEY_HEADER, key);
+    }
+
+    let response = shopify.send_blocking(req, options)?;
+    let json = parse_rest_response(&response, json_finder)?;
+
+    Ok((json, response.metadata()))
+}
+
+fn parse_rest_response<ReturnType>(
+    response: &ShopifyResponse,
+    json_finder: &Option<Vec<ReadJsonTreeSteps<'_>>>,
+) -> Result<ReturnType, ShopifyAPIError>
+where
+    ReturnType: serde::de::DeserializeOwned,
+{
     let json: serde_json::Value =
-        serde_json::from_str(&body).map_err(ShopifyAPIError::JsonParseError)?;
+        serde_json::from_str(&response.body).map_err(ShopifyAPIError::JsonParseError)?;
 
     let json = match json_finder {
         Some(json_finder) => match utils::read_json_tree(&json, json_finder) {
             Ok(v) => v,
-            Err(_) => {
-                return Err(ShopifyAPIError::NotWantedJsonFormat(json.to_string()));
+            Err(error) => {
+                return Err(ShopifyAPIError::NotWantedJsonFormat(error.to_string()));
             }
         },
         None => &json,
     };
 
-    let json = match serde_json::to_string(json) {
-        Ok(v) => v,
-        Err(_) => {
-            return Err(ShopifyAPIError::NotWantedJsonFormat(json.to_string()));
-        }
-    };
-
-    let json = match serde_json::from_str(&json) {
+    let json = match serde_json::from_value(json.to_owned()) {
         Ok(v) => v,
-        Err(_) => {
-            return Err(ShopifyAPIError::NotWantedJsonFormat(json.to_string()));
+        Err(error) => {
+            return Err(ShopifyAPIError::NotWantedJsonFormat(error.to_string()));
         }
     };
 
@@ -155,8 +256,131 @@ impl Shopify {
     where
         ReturnType: serde::de::DeserializeOwned,
     {
-        let args = (self, rest_query, json_finder);
-        let response_json = utils::retry_async(10, shopify_rest_query::<ReturnType>, &args).await?;
+        self.rest_query_with_options(rest_query, json_finder, &RequestOptions::default())
+            .await
+    }
+
+    /// Query REST shopify api with options applied to this request only
+    /// # Example
+    /// ```no_run
+    /// use std::collections::HashMap;
+    /// use std::time::Duration;
+    /// use shopify_api::*;
+    /// use shopify_api::rest::ShopifyAPIRestType;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let shopify = Shopify::new("myshop", "myapikey", ShopifyAPIVersion::V2023_10, None);
+    ///     let options = RequestOptions::new().timeout(Duration::from_secs(5));
+    ///
+    ///     let products: serde_json::Value = shopify
+    ///         .rest_query_with_options(&ShopifyAPIRestType::Get("products.json", &HashMap::new()), &None, &options)
+    ///         .await
+    ///         .unwrap();
+    /// }
+    /// ```
+    pub async fn rest_query_with_options<ReturnType>(
+        &self,
+        rest_query: &ShopifyAPIRestType<'_>,
+        json_finder: &Option<Vec<ReadJsonTreeSteps<'_>>>,
+        options: &RequestOptions,
+    ) -> Result<ReturnType, ShopifyAPIError>
+    where
+        ReturnType: serde::de::DeserializeOwned,
+    {
+        let (response_json, _) = self
+            .rest_query_with_metadata(rest_query, json_finder, options)
+            .await?;
+
+        Ok(response_json)
+    }
+
+    /// Query REST shopify api, returning the metadata of the response with the data
+    /// # Example
+    /// ```no_run
+    /// use std::collections::HashMap;
+    /// use shopify_api::*;
+    /// use shopify_api::rest::ShopifyAPIRestType;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let shopify = Shopify::new("myshop", "myapikey", ShopifyAPIVersion::V2026_10, None);
+    ///
+    ///     let (products, metadata): (serde_json::Value, ResponseMetadata) = shopify
+    ///         .rest_query_with_metadata(&ShopifyAPIRestType::Get("products.json", &HashMap::new()), &None, &RequestOptions::new())
+    ///         .await
+    ///         .unwrap();
+    ///
+    ///     println!("Request id: {:?}, call limit: {:?}", metadata.request_id, metadata.call_limit);
+    /// }
+    /// ```
+    pub async fn rest_query_with_metadata<ReturnType>(
+        &self,
+        rest_query: &ShopifyAPIRestType<'_>,
+        json_finder: &Option<Vec<ReadJsonTreeSteps<'_>>>,
+        options: &RequestOptions,
+    ) -> Result<(ReturnType, ResponseMetadata), ShopifyAPIError>
+    where
+        ReturnType: serde::de::DeserializeOwned,
+    {
+        let args = (
+            self,
+            rest_query.prepare(self, options),
+            json_finder,
+            options,
+        );
+
+        retry::retry(
+            self.retry_policy(options),
+            options.get_cancellation_token(),
+            &|error, delay| self.retrying(error, delay),
+            shopify_rest_query::<ReturnType>,
+            &args,
+        )
+        .await
+        .map_err(|failed| self.failed(failed, &args.1.url))
+    }
+
+    /// Query REST shopify api without an async runtime
+    ///
+    /// This is the blocking counterpart of [`Shopify::rest_query_with_options`], available with the `blocking` feature.
+    /// Middlewares are run on the calling thread, so they must not depend on an async runtime.
+    /// # Example
+    /// ```no_run
+    /// use std::collections::HashMap;
+    /// use shopify_api::*;
+    /// use shopify_api::rest::ShopifyAPIRestType;
+    ///
+    /// let shopify = Shopify::new("myshop", "myapikey", ShopifyAPIVersion::V2023_10, None);
+    /// let products: serde_json::Value = shopify
+    ///     .rest_query_blocking(&ShopifyAPIRestType::Get("products.json", &HashMap::new()), &None, &RequestOptions::new())
+    ///     .unwrap();
+    /// ```
+    #[cfg(feature = "blocking")]
+    pub fn rest_query_blocking<ReturnType>(
+        &self,
+        rest_query: &ShopifyAPIRestType<'_>,
+        json_finder: &Option<Vec<ReadJsonTreeSteps<'_>>>,
+        options: &RequestOptions,
+    ) -> Result<ReturnType, ShopifyAPIError>
+    where
+        ReturnType: serde::de::DeserializeOwned,
+    {
+        let args = (
+            self,
+            rest_query.prepare(self, options),
+            json_finder,
+            options,
+        );
+
+        let (response_json, _) = retry::retry_blocking(
+            self.retry_policy(options),
+            options.get_cancellation_token(),
+            &|error, delay| self.retrying(error, delay),
+            shopify_rest_query_blocking::<ReturnType>,
+            &args,
+        )
+        .map_err(|failed| self.failed(failed, &args.1.url))?;
 
         Ok(response_json)
     }
@@ -0,0 +1,755 @@
+//! Content of the online store: pages, blogs and their articles, through the REST `pages.json`,
+//! `blogs.json` and `blogs/{id}/articles.json` endpoints
+//!
+//! Metafields can be created with a page, a blog or an article, and are then managed with
+//! [`Shopify::metafields`] and [`MetafieldOwner::Page`](super::metafields::MetafieldOwner::Page),
+//! [`MetafieldOwner::Blog`](super::metafields::MetafieldOwner::Blog) or
+//! [`MetafieldOwner::Article`](super::metafields::MetafieldOwner::Article).
+
+use futures_util::Stream;
+use serde::{Deserialize, Serialize};
+
+use super::{metafields::Metafield, resource::Params};
+use crate::{
+    pagination::{Page as ResultPage, PageCursor},
+    Shopify, ShopifyAPIError,
+};
+
+/// Page of the online store, e.g. "About us"
+///
+/// # Example
+/// ```
+/// use shopify_api::rest::metafields::Metafield;
+/// use shopify_api::rest::online_store::Page;
+///
+/// let page = Page::new("About us", "<p>We make wool socks.</p>")
+///     .author("Bob")
+///     .published(false)
+///     .metafield(Metafield::new("seo", "hidden", "number_integer", 1));
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct Page {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// Unique name of the page in its URL, generated from the title when not set
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub handle: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub body_html: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub author: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shop_id: Option<u64>,
+    /// Suffix of the `page.{suffix}.liquid` template of the page
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub template_suffix: Option<String>,
+    /// Whether the page is visible, only sent: read [`Page::published_at`] instead
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub published: Option<bool>,
+    /// Set while the page is visible
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub published_at: Option<String>,
+    /// Metafields created with the page, only sent
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub metafields: Vec<Metafield>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub admin_graphql_api_id: Option<String>,
+}
+
+impl Page {
+    pub fn new(title: &str, body_html: &str) -> Page {
+        Page {
+            title: Some(title.to_string()),
+            body_html: Some(body_html.to_string()),
+            ..Page::default()
+        }
+    }
+
+    pub fn author(mut self, author: &str) -> Self {
+        self.author = Some(author.to_string());
+        self
+    }
+
+    /// Set whether the page is visible, published by default
+    pub fn published(mut self, published: bool) -> Self {
+        self.published = Some(published);
+        self
+    }
+
+    /// Add a metafield created with the page
+    pub fn metafield(mut self, metafield: Metafield) -> Self {
+        self.metafields.push(metafield);
+        self
+    }
+
+    /// Whether the page is visible
+    pub fn is_published(&self) -> bool {
+        self.published_at.is_some()
+    }
+}
+
+/// Whether the readers can comment the articles of a blog
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BlogCommentable {
+    /// Comments are disabled
+    No,
+    /// Comments are published once approved
+    Moderate,
+    /// Comments are published right away
+    Yes,
+}
+
+/// Blog of the online store
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct Blog {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// Unique name of the blog in its URL, generated from the title when not set
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub handle: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub commentable: Option<BlogCommentable>,
+    /// Comma-separated tags of the articles of the blog
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tags: Option<String>,
+    /// Suffix of the `blog.{suffix}.liquid` template of the blog
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub template_suffix: Option<String>,
+    /// Metafields created with the blog, only sent
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub metafields: Vec<Metafield>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub admin_graphql_api_id: Option<String>,
+}
+
+impl Blog {
+    pub fn new(title: &str) -> Blog {
+        Blog {
+            title: Some(title.to_string()),
+            ..Blog::default()
+        }
+    }
+
+    pub fn commentable(mut self, commentable: BlogCommentable) -> Self {
+        self.commentable = Some(commentable);
+        self
+    }
+
+    /// Add a metafield created with the blog
+    pub fn metafield(mut self, metafield: Metafield) -> Self {
+        self.metafields.push(metafield);
+        self
+    }
+}
+
+/// Image of an article, created from the URL of its `src`, or from its contents encoded in base64
+/// in `attachment`
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ArticleImage {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub src: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attachment: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub alt: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub width: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub height: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<String>,
+}
+
+/// Article of a blog
+///
+/// # Example
+/// ```
+/// use shopify_api::rest::online_store::Article;
+///
+/// let article = Article::new("Caring for wool", "<p>Wash it cold.</p>")
+///     .author("Bob")
+///     .tags(&["care", "wool"])
+///     .published(true);
+///
+/// assert_eq!(article.tags.as_deref(), Some("care, wool"));
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct Article {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub blog_id: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// Unique name of the article in its URL, generated from the title when not set
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub handle: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub author: Option<String>,
+    /// Staff member who created the article
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_id: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub body_html: Option<String>,
+    /// Excerpt shown in the blog
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub summary_html: Option<String>,
+    /// Comma-separated tags
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tags: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub image: Option<ArticleImage>,
+    /// Suffix of the `article.{suffix}.liquid` template of the article
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub template_suffix: Option<String>,
+    /// Whether the article is visible, only sent: read [`Article::published_at`] instead
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub published: Option<bool>,
+    /// Set while the article is visible, or in the future for a scheduled article
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub published_at: Option<String>,
+    /// Metafields created with the article, only sent
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub metafields: Vec<Metafield>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub admin_graphql_api_id: Option<String>,
+}
+
+impl Article {
+    pub fn new(title: &str, body_html: &str) -> Article {
+        Article {
+            title: Some(title.to_string()),
+            body_html: Some(body_html.to_string()),
+            ..Article::default()
+        }
+    }
+
+    pub fn author(mut self, author: &str) -> Self {
+        self.author = Some(author.to_string());
+        self
+    }
+
+    pub fn tags(mut self, tags: &[&str]) -> Self {
+        self.tags = Some(tags.join(", "));
+        self
+    }
+
+    /// Set whether the article is visible, published by default
+    pub fn published(mut self, published: bool) -> Self {
+        self.published = Some(published);
+        self
+    }
+
+    /// Set the date the article is published at, scheduling it when in the future
+    pub fn published_at(mut self, published_at: chrono::DateTime<chrono::Utc>) -> Self {
+        self.published_at = Some(published_at.to_rfc3339());
+        self
+    }
+
+    pub fn image(mut self, image: ArticleImage) -> Self {
+        self.image = Some(image);
+        self
+    }
+
+    /// Add a metafield created with the article
+    pub fn metafield(mut self, metafield: Metafield) -> Self {
+        self.metafields.push(metafield);
+        self
+    }
+
+    /// Whether the article is visible, or scheduled
+    pub fn is_published(&self) -> bool {
+        self.published_at.is_some()
+    }
+}
+
+/// Filters of [`Pages::list`], [`Pages::stream`] and [`Pages::count`]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct PageListParams {
+    params: Params,
+}
+
+impl PageListParams {
+    pub fn new() -> PageListParams {
+        PageListParams::default()
+    }
+
+    /// Set the number of pages of each page of results, 50 by default and at most 250
+    pub fn limit(mut self, limit: u32) -> Self {
+        self.params.set("limit", limit);
+        self
+    }
+
+    /// Only list the pages with an ID greater than `since_id`
+    pub fn since_id(mut self, since_id: u64) -> Self {
+        self.params.set("since_id", since_id);
+        self
+    }
+
+    pub fn title(mut self, title: &str) -> Self {
+        self.params.set("title", title);
+        self
+    }
+
+    pub fn handle(mut self, handle: &str) -> Self {
+        self.params.set("handle", handle);
+        self
+    }
+
+    /// `published`, `unpublished` or `any` (the default)
+    pub fn published_status(mut self, published_status: &str) -> Self {
+        self.params.set("published_status", published_status);
+        self
+    }
+
+    pub fn created_at_min(mut self, created_at_min: chrono::DateTime<chrono::Utc>) -> Self {
+        self.params.set_time("created_at_min", created_at_min);
+        self
+    }
+
+    pub fn created_at_max(mut self, created_at_max: chrono::DateTime<chrono::Utc>) -> Self {
+        self.params.set_time("created_at_max", created_at_max);
+        self
+    }
+
+    pub fn updated_at_min(mut self, updated_at_min: chrono::DateTime<chrono::Utc>) -> Self {
+        self.params.set_time("updated_at_min", updated_at_min);
+        self
+    }
+
+    pub fn updated_at_max(mut self, updated_at_max: chrono::DateTime<chrono::Utc>) -> Self {
+        self.params.set_time("updated_at_max", updated_at_max);
+        self
+    }
+
+    pub fn published_at_min(mut self, published_at_min: chrono::DateTime<chrono::Utc>) -> Self {
+        self.params.set_time("published_at_min", published_at_min);
+        self
+    }
+
+    pub fn published_at_max(mut self, published_at_max: chrono::DateTime<chrono::Utc>) -> Self {
+        self.params.set_time("published_at_max", published_at_max);
+        self
+    }
+
+    /// Only return the given fields of each page
+    pub fn fields(mut self, fields: &[&str]) -> Self {
+        self.params.set_list("fields", fields);
+        self
+    }
+}
+
+/// Filters of [`Blogs::list`] and [`Blogs::stream`]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct BlogListParams {
+    params: Params,
+}
+
+impl BlogListParams {
+    pub fn new() -> BlogListParams {
+        BlogListParams::default()
+    }
+
+    /// Set the number of blogs of each page, 50 by default and at most 250
+    pub fn limit(mut self, limit: u32) -> Self {
+        self.params.set("limit", limit);
+        self
+    }
+
+    /// Only list the blogs with an ID greater than `since_id`
+    pub fn since_id(mut self, since_id: u64) -> Self {
+        self.params.set("since_id", since_id);
+        self
+    }
+
+    pub fn handle(mut self, handle: &str) -> Self {
+        self.params.set("handle", handle);
+        self
+    }
+
+    /// Only return the given fields of each blog
+    pub fn fields(mut self, fields: &[&str]) -> Self {
+        self.params.set_list("fields", fields);
+        self
+    }
+}
+
+/// Filters of [`BlogArticles::list`], [`BlogArticles::stream`] and [`BlogArticles::count`]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ArticleListParams {
+    params: Params,
+}
+
+impl ArticleListParams {
+    pub fn new() -> ArticleListParams {
+        ArticleListParams::default()
+    }
+
+    /// Set the number of articles of each page, 50 by default and at most 250
+    pub fn limit(mut self, limit: u32) -> Self {
+        self.params.set("limit", limit);
+        self
+    }
+
+    /// Only list the articles with an ID greater than `since_id`
+    pub fn since_id(mut self, since_id: u64) -> Self {
+        self.params.set("since_id", since_id);
+        self
+    }
+
+    pub fn author(mut self, author: &str) -> Self {
+        self.params.set("author", author);
+        self
+    }
+
+    pub fn handle(mut self, handle: &str) -> Self {
+        self.params.set("handle", handle);
+        self
+    }
+
+    pub fn tag(mut self, tag: &str) -> Self {
+        self.params.set("tag", tag);
+        self
+    }
+
+    /// `published`, `unpublished` or `any` (the default)
+    pub fn published_status(mut self, published_status: &str) -> Self {
+        self.params.set("published_status", published_status);
+        self
+    }
+
+    pub fn created_at_min(mut self, created_at_min: chrono::DateTime<chrono::Utc>) -> Self {
+        self.params.set_time("created_at_min", created_at_min);
+        self
+    }
+
+    pub fn created_at_max(mut self, created_at_max: chrono::DateTime<chrono::Utc>) -> Self {
+        self.params.set_time("created_at_max", created_at_max);
+        self
+    }
+
+    pub fn updated_at_min(mut self, updated_at_min: chrono::DateTime<chrono::Utc>) -> Self {
+        self.params.set_time("updated_at_min", updated_at_min);
+        self
+    }
+
+    pub fn updated_at_max(mut self, updated_at_max: chrono::DateTime<chrono::Utc>) -> Self {
+        self.params.set_time("updated_at_max", updated_at_max);
+        self
+    }
+
+    pub fn published_at_min(mut self, published_at_min: chrono::DateTime<chrono::Utc>) -> Self {
+        self.params.set_time("published_at_min", published_at_min);
+        self
+    }
+
+    pub fn published_at_max(mut self, published_at_max: chrono::DateTime<chrono::Utc>) -> Self {
+        self.params.set_time("published_at_max", published_at_max);
+        self
+    }
+
+    /// Only return the given fields of each article
+    pub fn fields(mut self, fields: &[&str]) -> Self {
+        self.params.set_list("fields", fields);
+        self
+    }
+}
+
+/// Pages of the online store, returned by [`Shopify::pages`]
+#[derive(Clone, Copy, Debug)]
+pub struct Pages<'a> {
+    shopify: &'a Shopify,
+}
+
+/// Blogs of the online store, returned by [`Shopify::blogs`]
+#[derive(Clone, Copy, Debug)]
+pub struct Blogs<'a> {
+    shopify: &'a Shopify,
+}
+
+/// Articles of a blog, returned by [`Blogs::articles`]
+#[derive(Clone, Copy, Debug)]
+pub struct BlogArticles<'a> {
+    shopify: &'a Shopify,
+    blog_id: u64,
+}
+
+impl Shopify {
+    /// Query and manage the pages of the online store
+    ///
+    /// # Example
+    /// ```no_run
+    /// use shopify_api::*;
+    /// use shopify_api::rest::online_store::{Page, PageListParams};
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let shopify = Shopify::new("myshop", "myapikey", ShopifyAPIVersion::V2026_10, None);
+    ///     let page = shopify
+    ///         .pages()
+    ///         .create(&Page::new("About us", "<p>We make wool socks.</p>"))
+    ///         .await
+    ///         .unwrap();
+    ///
+    ///     let params = PageListParams::new().published_status("unpublished");
+    ///     let drafts = shopify.pages().count(&params).await.unwrap();
+    /// }
+    /// ```
+    pub fn pages(&self) -> Pages<'_> {
+        Pages { shopify: self }
+    }
+
+    /// Query and manage the blogs of the online store, and their articles
+    ///
+    /// # Example
+    /// ```no_run
+    /// use shopify_api::*;
+    /// use shopify_api::rest::online_store::{Article, ArticleListParams, Blog};
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let shopify = Shopify::new("myshop", "myapikey", ShopifyAPIVersion::V2026_10, None);
+    ///     let blog = shopify.blogs().create(&Blog::new("News")).await.unwrap();
+    ///     let articles = shopify.blogs().articles(blog.id.unwrap());
+    ///
+    ///     let article = Article::new("Caring for wool", "<p>Wash it cold.</p>").tags(&["care"]);
+    ///     articles.create(&article).await.unwrap();
+    ///
+    ///     let care = articles
+    ///         .list(&ArticleListParams::new().tag("care"), None)
+    ///         .await
+    ///         .unwrap();
+    /// }
+    /// ```
+    pub fn blogs(&self) -> Blogs<'_> {
+        Blogs { shopify: self }
+    }
+}
+
+impl<'a> Pages<'a> {
+    /// Query a page of pages, starting from the first one without a cursor
+    pub async fn list(
+        &self,
+        params: &PageListParams,
+        cursor: Option<&PageCursor>,
+    ) -> Result<ResultPage<Page>, ShopifyAPIError> {
+        self.shopify
+            .rest_list("pages.json", &params.params, cursor)
+            .await
+    }
+
+    /// Stream every page matching the filters, following the pages of results
+    pub fn stream(
+        &self,
+        params: &PageListParams,
+    ) -> impl Stream<Item = Result<Page, ShopifyAPIError>> + Unpin + 'a {
+        self.shopify
+            .rest_list_stream("pages.json".to_string(), params.params.clone())
+    }
+
+    pub async fn get(&self, id: u64) -> Result<Page, ShopifyAPIError> {
+        self.shopify
+            .rest_get_item(&format!("pages/{}.json", id), &Params::default(), "page")
+            .await
+    }
+
+    /// Count the pages matching the filters, `limit`, `since_id` and `fields` being ignored
+    pub async fn count(&self, params: &PageListParams) -> Result<u64, ShopifyAPIError> {
+        self.shopify
+            .rest_count("pages/count.json", &params.params)
+            .await
+    }
+
+    pub async fn create(&self, page: &Page) -> Result<Page, ShopifyAPIError> {
+        self.shopify
+            .rest_post_item("pages.json", "page", page)
+            .await
+    }
+
+    /// Update the fields set on `page`
+    pub async fn update(&self, id: u64, page: &Page) -> Result<Page, ShopifyAPIError> {
+        self.shopify
+            .rest_put_item(&format!("pages/{}.json", id), "page", page)
+            .await
+    }
+
+    pub async fn delete(&self, id: u64) -> Result<(), ShopifyAPIError> {
+        self.shopify
+            .rest_delete_item(&format!("pages/{}.json", id))
+            .await
+    }
+}
+
+impl<'a> Blogs<'a> {
+    /// Query a page of blogs, starting from the first page without a cursor
+    pub async fn list(
+        &self,
+        params: &BlogListParams,
+        cursor: Option<&PageCursor>,
+    ) -> Result<ResultPage<Blog>, ShopifyAPIError> {
+        self.shopify
+            .rest_list("blogs.json", &params.params, cursor)
+            .await
+    }
+
+    /// Stream every blog matching the filters, following the pages
+    pub fn stream(
+        &self,
+        params: &BlogListParams,
+    ) -> impl Stream<Item = Result<Blog, ShopifyAPIError>> + Unpin + 'a {
+        self.shopify
+            .rest_list_stream("blogs.json".to_string(), params.params.clone())
+    }
+
+    pub async fn get(&self, id: u64) -> Result<Blog, ShopifyAPIError> {
+        self.shopify
+            .rest_get_item(&format!("blogs/{}.json", id), &Params::default(), "blog")
+            .await
+    }
+
+    pub async fn count(&self) -> Result<u64, ShopifyAPIError> {
+        self.shopify
+            .rest_count("blogs/count.json", &Params::default())
+            .await
+    }
+
+    pub async fn create(&self, blog: &Blog) -> Result<Blog, ShopifyAPIError> {
+        self.shopify
+            .rest_post_item("blogs.json", "blog", blog)
+            .await
+    }
+
+    /// Update the fields set on `blog`
+    pub async fn update(&self, id: u64, blog: &Blog) -> Result<Blog, ShopifyAPIError> {
+        self.shopify
+            .rest_put_item(&format!("blogs/{}.json", id), "blog", blog)
+            .await
+    }
+
+    /// Delete a blog and its articles
+    pub async fn delete(&self, id: u64) -> Result<(), ShopifyAPIError> {
+        self.shopify
+            .rest_delete_item(&format!("blogs/{}.json", id))
+            .await
+    }
+
+    /// Get the authors of the articles of every blog
+    pub async fn article_authors(&self) -> Result<Vec<String>, ShopifyAPIError> {
+        self.shopify
+            .rest_get_item("articles/authors.json", &Params::default(), "authors")
+            .await
+    }
+
+    /// Query and manage the articles of a blog
+    pub fn articles(&self, blog_id: u64) -> BlogArticles<'a> {
+        BlogArticles {
+            shopify: self.shopify,
+            blog_id,
+        }
+    }
+}
+
+impl<'a> BlogArticles<'a> {
+    /// Query a page of articles, starting from the first page without a cursor
+    pub async fn list(
+        &self,
+        params: &ArticleListParams,
+        cursor: Option<&PageCursor>,
+    ) -> Result<ResultPage<Article>, ShopifyAPIError> {
+        self.shopify
+            .rest_list(
+                &format!("blogs/{}/articles.json", self.blog_id),
+                &params.params,
+                cursor,
+            )
+            .await
+    }
+
+    /// Stream every article matching the filters, following the pages
+    pub fn stream(
+        &self,
+        params: &ArticleListParams,
+    ) -> impl Stream<Item = Result<Article, ShopifyAPIError>> + Unpin + 'a {
+        self.shopify.rest_list_stream(
+            format!("blogs/{}/articles.json", self.blog_id),
+            params.params.clone(),
+        )
+    }
+
+    pub async fn get(&self, id: u64) -> Result<Article, ShopifyAPIError> {
+        self.shopify
+            .rest_get_item(
+                &format!("blogs/{}/articles/{}.json", self.blog_id, id),
+                &Params::default(),
+                "article",
+            )
+            .await
+    }
+
+    /// Count the articles matching the filters, `limit`, `since_id`, `author`, `handle`, `tag`
+    /// and `fields` being ignored
+    pub async fn count(&self, params: &ArticleListParams) -> Result<u64, ShopifyAPIError> {
+        self.shopify
+            .rest_count(
+                &format!("blogs/{}/articles/count.json", self.blog_id),
+                &params.params,
+            )
+            .await
+    }
+
+    pub async fn create(&self, article: &Article) -> Result<Article, ShopifyAPIError> {
+        self.shopify
+            .rest_post_item(
+                &format!("blogs/{}/articles.json", self.blog_id),
+                "article",
+                article,
+            )
+            .await
+    }
+
+    /// Update the fields set on `article`, which is moved to another blog when its `blog_id` is
+    /// set
+    pub async fn update(&self, id: u64, article: &Article) -> Result<Article, ShopifyAPIError> {
+        self.shopify
+            .rest_put_item(
+                &format!("blogs/{}/articles/{}.json", self.blog_id, id),
+                "article",
+                article,
+            )
+            .await
+    }
+
+    pub async fn delete(&self, id: u64) -> Result<(), ShopifyAPIError> {
+        self.shopify
+            .rest_delete_item(&format!("blogs/{}/articles/{}.json", self.blog_id, id))
+            .await
+    }
+
+    /// Get the tags of the articles of the blog
+    pub async fn tags(&self) -> Result<Vec<String>, ShopifyAPIError> {
+        self.shopify
+            .rest_get_item(
+                &format!("blogs/{}/articles/tags.json", self.blog_id),
+                &Params::default(),
+                "tags",
+            )
+            .await
+    }
+}
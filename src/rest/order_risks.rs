@@ -0,0 +1,210 @@
+//! Fraud risks of an order, through the REST `orders/{id}/risks.json` endpoints
+//!
+//! Fraud-analysis apps attach their findings to an order as risks: the merchant sees their
+//! message in the admin, and their recommendation to `cancel` or `investigate` the order
+//! before fulfilling it.
+
+use serde::{Deserialize, Deserializer, Serialize};
+
+use super::{orders::Orders, resource::Params};
+use crate::{Shopify, ShopifyAPIError};
+
+/// Action recommended by a risk
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OrderRiskRecommendation {
+    /// The order looks legitimate
+    Accept,
+    /// The order should be reviewed before being fulfilled
+    Investigate,
+    /// The order is likely fraudulent
+    Cancel,
+    #[serde(other)]
+    Unknown,
+}
+
+/// Read a score sent either as a string or as a number, e.g. `"0.8"` or `0.8`
+fn deserialize_score<'de, D>(deserializer: D) -> Result<Option<f64>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let score = Option::<serde_json::Value>::deserialize(deserializer)?;
+
+    Ok(match score {
+        Some(serde_json::Value::String(score)) => score.parse().ok(),
+        Some(serde_json::Value::Number(score)) => score.as_f64(),
+        _ => None,
+    })
+}
+
+/// Fraud risk of an order
+///
+/// # Example
+/// ```
+/// use shopify_api::rest::order_risks::{OrderRisk, OrderRiskRecommendation};
+///
+/// let risk = OrderRisk::new(
+///     OrderRiskRecommendation::Cancel,
+///     0.9,
+///     "The billing address doesn't match the card",
+/// )
+/// .source("Acme Fraud");
+///
+/// assert_eq!(
+///     serde_json::to_value(&risk).unwrap(),
+///     serde_json::json!({
+///         "recommendation": "cancel",
+///         "score": 0.9,
+///         "message": "The billing address doesn't match the card",
+///         "source": "Acme Fraud",
+///         "display": true,
+///     })
+/// );
+///
+/// // Shopify returns the score as a string
+/// let risk: OrderRisk = serde_json::from_value(serde_json::json!({
+///     "id": 1,
+///     "recommendation": "investigate",
+///     "score": "0.5",
+/// }))
+/// .unwrap();
+/// assert_eq!(risk.score, Some(0.5));
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct OrderRisk {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order_id: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub checkout_id: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recommendation: Option<OrderRiskRecommendation>,
+    /// Likelihood of fraud, from 0.0 to 1.0
+    #[serde(
+        default,
+        deserialize_with = "deserialize_score",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub score: Option<f64>,
+    /// Reason of the recommendation, shown to the merchant
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    /// Message shown in the order details, defaulting to `message`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub merchant_message: Option<String>,
+    /// Origin of the risk, e.g. the name of the app
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source: Option<String>,
+    /// Whether the risk is shown in the admin
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub display: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cause_cancel: Option<bool>,
+}
+
+impl OrderRisk {
+    /// Risk shown to the merchant, its origin being named with [`OrderRisk::source`]
+    pub fn new(recommendation: OrderRiskRecommendation, score: f64, message: &str) -> OrderRisk {
+        OrderRisk {
+            recommendation: Some(recommendation),
+            score: Some(score),
+            message: Some(message.to_string()),
+            display: Some(true),
+            ..OrderRisk::default()
+        }
+    }
+
+    pub fn source(mut self, source: &str) -> Self {
+        self.source = Some(source.to_string());
+        self
+    }
+
+    /// Set whether the risk is shown in the admin
+    pub fn display(mut self, display: bool) -> Self {
+        self.display = Some(display);
+        self
+    }
+}
+
+/// Risks of an order, returned by [`Orders::risks`]
+#[derive(Clone, Copy, Debug)]
+pub struct OrderRisks<'a> {
+    shopify: &'a Shopify,
+    order_id: u64,
+}
+
+impl<'a> Orders<'a> {
+    /// Query, create, update and delete the fraud risks of an order
+    ///
+    /// # Example
+    /// ```no_run
+    /// use shopify_api::*;
+    /// use shopify_api::rest::order_risks::{OrderRisk, OrderRiskRecommendation};
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let shopify = Shopify::new("myshop", "myapikey", ShopifyAPIVersion::V2026_10, None);
+    ///     let risks = shopify.orders().risks(450789469);
+    ///
+    ///     let risk = OrderRisk::new(
+    ///         OrderRiskRecommendation::Investigate,
+    ///         0.6,
+    ///         "The order was placed through a proxy",
+    ///     )
+    ///     .source("Acme Fraud");
+    ///     let risk = risks.create(&risk).await.unwrap();
+    ///
+    ///     // Raise the recommendation once the card is reported stolen
+    ///     let update = OrderRisk::new(OrderRiskRecommendation::Cancel, 1.0, "The card was stolen");
+    ///     risks.update(risk.id.unwrap(), &update).await.unwrap();
+    /// }
+    /// ```
+    pub fn risks(&self, order_id: u64) -> OrderRisks<'a> {
+        OrderRisks {
+            shopify: self.shopify,
+            order_id,
+        }
+    }
+}
+
+impl<'a> OrderRisks<'a> {
+    fn endpoint(&self) -> String {
+        format!("orders/{}/risks.json", self.order_id)
+    }
+
+    fn item_endpoint(&self, id: u64) -> String {
+        format!("orders/{}/risks/{}.json", self.order_id, id)
+    }
+
+    /// Get every risk of the order, including the ones computed by Shopify
+    pub async fn list(&self) -> Result<Vec<OrderRisk>, ShopifyAPIError> {
+        self.shopify
+            .rest_get_item(&self.endpoint(), &Params::default(), "risks")
+            .await
+    }
+
+    pub async fn get(&self, id: u64) -> Result<OrderRisk, ShopifyAPIError> {
+        self.shopify
+            .rest_get_item(&self.item_endpoint(id), &Params::default(), "risk")
+            .await
+    }
+
+    pub async fn create(&self, risk: &OrderRisk) -> Result<OrderRisk, ShopifyAPIError> {
+        self.shopify
+            .rest_post_item(&self.endpoint(), "risk", risk)
+            .await
+    }
+
+    /// Update the fields set on `risk`, only the risks created by the app being editable
+    pub async fn update(&self, id: u64, risk: &OrderRisk) -> Result<OrderRisk, ShopifyAPIError> {
+        self.shopify
+            .rest_put_item(&self.item_endpoint(id), "risk", risk)
+            .await
+    }
+
+    /// Delete a risk created by the app
+    pub async fn delete(&self, id: u64) -> Result<(), ShopifyAPIError> {
+        self.shopify.rest_delete_item(&self.item_endpoint(id)).await
+    }
+}
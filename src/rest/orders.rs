@@ -0,0 +1,552 @@
+//! Orders, with their line items, shipping lines and taxes, through the REST `orders.json` endpoints
+//!
+//! Orders are listed with their `status` set to `open` by default: use [`OrderStatus::Any`] to
+//! include the closed and cancelled orders. Only the orders of the last 60 days are returned
+//! without the `read_all_orders` scope.
+
+use futures_util::Stream;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+use super::{customers::Customer, resource::Params};
+use crate::{
+    pagination::{Page, PageCursor},
+    Shopify, ShopifyAPIError,
+};
+
+/// Status filter of [`OrderListParams::status`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OrderStatus {
+    Open,
+    Closed,
+    Cancelled,
+    Any,
+}
+
+impl OrderStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            OrderStatus::Open => "open",
+            OrderStatus::Closed => "closed",
+            OrderStatus::Cancelled => "cancelled",
+            OrderStatus::Any => "any",
+        }
+    }
+}
+
+/// Payment status of an order
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FinancialStatus {
+    Pending,
+    Authorized,
+    PartiallyPaid,
+    Paid,
+    PartiallyRefunded,
+    Refunded,
+    Voided,
+    Expired,
+    /// A status added after this version of the crate
+    #[serde(other)]
+    Unknown,
+}
+
+impl FinancialStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            FinancialStatus::Pending => "pending",
+            FinancialStatus::Authorized => "authorized",
+            FinancialStatus::PartiallyPaid => "partially_paid",
+            FinancialStatus::Paid => "paid",
+            FinancialStatus::PartiallyRefunded => "partially_refunded",
+            FinancialStatus::Refunded => "refunded",
+            FinancialStatus::Voided => "voided",
+            FinancialStatus::Expired => "expired",
+            FinancialStatus::Unknown => "unknown",
+        }
+    }
+}
+
+/// Fulfillment status of an order or a line item, `None` when nothing is fulfilled
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FulfillmentStatus {
+    Fulfilled,
+    Partial,
+    Restocked,
+    #[serde(other)]
+    Unknown,
+}
+
+/// Fulfillment status filter of [`OrderListParams::fulfillment_status`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FulfillmentStatusFilter {
+    /// Fully fulfilled orders
+    Shipped,
+    /// Partially fulfilled orders
+    Partial,
+    /// Orders with nothing fulfilled
+    Unshipped,
+    /// Orders not fully fulfilled, i.e. unshipped or partial
+    Unfulfilled,
+    Any,
+}
+
+impl FulfillmentStatusFilter {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            FulfillmentStatusFilter::Shipped => "shipped",
+            FulfillmentStatusFilter::Partial => "partial",
+            FulfillmentStatusFilter::Unshipped => "unshipped",
+            FulfillmentStatusFilter::Unfulfilled => "unfulfilled",
+            FulfillmentStatusFilter::Any => "any",
+        }
+    }
+}
+
+/// Reason of an order cancellation
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CancelReason {
+    Customer,
+    Fraud,
+    Inventory,
+    Declined,
+    Other,
+}
+
+/// Order of the shop
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct Order {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<u64>,
+    /// Name shown to the customer, e.g. `#1001`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order_number: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub phone: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub customer: Option<Customer>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub financial_status: Option<FinancialStatus>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fulfillment_status: Option<FulfillmentStatus>,
+    /// Currency of the shop, e.g. `USD`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub currency: Option<String>,
+    /// Currency the customer paid in
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub presentment_currency: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total_price: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subtotal_price: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total_tax: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total_discounts: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total_line_items_price: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub taxes_included: Option<bool>,
+    /// Whether the order was placed with a test payment gateway
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub test: Option<bool>,
+    /// Comma-separated tags
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tags: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub note: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub line_items: Vec<LineItem>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub shipping_lines: Vec<ShippingLine>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tax_lines: Vec<TaxLine>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub billing_address: Option<Address>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shipping_address: Option<Address>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cancel_reason: Option<CancelReason>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cancelled_at: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub closed_at: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub processed_at: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<String>,
+    /// Page where the customer follows the status of the order
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order_status_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub admin_graphql_api_id: Option<String>,
+}
+
+/// Product bought in an order
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct LineItem {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub product_id: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub variant_id: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub variant_title: Option<String>,
+    /// Title of the product and of the variant
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sku: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vendor: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub quantity: Option<u32>,
+    /// Price of one item before discounts
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub price: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total_discount: Option<String>,
+    /// Quantity left to fulfill
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fulfillable_quantity: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fulfillment_status: Option<FulfillmentStatus>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub requires_shipping: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub taxable: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gift_card: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub grams: Option<u64>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tax_lines: Vec<TaxLine>,
+    /// Custom properties set by the customer or an app, e.g. an engraving
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub properties: Vec<LineItemProperty>,
+}
+
+/// Custom property of a line item
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct LineItemProperty {
+    pub name: String,
+    pub value: serde_json::Value,
+}
+
+/// Shipping method of an order
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct ShippingLine {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub code: Option<String>,
+    /// Where the rate comes from, e.g. `shopify` or the name of a carrier service
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub price: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub discounted_price: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub carrier_identifier: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tax_lines: Vec<TaxLine>,
+}
+
+/// Tax applied to an order, a line item or a shipping line
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct TaxLine {
+    pub title: String,
+    pub price: String,
+    /// Rate as a fraction, e.g. `0.06`
+    pub rate: f64,
+    /// Whether the channel that submitted the order collects the tax
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub channel_liable: Option<bool>,
+}
+
+/// Billing or shipping address of an order
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct Address {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub first_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub company: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address1: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address2: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub city: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub province: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub province_code: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub country: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub country_code: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub zip: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub phone: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub latitude: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub longitude: Option<f64>,
+}
+
+/// Filters of [`Orders::list`], [`Orders::stream`] and [`Orders::count`]
+///
+/// # Example
+/// ```
+/// use chrono::{TimeZone, Utc};
+/// use shopify_api::rest::orders::{FinancialStatus, FulfillmentStatusFilter, OrderListParams, OrderStatus};
+///
+/// let params = OrderListParams::new()
+///     .status(OrderStatus::Any)
+///     .financial_status(FinancialStatus::Paid)
+///     .fulfillment_status(FulfillmentStatusFilter::Unfulfilled)
+///     .created_at_min(Utc.with_ymd_and_hms(2026, 1, 1, 0, 0, 0).unwrap());
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct OrderListParams {
+    pub(super) params: Params,
+}
+
+impl OrderListParams {
+    pub fn new() -> OrderListParams {
+        OrderListParams::default()
+    }
+
+    /// Set the number of orders of each page, 50 by default and at most 250
+    pub fn limit(mut self, limit: u32) -> Self {
+        self.params.set("limit", limit);
+        self
+    }
+
+    pub fn ids(mut self, ids: &[u64]) -> Self {
+        self.params.set_list("ids", ids);
+        self
+    }
+
+    /// Only list the orders with an ID greater than `since_id`
+    pub fn since_id(mut self, since_id: u64) -> Self {
+        self.params.set("since_id", since_id);
+        self
+    }
+
+    /// Filter on the name of the order, e.g. `#1001`
+    pub fn name(mut self, name: &str) -> Self {
+        self.params.set("name", name);
+        self
+    }
+
+    /// Set the status of the orders, [`OrderStatus::Open`] by default
+    pub fn status(mut self, status: OrderStatus) -> Self {
+        self.params.set("status", status.as_str());
+        self
+    }
+
+    pub fn financial_status(mut self, financial_status: FinancialStatus) -> Self {
+        self.params
+            .set("financial_status", financial_status.as_str());
+        self
+    }
+
+    pub fn fulfillment_status(mut self, fulfillment_status: FulfillmentStatusFilter) -> Self {
+        self.params
+            .set("fulfillment_status", fulfillment_status.as_str());
+        self
+    }
+
+    pub fn created_at_min(mut self, created_at_min: chrono::DateTime<chrono::Utc>) -> Self {
+        self.params.set_time("created_at_min", created_at_min);
+        self
+    }
+
+    pub fn created_at_max(mut self, created_at_max: chrono::DateTime<chrono::Utc>) -> Self {
+        self.params.set_time("created_at_max", created_at_max);
+        self
+    }
+
+    pub fn updated_at_min(mut self, updated_at_min: chrono::DateTime<chrono::Utc>) -> Self {
+        self.params.set_time("updated_at_min", updated_at_min);
+        self
+    }
+
+    pub fn updated_at_max(mut self, updated_at_max: chrono::DateTime<chrono::Utc>) -> Self {
+        self.params.set_time("updated_at_max", updated_at_max);
+        self
+    }
+
+    pub fn processed_at_min(mut self, processed_at_min: chrono::DateTime<chrono::Utc>) -> Self {
+        self.params.set_time("processed_at_min", processed_at_min);
+        self
+    }
+
+    pub fn processed_at_max(mut self, processed_at_max: chrono::DateTime<chrono::Utc>) -> Self {
+        self.params.set_time("processed_at_max", processed_at_max);
+        self
+    }
+
+    /// Only return the given fields of each order
+    pub fn fields(mut self, fields: &[&str]) -> Self {
+        self.params.set_list("fields", fields);
+        self
+    }
+}
+
+/// Options of [`Orders::cancel`]
+///
+/// # Example
+/// ```
+/// use shopify_api::rest::orders::{CancelReason, OrderCancelOptions};
+///
+/// let options = OrderCancelOptions::new().reason(CancelReason::Inventory).email(true);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize)]
+pub struct OrderCancelOptions {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    reason: Option<CancelReason>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    email: Option<bool>,
+}
+
+impl OrderCancelOptions {
+    pub fn new() -> OrderCancelOptions {
+        OrderCancelOptions::default()
+    }
+
+    /// Set the reason of the cancellation, `other` by default
+    pub fn reason(mut self, reason: CancelReason) -> Self {
+        self.reason = Some(reason);
+        self
+    }
+
+    /// Send a cancellation email to the customer
+    pub fn email(mut self, email: bool) -> Self {
+        self.email = Some(email);
+        self
+    }
+}
+
+/// Orders of a shop, returned by [`Shopify::orders`]
+#[derive(Clone, Copy, Debug)]
+pub struct Orders<'a> {
+    pub(super) shopify: &'a Shopify,
+}
+
+impl Shopify {
+    /// Query the orders of the shop
+    ///
+    /// # Example
+    /// ```no_run
+    /// use futures_util::StreamExt;
+    /// use shopify_api::*;
+    /// use shopify_api::rest::orders::{FinancialStatus, OrderListParams, OrderStatus};
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let shopify = Shopify::new("myshop", "myapikey", ShopifyAPIVersion::V2026_10, None);
+    ///     let params = OrderListParams::new()
+    ///         .status(OrderStatus::Open)
+    ///         .financial_status(FinancialStatus::Paid)
+    ///         .limit(250);
+    ///
+    ///     let mut orders = shopify.orders().stream(&params);
+    ///     while let Some(order) = orders.next().await {
+    ///         let order = order.unwrap();
+    ///         println!("{:?}: {} line items", order.name, order.line_items.len());
+    ///     }
+    ///
+    ///     shopify.orders().close(450789469).await.unwrap();
+    /// }
+    /// ```
+    pub fn orders(&self) -> Orders<'_> {
+        Orders { shopify: self }
+    }
+}
+
+impl<'a> Orders<'a> {
+    /// Query a page of orders, starting from the first page without a cursor
+    pub async fn list(
+        &self,
+        params: &OrderListParams,
+        cursor: Option<&PageCursor>,
+    ) -> Result<Page<Order>, ShopifyAPIError> {
+        self.shopify
+            .rest_list("orders.json", &params.params, cursor)
+            .await
+    }
+
+    /// Stream every order matching the filters, following the pages
+    pub fn stream(
+        &self,
+        params: &OrderListParams,
+    ) -> impl Stream<Item = Result<Order, ShopifyAPIError>> + Unpin + 'a {
+        self.shopify
+            .rest_list_stream("orders.json".to_string(), params.params.clone())
+    }
+
+    pub async fn get(&self, id: u64) -> Result<Order, ShopifyAPIError> {
+        self.shopify
+            .rest_get_item(&format!("orders/{}.json", id), &Params::default(), "order")
+            .await
+    }
+
+    /// Count the orders matching the filters, `limit`, `since_id` and `fields` being ignored
+    pub async fn count(&self, params: &OrderListParams) -> Result<u64, ShopifyAPIError> {
+        self.shopify
+            .rest_count("orders/count.json", &params.params)
+            .await
+    }
+
+    /// Close an order, marking it as done
+    pub async fn close(&self, id: u64) -> Result<Order, ShopifyAPIError> {
+        self.shopify
+            .rest_post_action(&format!("orders/{}/close.json", id), &json!({}), "order")
+            .await
+    }
+
+    /// Re-open a closed order
+    pub async fn open(&self, id: u64) -> Result<Order, ShopifyAPIError> {
+        self.shopify
+            .rest_post_action(&format!("orders/{}/open.json", id), &json!({}), "order")
+            .await
+    }
+
+    /// Cancel an order
+    ///
+    /// Paid orders must be refunded before being cancelled, see [`Orders::refunds`].
+    pub async fn cancel(
+        &self,
+        id: u64,
+        options: &OrderCancelOptions,
+    ) -> Result<Order, ShopifyAPIError> {
+        self.shopify
+            .rest_post_action(
+                &format!("orders/{}/cancel.json", id),
+                &serde_json::to_value(options)?,
+                "order",
+            )
+            .await
+    }
+}
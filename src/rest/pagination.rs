@@ -0,0 +1,511 @@
+use std::collections::HashMap;
+
+use futures_util::{stream, Stream, StreamExt};
+use reqwest::header::{HeaderMap, LINK};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    pagination::{Page, PageCursor, PageStream},
+    rest::ShopifyAPIRestType,
+    utils::ReadJsonTreeSteps,
+    RequestOptions, Shopify, ShopifyAPIError,
+};
+
+/// Maximum number of items collected by [`Shopify::rest_all`]
+pub const DEFAULT_MAX_ITEMS: usize = 10_000;
+
+/// Parameters kept when querying the next pages, Shopify rejecting the other ones with `page_info`
+const PAGE_PARAMS: [&str; 2] = ["limit", "fields"];
+
+/// Cursors of the pages before and after a page of a REST list endpoint
+///
+/// Read from the `Link` header, e.g.
+/// `<https://myshop.myshopify.com/admin/api/2026-10/products.json?limit=50&page_info=abc>; rel="next"`.
+/// # Example
+/// ```
+/// use reqwest::header::{HeaderMap, HeaderValue, LINK};
+/// use shopify_api::rest::PageInfo;
+///
+/// let mut headers = HeaderMap::new();
+/// headers.insert(LINK, HeaderValue::from_static(
+///     r#"<https://myshop.myshopify.com/admin/api/2026-10/products.json?limit=50&page_info=prev123>; rel="previous", <https://myshop.myshopify.com/admin/api/2026-10/products.json?limit=50&page_info=next456>; rel="next""#,
+/// ));
+///
+/// let page_info = PageInfo::from_headers(&headers);
+/// assert_eq!(page_info.next_page_info.as_deref(), Some("next456"));
+/// assert_eq!(page_info.prev_page_info.as_deref(), Some("prev123"));
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PageInfo {
+    /// `page_info` parameter of the next page, missing on the last page
+    pub next_page_info: Option<String>,
+    /// `page_info` parameter of the previous page, missing on the first page
+    pub prev_page_info: Option<String>,
+}
+
+impl PageInfo {
+    /// Parse the `Link` header of a response
+    pub fn from_headers(headers: &HeaderMap) -> PageInfo {
+        let mut page_info = PageInfo::default();
+
+        for value in headers.get_all(LINK) {
+            let Ok(value) = value.to_str() else {
+                continue;
+            };
+
+            for link in value.split(',') {
+                let Some((url, rel)) = parse_link(link) else {
+                    continue;
+                };
+                match rel {
+                    "next" => page_info.next_page_info = page_info_param(url),
+                    "previous" => page_info.prev_page_info = page_info_param(url),
+                    _ => {}
+                }
+            }
+        }
+
+        page_info
+    }
+
+    /// Check if there is a page after this one
+    pub fn has_next(&self) -> bool {
+        self.next_page_info.is_some()
+    }
+
+    /// Check if there is a page before this one
+    pub fn has_prev(&self) -> bool {
+        self.prev_page_info.is_some()
+    }
+}
+
+/// Split a link (`<url>; rel="next"`) into its url and relation
+fn parse_link(link: &str) -> Option<(&str, &str)> {
+    let (url, params) = link.trim().strip_prefix('<')?.split_once('>')?;
+    let rel = params.split(';').find_map(|param| {
+        let (name, value) = param.split_once('=')?;
+        (name.trim() == "rel").then(|| value.trim().trim_matches('"'))
+    })?;
+
+    Some((url, rel))
+}
+
+/// Read the `page_info` parameter of a link url
+fn page_info_param(url: &str) -> Option<String> {
+    let url = reqwest::Url::parse(url).ok()?;
+    let page_info = url
+        .query_pairs()
+        .find(|(name, _)| name == "page_info")
+        .map(|(_, value)| value.into_owned());
+
+    page_info
+}
+
+impl Shopify {
+    /// Query a page of a REST list endpoint, returning the cursors of the pages around it
+    ///
+    /// The next page is queried with the `page_info` parameter, and optionally `limit`:
+    /// Shopify rejects the other filters when `page_info` is given.
+    /// # Example
+    /// ```no_run
+    /// use std::collections::HashMap;
+    /// use shopify_api::*;
+    /// use shopify_api::rest::{PageInfo, ShopifyAPIRestType};
+    /// use shopify_api::utils::ReadJsonTreeSteps;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let shopify = Shopify::new("myshop", "myapikey", ShopifyAPIVersion::V2026_10, None);
+    ///     let json_finder = Some(vec![ReadJsonTreeSteps::Key("products")]);
+    ///
+    ///     let params = HashMap::from([("limit", "250")]);
+    ///     let (products, page_info): (Vec<serde_json::Value>, PageInfo) = shopify
+    ///         .rest_query_paged(&ShopifyAPIRestType::Get("products.json", &params), &json_finder, &RequestOptions::new())
+    ///         .await
+    ///         .unwrap();
+    ///
+    ///     if let Some(next_page_info) = &page_info.next_page_info {
+    ///         let params = HashMap::from([("limit", "250"), ("page_info", next_page_info.as_str())]);
+    ///         let (next_products, _): (Vec<serde_json::Value>, PageInfo) = shopify
+    ///             .rest_query_paged(&ShopifyAPIRestType::Get("products.json", &params), &json_finder, &RequestOptions::new())
+    ///             .await
+    ///             .unwrap();
+    ///     }
+    /// }
+    /// ```
+    pub async fn rest_query_paged<ReturnType>(
+        &self,
+        rest_query: &ShopifyAPIRestType<'_>,
+        json_finder: &Option<Vec<ReadJsonTreeSteps<'_>>>,
+        options: &RequestOptions,
+    ) -> Result<(ReturnType, PageInfo), ShopifyAPIError>
+    where
+        ReturnType: serde::de::DeserializeOwned,
+    {
+        let (response_json, metadata) = self
+            .rest_query_with_metadata(rest_query, json_finder, options)
+            .await?;
+
+        Ok((response_json, PageInfo::from_headers(&metadata.headers)))
+    }
+
+    /// Stream every item of a REST list endpoint, following the `page_info` cursors
+    ///
+    /// Pages are queried one after the other when the items of the previous page have been consumed,
+    /// through the retry policy and the rate limits of the client. Only the `limit` and `fields`
+    /// parameters are kept after the first page, as required by Shopify.
+    /// The items are read from the single field of the response, e.g. `products` for `products.json`.
+    /// The stream ends after the last page, or after the first error.
+    /// # Example
+    /// ```no_run
+    /// use std::collections::HashMap;
+    /// use futures_util::StreamExt;
+    /// use shopify_api::*;
+    ///
+    /// #[derive(serde::Deserialize)]
+    /// struct Product {
+    ///     id: u64,
+    ///     title: String,
+    /// }
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let shopify = Shopify::new("myshop", "myapikey", ShopifyAPIVersion::V2026_10, None);
+    ///     let params = HashMap::from([("limit", "250"), ("status", "active")]);
+    ///
+    ///     let mut products = shopify.rest_stream::<Product>("products.json", &params);
+    ///     while let Some(product) = products.next().await {
+    ///         let product = product.unwrap();
+    ///         println!("{}: {}", product.id, product.title);
+    ///     }
+    /// }
+    /// ```
+    pub fn rest_stream<'a, ItemType>(
+        &'a self,
+        endpoint: &'a str,
+        params: &'a HashMap<&'a str, &'a str>,
+    ) -> impl Stream<Item = Result<ItemType, ShopifyAPIError>> + Unpin + 'a
+    where
+        ItemType: serde::de::DeserializeOwned + 'a,
+    {
+        self.rest_stream_with_prefetch(endpoint, params, 0)
+    }
+
+    /// Stream every item of a REST list endpoint, buffering up to `prefetch` pages ahead
+    ///
+    /// The next page is requested as soon as a page is received, instead of once its items are
+    /// consumed. Each page holds the cursor of the next one, so this is a single request of
+    /// lookahead: it is driven by the polls of the stream, i.e. it progresses each time an item is
+    /// taken, and stalls while the consumer holds on to an item. The pages received ahead are
+    /// buffered, at most `prefetch` of them. See [`Shopify::rest_stream`] for the parameters.
+    /// # Example
+    /// ```no_run
+    /// use std::collections::HashMap;
+    /// use futures_util::StreamExt;
+    /// use shopify_api::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let shopify = Shopify::new("myshop", "myapikey", ShopifyAPIVersion::V2026_10, None);
+    ///     let params = HashMap::from([("limit", "250")]);
+    ///
+    ///     let mut orders = shopify.rest_stream_with_prefetch::<serde_json::Value>("orders.json", &params, 2);
+    ///     while let Some(order) = orders.next().await {
+    ///         println!("{}", order.unwrap()["name"]);
+    ///     }
+    /// }
+    /// ```
+    pub fn rest_stream_with_prefetch<'a, ItemType>(
+        &'a self,
+        endpoint: &'a str,
+        params: &'a HashMap<&'a str, &'a str>,
+        prefetch: usize,
+    ) -> impl Stream<Item = Result<ItemType, ShopifyAPIError>> + Unpin + 'a
+    where
+        ItemType: serde::de::DeserializeOwned + 'a,
+    {
+        PageStream::new(
+            move |cursor: Option<PageCursor>| async move {
+                self.rest_page(endpoint, params, cursor.as_ref()).await
+            },
+            prefetch,
+        )
+    }
+
+    /// Stream the items of a REST list endpoint created after `since_id`, in ascending ID order
+    ///
+    /// Each page is queried with the `since_id` parameter set to the highest ID of the previous page,
+    /// which suits incremental syncs: store the ID of the last item processed and start from it on
+    /// the next run. Only some endpoints support `since_id`, e.g. `orders.json` and `customers.json`.
+    /// The items must have a numeric `id` field. The stream ends after an empty page, or after the
+    /// first error.
+    /// # Example
+    /// ```no_run
+    /// use std::collections::HashMap;
+    /// use futures_util::StreamExt;
+    /// use shopify_api::*;
+    ///
+    /// #[derive(serde::Deserialize)]
+    /// struct Order {
+    ///     id: u64,
+    ///     name: String,
+    /// }
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let shopify = Shopify::new("myshop", "myapikey", ShopifyAPIVersion::V2026_10, None);
+    ///     let params = HashMap::from([("limit", "250"), ("status", "any")]);
+    ///     let mut last_synced_id = 450789469;
+    ///
+    ///     let mut orders = shopify.rest_since_id_stream::<Order>("orders.json", &params, last_synced_id);
+    ///     while let Some(order) = orders.next().await {
+    ///         let order = order.unwrap();
+    ///         println!("{}", order.name);
+    ///         last_synced_id = order.id;
+    ///     }
+    /// }
+    /// ```
+    pub fn rest_since_id_stream<'a, ItemType>(
+        &'a self,
+        endpoint: &'a str,
+        params: &'a HashMap<&'a str, &'a str>,
+        since_id: u64,
+    ) -> impl Stream<Item = Result<ItemType, ShopifyAPIError>> + Unpin + 'a
+    where
+        ItemType: serde::de::DeserializeOwned + 'a,
+    {
+        let state = SinceIdState {
+            items: Vec::new().into_iter(),
+            next: Some(since_id),
+        };
+
+        Box::pin(stream::unfold(state, move |mut state| async move {
+            loop {
+                if let Some(item) = state.items.next() {
+                    return Some((Ok(item), state));
+                }
+
+                let since_id = state.next.take()?;
+                match self.rest_since_id_page(endpoint, params, since_id).await {
+                    Ok((items, last_id)) => {
+                        state.items = items.into_iter();
+                        state.next = last_id;
+                    }
+                    Err(error) => return Some((Err(error), state)),
+                }
+            }
+        }))
+    }
+
+    /// Query the items created after `since_id`, with the highest ID of the page (`None` if empty)
+    async fn rest_since_id_page<ItemType>(
+        &self,
+        endpoint: &str,
+        params: &HashMap<&str, &str>,
+        since_id: u64,
+    ) -> Result<(Vec<ItemType>, Option<u64>), ShopifyAPIError>
+    where
+        ItemType: serde::de::DeserializeOwned,
+    {
+        let since_id = since_id.to_string();
+        let mut page_params = params.clone();
+        page_params.insert("since_id", &since_id);
+
+        let json: serde_json::Value = self
+            .rest_query(&ShopifyAPIRestType::Get(endpoint, &page_params), &None)
+            .await?;
+        let items: Vec<serde_json::Value> = list_items(json)?;
+
+        let mut last_id = None;
+        for item in &items {
+            let id = item["id"].as_u64().ok_or_else(|| {
+                ShopifyAPIError::NotWantedJsonFormat("item without a numeric id".to_string())
+            })?;
+            last_id = last_id.max(Some(id));
+        }
+
+        let items = items
+            .into_iter()
+            .map(serde_json::from_value)
+            .collect::<Result<_, _>>()
+            .map_err(ShopifyAPIError::JsonParseError)?;
+
+        Ok((items, last_id))
+    }
+
+    /// Collect every item of a REST list endpoint, following the `page_info` cursors
+    ///
+    /// This fails with [`ShopifyAPIError::TooManyItems`] after [`DEFAULT_MAX_ITEMS`] items,
+    /// see [`Shopify::rest_all_with_max`] to change the maximum and [`Shopify::rest_stream`]
+    /// for larger datasets.
+    /// # Example
+    /// ```no_run
+    /// use std::collections::HashMap;
+    /// use shopify_api::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let shopify = Shopify::new("myshop", "myapikey", ShopifyAPIVersion::V2026_10, None);
+    ///     let params = HashMap::from([("limit", "250")]);
+    ///
+    ///     let locations: Vec<serde_json::Value> = shopify
+    ///         .rest_all("locations.json", &params)
+    ///         .await
+    ///         .unwrap();
+    /// }
+    /// ```
+    pub async fn rest_all<ItemType>(
+        &self,
+        endpoint: &str,
+        params: &HashMap<&str, &str>,
+    ) -> Result<Vec<ItemType>, ShopifyAPIError>
+    where
+        ItemType: serde::de::DeserializeOwned,
+    {
+        self.rest_all_with_max(endpoint, params, DEFAULT_MAX_ITEMS)
+            .await
+    }
+
+    /// Collect every item of a REST list endpoint, failing with [`ShopifyAPIError::TooManyItems`]
+    /// after `max_items` items
+    pub async fn rest_all_with_max<ItemType>(
+        &self,
+        endpoint: &str,
+        params: &HashMap<&str, &str>,
+        max_items: usize,
+    ) -> Result<Vec<ItemType>, ShopifyAPIError>
+    where
+        ItemType: serde::de::DeserializeOwned,
+    {
+        let mut stream = self.rest_stream(endpoint, params);
+        let mut items = Vec::new();
+
+        while let Some(item) = stream.next().await {
+            let item = item?;
+            if items.len() == max_items {
+                return Err(ShopifyAPIError::TooManyItems(max_items));
+            }
+            items.push(item);
+        }
+
+        Ok(items)
+    }
+
+    /// Query a page of a REST list endpoint, starting from the first page without a cursor
+    ///
+    /// The items are read from the single field of the response, e.g. `products` for `products.json`.
+    /// Only the `limit` and `fields` parameters are sent with a cursor, as required by Shopify.
+    /// # Example
+    /// ```no_run
+    /// use std::collections::HashMap;
+    /// use shopify_api::*;
+    /// use shopify_api::pagination::{Page, PageCursor};
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let shopify = Shopify::new("myshop", "myapikey", ShopifyAPIVersion::V2026_10, None);
+    ///     let params = HashMap::from([("limit", "250")]);
+    ///
+    ///     // Resume from a cursor stored by a previous run
+    ///     let cursor = Some(PageCursor::new("eyJsYXN0X2lkIjo0MjB9"));
+    ///     let page: Page<serde_json::Value> = shopify
+    ///         .rest_page("orders.json", &params, cursor.as_ref())
+    ///         .await
+    ///         .unwrap();
+    ///
+    ///     println!("{} orders, next page: {:?}", page.items.len(), page.next);
+    /// }
+    /// ```
+    pub async fn rest_page<ItemType>(
+        &self,
+        endpoint: &str,
+        params: &HashMap<&str, &str>,
+        cursor: Option<&PageCursor>,
+    ) -> Result<Page<ItemType>, ShopifyAPIError>
+    where
+        ItemType: serde::de::DeserializeOwned,
+    {
+        self.rest_page_with_options(endpoint, params, cursor, &RequestOptions::new())
+            .await
+    }
+
+    /// Query a page of a REST list endpoint with per-request options, see [`Shopify::rest_page`]
+    /// # Example
+    /// ```no_run
+    /// use std::collections::HashMap;
+    /// use std::time::Duration;
+    /// use shopify_api::*;
+    /// use shopify_api::pagination::Page;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let shopify = Shopify::new("myshop", "myapikey", ShopifyAPIVersion::V2026_10, None);
+    ///     let params = HashMap::from([("limit", "250")]);
+    ///     let options = RequestOptions::new().timeout(Duration::from_secs(5));
+    ///
+    ///     let page: Page<serde_json::Value> = shopify
+    ///         .rest_page_with_options("orders.json", &params, None, &options)
+    ///         .await
+    ///         .unwrap();
+    /// }
+    /// ```
+    pub async fn rest_page_with_options<ItemType>(
+        &self,
+        endpoint: &str,
+        params: &HashMap<&str, &str>,
+        cursor: Option<&PageCursor>,
+        options: &RequestOptions,
+    ) -> Result<Page<ItemType>, ShopifyAPIError>
+    where
+        ItemType: serde::de::DeserializeOwned,
+    {
+        let mut page_params = params.clone();
+        if let Some(cursor) = cursor {
+            page_params.retain(|name, _| PAGE_PARAMS.contains(name));
+            page_params.insert("page_info", cursor.as_str());
+        }
+
+        let (json, metadata): (serde_json::Value, _) = self
+            .rest_query_with_metadata(
+                &ShopifyAPIRestType::Get(endpoint, &page_params),
+                &None,
+                options,
+            )
+            .await?;
+        let page_info = PageInfo::from_headers(&metadata.headers);
+
+        Ok(Page {
+            items: list_items(json)?,
+            next: page_info.next_page_info.map(PageCursor::new),
+            prev: page_info.prev_page_info.map(PageCursor::new),
+            request_id: metadata.request_id,
+        })
+    }
+}
+
+/// Position of a `since_id` stream in a REST list endpoint
+struct SinceIdState<ItemType> {
+    /// Items of the current page not yielded yet
+    items: std::vec::IntoIter<ItemType>,
+    /// ID after which the next page is queried, `None` after the last page
+    next: Option<u64>,
+}
+
+/// Read the items of a list response, found in its single field (e.g. `{"products": [...]}`)
+fn list_items<ItemType>(json: serde_json::Value) -> Result<Vec<ItemType>, ShopifyAPIError>
+where
+    ItemType: serde::de::DeserializeOwned,
+{
+    let items = match json {
+        serde_json::Value::Object(object) if object.len() == 1 => {
+            object.into_iter().next().map(|(_, items)| items)
+        }
+        serde_json::Value::Array(_) => Some(json),
+        _ => None,
+    }
+    .ok_or_else(|| {
+        ShopifyAPIError::NotWantedJsonFormat("expected an object with a single list".to_string())
+    })?;
+
+    serde_json::from_value(items).map_err(ShopifyAPIError::JsonParseError)
+}
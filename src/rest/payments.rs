@@ -0,0 +1,609 @@
+//! Shopify Payments, through the REST `shopify_payments` endpoints
+//!
+//! Every payment of a shop using Shopify Payments is recorded as a balance transaction, and the
+//! balance is paid out to the bank account of the merchant. Reconciling the payouts means listing
+//! the balance transactions of each payout with [`BalanceTransactionListParams::payout_id`].
+
+use futures_util::Stream;
+use serde::{Deserialize, Serialize};
+
+use super::resource::Params;
+use crate::{
+    pagination::{Page, PageCursor},
+    Shopify, ShopifyAPIError,
+};
+
+/// Amount of the balance in a currency
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Balance {
+    pub amount: String,
+    pub currency: String,
+}
+
+/// Kind of a balance transaction
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BalanceTransactionType {
+    Charge,
+    Refund,
+    Dispute,
+    Reserve,
+    Adjustment,
+    Credit,
+    Debit,
+    Payout,
+    PayoutFailure,
+    PayoutCancellation,
+    #[serde(other)]
+    Unknown,
+}
+
+/// Status of a payout
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PayoutStatus {
+    Scheduled,
+    InTransit,
+    Paid,
+    Failed,
+    Canceled,
+    #[serde(other)]
+    Unknown,
+}
+
+impl PayoutStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PayoutStatus::Scheduled => "scheduled",
+            PayoutStatus::InTransit => "in_transit",
+            PayoutStatus::Paid => "paid",
+            PayoutStatus::Failed => "failed",
+            PayoutStatus::Canceled => "canceled",
+            PayoutStatus::Unknown => "unknown",
+        }
+    }
+}
+
+/// Movement of the balance, e.g. a charge of an order or a payout
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BalanceTransaction {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<u64>,
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub transaction_type: Option<BalanceTransactionType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub test: Option<bool>,
+    /// Payout the transaction is paid out with
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payout_id: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payout_status: Option<PayoutStatus>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub currency: Option<String>,
+    /// Gross amount, negative for refunds and disputes
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amount: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fee: Option<String>,
+    /// Amount minus the fee
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub net: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source_id: Option<u64>,
+    /// Kind of the source, e.g. `charge` or `dispute`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source_order_id: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source_order_transaction_id: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub processed_at: Option<String>,
+}
+
+/// Totals of a payout by kind of transaction
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PayoutSummary {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub charges_gross_amount: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub charges_fee_amount: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub refunds_gross_amount: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub refunds_fee_amount: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub adjustments_gross_amount: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub adjustments_fee_amount: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reserved_funds_gross_amount: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reserved_funds_fee_amount: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub retried_payouts_gross_amount: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub retried_payouts_fee_amount: Option<String>,
+}
+
+/// Transfer of the balance to the bank account of the merchant
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Payout {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<PayoutStatus>,
+    /// Date the payout was issued, e.g. `2026-01-31`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub date: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub currency: Option<String>,
+    /// Net amount, negative when the merchant owes Shopify
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amount: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub summary: Option<PayoutSummary>,
+}
+
+/// Kind of a dispute
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DisputeType {
+    /// The customer asks their bank for their money back
+    Chargeback,
+    /// The bank asks for details before a possible chargeback
+    Inquiry,
+    #[serde(other)]
+    Unknown,
+}
+
+/// Status of a dispute
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DisputeStatus {
+    /// Waiting for the evidence of the merchant, until `evidence_due_by`
+    NeedsResponse,
+    UnderReview,
+    ChargeRefunded,
+    Accepted,
+    Won,
+    Lost,
+    #[serde(other)]
+    Unknown,
+}
+
+impl DisputeStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DisputeStatus::NeedsResponse => "needs_response",
+            DisputeStatus::UnderReview => "under_review",
+            DisputeStatus::ChargeRefunded => "charge_refunded",
+            DisputeStatus::Accepted => "accepted",
+            DisputeStatus::Won => "won",
+            DisputeStatus::Lost => "lost",
+            DisputeStatus::Unknown => "unknown",
+        }
+    }
+}
+
+/// Dispute of a payment, opened by the bank of the customer
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Dispute {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order_id: Option<u64>,
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub dispute_type: Option<DisputeType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<DisputeStatus>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amount: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub currency: Option<String>,
+    /// Reason given by the bank, e.g. `fraudulent` or `product_not_received`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub network_reason_code: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub evidence_due_by: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub evidence_sent_on: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub finalized_on: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub initiated_at: Option<String>,
+}
+
+/// Evidence of the merchant against a dispute
+///
+/// # Example
+/// ```
+/// use shopify_api::rest::payments::DisputeEvidence;
+///
+/// let evidence = DisputeEvidence {
+///     uncategorized_text: Some("Delivered on 2026-01-12, see the tracking".to_string()),
+///     ..DisputeEvidence::default()
+/// }
+/// .submit();
+///
+/// assert_eq!(
+///     serde_json::to_value(&evidence).unwrap(),
+///     serde_json::json!({
+///         "uncategorized_text": "Delivered on 2026-01-12, see the tracking",
+///         "submit_evidence": true,
+///     })
+/// );
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DisputeEvidence {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payments_dispute_id: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub customer_email_address: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub customer_first_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub customer_last_name: Option<String>,
+    /// Activity of the customer showing they made the purchase, e.g. their IP address
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub access_activity_log: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cancellation_policy_disclosure: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cancellation_rebuttal: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub refund_policy_disclosure: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub refund_refusal_explanation: Option<String>,
+    /// Any other evidence
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub uncategorized_text: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shipping_address: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub product_description: Option<serde_json::Value>,
+    /// Files uploaded as evidence, by kind
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dispute_evidence_files: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub submitted_by_merchant_on: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub updated_on: Option<String>,
+    /// Whether the evidence is sent to the bank with this update, no longer being editable
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub submit_evidence: Option<bool>,
+}
+
+impl DisputeEvidence {
+    /// Send the evidence to the bank with [`Payments::update_dispute_evidence`]
+    pub fn submit(mut self) -> Self {
+        self.submit_evidence = Some(true);
+        self
+    }
+}
+
+/// Filters of [`Payments::transactions`] and [`Payments::transactions_stream`]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct BalanceTransactionListParams {
+    params: Params,
+}
+
+impl BalanceTransactionListParams {
+    pub fn new() -> BalanceTransactionListParams {
+        BalanceTransactionListParams::default()
+    }
+
+    /// Set the number of transactions of each page, 50 by default and at most 250
+    pub fn limit(mut self, limit: u32) -> Self {
+        self.params.set("limit", limit);
+        self
+    }
+
+    /// Only list the transactions with an ID greater than `since_id`
+    pub fn since_id(mut self, since_id: u64) -> Self {
+        self.params.set("since_id", since_id);
+        self
+    }
+
+    /// Only list the transactions with an ID lower than `last_id`
+    pub fn last_id(mut self, last_id: u64) -> Self {
+        self.params.set("last_id", last_id);
+        self
+    }
+
+    /// Only list the transactions paid out with a payout
+    pub fn payout_id(mut self, payout_id: u64) -> Self {
+        self.params.set("payout_id", payout_id);
+        self
+    }
+
+    pub fn payout_status(mut self, payout_status: PayoutStatus) -> Self {
+        self.params.set("payout_status", payout_status.as_str());
+        self
+    }
+
+    /// Only list the test transactions, or the real ones
+    pub fn test(mut self, test: bool) -> Self {
+        self.params.set("test", test);
+        self
+    }
+
+    /// Only list the transactions processed at a date
+    pub fn processed_at(mut self, processed_at: chrono::NaiveDate) -> Self {
+        self.params.set("processed_at", processed_at);
+        self
+    }
+}
+
+/// Filters of [`Payments::payouts`] and [`Payments::payouts_stream`]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct PayoutListParams {
+    params: Params,
+}
+
+impl PayoutListParams {
+    pub fn new() -> PayoutListParams {
+        PayoutListParams::default()
+    }
+
+    /// Set the number of payouts of each page, 50 by default and at most 250
+    pub fn limit(mut self, limit: u32) -> Self {
+        self.params.set("limit", limit);
+        self
+    }
+
+    pub fn since_id(mut self, since_id: u64) -> Self {
+        self.params.set("since_id", since_id);
+        self
+    }
+
+    pub fn last_id(mut self, last_id: u64) -> Self {
+        self.params.set("last_id", last_id);
+        self
+    }
+
+    pub fn status(mut self, status: PayoutStatus) -> Self {
+        self.params.set("status", status.as_str());
+        self
+    }
+
+    /// Only list the payouts issued at a date
+    pub fn date(mut self, date: chrono::NaiveDate) -> Self {
+        self.params.set("date", date);
+        self
+    }
+
+    /// Only list the payouts issued at or after a date
+    pub fn date_min(mut self, date_min: chrono::NaiveDate) -> Self {
+        self.params.set("date_min", date_min);
+        self
+    }
+
+    /// Only list the payouts issued at or before a date
+    pub fn date_max(mut self, date_max: chrono::NaiveDate) -> Self {
+        self.params.set("date_max", date_max);
+        self
+    }
+}
+
+/// Filters of [`Payments::disputes`] and [`Payments::disputes_stream`]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct DisputeListParams {
+    params: Params,
+}
+
+impl DisputeListParams {
+    pub fn new() -> DisputeListParams {
+        DisputeListParams::default()
+    }
+
+    /// Set the number of disputes of each page, 50 by default and at most 250
+    pub fn limit(mut self, limit: u32) -> Self {
+        self.params.set("limit", limit);
+        self
+    }
+
+    pub fn since_id(mut self, since_id: u64) -> Self {
+        self.params.set("since_id", since_id);
+        self
+    }
+
+    pub fn last_id(mut self, last_id: u64) -> Self {
+        self.params.set("last_id", last_id);
+        self
+    }
+
+    pub fn status(mut self, status: DisputeStatus) -> Self {
+        self.params.set("status", status.as_str());
+        self
+    }
+
+    /// Only list the disputes initiated at a date
+    pub fn initiated_at(mut self, initiated_at: chrono::NaiveDate) -> Self {
+        self.params.set("initiated_at", initiated_at);
+        self
+    }
+}
+
+/// Shopify Payments account of the shop, returned by [`Shopify::payments`]
+#[derive(Clone, Copy, Debug)]
+pub struct Payments<'a> {
+    shopify: &'a Shopify,
+}
+
+impl Shopify {
+    /// Query the balance, payouts and disputes of Shopify Payments
+    ///
+    /// # Example
+    /// ```no_run
+    /// use futures_util::StreamExt;
+    /// use shopify_api::*;
+    /// use shopify_api::rest::payments::{BalanceTransactionListParams, PayoutListParams};
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let shopify = Shopify::new("myshop", "myapikey", ShopifyAPIVersion::V2026_10, None);
+    ///     let january = PayoutListParams::new()
+    ///         .date_min(chrono::NaiveDate::from_ymd_opt(2026, 1, 1).unwrap())
+    ///         .date_max(chrono::NaiveDate::from_ymd_opt(2026, 1, 31).unwrap());
+    ///
+    ///     let mut payouts = shopify.payments().payouts_stream(&january);
+    ///     while let Some(payout) = payouts.next().await {
+    ///         let payout = payout.unwrap();
+    ///         let params = BalanceTransactionListParams::new().payout_id(payout.id.unwrap());
+    ///         let transactions: Vec<_> = shopify
+    ///             .payments()
+    ///             .transactions_stream(&params)
+    ///             .collect()
+    ///             .await;
+    ///         println!("{:?}: {} transactions", payout.amount, transactions.len());
+    ///     }
+    /// }
+    /// ```
+    pub fn payments(&self) -> Payments<'_> {
+        Payments { shopify: self }
+    }
+}
+
+impl<'a> Payments<'a> {
+    /// Get the current balance, in each currency of the account
+    pub async fn balance(&self) -> Result<Vec<Balance>, ShopifyAPIError> {
+        self.shopify
+            .rest_get_item(
+                "shopify_payments/balance.json",
+                &Params::default(),
+                "balance",
+            )
+            .await
+    }
+
+    /// Query a page of balance transactions, starting from the first page without a cursor
+    pub async fn transactions(
+        &self,
+        params: &BalanceTransactionListParams,
+        cursor: Option<&PageCursor>,
+    ) -> Result<Page<BalanceTransaction>, ShopifyAPIError> {
+        self.shopify
+            .rest_list(
+                "shopify_payments/balance/transactions.json",
+                &params.params,
+                cursor,
+            )
+            .await
+    }
+
+    /// Stream every balance transaction matching the filters, following the pages
+    pub fn transactions_stream(
+        &self,
+        params: &BalanceTransactionListParams,
+    ) -> impl Stream<Item = Result<BalanceTransaction, ShopifyAPIError>> + Unpin + 'a {
+        self.shopify.rest_list_stream(
+            "shopify_payments/balance/transactions.json".to_string(),
+            params.params.clone(),
+        )
+    }
+
+    /// Query a page of payouts, the most recent first
+    pub async fn payouts(
+        &self,
+        params: &PayoutListParams,
+        cursor: Option<&PageCursor>,
+    ) -> Result<Page<Payout>, ShopifyAPIError> {
+        self.shopify
+            .rest_list("shopify_payments/payouts.json", &params.params, cursor)
+            .await
+    }
+
+    /// Stream every payout matching the filters, following the pages
+    pub fn payouts_stream(
+        &self,
+        params: &PayoutListParams,
+    ) -> impl Stream<Item = Result<Payout, ShopifyAPIError>> + Unpin + 'a {
+        self.shopify.rest_list_stream(
+            "shopify_payments/payouts.json".to_string(),
+            params.params.clone(),
+        )
+    }
+
+    pub async fn payout(&self, id: u64) -> Result<Payout, ShopifyAPIError> {
+        self.shopify
+            .rest_get_item(
+                &format!("shopify_payments/payouts/{}.json", id),
+                &Params::default(),
+                "payout",
+            )
+            .await
+    }
+
+    /// Query a page of disputes, the most recent first
+    pub async fn disputes(
+        &self,
+        params: &DisputeListParams,
+        cursor: Option<&PageCursor>,
+    ) -> Result<Page<Dispute>, ShopifyAPIError> {
+        self.shopify
+            .rest_list("shopify_payments/disputes.json", &params.params, cursor)
+            .await
+    }
+
+    /// Stream every dispute matching the filters, following the pages
+    pub fn disputes_stream(
+        &self,
+        params: &DisputeListParams,
+    ) -> impl Stream<Item = Result<Dispute, ShopifyAPIError>> + Unpin + 'a {
+        self.shopify.rest_list_stream(
+            "shopify_payments/disputes.json".to_string(),
+            params.params.clone(),
+        )
+    }
+
+    pub async fn dispute(&self, id: u64) -> Result<Dispute, ShopifyAPIError> {
+        self.shopify
+            .rest_get_item(
+                &format!("shopify_payments/disputes/{}.json", id),
+                &Params::default(),
+                "dispute",
+            )
+            .await
+    }
+
+    /// Get the evidence of a dispute, as saved so far
+    pub async fn dispute_evidence(
+        &self,
+        dispute_id: u64,
+    ) -> Result<DisputeEvidence, ShopifyAPIError> {
+        self.shopify
+            .rest_get_item(
+                &format!(
+                    "shopify_payments/disputes/{}/dispute_evidences.json",
+                    dispute_id
+                ),
+                &Params::default(),
+                "dispute_evidence",
+            )
+            .await
+    }
+
+    /// Save the fields set on `evidence`, sending it to the bank with
+    /// [`DisputeEvidence::submit`]
+    pub async fn update_dispute_evidence(
+        &self,
+        dispute_id: u64,
+        evidence: &DisputeEvidence,
+    ) -> Result<DisputeEvidence, ShopifyAPIError> {
+        self.shopify
+            .rest_put_item(
+                &format!(
+                    "shopify_payments/disputes/{}/dispute_evidences.json",
+                    dispute_id
+                ),
+                "dispute_evidence",
+                evidence,
+            )
+            .await
+    }
+}
@@ -0,0 +1,512 @@
+//! Price rules and their discount codes, through the REST `price_rules.json` and
+//! `discount_codes` endpoints
+//!
+//! A price rule describes a discount: its value, what it applies to and who can use it. Customers
+//! apply it with one of its discount codes, created one by one or in batches of up to
+//! [`DISCOUNT_CODES_BATCH_LIMIT`]. For the newer discount types, see [`crate::graphql::discounts`].
+
+use futures_util::Stream;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+use super::{draft_orders::DiscountValueType, resource::Params};
+use crate::{
+    pagination::{Page, PageCursor},
+    Shopify, ShopifyAPIError,
+};
+
+/// Maximum number of discount codes of [`PriceRules::batch_create_discount_codes`]
+pub const DISCOUNT_CODES_BATCH_LIMIT: usize = 100;
+
+/// What a price rule discounts
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TargetType {
+    LineItem,
+    ShippingLine,
+}
+
+/// Which line items or shipping lines a price rule discounts
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TargetSelection {
+    All,
+    /// Only the entitled products, variants, collections or countries
+    Entitled,
+}
+
+/// How the value of a price rule is spread over its targets
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AllocationMethod {
+    /// The value is applied to each target, e.g. 5.00 off each item
+    Each,
+    /// The value is split across the targets, e.g. 5.00 off the order
+    Across,
+}
+
+/// Which customers can use a price rule
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CustomerSelection {
+    All,
+    /// Only the customers of `prerequisite_customer_ids` or of the prerequisite segments
+    Prerequisite,
+}
+
+/// Lower bound of a prerequisite of a price rule, e.g. a minimum subtotal
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PrerequisiteRange {
+    pub greater_than_or_equal_to: String,
+}
+
+/// Discount of a shop, applied with its discount codes
+///
+/// The value is negative, e.g. `-10.0` for 10% off with the `percentage` value type.
+/// # Example
+/// ```
+/// use chrono::{TimeZone, Utc};
+/// use shopify_api::rest::price_rules::{PrerequisiteRange, PriceRule};
+///
+/// // 15% off orders of 50.00 or more
+/// let price_rule = PriceRule {
+///     prerequisite_subtotal_range: Some(PrerequisiteRange {
+///         greater_than_or_equal_to: "50.00".to_string(),
+///     }),
+///     ..PriceRule::percentage("SUMMER15", "15.0", Utc.with_ymd_and_hms(2026, 6, 1, 0, 0, 0).unwrap())
+/// };
+///
+/// assert_eq!(price_rule.value.as_deref(), Some("-15.0"));
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PriceRule {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<u64>,
+    /// Name of the price rule, also used as the discount code by the admin
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value_type: Option<DiscountValueType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target_type: Option<TargetType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target_selection: Option<TargetSelection>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allocation_method: Option<AllocationMethod>,
+    /// Number of times the discount applies to the items of an order, with `each` allocation
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allocation_limit: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub customer_selection: Option<CustomerSelection>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub once_per_customer: Option<bool>,
+    /// Number of times the discount can be used in total
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub usage_limit: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starts_at: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ends_at: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub entitled_product_ids: Vec<u64>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub entitled_variant_ids: Vec<u64>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub entitled_collection_ids: Vec<u64>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub entitled_country_ids: Vec<u64>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub prerequisite_customer_ids: Vec<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub prerequisite_subtotal_range: Option<PrerequisiteRange>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub prerequisite_quantity_range: Option<PrerequisiteRange>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub admin_graphql_api_id: Option<String>,
+}
+
+impl PriceRule {
+    /// Price rule of `percentage` percent off every line item for every customer, e.g. `"10.0"`
+    pub fn percentage(
+        title: &str,
+        percentage: &str,
+        starts_at: chrono::DateTime<chrono::Utc>,
+    ) -> PriceRule {
+        PriceRule::order_discount(title, DiscountValueType::Percentage, percentage, starts_at)
+    }
+
+    /// Price rule of a fixed `amount` off the order for every customer, e.g. `"5.00"`
+    pub fn fixed_amount(
+        title: &str,
+        amount: &str,
+        starts_at: chrono::DateTime<chrono::Utc>,
+    ) -> PriceRule {
+        PriceRule::order_discount(title, DiscountValueType::FixedAmount, amount, starts_at)
+    }
+
+    fn order_discount(
+        title: &str,
+        value_type: DiscountValueType,
+        value: &str,
+        starts_at: chrono::DateTime<chrono::Utc>,
+    ) -> PriceRule {
+        PriceRule {
+            title: Some(title.to_string()),
+            value_type: Some(value_type),
+            value: Some(format!("-{}", value.trim_start_matches('-'))),
+            target_type: Some(TargetType::LineItem),
+            target_selection: Some(TargetSelection::All),
+            allocation_method: Some(AllocationMethod::Across),
+            customer_selection: Some(CustomerSelection::All),
+            starts_at: Some(starts_at.to_rfc3339()),
+            ..PriceRule::default()
+        }
+    }
+}
+
+/// Code customers enter to apply a price rule
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DiscountCode {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub price_rule_id: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub code: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub usage_count: Option<u64>,
+    /// Why the code could not be created, for the codes of a batch
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub errors: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<String>,
+}
+
+/// Status of a batch of discount codes
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DiscountCodeCreationStatus {
+    Queued,
+    Running,
+    Completed,
+    #[serde(other)]
+    Unknown,
+}
+
+/// Batch of discount codes created in the background by
+/// [`PriceRules::batch_create_discount_codes`]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DiscountCodeCreation {
+    pub id: u64,
+    pub price_rule_id: u64,
+    pub status: DiscountCodeCreationStatus,
+    pub codes_count: u64,
+    pub imported_count: u64,
+    pub failed_count: u64,
+    pub started_at: Option<String>,
+    pub completed_at: Option<String>,
+    pub created_at: Option<String>,
+    pub updated_at: Option<String>,
+}
+
+/// Filters of [`PriceRules::list`], [`PriceRules::stream`] and [`PriceRules::count`]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct PriceRuleListParams {
+    params: Params,
+}
+
+impl PriceRuleListParams {
+    pub fn new() -> PriceRuleListParams {
+        PriceRuleListParams::default()
+    }
+
+    /// Set the number of price rules of each page, 50 by default and at most 250
+    pub fn limit(mut self, limit: u32) -> Self {
+        self.params.set("limit", limit);
+        self
+    }
+
+    /// Only list the price rules with an ID greater than `since_id`
+    pub fn since_id(mut self, since_id: u64) -> Self {
+        self.params.set("since_id", since_id);
+        self
+    }
+
+    pub fn starts_at_min(mut self, starts_at_min: chrono::DateTime<chrono::Utc>) -> Self {
+        self.params.set_time("starts_at_min", starts_at_min);
+        self
+    }
+
+    pub fn starts_at_max(mut self, starts_at_max: chrono::DateTime<chrono::Utc>) -> Self {
+        self.params.set_time("starts_at_max", starts_at_max);
+        self
+    }
+
+    pub fn ends_at_min(mut self, ends_at_min: chrono::DateTime<chrono::Utc>) -> Self {
+        self.params.set_time("ends_at_min", ends_at_min);
+        self
+    }
+
+    pub fn ends_at_max(mut self, ends_at_max: chrono::DateTime<chrono::Utc>) -> Self {
+        self.params.set_time("ends_at_max", ends_at_max);
+        self
+    }
+
+    pub fn created_at_min(mut self, created_at_min: chrono::DateTime<chrono::Utc>) -> Self {
+        self.params.set_time("created_at_min", created_at_min);
+        self
+    }
+
+    pub fn created_at_max(mut self, created_at_max: chrono::DateTime<chrono::Utc>) -> Self {
+        self.params.set_time("created_at_max", created_at_max);
+        self
+    }
+
+    pub fn updated_at_min(mut self, updated_at_min: chrono::DateTime<chrono::Utc>) -> Self {
+        self.params.set_time("updated_at_min", updated_at_min);
+        self
+    }
+
+    pub fn updated_at_max(mut self, updated_at_max: chrono::DateTime<chrono::Utc>) -> Self {
+        self.params.set_time("updated_at_max", updated_at_max);
+        self
+    }
+
+    /// Only list the price rules used this number of times
+    pub fn times_used(mut self, times_used: u64) -> Self {
+        self.params.set("times_used", times_used);
+        self
+    }
+}
+
+/// Price rules of a shop and their discount codes, returned by [`Shopify::price_rules`]
+#[derive(Clone, Copy, Debug)]
+pub struct PriceRules<'a> {
+    shopify: &'a Shopify,
+}
+
+impl Shopify {
+    /// Query and manage the price rules of the shop and their discount codes
+    ///
+    /// # Example
+    /// ```no_run
+    /// use shopify_api::*;
+    /// use shopify_api::rest::price_rules::PriceRule;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let shopify = Shopify::new("myshop", "myapikey", ShopifyAPIVersion::V2026_10, None);
+    ///     let price_rule = PriceRule::percentage("WELCOME", "10.0", chrono::Utc::now());
+    ///
+    ///     let price_rule = shopify.price_rules().create(&price_rule).await.unwrap();
+    ///     let batch = shopify
+    ///         .price_rules()
+    ///         .batch_create_discount_codes(price_rule.id.unwrap(), &["WELCOME-A1", "WELCOME-B2"])
+    ///         .await
+    ///         .unwrap();
+    ///     println!("{:?}", batch.status);
+    ///
+    ///     let code = shopify.price_rules().lookup_discount_code("WELCOME-A1").await.unwrap();
+    ///     println!("{:?}", code.price_rule_id);
+    /// }
+    /// ```
+    pub fn price_rules(&self) -> PriceRules<'_> {
+        PriceRules { shopify: self }
+    }
+}
+
+impl<'a> PriceRules<'a> {
+    /// Query a page of price rules, starting from the first page without a cursor
+    pub async fn list(
+        &self,
+        params: &PriceRuleListParams,
+        cursor: Option<&PageCursor>,
+    ) -> Result<Page<PriceRule>, ShopifyAPIError> {
+        self.shopify
+            .rest_list("price_rules.json", &params.params, cursor)
+            .await
+    }
+
+    /// Stream every price rule matching the filters, following the pages
+    pub fn stream(
+        &self,
+        params: &PriceRuleListParams,
+    ) -> impl Stream<Item = Result<PriceRule, ShopifyAPIError>> + Unpin + 'a {
+        self.shopify
+            .rest_list_stream("price_rules.json".to_string(), params.params.clone())
+    }
+
+    pub async fn get(&self, id: u64) -> Result<PriceRule, ShopifyAPIError> {
+        self.shopify
+            .rest_get_item(
+                &format!("price_rules/{}.json", id),
+                &Params::default(),
+                "price_rule",
+            )
+            .await
+    }
+
+    pub async fn count(&self, params: &PriceRuleListParams) -> Result<u64, ShopifyAPIError> {
+        self.shopify
+            .rest_count("price_rules/count.json", &params.params)
+            .await
+    }
+
+    pub async fn create(&self, price_rule: &PriceRule) -> Result<PriceRule, ShopifyAPIError> {
+        self.shopify
+            .rest_post_item("price_rules.json", "price_rule", price_rule)
+            .await
+    }
+
+    /// Update the fields set on `price_rule`
+    pub async fn update(
+        &self,
+        id: u64,
+        price_rule: &PriceRule,
+    ) -> Result<PriceRule, ShopifyAPIError> {
+        self.shopify
+            .rest_put_item(
+                &format!("price_rules/{}.json", id),
+                "price_rule",
+                price_rule,
+            )
+            .await
+    }
+
+    /// Delete a price rule and its discount codes
+    pub async fn delete(&self, id: u64) -> Result<(), ShopifyAPIError> {
+        self.shopify
+            .rest_delete_item(&format!("price_rules/{}.json", id))
+            .await
+    }
+
+    /// Query a page of the discount codes of a price rule, starting from the first page without
+    /// a cursor
+    pub async fn discount_codes(
+        &self,
+        price_rule_id: u64,
+        cursor: Option<&PageCursor>,
+    ) -> Result<Page<DiscountCode>, ShopifyAPIError> {
+        self.shopify
+            .rest_list(
+                &format!("price_rules/{}/discount_codes.json", price_rule_id),
+                &Params::default(),
+                cursor,
+            )
+            .await
+    }
+
+    pub async fn create_discount_code(
+        &self,
+        price_rule_id: u64,
+        code: &str,
+    ) -> Result<DiscountCode, ShopifyAPIError> {
+        let discount_code = DiscountCode {
+            code: Some(code.to_string()),
+            ..DiscountCode::default()
+        };
+
+        self.shopify
+            .rest_post_item(
+                &format!("price_rules/{}/discount_codes.json", price_rule_id),
+                "discount_code",
+                &discount_code,
+            )
+            .await
+    }
+
+    pub async fn delete_discount_code(
+        &self,
+        price_rule_id: u64,
+        id: u64,
+    ) -> Result<(), ShopifyAPIError> {
+        self.shopify
+            .rest_delete_item(&format!(
+                "price_rules/{}/discount_codes/{}.json",
+                price_rule_id, id
+            ))
+            .await
+    }
+
+    /// Find a discount code among the codes of every price rule
+    pub async fn lookup_discount_code(&self, code: &str) -> Result<DiscountCode, ShopifyAPIError> {
+        let mut params = Params::default();
+        params.set("code", code);
+
+        // Shopify redirects to the discount code, the redirection being followed
+        self.shopify
+            .rest_get_item("discount_codes/lookup.json", &params, "discount_code")
+            .await
+    }
+
+    /// Create up to [`DISCOUNT_CODES_BATCH_LIMIT`] discount codes in the background
+    ///
+    /// The progress is followed with [`PriceRules::batch`], and the codes that could not be
+    /// created are listed with [`PriceRules::batch_discount_codes`]. This fails with
+    /// [`ShopifyAPIError::InvalidInput`] before sending the query if there are too many codes.
+    pub async fn batch_create_discount_codes(
+        &self,
+        price_rule_id: u64,
+        codes: &[&str],
+    ) -> Result<DiscountCodeCreation, ShopifyAPIError> {
+        if codes.len() > DISCOUNT_CODES_BATCH_LIMIT {
+            return Err(ShopifyAPIError::InvalidInput(format!(
+                "{} discount codes, at most {} can be created at once",
+                codes.len(),
+                DISCOUNT_CODES_BATCH_LIMIT
+            )));
+        }
+
+        let discount_codes: Vec<serde_json::Value> =
+            codes.iter().map(|code| json!({ "code": code })).collect();
+
+        self.shopify
+            .rest_post_action(
+                &format!("price_rules/{}/batch.json", price_rule_id),
+                &json!({ "discount_codes": discount_codes }),
+                "discount_code_creation",
+            )
+            .await
+    }
+
+    /// Get the progress of a batch of discount codes
+    pub async fn batch(
+        &self,
+        price_rule_id: u64,
+        batch_id: u64,
+    ) -> Result<DiscountCodeCreation, ShopifyAPIError> {
+        self.shopify
+            .rest_get_item(
+                &format!("price_rules/{}/batch/{}.json", price_rule_id, batch_id),
+                &Params::default(),
+                "discount_code_creation",
+            )
+            .await
+    }
+
+    /// Query the discount codes of a batch, with the `errors` of those not created
+    pub async fn batch_discount_codes(
+        &self,
+        price_rule_id: u64,
+        batch_id: u64,
+    ) -> Result<Vec<DiscountCode>, ShopifyAPIError> {
+        self.shopify
+            .rest_get_item(
+                &format!(
+                    "price_rules/{}/batch/{}/discount_codes.json",
+                    price_rule_id, batch_id
+                ),
+                &Params::default(),
+                "discount_codes",
+            )
+            .await
+    }
+}
@@ -0,0 +1,422 @@
+//! Products, with their variants, options and images, through the REST `products.json` endpoints
+//!
+//! The models double as the bodies of the create and update queries: only the fields that are
+//! set are sent, so an update changes nothing else.
+
+use futures_util::Stream;
+use serde::{Deserialize, Serialize};
+
+use super::resource::Params;
+use crate::{
+    pagination::{Page, PageCursor},
+    Shopify, ShopifyAPIError,
+};
+
+/// Status of a product
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ProductStatus {
+    Active,
+    Archived,
+    Draft,
+}
+
+impl ProductStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ProductStatus::Active => "active",
+            ProductStatus::Archived => "archived",
+            ProductStatus::Draft => "draft",
+        }
+    }
+}
+
+/// Product of the shop
+///
+/// # Example
+/// ```
+/// use shopify_api::rest::products::{Product, ProductStatus, Variant};
+///
+/// let product = Product {
+///     title: Some("Burton Custom Freestyle 151".to_string()),
+///     vendor: Some("Burton".to_string()),
+///     status: Some(ProductStatus::Draft),
+///     variants: vec![Variant {
+///         option1: Some("151cm".to_string()),
+///         price: Some("299.00".to_string()),
+///         ..Variant::default()
+///     }],
+///     ..Product::default()
+/// };
+///
+/// assert_eq!(
+///     serde_json::to_value(&product).unwrap(),
+///     serde_json::json!({
+///         "title": "Burton Custom Freestyle 151",
+///         "vendor": "Burton",
+///         "status": "draft",
+///         "variants": [{ "option1": "151cm", "price": "299.00" }]
+///     })
+/// );
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct Product {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// Description of the product, in HTML
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub body_html: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vendor: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub product_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub handle: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<ProductStatus>,
+    /// Comma-separated tags, e.g. `"Barnes & Noble, Big Air"`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tags: Option<String>,
+    /// `web` to publish on the online store only, `global` to publish on every channel
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub published_scope: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub template_suffix: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub published_at: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub admin_graphql_api_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub variants: Vec<Variant>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub options: Vec<ProductOption>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub images: Vec<ProductImage>,
+    /// Main image of the product
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub image: Option<ProductImage>,
+}
+
+/// Variant of a product
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct Variant {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub product_id: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// Price as a decimal string, e.g. `"19.99"`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub price: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub compare_at_price: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sku: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub barcode: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub position: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub option1: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub option2: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub option3: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub taxable: Option<bool>,
+    /// `deny` or `continue` selling when out of stock
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub inventory_policy: Option<String>,
+    /// `shopify` when the inventory is tracked by Shopify
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub inventory_management: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fulfillment_service: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub inventory_item_id: Option<u64>,
+    /// Available quantity summed over the locations, read only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub inventory_quantity: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub grams: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub weight: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub weight_unit: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub requires_shipping: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub image_id: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub admin_graphql_api_id: Option<String>,
+}
+
+/// Option of a product, e.g. `Size` with its values
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProductOption {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub product_id: Option<u64>,
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub position: Option<u32>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub values: Vec<String>,
+}
+
+/// Image of a product
+///
+/// An image is created from the URL of its `src`, or from its contents encoded in base64 in
+/// `attachment` with a `filename`.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProductImage {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub product_id: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub position: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub src: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub alt: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub width: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub height: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attachment: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filename: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub variant_ids: Vec<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<String>,
+}
+
+/// Filters of [`Products::list`], [`Products::stream`] and [`Products::count`]
+///
+/// # Example
+/// ```
+/// use shopify_api::rest::products::{ProductListParams, ProductStatus};
+///
+/// let params = ProductListParams::new()
+///     .status(ProductStatus::Active)
+///     .vendor("Burton")
+///     .limit(250);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ProductListParams {
+    params: Params,
+}
+
+impl ProductListParams {
+    pub fn new() -> ProductListParams {
+        ProductListParams::default()
+    }
+
+    /// Set the number of products of each page, 50 by default and at most 250
+    pub fn limit(mut self, limit: u32) -> Self {
+        self.params.set("limit", limit);
+        self
+    }
+
+    pub fn ids(mut self, ids: &[u64]) -> Self {
+        self.params.set_list("ids", ids);
+        self
+    }
+
+    /// Only list the products with an ID greater than `since_id`
+    pub fn since_id(mut self, since_id: u64) -> Self {
+        self.params.set("since_id", since_id);
+        self
+    }
+
+    pub fn title(mut self, title: &str) -> Self {
+        self.params.set("title", title);
+        self
+    }
+
+    pub fn vendor(mut self, vendor: &str) -> Self {
+        self.params.set("vendor", vendor);
+        self
+    }
+
+    pub fn handles(mut self, handles: &[&str]) -> Self {
+        self.params.set_list("handle", handles);
+        self
+    }
+
+    pub fn product_type(mut self, product_type: &str) -> Self {
+        self.params.set("product_type", product_type);
+        self
+    }
+
+    pub fn status(mut self, status: ProductStatus) -> Self {
+        self.params.set("status", status.as_str());
+        self
+    }
+
+    /// Only list the products of a collection
+    pub fn collection_id(mut self, collection_id: u64) -> Self {
+        self.params.set("collection_id", collection_id);
+        self
+    }
+
+    /// `published`, `unpublished` or `any` (the default)
+    pub fn published_status(mut self, published_status: &str) -> Self {
+        self.params.set("published_status", published_status);
+        self
+    }
+
+    pub fn created_at_min(mut self, created_at_min: chrono::DateTime<chrono::Utc>) -> Self {
+        self.params.set_time("created_at_min", created_at_min);
+        self
+    }
+
+    pub fn created_at_max(mut self, created_at_max: chrono::DateTime<chrono::Utc>) -> Self {
+        self.params.set_time("created_at_max", created_at_max);
+        self
+    }
+
+    pub fn updated_at_min(mut self, updated_at_min: chrono::DateTime<chrono::Utc>) -> Self {
+        self.params.set_time("updated_at_min", updated_at_min);
+        self
+    }
+
+    pub fn updated_at_max(mut self, updated_at_max: chrono::DateTime<chrono::Utc>) -> Self {
+        self.params.set_time("updated_at_max", updated_at_max);
+        self
+    }
+
+    /// Only return the given fields of each product
+    pub fn fields(mut self, fields: &[&str]) -> Self {
+        self.params.set_list("fields", fields);
+        self
+    }
+}
+
+/// Products of a shop, returned by [`Shopify::products`]
+#[derive(Clone, Copy, Debug)]
+pub struct Products<'a> {
+    shopify: &'a Shopify,
+}
+
+impl Shopify {
+    /// Query the products of the shop
+    ///
+    /// # Example
+    /// ```no_run
+    /// use futures_util::StreamExt;
+    /// use shopify_api::*;
+    /// use shopify_api::rest::products::{Product, ProductListParams, ProductStatus};
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let shopify = Shopify::new("myshop", "myapikey", ShopifyAPIVersion::V2026_10, None);
+    ///
+    ///     let product = shopify
+    ///         .products()
+    ///         .create(&Product {
+    ///             title: Some("Burton Custom Freestyle 151".to_string()),
+    ///             ..Product::default()
+    ///         })
+    ///         .await
+    ///         .unwrap();
+    ///     let id = product.id.unwrap();
+    ///
+    ///     shopify
+    ///         .products()
+    ///         .update(id, &Product { status: Some(ProductStatus::Active), ..Product::default() })
+    ///         .await
+    ///         .unwrap();
+    ///
+    ///     let params = ProductListParams::new().status(ProductStatus::Active).limit(250);
+    ///     let mut products = shopify.products().stream(&params);
+    ///     while let Some(product) = products.next().await {
+    ///         println!("{:?}", product.unwrap().title);
+    ///     }
+    ///
+    ///     shopify.products().delete(id).await.unwrap();
+    /// }
+    /// ```
+    pub fn products(&self) -> Products<'_> {
+        Products { shopify: self }
+    }
+}
+
+impl<'a> Products<'a> {
+    /// Query a page of products, starting from the first page without a cursor
+    pub async fn list(
+        &self,
+        params: &ProductListParams,
+        cursor: Option<&PageCursor>,
+    ) -> Result<Page<Product>, ShopifyAPIError> {
+        self.shopify
+            .rest_list("products.json", &params.params, cursor)
+            .await
+    }
+
+    /// Stream every product matching the filters, following the pages
+    pub fn stream(
+        &self,
+        params: &ProductListParams,
+    ) -> impl Stream<Item = Result<Product, ShopifyAPIError>> + Unpin + 'a {
+        self.shopify
+            .rest_list_stream("products.json".to_string(), params.params.clone())
+    }
+
+    pub async fn get(&self, id: u64) -> Result<Product, ShopifyAPIError> {
+        self.shopify
+            .rest_get_item(
+                &format!("products/{}.json", id),
+                &Params::default(),
+                "product",
+            )
+            .await
+    }
+
+    /// Count the products matching the filters, `limit`, `since_id` and `fields` being ignored
+    pub async fn count(&self, params: &ProductListParams) -> Result<u64, ShopifyAPIError> {
+        self.shopify
+            .rest_count("products/count.json", &params.params)
+            .await
+    }
+
+    /// Create a product, with its variants, options and images
+    pub async fn create(&self, product: &Product) -> Result<Product, ShopifyAPIError> {
+        self.shopify
+            .rest_post_item("products.json", "product", product)
+            .await
+    }
+
+    /// Update the fields set on `product`
+    ///
+    /// The variants, options and images given replace the existing ones, so leave them empty to
+    /// keep them.
+    pub async fn update(&self, id: u64, product: &Product) -> Result<Product, ShopifyAPIError> {
+        self.shopify
+            .rest_put_item(&format!("products/{}.json", id), "product", product)
+            .await
+    }
+
+    pub async fn delete(&self, id: u64) -> Result<(), ShopifyAPIError> {
+        self.shopify
+            .rest_delete_item(&format!("products/{}.json", id))
+            .await
+    }
+}
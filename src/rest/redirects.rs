@@ -0,0 +1,257 @@
+//! URL redirects, through the REST `redirects.json` endpoints
+//!
+//! A redirect sends the visitors of an old path of the online store to a new one, e.g. after a
+//! migration from another platform. [`Redirects::import`] creates thousands of them in chunks,
+//! staying under the REST rate limits of the shop.
+
+use futures_util::{future::join_all, Stream};
+use serde::{Deserialize, Serialize};
+
+use super::resource::Params;
+use crate::{
+    pagination::{Page, PageCursor},
+    rate_limit::ShopifyPlan,
+    Shopify, ShopifyAPIError,
+};
+
+/// Redirect of the online store
+///
+/// # Example
+/// ```
+/// use shopify_api::rest::redirects::Redirect;
+///
+/// let redirect = Redirect::new("/ipod", "/pages/itunes");
+///
+/// assert_eq!(
+///     serde_json::to_value(&redirect).unwrap(),
+///     serde_json::json!({ "path": "/ipod", "target": "/pages/itunes" })
+/// );
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Redirect {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<u64>,
+    /// Old path, e.g. `/ipod`, unique in the shop
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+    /// New path or full URL, e.g. `/pages/itunes` or `https://example.com`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target: Option<String>,
+}
+
+impl Redirect {
+    pub fn new(path: &str, target: &str) -> Redirect {
+        Redirect {
+            id: None,
+            path: Some(path.to_string()),
+            target: Some(target.to_string()),
+        }
+    }
+}
+
+/// Result of [`Redirects::import`]
+#[derive(Debug, Default)]
+pub struct RedirectImport {
+    /// Redirects created, in the order given
+    pub created: Vec<Redirect>,
+    /// Redirects rejected, with the error of their creation, e.g. when their path is taken
+    pub failed: Vec<(Redirect, ShopifyAPIError)>,
+}
+
+impl RedirectImport {
+    /// Whether every redirect was created
+    pub fn is_complete(&self) -> bool {
+        self.failed.is_empty()
+    }
+}
+
+/// Filters of [`Redirects::list`], [`Redirects::stream`] and [`Redirects::count`]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct RedirectListParams {
+    params: Params,
+}
+
+impl RedirectListParams {
+    pub fn new() -> RedirectListParams {
+        RedirectListParams::default()
+    }
+
+    /// Set the number of redirects of each page, 50 by default and at most 250
+    pub fn limit(mut self, limit: u32) -> Self {
+        self.params.set("limit", limit);
+        self
+    }
+
+    /// Only list the redirects with an ID greater than `since_id`
+    pub fn since_id(mut self, since_id: u64) -> Self {
+        self.params.set("since_id", since_id);
+        self
+    }
+
+    pub fn path(mut self, path: &str) -> Self {
+        self.params.set("path", path);
+        self
+    }
+
+    pub fn target(mut self, target: &str) -> Self {
+        self.params.set("target", target);
+        self
+    }
+
+    /// Only return the given fields of each redirect
+    pub fn fields(mut self, fields: &[&str]) -> Self {
+        self.params.set_list("fields", fields);
+        self
+    }
+}
+
+/// Redirects of the online store, returned by [`Shopify::redirects`]
+#[derive(Clone, Copy, Debug)]
+pub struct Redirects<'a> {
+    shopify: &'a Shopify,
+}
+
+impl Shopify {
+    /// Query and manage the URL redirects of the online store
+    ///
+    /// # Example
+    /// ```no_run
+    /// use shopify_api::*;
+    /// use shopify_api::rest::redirects::{Redirect, RedirectListParams};
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let shopify = Shopify::new("myshop", "myapikey", ShopifyAPIVersion::V2026_10, None);
+    ///     shopify
+    ///         .redirects()
+    ///         .create(&Redirect::new("/ipod", "/pages/itunes"))
+    ///         .await
+    ///         .unwrap();
+    ///
+    ///     let params = RedirectListParams::new().target("/pages/itunes");
+    ///     let count = shopify.redirects().count(&params).await.unwrap();
+    /// }
+    /// ```
+    pub fn redirects(&self) -> Redirects<'_> {
+        Redirects { shopify: self }
+    }
+}
+
+impl<'a> Redirects<'a> {
+    /// Query a page of redirects, starting from the first page without a cursor
+    pub async fn list(
+        &self,
+        params: &RedirectListParams,
+        cursor: Option<&PageCursor>,
+    ) -> Result<Page<Redirect>, ShopifyAPIError> {
+        self.shopify
+            .rest_list("redirects.json", &params.params, cursor)
+            .await
+    }
+
+    /// Stream every redirect matching the filters, following the pages
+    pub fn stream(
+        &self,
+        params: &RedirectListParams,
+    ) -> impl Stream<Item = Result<Redirect, ShopifyAPIError>> + Unpin + 'a {
+        self.shopify
+            .rest_list_stream("redirects.json".to_string(), params.params.clone())
+    }
+
+    pub async fn get(&self, id: u64) -> Result<Redirect, ShopifyAPIError> {
+        self.shopify
+            .rest_get_item(
+                &format!("redirects/{}.json", id),
+                &Params::default(),
+                "redirect",
+            )
+            .await
+    }
+
+    /// Count the redirects matching the filters, only `path` and `target` being used
+    pub async fn count(&self, params: &RedirectListParams) -> Result<u64, ShopifyAPIError> {
+        self.shopify
+            .rest_count("redirects/count.json", &params.params)
+            .await
+    }
+
+    /// Create a redirect, the path of a full URL being kept
+    pub async fn create(&self, redirect: &Redirect) -> Result<Redirect, ShopifyAPIError> {
+        self.shopify
+            .rest_post_item("redirects.json", "redirect", redirect)
+            .await
+    }
+
+    /// Update the path or the target of a redirect
+    pub async fn update(&self, id: u64, redirect: &Redirect) -> Result<Redirect, ShopifyAPIError> {
+        self.shopify
+            .rest_put_item(&format!("redirects/{}.json", id), "redirect", redirect)
+            .await
+    }
+
+    pub async fn delete(&self, id: u64) -> Result<(), ShopifyAPIError> {
+        self.shopify
+            .rest_delete_item(&format!("redirects/{}.json", id))
+            .await
+    }
+
+    /// Create many redirects, e.g. the old URLs of a migrated store
+    ///
+    /// The redirects are sent in chunks of the size of the REST bucket of the plan of the shop
+    /// (see [`Shopify::with_plan`]), each chunk waiting for the previous one. Without a rate
+    /// limiter set on the client, the requests go through one matching the plan, so the import
+    /// doesn't run into 429 responses. A rejected redirect doesn't stop the import: it is
+    /// returned in [`RedirectImport::failed`].
+    /// # Example
+    /// ```no_run
+    /// use shopify_api::*;
+    /// use shopify_api::rest::redirects::Redirect;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let shopify = Shopify::new("myshop", "myapikey", ShopifyAPIVersion::V2026_10, None)
+    ///         .with_plan(rate_limit::ShopifyPlan::Plus);
+    ///     let redirects: Vec<Redirect> = (1..=5000)
+    ///         .map(|id| Redirect::new(&format!("/old/{}", id), &format!("/products/{}", id)))
+    ///         .collect();
+    ///
+    ///     let import = shopify.redirects().import(&redirects).await;
+    ///     for (redirect, error) in &import.failed {
+    ///         println!("{:?}: {}", redirect.path, error);
+    ///     }
+    /// }
+    /// ```
+    pub async fn import(&self, redirects: &[Redirect]) -> RedirectImport {
+        let plan = self.shopify.get_plan().unwrap_or(ShopifyPlan::Standard);
+        let rate_limiter = match self.shopify.rate_limiter {
+            Some(_) => None,
+            None => Some(plan.rate_limiter()),
+        };
+        let chunk_size = plan.rest_bucket_size() as usize;
+
+        let mut import = RedirectImport::default();
+        for (index, chunk) in redirects.chunks(chunk_size).enumerate() {
+            let results = join_all(chunk.iter().map(|redirect| async {
+                if let Some(rate_limiter) = &rate_limiter {
+                    rate_limiter.acquire().await;
+                }
+                self.create(redirect).await
+            }))
+            .await;
+
+            for (redirect, result) in chunk.iter().zip(results) {
+                match result {
+                    Ok(created) => import.created.push(created),
+                    Err(error) => import.failed.push((redirect.clone(), error)),
+                }
+            }
+            log::debug!(
+                "shopify redirects import: {} of {} sent",
+                (index * chunk_size + chunk.len()),
+                redirects.len()
+            );
+        }
+
+        import
+    }
+}
@@ -0,0 +1,206 @@
+//! Queries shared by the typed REST resources, e.g. [`crate::rest::products`]
+//!
+//! Shopify wraps every REST resource in a root key, e.g. `{"product": {...}}`: the bodies are
+//! wrapped and the responses unwrapped here, so the resources only deal with their models.
+
+use std::collections::HashMap;
+
+use futures_util::Stream;
+use serde::{de::DeserializeOwned, Serialize};
+
+use crate::{
+    options::RequestOptions,
+    pagination::{Page, PageCursor, PageStream},
+    rest::ShopifyAPIRestType,
+    utils::ReadJsonTreeSteps,
+    Shopify, ShopifyAPIError,
+};
+
+/// Query parameters of a typed REST query, in the order they were set
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub(crate) struct Params(Vec<(&'static str, String)>);
+
+impl Params {
+    /// Set a parameter, replacing its previous value
+    pub(crate) fn set(&mut self, name: &'static str, value: impl ToString) {
+        let value = value.to_string();
+        match self.0.iter_mut().find(|(param, _)| *param == name) {
+            Some(param) => param.1 = value,
+            None => self.0.push((name, value)),
+        }
+    }
+
+    /// Set a parameter taking a comma-separated list, e.g. `ids`
+    pub(crate) fn set_list<T: ToString>(
+        &mut self,
+        name: &'static str,
+        values: impl IntoIterator<Item = T>,
+    ) {
+        let values: Vec<String> = values.into_iter().map(|value| value.to_string()).collect();
+        self.set(name, values.join(","));
+    }
+
+    /// Set a date parameter, e.g. `created_at_min`, in the ISO 8601 format expected by Shopify
+    pub(crate) fn set_time(&mut self, name: &'static str, time: chrono::DateTime<chrono::Utc>) {
+        self.set(name, time.to_rfc3339());
+    }
+
+    pub(crate) fn to_map(&self) -> HashMap<&str, &str> {
+        self.0
+            .iter()
+            .map(|(name, value)| (*name, value.as_str()))
+            .collect()
+    }
+}
+
+/// Wrap a body in the root key of its resource
+fn wrap<BodyType: Serialize>(
+    key: &str,
+    body: &BodyType,
+) -> Result<serde_json::Value, ShopifyAPIError> {
+    let mut root = serde_json::Map::new();
+    root.insert(key.to_string(), serde_json::to_value(body)?);
+
+    Ok(serde_json::Value::Object(root))
+}
+
+impl Shopify {
+    /// Get a resource, read from the given root key of the response
+    pub(crate) async fn rest_get_item<ReturnType>(
+        &self,
+        endpoint: &str,
+        params: &Params,
+        key: &str,
+    ) -> Result<ReturnType, ShopifyAPIError>
+    where
+        ReturnType: DeserializeOwned,
+    {
+        self.rest_query(
+            &ShopifyAPIRestType::Get(endpoint, &params.to_map()),
+            &Some(vec![ReadJsonTreeSteps::Key(key)]),
+        )
+        .await
+    }
+
+    /// Create a resource with a POST, the body and the response being wrapped in the root key
+    pub(crate) async fn rest_post_item<ReturnType, BodyType>(
+        &self,
+        endpoint: &str,
+        key: &str,
+        body: &BodyType,
+    ) -> Result<ReturnType, ShopifyAPIError>
+    where
+        ReturnType: DeserializeOwned,
+        BodyType: Serialize,
+    {
+        self.rest_query(
+            &ShopifyAPIRestType::Post(endpoint, &HashMap::new(), &wrap(key, body)?),
+            &Some(vec![ReadJsonTreeSteps::Key(key)]),
+        )
+        .await
+    }
+
+    /// Update a resource with a PUT, the body and the response being wrapped in the root key
+    pub(crate) async fn rest_put_item<ReturnType, BodyType>(
+        &self,
+        endpoint: &str,
+        key: &str,
+        body: &BodyType,
+    ) -> Result<ReturnType, ShopifyAPIError>
+    where
+        ReturnType: DeserializeOwned,
+        BodyType: Serialize,
+    {
+        self.rest_query(
+            &ShopifyAPIRestType::Put(endpoint, &HashMap::new(), &wrap(key, body)?),
+            &Some(vec![ReadJsonTreeSteps::Key(key)]),
+        )
+        .await
+    }
+
+    /// Run an action on a resource with a POST, e.g. `orders/1/close.json`, the response being
+    /// read from the root key
+    pub(crate) async fn rest_post_action<ReturnType>(
+        &self,
+        endpoint: &str,
+        body: &serde_json::Value,
+        key: &str,
+    ) -> Result<ReturnType, ShopifyAPIError>
+    where
+        ReturnType: DeserializeOwned,
+    {
+        self.rest_query(
+            &ShopifyAPIRestType::Post(endpoint, &HashMap::new(), body),
+            &Some(vec![ReadJsonTreeSteps::Key(key)]),
+        )
+        .await
+    }
+
+    /// Delete a resource, ignoring the body of the response
+    pub(crate) async fn rest_delete_item(&self, endpoint: &str) -> Result<(), ShopifyAPIError> {
+        self.rest_query::<serde_json::Value>(
+            &ShopifyAPIRestType::Delete(endpoint, &HashMap::new()),
+            &None,
+        )
+        .await?;
+
+        Ok(())
+    }
+
+    /// Count the resources of a `count.json` endpoint
+    pub(crate) async fn rest_count(
+        &self,
+        endpoint: &str,
+        params: &Params,
+    ) -> Result<u64, ShopifyAPIError> {
+        self.rest_get_item(endpoint, params, "count").await
+    }
+
+    /// Query a page of a list endpoint, see [`Shopify::rest_page`]
+    pub(crate) async fn rest_list<ItemType>(
+        &self,
+        endpoint: &str,
+        params: &Params,
+        cursor: Option<&PageCursor>,
+    ) -> Result<Page<ItemType>, ShopifyAPIError>
+    where
+        ItemType: DeserializeOwned,
+    {
+        self.rest_list_with_options(endpoint, params, cursor, &RequestOptions::new())
+            .await
+    }
+
+    /// Query a page of a list endpoint with per-request options, see
+    /// [`Shopify::rest_page_with_options`]
+    pub(crate) async fn rest_list_with_options<ItemType>(
+        &self,
+        endpoint: &str,
+        params: &Params,
+        cursor: Option<&PageCursor>,
+        options: &RequestOptions,
+    ) -> Result<Page<ItemType>, ShopifyAPIError>
+    where
+        ItemType: DeserializeOwned,
+    {
+        self.rest_page_with_options(endpoint, &params.to_map(), cursor, options)
+            .await
+    }
+
+    /// Stream every item of a list endpoint, see [`Shopify::rest_stream`]
+    pub(crate) fn rest_list_stream<'a, ItemType>(
+        &'a self,
+        endpoint: String,
+        params: Params,
+    ) -> impl Stream<Item = Result<ItemType, ShopifyAPIError>> + Unpin + 'a
+    where
+        ItemType: DeserializeOwned + 'a,
+    {
+        PageStream::new(
+            move |cursor: Option<PageCursor>| {
+                let (endpoint, params) = (endpoint.clone(), params.clone());
+                async move { self.rest_list(&endpoint, &params, cursor.as_ref()).await }
+            },
+            0,
+        )
+    }
+}
@@ -0,0 +1,248 @@
+//! Script tags, through the REST `script_tags.json` endpoints
+//!
+//! Script tags load a remote script in the storefront or the order status page of a shop. They
+//! only work with vintage themes: new apps should use theme app extensions, or web pixels from
+//! [`crate::graphql::web_pixels`] for tracking.
+
+use futures_util::Stream;
+use serde::{Deserialize, Serialize};
+
+use super::resource::Params;
+use crate::{
+    pagination::{Page, PageCursor},
+    Shopify, ShopifyAPIError,
+};
+
+/// DOM event on which a script tag is loaded
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ScriptTagEvent {
+    #[default]
+    Onload,
+}
+
+/// Pages on which a script tag is loaded
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ScriptTagDisplayScope {
+    OnlineStore,
+    OrderStatus,
+    All,
+}
+
+/// Script tag of the app
+///
+/// # Example
+/// ```
+/// use shopify_api::rest::script_tags::{ScriptTag, ScriptTagDisplayScope};
+///
+/// let script_tag = ScriptTag::new("https://example.com/widget.js")
+///     .display_scope(ScriptTagDisplayScope::OnlineStore)
+///     .cache(true);
+///
+/// assert_eq!(
+///     serde_json::to_value(&script_tag).unwrap(),
+///     serde_json::json!({
+///         "src": "https://example.com/widget.js",
+///         "event": "onload",
+///         "display_scope": "online_store",
+///         "cache": true,
+///     })
+/// );
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ScriptTag {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<u64>,
+    /// HTTPS URL of the script
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub src: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub event: Option<ScriptTagEvent>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub display_scope: Option<ScriptTagDisplayScope>,
+    /// Whether the script is served from Shopify's CDN, updates of the script being ignored
+    /// until the script tag is updated
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cache: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<String>,
+}
+
+impl ScriptTag {
+    /// New script tag loading `src` when the page loads
+    pub fn new(src: &str) -> ScriptTag {
+        ScriptTag {
+            src: Some(src.to_string()),
+            event: Some(ScriptTagEvent::Onload),
+            ..ScriptTag::default()
+        }
+    }
+
+    /// Set the pages on which the script is loaded, the online store by default
+    pub fn display_scope(mut self, display_scope: ScriptTagDisplayScope) -> Self {
+        self.display_scope = Some(display_scope);
+        self
+    }
+
+    pub fn cache(mut self, cache: bool) -> Self {
+        self.cache = Some(cache);
+        self
+    }
+}
+
+/// Filters of [`ScriptTags::list`], [`ScriptTags::stream`] and [`ScriptTags::count`]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ScriptTagListParams {
+    params: Params,
+}
+
+impl ScriptTagListParams {
+    pub fn new() -> ScriptTagListParams {
+        ScriptTagListParams::default()
+    }
+
+    /// Set the number of script tags of each page, 50 by default and at most 250
+    pub fn limit(mut self, limit: u32) -> Self {
+        self.params.set("limit", limit);
+        self
+    }
+
+    /// Only list the script tags with an ID greater than `since_id`
+    pub fn since_id(mut self, since_id: u64) -> Self {
+        self.params.set("since_id", since_id);
+        self
+    }
+
+    pub fn src(mut self, src: &str) -> Self {
+        self.params.set("src", src);
+        self
+    }
+
+    pub fn created_at_min(mut self, created_at_min: chrono::DateTime<chrono::Utc>) -> Self {
+        self.params.set_time("created_at_min", created_at_min);
+        self
+    }
+
+    pub fn created_at_max(mut self, created_at_max: chrono::DateTime<chrono::Utc>) -> Self {
+        self.params.set_time("created_at_max", created_at_max);
+        self
+    }
+
+    pub fn updated_at_min(mut self, updated_at_min: chrono::DateTime<chrono::Utc>) -> Self {
+        self.params.set_time("updated_at_min", updated_at_min);
+        self
+    }
+
+    pub fn updated_at_max(mut self, updated_at_max: chrono::DateTime<chrono::Utc>) -> Self {
+        self.params.set_time("updated_at_max", updated_at_max);
+        self
+    }
+
+    /// Only return the given fields of each script tag
+    pub fn fields(mut self, fields: &[&str]) -> Self {
+        self.params.set_list("fields", fields);
+        self
+    }
+}
+
+/// Script tags of the app, returned by [`Shopify::script_tags`]
+#[derive(Clone, Copy, Debug)]
+pub struct ScriptTags<'a> {
+    shopify: &'a Shopify,
+}
+
+impl Shopify {
+    /// Query and manage the script tags of the app on the shop
+    ///
+    /// # Example
+    /// ```no_run
+    /// use shopify_api::*;
+    /// use shopify_api::rest::script_tags::{ScriptTag, ScriptTagDisplayScope};
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let shopify = Shopify::new("myshop", "myapikey", ShopifyAPIVersion::V2026_10, None);
+    ///     let script_tag = ScriptTag::new("https://example.com/widget.js")
+    ///         .display_scope(ScriptTagDisplayScope::All);
+    ///
+    ///     let script_tag = shopify.script_tags().create(&script_tag).await.unwrap();
+    ///     shopify
+    ///         .script_tags()
+    ///         .delete(script_tag.id.unwrap())
+    ///         .await
+    ///         .unwrap();
+    /// }
+    /// ```
+    pub fn script_tags(&self) -> ScriptTags<'_> {
+        ScriptTags { shopify: self }
+    }
+}
+
+impl<'a> ScriptTags<'a> {
+    /// Query a page of script tags, starting from the first page without a cursor
+    pub async fn list(
+        &self,
+        params: &ScriptTagListParams,
+        cursor: Option<&PageCursor>,
+    ) -> Result<Page<ScriptTag>, ShopifyAPIError> {
+        self.shopify
+            .rest_list("script_tags.json", &params.params, cursor)
+            .await
+    }
+
+    /// Stream every script tag matching the filters, following the pages
+    pub fn stream(
+        &self,
+        params: &ScriptTagListParams,
+    ) -> impl Stream<Item = Result<ScriptTag, ShopifyAPIError>> + Unpin + 'a {
+        self.shopify
+            .rest_list_stream("script_tags.json".to_string(), params.params.clone())
+    }
+
+    pub async fn get(&self, id: u64) -> Result<ScriptTag, ShopifyAPIError> {
+        self.shopify
+            .rest_get_item(
+                &format!("script_tags/{}.json", id),
+                &Params::default(),
+                "script_tag",
+            )
+            .await
+    }
+
+    /// Count the script tags matching the filters, only `src` being used
+    pub async fn count(&self, params: &ScriptTagListParams) -> Result<u64, ShopifyAPIError> {
+        self.shopify
+            .rest_count("script_tags/count.json", &params.params)
+            .await
+    }
+
+    pub async fn create(&self, script_tag: &ScriptTag) -> Result<ScriptTag, ShopifyAPIError> {
+        self.shopify
+            .rest_post_item("script_tags.json", "script_tag", script_tag)
+            .await
+    }
+
+    /// Update the fields set on `script_tag`
+    pub async fn update(
+        &self,
+        id: u64,
+        script_tag: &ScriptTag,
+    ) -> Result<ScriptTag, ShopifyAPIError> {
+        self.shopify
+            .rest_put_item(
+                &format!("script_tags/{}.json", id),
+                "script_tag",
+                script_tag,
+            )
+            .await
+    }
+
+    pub async fn delete(&self, id: u64) -> Result<(), ShopifyAPIError> {
+        self.shopify
+            .rest_delete_item(&format!("script_tags/{}.json", id))
+            .await
+    }
+}
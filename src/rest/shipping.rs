@@ -0,0 +1,374 @@
+//! Shipping zones and carrier services, through the REST `shipping_zones.json` and
+//! `carrier_services.json` endpoints
+//!
+//! A carrier service makes Shopify ask the `callback_url` of the app for the shipping rates at
+//! checkout: the request is read as a [`ShippingRateRequest`], and answered with a
+//! [`ShippingRatesResponse`].
+
+use serde::{Deserialize, Serialize};
+
+use super::{countries::Country, resource::Params};
+use crate::{Shopify, ShopifyAPIError};
+
+/// Rate of a shipping zone depending on the weight of the order
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct WeightBasedShippingRate {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub price: Option<String>,
+    /// Minimum weight of the order, in kilograms
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub weight_low: Option<f64>,
+    /// Maximum weight of the order, in kilograms
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub weight_high: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shipping_zone_id: Option<u64>,
+}
+
+/// Rate of a shipping zone depending on the subtotal of the order
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PriceBasedShippingRate {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub price: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_order_subtotal: Option<String>,
+    /// Unset when the rate has no maximum
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_order_subtotal: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shipping_zone_id: Option<u64>,
+}
+
+/// Rates of a carrier service offered in a shipping zone
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct CarrierShippingRateProvider {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub carrier_service_id: Option<u64>,
+    /// Amount added to the rates, e.g. `"2.00"`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub flat_modifier: Option<String>,
+    /// Percentage added to the rates, e.g. `10.0`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub percent_modifier: Option<f64>,
+    /// Services of the carrier offered in the zone, by service code
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub service_filter: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shipping_zone_id: Option<u64>,
+}
+
+/// Shipping zone of the shop: the countries it covers and their rates
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct ShippingZone {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// Shipping profile of the zone
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub profile_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub location_group_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub countries: Vec<Country>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub weight_based_shipping_rates: Vec<WeightBasedShippingRate>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub price_based_shipping_rates: Vec<PriceBasedShippingRate>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub carrier_shipping_rate_providers: Vec<CarrierShippingRateProvider>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub admin_graphql_api_id: Option<String>,
+}
+
+/// Kind of a carrier service
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CarrierServiceType {
+    /// Carrier service of an app
+    Api,
+    /// Carrier service built into Shopify, e.g. UPS
+    Legacy,
+    #[serde(other)]
+    Unknown,
+}
+
+/// Carrier service of the app, returning shipping rates from its callback URL
+///
+/// # Example
+/// ```
+/// use shopify_api::rest::shipping::CarrierService;
+///
+/// let carrier_service = CarrierService::new("Acme Shipping", "https://example.com/rates")
+///     .service_discovery(true);
+///
+/// assert_eq!(
+///     serde_json::to_value(&carrier_service).unwrap(),
+///     serde_json::json!({
+///         "name": "Acme Shipping",
+///         "callback_url": "https://example.com/rates",
+///         "service_discovery": true,
+///     })
+/// );
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CarrierService {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<u64>,
+    /// Name shown to the merchant
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// URL receiving the [`ShippingRateRequest`] at checkout
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub callback_url: Option<String>,
+    /// Whether the merchant can see sample rates of the service in the shipping settings
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub service_discovery: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub active: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub carrier_service_type: Option<CarrierServiceType>,
+    /// Format of the rate requests, `json`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub format: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub admin_graphql_api_id: Option<String>,
+}
+
+impl CarrierService {
+    pub fn new(name: &str, callback_url: &str) -> CarrierService {
+        CarrierService {
+            name: Some(name.to_string()),
+            callback_url: Some(callback_url.to_string()),
+            ..CarrierService::default()
+        }
+    }
+
+    /// Set whether the merchant can see sample rates of the service
+    pub fn service_discovery(mut self, service_discovery: bool) -> Self {
+        self.service_discovery = Some(service_discovery);
+        self
+    }
+
+    pub fn active(mut self, active: bool) -> Self {
+        self.active = Some(active);
+        self
+    }
+}
+
+/// Origin or destination of a [`ShippingRateRequest`]
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ShippingRateAddress {
+    pub country: Option<String>,
+    pub postal_code: Option<String>,
+    pub province: Option<String>,
+    pub city: Option<String>,
+    pub name: Option<String>,
+    pub address1: Option<String>,
+    pub address2: Option<String>,
+    pub address3: Option<String>,
+    pub phone: Option<String>,
+    pub fax: Option<String>,
+    pub email: Option<String>,
+    pub address_type: Option<String>,
+    pub company_name: Option<String>,
+}
+
+/// Item of a [`ShippingRateRequest`]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct ShippingRateItem {
+    pub name: Option<String>,
+    pub sku: Option<String>,
+    pub quantity: Option<u32>,
+    /// Weight of one item, in grams
+    pub grams: Option<u64>,
+    /// Price of one item, in the subunit of the currency, e.g. cents
+    pub price: Option<u64>,
+    pub vendor: Option<String>,
+    pub requires_shipping: Option<bool>,
+    pub taxable: Option<bool>,
+    pub fulfillment_service: Option<String>,
+    #[serde(default)]
+    pub properties: Option<serde_json::Value>,
+    pub product_id: Option<u64>,
+    pub variant_id: Option<u64>,
+}
+
+/// Request sent by Shopify to the callback URL of a carrier service
+///
+/// # Example
+/// ```
+/// use shopify_api::rest::shipping::ShippingRateRequest;
+///
+/// let body = r#"{
+///     "rate": {
+///         "origin": { "country": "CA", "postal_code": "K2P1L4" },
+///         "destination": { "country": "CA", "postal_code": "K1M1M4" },
+///         "items": [{ "name": "Socks", "quantity": 2, "grams": 200, "price": 1500 }],
+///         "currency": "CAD",
+///         "locale": "en"
+///     }
+/// }"#;
+///
+/// let request = ShippingRateRequest::from_body(body).unwrap();
+/// assert_eq!(request.total_grams(), 400);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct ShippingRateRequest {
+    pub origin: ShippingRateAddress,
+    pub destination: ShippingRateAddress,
+    #[serde(default)]
+    pub items: Vec<ShippingRateItem>,
+    pub currency: Option<String>,
+    pub locale: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct ShippingRateRequestBody {
+    rate: ShippingRateRequest,
+}
+
+impl ShippingRateRequest {
+    /// Read the body of a request sent to the callback URL, wrapped in `rate`
+    pub fn from_body(body: &str) -> Result<ShippingRateRequest, ShopifyAPIError> {
+        let body: ShippingRateRequestBody = serde_json::from_str(body)?;
+        Ok(body.rate)
+    }
+
+    /// Total weight of the items, in grams
+    pub fn total_grams(&self) -> u64 {
+        self.items
+            .iter()
+            .map(|item| item.grams.unwrap_or(0) * u64::from(item.quantity.unwrap_or(0)))
+            .sum()
+    }
+}
+
+/// Shipping rate returned by the callback URL of a carrier service
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ShippingRate {
+    /// Name shown to the customer, e.g. `Express`
+    pub service_name: String,
+    /// Unique code of the service, e.g. `EXP`
+    pub service_code: String,
+    /// Price in the subunit of the currency, e.g. `"1295"` for 12.95
+    pub total_price: String,
+    pub currency: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_delivery_date: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_delivery_date: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub phone_required: Option<bool>,
+}
+
+/// Body of the response of the callback URL of a carrier service
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ShippingRatesResponse {
+    pub rates: Vec<ShippingRate>,
+}
+
+/// Carrier services of the app, returned by [`Shopify::carrier_services`]
+#[derive(Clone, Copy, Debug)]
+pub struct CarrierServices<'a> {
+    shopify: &'a Shopify,
+}
+
+impl Shopify {
+    /// Get the shipping zones of the shop, with their countries and rates
+    pub async fn shipping_zones(&self) -> Result<Vec<ShippingZone>, ShopifyAPIError> {
+        self.rest_get_item("shipping_zones.json", &Params::default(), "shipping_zones")
+            .await
+    }
+
+    /// Query and manage the carrier services of the app
+    ///
+    /// # Example
+    /// ```no_run
+    /// use shopify_api::*;
+    /// use shopify_api::rest::shipping::CarrierService;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let shopify = Shopify::new("myshop", "myapikey", ShopifyAPIVersion::V2026_10, None);
+    ///     let carrier_service = CarrierService::new("Acme Shipping", "https://example.com/rates")
+    ///         .service_discovery(true);
+    ///
+    ///     let carrier_service = shopify
+    ///         .carrier_services()
+    ///         .create(&carrier_service)
+    ///         .await
+    ///         .unwrap();
+    ///     println!("Registered: {:?}", carrier_service.id);
+    /// }
+    /// ```
+    pub fn carrier_services(&self) -> CarrierServices<'_> {
+        CarrierServices { shopify: self }
+    }
+}
+
+impl<'a> CarrierServices<'a> {
+    /// Get every carrier service of the shop
+    pub async fn list(&self) -> Result<Vec<CarrierService>, ShopifyAPIError> {
+        self.shopify
+            .rest_get_item(
+                "carrier_services.json",
+                &Params::default(),
+                "carrier_services",
+            )
+            .await
+    }
+
+    pub async fn get(&self, id: u64) -> Result<CarrierService, ShopifyAPIError> {
+        self.shopify
+            .rest_get_item(
+                &format!("carrier_services/{}.json", id),
+                &Params::default(),
+                "carrier_service",
+            )
+            .await
+    }
+
+    /// Create a carrier service, which requires the shop to have carrier-calculated shipping
+    pub async fn create(
+        &self,
+        carrier_service: &CarrierService,
+    ) -> Result<CarrierService, ShopifyAPIError> {
+        self.shopify
+            .rest_post_item("carrier_services.json", "carrier_service", carrier_service)
+            .await
+    }
+
+    /// Update the fields set on `carrier_service`
+    pub async fn update(
+        &self,
+        id: u64,
+        carrier_service: &CarrierService,
+    ) -> Result<CarrierService, ShopifyAPIError> {
+        self.shopify
+            .rest_put_item(
+                &format!("carrier_services/{}.json", id),
+                "carrier_service",
+                carrier_service,
+            )
+            .await
+    }
+
+    pub async fn delete(&self, id: u64) -> Result<(), ShopifyAPIError> {
+        self.shopify
+            .rest_delete_item(&format!("carrier_services/{}.json", id))
+            .await
+    }
+}
@@ -0,0 +1,224 @@
+//! Settings and policies of the shop, through the REST `shop.json` and `policies.json` endpoints
+//!
+//! Nearly every app reads the currency, timezone or domain of the shop again and again, so
+//! [`Shopify::shop`] keeps the shop on the client and its clones for a while, see
+//! [`Shopify::with_shop_cache_ttl`]. The legal policies of the shop are read with
+//! [`Shopify::policies`].
+
+use std::{sync::Mutex, time::Duration};
+
+use serde::{Deserialize, Serialize};
+
+use super::resource::Params;
+use crate::{utils, Shopify, ShopifyAPIError};
+
+/// Time a shop is kept by [`Shopify::shop`] by default
+pub const DEFAULT_SHOP_CACHE_TTL: Duration = Duration::from_secs(5 * 60);
+
+/// Settings of the shop
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Shop {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// Email of the shop, used to contact the merchant
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+    /// Email shown to the customers, the shop email when not set
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub customer_email: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shop_owner: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub phone: Option<String>,
+    /// Primary domain of the online store, e.g. `example.com`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub domain: Option<String>,
+    /// Permanent domain of the shop, e.g. `myshop.myshopify.com`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub myshopify_domain: Option<String>,
+    /// Default currency of the shop, e.g. `USD`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub currency: Option<String>,
+    /// Currencies the customers can pay in
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub enabled_presentment_currencies: Vec<String>,
+    /// Format of the prices, e.g. `${{amount}}`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub money_format: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub money_with_currency_format: Option<String>,
+    /// IANA name of the timezone of the shop, e.g. `America/New_York`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub iana_timezone: Option<String>,
+    /// Display name of the timezone, e.g. `(GMT-05:00) Eastern Time (US & Canada)`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timezone: Option<String>,
+    /// Name of the plan of the shop, e.g. `shopify_plus`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub plan_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub plan_display_name: Option<String>,
+    /// Default language of the shop, e.g. `en`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub primary_locale: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub primary_location_id: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address1: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address2: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub city: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub zip: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub province: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub province_code: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub country: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub country_code: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub country_name: Option<String>,
+    /// Default unit of the weights, e.g. `kg`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub weight_unit: Option<String>,
+    /// Whether the prices include the taxes
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub taxes_included: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tax_shipping: Option<bool>,
+    /// Whether the online store is protected by a password
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub password_enabled: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub has_storefront: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub multi_location_enabled: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub checkout_api_supported: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<String>,
+}
+
+impl Shop {
+    /// Whether the shop is on a Shopify Plus plan
+    pub fn is_plus(&self) -> bool {
+        matches!(
+            self.plan_name.as_deref(),
+            Some("shopify_plus" | "plus_partner_sandbox")
+        )
+    }
+}
+
+/// Legal policy of the shop, e.g. the refund policy
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Policy {
+    /// Title of the policy, e.g. `Refund policy`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// Kind of the policy, e.g. `refund-policy`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub handle: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub body: Option<String>,
+    /// Public URL of the policy in the online store
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<String>,
+}
+
+/// Shop kept by a client and its clones, with the time it was fetched at
+#[derive(Debug, Default)]
+pub(crate) struct ShopCache(Mutex<Option<(f64, Shop)>>);
+
+impl Shopify {
+    /// Get the settings of the shop, kept on the client for [`DEFAULT_SHOP_CACHE_TTL`]
+    ///
+    /// The shop is shared with the clones of the client. On wasm32, where there is no monotonic
+    /// clock, the shop is fetched every time.
+    /// # Example
+    /// ```no_run
+    /// use shopify_api::*;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let shopify = Shopify::new("myshop", "myapikey", ShopifyAPIVersion::V2026_10, None);
+    ///
+    ///     let shop = shopify.shop().await.unwrap();
+    ///     println!("{:?} {:?}", shop.currency, shop.iana_timezone);
+    ///
+    ///     // Served from the cache
+    ///     let shop = shopify.shop().await.unwrap();
+    /// }
+    /// ```
+    pub async fn shop(&self) -> Result<Shop, ShopifyAPIError> {
+        if let Some(shop) = self.cached_shop() {
+            return Ok(shop);
+        }
+
+        self.refresh_shop().await
+    }
+
+    /// Fetch the settings of the shop, replacing the cached shop
+    pub async fn refresh_shop(&self) -> Result<Shop, ShopifyAPIError> {
+        let shop: Shop = self
+            .rest_get_item("shop.json", &Params::default(), "shop")
+            .await?;
+
+        if let Some(now) = utils::now() {
+            *self.shop_cache.0.lock().unwrap() = Some((now, shop.clone()));
+        }
+
+        Ok(shop)
+    }
+
+    /// Get the legal policies of the shop, e.g. the refund and privacy policies
+    pub async fn policies(&self) -> Result<Vec<Policy>, ShopifyAPIError> {
+        self.rest_get_item("policies.json", &Params::default(), "policies")
+            .await
+    }
+
+    /// Forget the cached shop, e.g. when a `shop/update` webhook is received
+    pub fn clear_shop_cache(&self) {
+        *self.shop_cache.0.lock().unwrap() = None;
+    }
+
+    /// Set how long [`Shopify::shop`] keeps the shop, [`Duration::ZERO`] disabling the cache
+    /// # Example
+    /// ```
+    /// use std::time::Duration;
+    /// use shopify_api::*;
+    ///
+    /// let shopify = Shopify::new("myshop", "myapikey", ShopifyAPIVersion::V2026_10, None)
+    ///     .with_shop_cache_ttl(Duration::from_secs(3600));
+    ///
+    /// assert_eq!(shopify.get_shop_cache_ttl(), Duration::from_secs(3600));
+    /// ```
+    pub fn with_shop_cache_ttl(mut self, ttl: Duration) -> Shopify {
+        self.shop_cache_ttl = ttl;
+        self
+    }
+
+    /// Get how long [`Shopify::shop`] keeps the shop
+    pub fn get_shop_cache_ttl(&self) -> Duration {
+        self.shop_cache_ttl
+    }
+
+    /// Get the cached shop, if fetched less than the TTL ago
+    fn cached_shop(&self) -> Option<Shop> {
+        let now = utils::now()?;
+        let cache = self.shop_cache.0.lock().unwrap();
+        let (fetched_at, shop) = cache.as_ref()?;
+
+        (now - fetched_at < self.shop_cache_ttl.as_secs_f64()).then(|| shop.clone())
+    }
+}
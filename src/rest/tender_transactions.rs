@@ -0,0 +1,195 @@
+//! Tender transactions, through the REST `tender_transactions.json` endpoint
+//!
+//! A tender transaction records money moving between the customer and the merchant, whatever the
+//! payment gateway: summing the tender transactions of a day by payment method gives the
+//! end-of-day totals to reconcile the cash drawer and the card terminals with.
+
+use futures_util::Stream;
+use serde::{Deserialize, Serialize};
+
+use super::resource::Params;
+use crate::{
+    pagination::{Page, PageCursor},
+    Shopify, ShopifyAPIError,
+};
+
+/// Payment method of a tender transaction
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TenderPaymentMethod {
+    CreditCard,
+    Cash,
+    AndroidPay,
+    ApplePay,
+    GooglePay,
+    SamsungPay,
+    ShopifyPay,
+    Amazon,
+    Klarna,
+    Paypal,
+    Unknown,
+    /// Any other method, e.g. a gift card or a manual payment
+    #[serde(other)]
+    Other,
+}
+
+/// Card of a tender transaction paid by card
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TenderPaymentDetails {
+    /// Masked number of the card, e.g. `•••• •••• •••• 4242`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub credit_card_number: Option<String>,
+    /// Brand of the card, e.g. `Visa`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub credit_card_company: Option<String>,
+}
+
+/// Money received or refunded for an order
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TenderTransaction {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order_id: Option<u64>,
+    /// Amount received, negative for refunds
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amount: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub currency: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payment_method: Option<TenderPaymentMethod>,
+    /// Set for card payments
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payment_details: Option<TenderPaymentDetails>,
+    /// Reference of the transaction at the payment provider
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub remote_reference: Option<String>,
+    /// Staff user who processed the transaction, e.g. at the point of sale
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_id: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub test: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub processed_at: Option<String>,
+}
+
+/// Filters of [`TenderTransactions::list`] and [`TenderTransactions::stream`]
+///
+/// # Example
+/// ```
+/// use chrono::{TimeZone, Utc};
+/// use shopify_api::rest::tender_transactions::TenderTransactionListParams;
+///
+/// // Transactions of January 12th, oldest first
+/// let params = TenderTransactionListParams::new()
+///     .processed_at_min(Utc.with_ymd_and_hms(2026, 1, 12, 0, 0, 0).unwrap())
+///     .processed_at_max(Utc.with_ymd_and_hms(2026, 1, 12, 23, 59, 59).unwrap())
+///     .oldest_first();
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct TenderTransactionListParams {
+    params: Params,
+}
+
+impl TenderTransactionListParams {
+    pub fn new() -> TenderTransactionListParams {
+        TenderTransactionListParams::default()
+    }
+
+    /// Set the number of transactions of each page, 50 by default and at most 250
+    pub fn limit(mut self, limit: u32) -> Self {
+        self.params.set("limit", limit);
+        self
+    }
+
+    /// Only list the transactions with an ID greater than `since_id`
+    pub fn since_id(mut self, since_id: u64) -> Self {
+        self.params.set("since_id", since_id);
+        self
+    }
+
+    pub fn processed_at_min(mut self, processed_at_min: chrono::DateTime<chrono::Utc>) -> Self {
+        self.params.set_time("processed_at_min", processed_at_min);
+        self
+    }
+
+    pub fn processed_at_max(mut self, processed_at_max: chrono::DateTime<chrono::Utc>) -> Self {
+        self.params.set_time("processed_at_max", processed_at_max);
+        self
+    }
+
+    /// Only list the transactions processed at an exact time
+    pub fn processed_at(mut self, processed_at: chrono::DateTime<chrono::Utc>) -> Self {
+        self.params.set_time("processed_at", processed_at);
+        self
+    }
+
+    /// List the oldest transactions first, rather than the most recent
+    pub fn oldest_first(mut self) -> Self {
+        self.params.set("order", "processed_at ASC");
+        self
+    }
+}
+
+/// Tender transactions of the shop, returned by [`Shopify::tender_transactions`]
+#[derive(Clone, Copy, Debug)]
+pub struct TenderTransactions<'a> {
+    shopify: &'a Shopify,
+}
+
+impl Shopify {
+    /// Query the tender transactions of the shop
+    ///
+    /// # Example
+    /// ```no_run
+    /// use std::collections::HashMap;
+    /// use chrono::{TimeZone, Utc};
+    /// use futures_util::StreamExt;
+    /// use shopify_api::*;
+    /// use shopify_api::rest::tender_transactions::TenderTransactionListParams;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let shopify = Shopify::new("myshop", "myapikey", ShopifyAPIVersion::V2026_10, None);
+    ///     let params = TenderTransactionListParams::new()
+    ///         .processed_at_min(Utc.with_ymd_and_hms(2026, 1, 12, 0, 0, 0).unwrap())
+    ///         .processed_at_max(Utc.with_ymd_and_hms(2026, 1, 12, 23, 59, 59).unwrap());
+    ///
+    ///     let mut totals = HashMap::new();
+    ///     let mut transactions = shopify.tender_transactions().stream(&params);
+    ///     while let Some(transaction) = transactions.next().await {
+    ///         let transaction = transaction.unwrap();
+    ///         let amount: f64 = transaction.amount.unwrap().parse().unwrap();
+    ///         *totals.entry(transaction.payment_method).or_insert(0.0) += amount;
+    ///     }
+    ///     println!("{:?}", totals);
+    /// }
+    /// ```
+    pub fn tender_transactions(&self) -> TenderTransactions<'_> {
+        TenderTransactions { shopify: self }
+    }
+}
+
+impl<'a> TenderTransactions<'a> {
+    /// Query a page of transactions, starting from the first page without a cursor
+    pub async fn list(
+        &self,
+        params: &TenderTransactionListParams,
+        cursor: Option<&PageCursor>,
+    ) -> Result<Page<TenderTransaction>, ShopifyAPIError> {
+        self.shopify
+            .rest_list("tender_transactions.json", &params.params, cursor)
+            .await
+    }
+
+    /// Stream every transaction matching the filters, following the pages
+    pub fn stream(
+        &self,
+        params: &TenderTransactionListParams,
+    ) -> impl Stream<Item = Result<TenderTransaction, ShopifyAPIError>> + Unpin + 'a {
+        self.shopify.rest_list_stream(
+            "tender_transactions.json".to_string(),
+            params.params.clone(),
+        )
+    }
+}
@@ -0,0 +1,366 @@
+//! Themes and their assets, through the REST `themes.json` and `themes/{id}/assets.json` endpoints
+//!
+//! An asset is a file of a theme, identified by its key, e.g. `templates/index.liquid`. Text
+//! assets are read and written as their `value`, binary assets such as images as their contents
+//! encoded in base64 in `attachment`.
+
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+use super::{resource::Params, ShopifyAPIRestType};
+use crate::{Shopify, ShopifyAPIError};
+
+/// Role of a theme in the online store
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ThemeRole {
+    /// Published theme, seen by the customers
+    Main,
+    Unpublished,
+    Demo,
+    /// Theme of a development store or of the Shopify CLI, deleted after a while
+    Development,
+    Archived,
+    Locked,
+    #[serde(other)]
+    Unknown,
+}
+
+/// Theme of the online store
+///
+/// # Example
+/// ```
+/// use shopify_api::rest::themes::{Theme, ThemeRole};
+///
+/// // Upload a theme from a zip file, without publishing it
+/// let theme = Theme::new("Spring sale")
+///     .src("https://example.com/themes/spring.zip")
+///     .role(ThemeRole::Unpublished);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Theme {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub role: Option<ThemeRole>,
+    /// URL of the zip file of the theme, only sent on creation
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub src: Option<String>,
+    /// Whether the theme can be previewed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub previewable: Option<bool>,
+    /// Whether the theme is still being uploaded
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub processing: Option<bool>,
+    /// ID of the theme in the Theme Store, for the themes installed from it
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub theme_store_id: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub admin_graphql_api_id: Option<String>,
+}
+
+impl Theme {
+    pub fn new(name: &str) -> Theme {
+        Theme {
+            name: Some(name.to_string()),
+            ..Theme::default()
+        }
+    }
+
+    /// Set the URL of the zip file the theme is created from
+    pub fn src(mut self, src: &str) -> Self {
+        self.src = Some(src.to_string());
+        self
+    }
+
+    /// Set the role of the theme, [`ThemeRole::Main`] publishing it
+    pub fn role(mut self, role: ThemeRole) -> Self {
+        self.role = Some(role);
+        self
+    }
+
+    /// Whether the theme is the published theme
+    pub fn is_published(&self) -> bool {
+        self.role == Some(ThemeRole::Main)
+    }
+}
+
+/// File of a theme
+///
+/// The contents of an asset are only returned by [`ThemeAssets::get`], in `value` for a text
+/// asset and in `attachment` for a binary one.
+/// # Example
+/// ```
+/// use shopify_api::rest::themes::Asset;
+///
+/// let asset = Asset::text("snippets/banner.liquid", "<div class=\"banner\">Sale</div>");
+///
+/// assert_eq!(
+///     serde_json::to_value(&asset).unwrap(),
+///     serde_json::json!({
+///         "key": "snippets/banner.liquid",
+///         "value": "<div class=\"banner\">Sale</div>",
+///     })
+/// );
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Asset {
+    /// Path of the asset in the theme, e.g. `templates/index.liquid`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub key: Option<String>,
+    /// Contents of a text asset
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+    /// Contents of a binary asset, encoded in base64
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attachment: Option<String>,
+    /// URL the asset is uploaded from, only sent on creation
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub src: Option<String>,
+    /// Key of an asset of the same theme the asset is copied from, only sent on creation
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source_key: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub public_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content_type: Option<String>,
+    /// Size in bytes
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub size: Option<u64>,
+    /// MD5 of the contents, to detect the changes
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub checksum: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub theme_id: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<String>,
+}
+
+impl Asset {
+    /// Text asset, e.g. a template, a snippet or a stylesheet
+    pub fn text(key: &str, value: &str) -> Asset {
+        Asset {
+            key: Some(key.to_string()),
+            value: Some(value.to_string()),
+            ..Asset::default()
+        }
+    }
+
+    /// Binary asset, e.g. an image or a font, from its contents encoded in base64
+    pub fn attachment(key: &str, base64: &str) -> Asset {
+        Asset {
+            key: Some(key.to_string()),
+            attachment: Some(base64.to_string()),
+            ..Asset::default()
+        }
+    }
+
+    /// Asset uploaded from the file at `src`
+    pub fn from_src(key: &str, src: &str) -> Asset {
+        Asset {
+            key: Some(key.to_string()),
+            src: Some(src.to_string()),
+            ..Asset::default()
+        }
+    }
+
+    /// Copy of the asset of the same theme at `source_key`
+    pub fn copy(key: &str, source_key: &str) -> Asset {
+        Asset {
+            key: Some(key.to_string()),
+            source_key: Some(source_key.to_string()),
+            ..Asset::default()
+        }
+    }
+
+    /// Whether the contents of the asset are binary, read from `attachment`
+    pub fn is_binary(&self) -> bool {
+        self.attachment.is_some()
+    }
+}
+
+/// Themes of a shop, returned by [`Shopify::themes`]
+#[derive(Clone, Copy, Debug)]
+pub struct Themes<'a> {
+    shopify: &'a Shopify,
+}
+
+/// Assets of a theme, returned by [`Themes::assets`]
+#[derive(Clone, Copy, Debug)]
+pub struct ThemeAssets<'a> {
+    shopify: &'a Shopify,
+    theme_id: u64,
+}
+
+impl Shopify {
+    /// Query and manage the themes of the online store
+    ///
+    /// # Example
+    /// ```no_run
+    /// use shopify_api::*;
+    /// use shopify_api::rest::themes::{Asset, Theme, ThemeRole};
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let shopify = Shopify::new("myshop", "myapikey", ShopifyAPIVersion::V2026_10, None);
+    ///
+    ///     // Publish a new theme with a changed layout of the published theme
+    ///     let main = shopify.themes().main().await.unwrap().unwrap();
+    ///     let copy = shopify
+    ///         .themes()
+    ///         .create(&Theme::new("Spring sale").role(ThemeRole::Unpublished))
+    ///         .await
+    ///         .unwrap();
+    ///     let copy_id = copy.id.unwrap();
+    ///
+    ///     let layout = shopify
+    ///         .themes()
+    ///         .assets(main.id.unwrap())
+    ///         .get("layout/theme.liquid")
+    ///         .await
+    ///         .unwrap();
+    ///     let value = layout.value.unwrap().replace("</body>", "<p>Sale</p></body>");
+    ///     shopify
+    ///         .themes()
+    ///         .assets(copy_id)
+    ///         .put(&Asset::text("layout/theme.liquid", &value))
+    ///         .await
+    ///         .unwrap();
+    ///
+    ///     shopify.themes().publish(copy_id).await.unwrap();
+    /// }
+    /// ```
+    pub fn themes(&self) -> Themes<'_> {
+        Themes { shopify: self }
+    }
+}
+
+impl<'a> Themes<'a> {
+    /// Get every theme of the shop
+    pub async fn list(&self) -> Result<Vec<Theme>, ShopifyAPIError> {
+        self.shopify
+            .rest_get_item("themes.json", &Params::default(), "themes")
+            .await
+    }
+
+    /// Get the published theme, if any
+    pub async fn main(&self) -> Result<Option<Theme>, ShopifyAPIError> {
+        Ok(self
+            .list()
+            .await?
+            .into_iter()
+            .find(|theme| theme.is_published()))
+    }
+
+    pub async fn get(&self, id: u64) -> Result<Theme, ShopifyAPIError> {
+        self.shopify
+            .rest_get_item(&format!("themes/{}.json", id), &Params::default(), "theme")
+            .await
+    }
+
+    /// Create a theme, uploaded from the zip file of its `src` in the background
+    ///
+    /// The theme can't be published while [`Theme::processing`] is set.
+    pub async fn create(&self, theme: &Theme) -> Result<Theme, ShopifyAPIError> {
+        self.shopify
+            .rest_post_item("themes.json", "theme", theme)
+            .await
+    }
+
+    /// Update the name or the role of a theme
+    pub async fn update(&self, id: u64, theme: &Theme) -> Result<Theme, ShopifyAPIError> {
+        self.shopify
+            .rest_put_item(&format!("themes/{}.json", id), "theme", theme)
+            .await
+    }
+
+    /// Publish a theme, the previously published theme being unpublished
+    pub async fn publish(&self, id: u64) -> Result<Theme, ShopifyAPIError> {
+        self.shopify
+            .rest_put_item(
+                &format!("themes/{}.json", id),
+                "theme",
+                &json!({ "role": ThemeRole::Main }),
+            )
+            .await
+    }
+
+    /// Delete a theme, which fails for the published theme
+    pub async fn delete(&self, id: u64) -> Result<(), ShopifyAPIError> {
+        self.shopify
+            .rest_delete_item(&format!("themes/{}.json", id))
+            .await
+    }
+
+    /// Query and manage the assets of a theme
+    pub fn assets(&self, theme_id: u64) -> ThemeAssets<'a> {
+        ThemeAssets {
+            shopify: self.shopify,
+            theme_id,
+        }
+    }
+}
+
+impl<'a> ThemeAssets<'a> {
+    /// Get every asset of the theme, without their contents
+    pub async fn list(&self) -> Result<Vec<Asset>, ShopifyAPIError> {
+        self.shopify
+            .rest_get_item(
+                &format!("themes/{}/assets.json", self.theme_id),
+                &Params::default(),
+                "assets",
+            )
+            .await
+    }
+
+    /// Get an asset with its contents, e.g. `templates/index.liquid`
+    pub async fn get(&self, key: &str) -> Result<Asset, ShopifyAPIError> {
+        let mut params = Params::default();
+        params.set("asset[key]", key);
+
+        self.shopify
+            .rest_get_item(
+                &format!("themes/{}/assets.json", self.theme_id),
+                &params,
+                "asset",
+            )
+            .await
+    }
+
+    /// Create or replace an asset, from its `value`, `attachment`, `src` or `source_key`
+    pub async fn put(&self, asset: &Asset) -> Result<Asset, ShopifyAPIError> {
+        self.shopify
+            .rest_put_item(
+                &format!("themes/{}/assets.json", self.theme_id),
+                "asset",
+                asset,
+            )
+            .await
+    }
+
+    /// Delete an asset, which fails for the assets required by the theme
+    pub async fn delete(&self, key: &str) -> Result<(), ShopifyAPIError> {
+        let mut params = Params::default();
+        params.set("asset[key]", key);
+
+        self.shopify
+            .rest_query::<serde_json::Value>(
+                &ShopifyAPIRestType::Delete(
+                    &format!("themes/{}/assets.json", self.theme_id),
+                    &params.to_map(),
+                ),
+                &None,
+            )
+            .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,503 @@
+//! Transactions and refunds of an order, through the REST `orders/{id}/transactions.json` and
+//! `orders/{id}/refunds.json` endpoints
+//!
+//! Money moves through the transactions of an order: an `authorization` is later captured or
+//! voided, and refunds create `refund` transactions. Refunds are best calculated first with
+//! [`OrderRefunds::calculate`], which suggests the transactions to refund.
+
+use serde::{Deserialize, Serialize};
+
+use super::{
+    orders::{LineItem, Orders},
+    resource::Params,
+};
+use crate::{
+    pagination::{Page, PageCursor},
+    Shopify, ShopifyAPIError,
+};
+
+/// Kind of a transaction
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TransactionKind {
+    /// Money reserved on the payment method, to capture later
+    Authorization,
+    /// Transfer of previously authorized money
+    Capture,
+    /// Authorization and capture at once
+    Sale,
+    /// Cancellation of an authorization
+    Void,
+    Refund,
+    /// Refund suggested by [`OrderRefunds::calculate`]
+    SuggestedRefund,
+    #[serde(other)]
+    Unknown,
+}
+
+/// Status of a transaction
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TransactionStatus {
+    Pending,
+    Failure,
+    Success,
+    Error,
+    #[serde(other)]
+    Unknown,
+}
+
+/// Transaction of an order
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Transaction {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order_id: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub kind: Option<TransactionKind>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<TransactionStatus>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amount: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub currency: Option<String>,
+    /// Payment gateway, e.g. `shopify_payments` or `manual`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gateway: Option<String>,
+    /// Transaction captured, voided or refunded by this one
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parent_id: Option<u64>,
+    /// Authorization code given by the gateway
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub authorization: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error_code: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    /// Whether the transaction was made with a test gateway
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub test: Option<bool>,
+    /// Amount that can still be refunded, for the suggested refunds
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub maximum_refundable: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub processed_at: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub admin_graphql_api_id: Option<String>,
+}
+
+/// What happens to the refunded items of a [`RefundLineItem`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RestockType {
+    /// The items are not restocked
+    NoRestock,
+    /// The items were never fulfilled and are restocked
+    Cancel,
+    /// The items were returned and are restocked
+    Return,
+    #[serde(other)]
+    LegacyRestock,
+}
+
+/// Line item refunded by a refund
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct RefundLineItem {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub line_item_id: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub quantity: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub restock_type: Option<RestockType>,
+    /// Location the items are restocked at
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub location_id: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subtotal: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total_tax: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub line_item: Option<LineItem>,
+}
+
+/// Refunded shipping of a refund
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RefundShipping {
+    /// Refund all the remaining shipping, rather than `amount`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub full_refund: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amount: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tax: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub maximum_refundable: Option<String>,
+}
+
+/// Refund of an order, or a refund calculated by [`OrderRefunds::calculate`]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct Refund {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order_id: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub note: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub currency: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub refund_line_items: Vec<RefundLineItem>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shipping: Option<RefundShipping>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub transactions: Vec<Transaction>,
+    /// Staff member who created the refund
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_id: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub processed_at: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub admin_graphql_api_id: Option<String>,
+}
+
+/// Refund to calculate with [`OrderRefunds::calculate`] or create with [`OrderRefunds::create`]
+///
+/// # Example
+/// ```
+/// use shopify_api::rest::transactions::{RefundRequest, RestockType};
+///
+/// // 1 returned item and 5.00 of shipping, refunded on the original payment
+/// let refund = RefundRequest::new()
+///     .line_item(518995019, 1, RestockType::Return, Some(487838322))
+///     .shipping_amount("5.00")
+///     .transaction(389404469, "41.94", "bogus")
+///     .notify(true);
+///
+/// assert_eq!(
+///     serde_json::to_value(&refund).unwrap(),
+///     serde_json::json!({
+///         "notify": true,
+///         "shipping": { "amount": "5.00" },
+///         "refund_line_items": [{
+///             "line_item_id": 518995019,
+///             "quantity": 1,
+///             "restock_type": "return",
+///             "location_id": 487838322
+///         }],
+///         "transactions": [{
+///             "kind": "refund",
+///             "amount": "41.94",
+///             "gateway": "bogus",
+///             "parent_id": 389404469
+///         }]
+///     })
+/// );
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct RefundRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    currency: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    notify: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    note: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    shipping: Option<RefundShipping>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    refund_line_items: Vec<RefundLineItem>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    transactions: Vec<Transaction>,
+}
+
+impl RefundRequest {
+    pub fn new() -> RefundRequest {
+        RefundRequest::default()
+    }
+
+    /// Set the currency of the refund, required when there are transactions
+    pub fn currency(mut self, currency: &str) -> Self {
+        self.currency = Some(currency.to_string());
+        self
+    }
+
+    /// Send a refund notification to the customer
+    pub fn notify(mut self, notify: bool) -> Self {
+        self.notify = Some(notify);
+        self
+    }
+
+    pub fn note(mut self, note: &str) -> Self {
+        self.note = Some(note.to_string());
+        self
+    }
+
+    /// Refund `quantity` items of a line item, restocked at `location_id` unless `NoRestock`
+    pub fn line_item(
+        mut self,
+        line_item_id: u64,
+        quantity: u32,
+        restock_type: RestockType,
+        location_id: Option<u64>,
+    ) -> Self {
+        self.refund_line_items.push(RefundLineItem {
+            line_item_id: Some(line_item_id),
+            quantity: Some(quantity),
+            restock_type: Some(restock_type),
+            location_id,
+            ..RefundLineItem::default()
+        });
+        self
+    }
+
+    /// Refund all the remaining shipping
+    pub fn full_shipping_refund(mut self) -> Self {
+        self.shipping = Some(RefundShipping {
+            full_refund: Some(true),
+            ..RefundShipping::default()
+        });
+        self
+    }
+
+    /// Refund an amount of the shipping
+    pub fn shipping_amount(mut self, amount: &str) -> Self {
+        self.shipping = Some(RefundShipping {
+            amount: Some(amount.to_string()),
+            ..RefundShipping::default()
+        });
+        self
+    }
+
+    /// Refund `amount` of a transaction of the order, on its gateway
+    pub fn transaction(mut self, parent_id: u64, amount: &str, gateway: &str) -> Self {
+        self.transactions.push(Transaction {
+            kind: Some(TransactionKind::Refund),
+            parent_id: Some(parent_id),
+            amount: Some(amount.to_string()),
+            gateway: Some(gateway.to_string()),
+            ..Transaction::default()
+        });
+        self
+    }
+
+    /// Refund the transactions suggested by a refund calculated with [`OrderRefunds::calculate`],
+    /// in its currency
+    pub fn suggested_transactions(mut self, calculated: &Refund) -> Self {
+        for suggested in &calculated.transactions {
+            self.transactions.push(Transaction {
+                kind: Some(TransactionKind::Refund),
+                parent_id: suggested.parent_id,
+                amount: suggested.amount.clone(),
+                gateway: suggested.gateway.clone(),
+                ..Transaction::default()
+            });
+        }
+        if self.currency.is_none() {
+            self.currency = calculated.currency.clone();
+        }
+        self
+    }
+}
+
+/// Transactions of an order, returned by [`Orders::transactions`]
+#[derive(Clone, Copy, Debug)]
+pub struct OrderTransactions<'a> {
+    shopify: &'a Shopify,
+    order_id: u64,
+}
+
+/// Refunds of an order, returned by [`Orders::refunds`]
+#[derive(Clone, Copy, Debug)]
+pub struct OrderRefunds<'a> {
+    shopify: &'a Shopify,
+    order_id: u64,
+}
+
+impl<'a> Orders<'a> {
+    /// Query the transactions of an order, and capture, void or refund them
+    ///
+    /// # Example
+    /// ```no_run
+    /// use shopify_api::*;
+    /// use shopify_api::rest::transactions::TransactionKind;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let shopify = Shopify::new("myshop", "myapikey", ShopifyAPIVersion::V2026_10, None);
+    ///     let transactions = shopify.orders().transactions(450789469);
+    ///
+    ///     for transaction in transactions.list().await.unwrap() {
+    ///         if transaction.kind == Some(TransactionKind::Authorization) {
+    ///             transactions.capture(transaction.id.unwrap(), None).await.unwrap();
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub fn transactions(&self, order_id: u64) -> OrderTransactions<'a> {
+        OrderTransactions {
+            shopify: self.shopify,
+            order_id,
+        }
+    }
+
+    /// Query, calculate and create the refunds of an order
+    ///
+    /// # Example
+    /// ```no_run
+    /// use shopify_api::*;
+    /// use shopify_api::rest::transactions::{RefundRequest, RestockType};
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let shopify = Shopify::new("myshop", "myapikey", ShopifyAPIVersion::V2026_10, None);
+    ///     let refunds = shopify.orders().refunds(450789469);
+    ///
+    ///     let request = RefundRequest::new()
+    ///         .line_item(518995019, 1, RestockType::Return, Some(487838322))
+    ///         .full_shipping_refund();
+    ///     let calculated = refunds.calculate(&request).await.unwrap();
+    ///
+    ///     let refund = refunds
+    ///         .create(&request.suggested_transactions(&calculated).notify(true))
+    ///         .await
+    ///         .unwrap();
+    ///     println!("{:?}", refund.transactions);
+    /// }
+    /// ```
+    pub fn refunds(&self, order_id: u64) -> OrderRefunds<'a> {
+        OrderRefunds {
+            shopify: self.shopify,
+            order_id,
+        }
+    }
+}
+
+impl<'a> OrderTransactions<'a> {
+    fn endpoint(&self) -> String {
+        format!("orders/{}/transactions.json", self.order_id)
+    }
+
+    /// Query every transaction of the order, the endpoint not being paginated
+    pub async fn list(&self) -> Result<Vec<Transaction>, ShopifyAPIError> {
+        self.shopify
+            .rest_get_item(&self.endpoint(), &Params::default(), "transactions")
+            .await
+    }
+
+    pub async fn get(&self, id: u64) -> Result<Transaction, ShopifyAPIError> {
+        self.shopify
+            .rest_get_item(
+                &format!("orders/{}/transactions/{}.json", self.order_id, id),
+                &Params::default(),
+                "transaction",
+            )
+            .await
+    }
+
+    pub async fn count(&self) -> Result<u64, ShopifyAPIError> {
+        self.shopify
+            .rest_count(
+                &format!("orders/{}/transactions/count.json", self.order_id),
+                &Params::default(),
+            )
+            .await
+    }
+
+    /// Create a transaction, usually a `capture`, `void` or `refund` of a parent transaction
+    pub async fn create(&self, transaction: &Transaction) -> Result<Transaction, ShopifyAPIError> {
+        self.shopify
+            .rest_post_item(&self.endpoint(), "transaction", transaction)
+            .await
+    }
+
+    /// Capture an authorization, fully without an `amount`
+    pub async fn capture(
+        &self,
+        authorization_id: u64,
+        amount: Option<&str>,
+    ) -> Result<Transaction, ShopifyAPIError> {
+        self.create(&Transaction {
+            kind: Some(TransactionKind::Capture),
+            parent_id: Some(authorization_id),
+            amount: amount.map(str::to_string),
+            ..Transaction::default()
+        })
+        .await
+    }
+
+    /// Void an authorization
+    pub async fn void(&self, authorization_id: u64) -> Result<Transaction, ShopifyAPIError> {
+        self.create(&Transaction {
+            kind: Some(TransactionKind::Void),
+            parent_id: Some(authorization_id),
+            ..Transaction::default()
+        })
+        .await
+    }
+
+    /// Refund `amount` of a capture or a sale, without restocking items: see
+    /// [`Orders::refunds`] to refund line items
+    pub async fn refund(
+        &self,
+        parent_id: u64,
+        amount: &str,
+        currency: &str,
+    ) -> Result<Transaction, ShopifyAPIError> {
+        self.create(&Transaction {
+            kind: Some(TransactionKind::Refund),
+            parent_id: Some(parent_id),
+            amount: Some(amount.to_string()),
+            currency: Some(currency.to_string()),
+            ..Transaction::default()
+        })
+        .await
+    }
+}
+
+impl<'a> OrderRefunds<'a> {
+    fn endpoint(&self) -> String {
+        format!("orders/{}/refunds.json", self.order_id)
+    }
+
+    /// Query a page of the refunds of the order, starting from the first page without a cursor
+    pub async fn list(&self, cursor: Option<&PageCursor>) -> Result<Page<Refund>, ShopifyAPIError> {
+        self.shopify
+            .rest_list(&self.endpoint(), &Params::default(), cursor)
+            .await
+    }
+
+    pub async fn get(&self, id: u64) -> Result<Refund, ShopifyAPIError> {
+        self.shopify
+            .rest_get_item(
+                &format!("orders/{}/refunds/{}.json", self.order_id, id),
+                &Params::default(),
+                "refund",
+            )
+            .await
+    }
+
+    /// Calculate a refund without creating it, Shopify adjusting the quantities and the shipping
+    /// amount to what can be refunded and suggesting the transactions to refund
+    pub async fn calculate(&self, refund: &RefundRequest) -> Result<Refund, ShopifyAPIError> {
+        self.shopify
+            .rest_post_item(
+                &format!("orders/{}/refunds/calculate.json", self.order_id),
+                "refund",
+                refund,
+            )
+            .await
+    }
+
+    /// Create a refund, restocking its line items and refunding its transactions
+    pub async fn create(&self, refund: &RefundRequest) -> Result<Refund, ShopifyAPIError> {
+        self.shopify
+            .rest_post_item(&self.endpoint(), "refund", refund)
+            .await
+    }
+}
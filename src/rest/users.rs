@@ -0,0 +1,290 @@
+//! Staff users of the shop, through the REST `users.json` endpoints
+//!
+//! Only Shopify Plus shops, and apps granted the `read_users` scope, can read their staff.
+//! [`Users::current`] gets the staff member using the app, with an online access token.
+
+use std::{fmt, str::FromStr};
+
+use futures_util::Stream;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use super::resource::Params;
+use crate::{
+    pagination::{Page, PageCursor},
+    Shopify, ShopifyAPIError,
+};
+
+/// Permission of a staff user, i.e. a section of the admin they can access
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum UserPermission {
+    /// Every permission, e.g. for the account owner
+    Full,
+    Applications,
+    Channels,
+    Customers,
+    Dashboard,
+    Domains,
+    DraftOrders,
+    EditOrders,
+    GiftCards,
+    Links,
+    Locations,
+    Marketing,
+    Orders,
+    Overviews,
+    Pages,
+    Preferences,
+    Products,
+    Reports,
+    Shipping,
+    Themes,
+    Translations,
+
+    /// Any other permission, by its REST name
+    Other(String),
+}
+
+impl UserPermission {
+    /// Get the REST name of the permission, e.g. `draft_orders`
+    pub fn as_str(&self) -> &str {
+        match self {
+            UserPermission::Full => "full",
+            UserPermission::Applications => "applications",
+            UserPermission::Channels => "channels",
+            UserPermission::Customers => "customers",
+            UserPermission::Dashboard => "dashboard",
+            UserPermission::Domains => "domains",
+            UserPermission::DraftOrders => "draft_orders",
+            UserPermission::EditOrders => "edit_orders",
+            UserPermission::GiftCards => "gift_cards",
+            UserPermission::Links => "links",
+            UserPermission::Locations => "locations",
+            UserPermission::Marketing => "marketing",
+            UserPermission::Orders => "orders",
+            UserPermission::Overviews => "overviews",
+            UserPermission::Pages => "pages",
+            UserPermission::Preferences => "preferences",
+            UserPermission::Products => "products",
+            UserPermission::Reports => "reports",
+            UserPermission::Shipping => "shipping",
+            UserPermission::Themes => "themes",
+            UserPermission::Translations => "translations",
+            UserPermission::Other(permission) => permission,
+        }
+    }
+}
+
+impl FromStr for UserPermission {
+    type Err = std::convert::Infallible;
+
+    fn from_str(permission: &str) -> Result<Self, Self::Err> {
+        Ok(match permission {
+            "full" => UserPermission::Full,
+            "applications" => UserPermission::Applications,
+            "channels" => UserPermission::Channels,
+            "customers" => UserPermission::Customers,
+            "dashboard" => UserPermission::Dashboard,
+            "domains" => UserPermission::Domains,
+            "draft_orders" => UserPermission::DraftOrders,
+            "edit_orders" => UserPermission::EditOrders,
+            "gift_cards" => UserPermission::GiftCards,
+            "links" => UserPermission::Links,
+            "locations" => UserPermission::Locations,
+            "marketing" => UserPermission::Marketing,
+            "orders" => UserPermission::Orders,
+            "overviews" => UserPermission::Overviews,
+            "pages" => UserPermission::Pages,
+            "preferences" => UserPermission::Preferences,
+            "products" => UserPermission::Products,
+            "reports" => UserPermission::Reports,
+            "shipping" => UserPermission::Shipping,
+            "themes" => UserPermission::Themes,
+            "translations" => UserPermission::Translations,
+            other => UserPermission::Other(other.to_string()),
+        })
+    }
+}
+
+impl fmt::Display for UserPermission {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl Serialize for UserPermission {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for UserPermission {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let permission = String::deserialize(deserializer)?;
+        Ok(permission.parse().unwrap_or_else(|never| match never {}))
+    }
+}
+
+/// Kind of account of a staff user
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum UserType {
+    Regular,
+    Restricted,
+    /// Invited, without an account yet
+    Invited,
+    /// Partner collaborating on the shop
+    Collaborator,
+    #[serde(other)]
+    Unknown,
+}
+
+/// Staff user of the shop
+///
+/// # Example
+/// ```
+/// use shopify_api::rest::users::{User, UserPermission};
+///
+/// let user: User = serde_json::from_value(serde_json::json!({
+///     "id": 1,
+///     "first_name": "Jane",
+///     "permissions": ["orders", "draft_orders", "beacons"],
+/// }))
+/// .unwrap();
+///
+/// assert!(user.has_permission(&UserPermission::DraftOrders));
+/// assert!(!user.has_permission(&UserPermission::Products));
+/// assert_eq!(user.permissions[2], UserPermission::Other("beacons".to_string()));
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct User {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub first_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub phone: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bio: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub im: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub screen_name: Option<String>,
+    /// Language of the user, e.g. `en`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub locale: Option<String>,
+    /// Whether the user owns the shop
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub account_owner: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub receive_announcements: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_type: Option<UserType>,
+    /// Whether the user signs in with two-step authentication
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tfa_enabled: Option<bool>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub permissions: Vec<UserPermission>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub admin_graphql_api_id: Option<String>,
+}
+
+impl User {
+    /// Check if the user has `permission`, the account owner and the users with
+    /// [`UserPermission::Full`] having every permission
+    pub fn has_permission(&self, permission: &UserPermission) -> bool {
+        self.account_owner == Some(true)
+            || self
+                .permissions
+                .iter()
+                .any(|granted| granted == permission || granted == &UserPermission::Full)
+    }
+}
+
+/// Filters of [`Users::list`] and [`Users::stream`]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct UserListParams {
+    params: Params,
+}
+
+impl UserListParams {
+    pub fn new() -> UserListParams {
+        UserListParams::default()
+    }
+
+    /// Set the number of users of each page, 50 by default and at most 250
+    pub fn limit(mut self, limit: u32) -> Self {
+        self.params.set("limit", limit);
+        self
+    }
+}
+
+/// Staff users of the shop, returned by [`Shopify::users`]
+#[derive(Clone, Copy, Debug)]
+pub struct Users<'a> {
+    shopify: &'a Shopify,
+}
+
+impl Shopify {
+    /// Query the staff users of a Shopify Plus shop
+    ///
+    /// # Example
+    /// ```no_run
+    /// use shopify_api::*;
+    /// use shopify_api::rest::users::UserPermission;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     // Online access token of the staff member using the app
+    ///     let shopify = Shopify::new("myshop", "myapikey", ShopifyAPIVersion::V2026_10, None);
+    ///
+    ///     let user = shopify.users().current().await.unwrap();
+    ///     if !user.has_permission(&UserPermission::Orders) {
+    ///         println!("{:?} can't manage the orders", user.first_name);
+    ///     }
+    /// }
+    /// ```
+    pub fn users(&self) -> Users<'_> {
+        Users { shopify: self }
+    }
+}
+
+impl<'a> Users<'a> {
+    /// Query a page of users, starting from the first page without a cursor
+    pub async fn list(
+        &self,
+        params: &UserListParams,
+        cursor: Option<&PageCursor>,
+    ) -> Result<Page<User>, ShopifyAPIError> {
+        self.shopify
+            .rest_list("users.json", &params.params, cursor)
+            .await
+    }
+
+    /// Stream every user of the shop, following the pages
+    pub fn stream(
+        &self,
+        params: &UserListParams,
+    ) -> impl Stream<Item = Result<User, ShopifyAPIError>> + Unpin + 'a {
+        self.shopify
+            .rest_list_stream("users.json".to_string(), params.params.clone())
+    }
+
+    pub async fn get(&self, id: u64) -> Result<User, ShopifyAPIError> {
+        self.shopify
+            .rest_get_item(&format!("users/{}.json", id), &Params::default(), "user")
+            .await
+    }
+
+    /// Get the user the access token was issued for, requiring an online access token
+    pub async fn current(&self) -> Result<User, ShopifyAPIError> {
+        self.shopify
+            .rest_get_item("users/current.json", &Params::default(), "user")
+            .await
+    }
+}
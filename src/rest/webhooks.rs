@@ -0,0 +1,296 @@
+//! Webhooks, through the REST `webhooks.json` endpoints
+//!
+//! The topics are the [`WebhookTopic`] of the GraphQL subscriptions, sent by their REST name, e.g.
+//! `orders/create`. For Amazon EventBridge and Google Cloud Pub/Sub endpoints, the address is the
+//! [`WebhookEndpoint::uri`](crate::graphql::webhooks::WebhookEndpoint::uri) of the endpoint.
+
+use futures_util::Stream;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use super::resource::Params;
+use crate::{
+    graphql::webhooks::{WebhookFormat, WebhookTopic},
+    pagination::{Page, PageCursor},
+    Shopify, ShopifyAPIError,
+};
+
+/// Webhook of the app, sending the events of a topic to an address
+///
+/// # Example
+/// ```
+/// use shopify_api::graphql::webhooks::{WebhookFormat, WebhookTopic};
+/// use shopify_api::rest::webhooks::Webhook;
+///
+/// let webhook = Webhook::new(WebhookTopic::OrdersCreate, "https://example.com/webhooks")
+///     .format(WebhookFormat::Json)
+///     .fields(&["id", "updated_at"]);
+///
+/// assert_eq!(
+///     serde_json::to_value(&webhook).unwrap(),
+///     serde_json::json!({
+///         "topic": "orders/create",
+///         "address": "https://example.com/webhooks",
+///         "format": "json",
+///         "fields": ["id", "updated_at"],
+///     })
+/// );
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Webhook {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<u64>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_topic",
+        deserialize_with = "deserialize_topic"
+    )]
+    pub topic: Option<WebhookTopic>,
+    /// URL, EventBridge ARN or Pub/Sub URI receiving the events
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address: Option<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_format",
+        deserialize_with = "deserialize_format"
+    )]
+    pub format: Option<WebhookFormat>,
+    /// Only send these fields of the payloads, every field being sent when empty
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub fields: Vec<String>,
+    /// Namespaces of the metafields sent with the payloads
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub metafield_namespaces: Vec<String>,
+    /// API version of the payloads, the version of the app
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub api_version: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<String>,
+}
+
+impl Webhook {
+    pub fn new(topic: WebhookTopic, address: &str) -> Webhook {
+        Webhook {
+            topic: Some(topic),
+            address: Some(address.to_string()),
+            ..Webhook::default()
+        }
+    }
+
+    /// Set the format of the payloads, JSON by default
+    pub fn format(mut self, format: WebhookFormat) -> Self {
+        self.format = Some(format);
+        self
+    }
+
+    /// Only send these fields of the payloads, e.g. `id` and `updated_at`
+    pub fn fields(mut self, fields: &[&str]) -> Self {
+        self.fields = fields.iter().map(|field| field.to_string()).collect();
+        self
+    }
+
+    /// Send the metafields of these namespaces with the payloads
+    pub fn metafield_namespaces(mut self, namespaces: &[&str]) -> Self {
+        self.metafield_namespaces = namespaces
+            .iter()
+            .map(|namespace| namespace.to_string())
+            .collect();
+        self
+    }
+}
+
+fn serialize_topic<S: Serializer>(
+    topic: &Option<WebhookTopic>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    match topic {
+        Some(topic) => serializer.serialize_str(&topic.rest_name()),
+        None => serializer.serialize_none(),
+    }
+}
+
+fn deserialize_topic<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<WebhookTopic>, D::Error> {
+    let topic = Option::<String>::deserialize(deserializer)?;
+    Ok(topic.map(|topic| WebhookTopic::from_rest_name(&topic)))
+}
+
+fn serialize_format<S: Serializer>(
+    format: &Option<WebhookFormat>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    match format {
+        Some(WebhookFormat::Json) => serializer.serialize_str("json"),
+        Some(WebhookFormat::Xml) => serializer.serialize_str("xml"),
+        None => serializer.serialize_none(),
+    }
+}
+
+fn deserialize_format<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<WebhookFormat>, D::Error> {
+    let format = Option::<String>::deserialize(deserializer)?;
+    format
+        .map(|format| match format.as_str() {
+            "json" => Ok(WebhookFormat::Json),
+            "xml" => Ok(WebhookFormat::Xml),
+            other => Err(serde::de::Error::unknown_variant(other, &["json", "xml"])),
+        })
+        .transpose()
+}
+
+/// Filters of [`Webhooks::list`], [`Webhooks::stream`] and [`Webhooks::count`]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct WebhookListParams {
+    params: Params,
+}
+
+impl WebhookListParams {
+    pub fn new() -> WebhookListParams {
+        WebhookListParams::default()
+    }
+
+    /// Set the number of webhooks of each page, 50 by default and at most 250
+    pub fn limit(mut self, limit: u32) -> Self {
+        self.params.set("limit", limit);
+        self
+    }
+
+    /// Only list the webhooks with an ID greater than `since_id`
+    pub fn since_id(mut self, since_id: u64) -> Self {
+        self.params.set("since_id", since_id);
+        self
+    }
+
+    pub fn topic(mut self, topic: &WebhookTopic) -> Self {
+        self.params.set("topic", topic.rest_name());
+        self
+    }
+
+    pub fn address(mut self, address: &str) -> Self {
+        self.params.set("address", address);
+        self
+    }
+
+    pub fn created_at_min(mut self, created_at_min: chrono::DateTime<chrono::Utc>) -> Self {
+        self.params.set_time("created_at_min", created_at_min);
+        self
+    }
+
+    pub fn created_at_max(mut self, created_at_max: chrono::DateTime<chrono::Utc>) -> Self {
+        self.params.set_time("created_at_max", created_at_max);
+        self
+    }
+
+    pub fn updated_at_min(mut self, updated_at_min: chrono::DateTime<chrono::Utc>) -> Self {
+        self.params.set_time("updated_at_min", updated_at_min);
+        self
+    }
+
+    pub fn updated_at_max(mut self, updated_at_max: chrono::DateTime<chrono::Utc>) -> Self {
+        self.params.set_time("updated_at_max", updated_at_max);
+        self
+    }
+
+    /// Only return the given fields of each webhook
+    pub fn fields(mut self, fields: &[&str]) -> Self {
+        self.params.set_list("fields", fields);
+        self
+    }
+}
+
+/// Webhooks of the app, returned by [`Shopify::webhooks`]
+#[derive(Clone, Copy, Debug)]
+pub struct Webhooks<'a> {
+    shopify: &'a Shopify,
+}
+
+impl Shopify {
+    /// Query and manage the webhooks of the app on the shop
+    ///
+    /// # Example
+    /// ```no_run
+    /// use shopify_api::*;
+    /// use shopify_api::graphql::webhooks::WebhookTopic;
+    /// use shopify_api::rest::webhooks::{Webhook, WebhookListParams};
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let shopify = Shopify::new("myshop", "myapikey", ShopifyAPIVersion::V2026_10, None);
+    ///     let webhook = Webhook::new(WebhookTopic::OrdersCreate, "https://example.com/webhooks");
+    ///
+    ///     let webhook = shopify.webhooks().create(&webhook).await.unwrap();
+    ///
+    ///     let params = WebhookListParams::new().topic(&WebhookTopic::OrdersCreate);
+    ///     let count = shopify.webhooks().count(&params).await.unwrap();
+    ///
+    ///     shopify.webhooks().delete(webhook.id.unwrap()).await.unwrap();
+    /// }
+    /// ```
+    pub fn webhooks(&self) -> Webhooks<'_> {
+        Webhooks { shopify: self }
+    }
+}
+
+impl<'a> Webhooks<'a> {
+    /// Query a page of webhooks, starting from the first page without a cursor
+    pub async fn list(
+        &self,
+        params: &WebhookListParams,
+        cursor: Option<&PageCursor>,
+    ) -> Result<Page<Webhook>, ShopifyAPIError> {
+        self.shopify
+            .rest_list("webhooks.json", &params.params, cursor)
+            .await
+    }
+
+    /// Stream every webhook matching the filters, following the pages
+    pub fn stream(
+        &self,
+        params: &WebhookListParams,
+    ) -> impl Stream<Item = Result<Webhook, ShopifyAPIError>> + Unpin + 'a {
+        self.shopify
+            .rest_list_stream("webhooks.json".to_string(), params.params.clone())
+    }
+
+    pub async fn get(&self, id: u64) -> Result<Webhook, ShopifyAPIError> {
+        self.shopify
+            .rest_get_item(
+                &format!("webhooks/{}.json", id),
+                &Params::default(),
+                "webhook",
+            )
+            .await
+    }
+
+    /// Count the webhooks matching the filters, only `topic` and `address` being used
+    pub async fn count(&self, params: &WebhookListParams) -> Result<u64, ShopifyAPIError> {
+        self.shopify
+            .rest_count("webhooks/count.json", &params.params)
+            .await
+    }
+
+    /// Create a webhook, which fails when the address is already subscribed to the topic
+    pub async fn create(&self, webhook: &Webhook) -> Result<Webhook, ShopifyAPIError> {
+        self.shopify
+            .rest_post_item("webhooks.json", "webhook", webhook)
+            .await
+    }
+
+    /// Update the address, format or fields of a webhook, its topic can't be changed
+    pub async fn update(&self, id: u64, webhook: &Webhook) -> Result<Webhook, ShopifyAPIError> {
+        self.shopify
+            .rest_put_item(&format!("webhooks/{}.json", id), "webhook", webhook)
+            .await
+    }
+
+    pub async fn delete(&self, id: u64) -> Result<(), ShopifyAPIError> {
+        self.shopify
+            .rest_delete_item(&format!("webhooks/{}.json", id))
+            .await
+    }
+}
@@ -0,0 +1,406 @@
+use std::{
+    collections::hash_map::RandomState,
+    fmt,
+    future::Future,
+    hash::{BuildHasher, Hasher},
+    sync::Arc,
+    time::Duration,
+};
+
+use crate::{cancel::CancellationToken, utils, ShopifyAPIError};
+
+/// Delay applied between two attempts of a query
+#[derive(Clone, Debug, PartialEq)]
+pub enum Backoff {
+    /// Retry immediately
+    None,
+
+    /// Wait the same delay before every retry
+    Fixed(Duration),
+
+    /// Double the delay after every retry, up to `max`
+    ///
+    /// With `jitter`, a random delay between half and the whole computed delay is used,
+    /// so clients failing at the same time do not retry at the same time.
+    Exponential {
+        initial: Duration,
+        max: Duration,
+        jitter: bool,
+    },
+}
+
+impl Backoff {
+    /// Exponential backoff with jitter starting at `initial` and capped at `max`
+    /// # Example
+    /// ```
+    /// use std::time::Duration;
+    /// use shopify_api::retry::Backoff;
+    ///
+    /// let backoff = Backoff::exponential(Duration::from_millis(100), Duration::from_secs(1));
+    ///
+    /// assert!(backoff.delay(1) <= Duration::from_millis(100));
+    /// assert!(backoff.delay(3) >= Duration::from_millis(200));
+    /// assert!(backoff.delay(3) <= Duration::from_millis(400));
+    /// assert!(backoff.delay(20) <= Duration::from_secs(1));
+    /// ```
+    pub fn exponential(initial: Duration, max: Duration) -> Backoff {
+        Backoff::Exponential {
+            initial,
+            max,
+            jitter: true,
+        }
+    }
+
+    /// Get the delay to wait before the given retry (starting at 1)
+    pub fn delay(&self, retry: u64) -> Duration {
+        match self {
+            Backoff::None => Duration::ZERO,
+            Backoff::Fixed(delay) => *delay,
+            Backoff::Exponential {
+                initial,
+                max,
+                jitter,
+            } => {
+                let exponent = retry.saturating_sub(1).min(31) as u32;
+                let delay = initial.saturating_mul(2u32.pow(exponent)).min(*max);
+
+                if *jitter {
+                    delay.mul_f64(0.5 + random_fraction() / 2.0)
+                } else {
+                    delay
+                }
+            }
+        }
+    }
+}
+
+/// Random number in `[0, 1)`, good enough to spread retries
+fn random_fraction() -> f64 {
+    let random = RandomState::new().build_hasher().finish();
+
+    (random >> 11) as f64 / (1u64 << 53) as f64
+}
+
+type RetryOn = Arc<dyn Fn(&ShopifyAPIError) -> bool + Send + Sync>;
+
+/// Decide how many times and when a failed query is retried
+/// # Example
+/// ```
+/// use std::time::Duration;
+/// use shopify_api::*;
+/// use shopify_api::retry::{Backoff, RetryPolicy};
+///
+/// let policy = RetryPolicy::new(3)
+///     .backoff(Backoff::Fixed(Duration::from_millis(500)))
+///     .retry_on(|error| matches!(error, ShopifyAPIError::Throttled | ShopifyAPIError::Timeout));
+///
+/// assert_eq!(policy.get_max_attempts(), 3);
+/// assert!(policy.should_retry(&ShopifyAPIError::Throttled));
+/// assert!(!policy.should_retry(&ShopifyAPIError::ResponseBroken));
+///
+/// let shopify = ShopifyBuilder::new()
+///     .shop_name("myshop")
+///     .access_token("myapikey")
+///     .api_version(ShopifyAPIVersion::V2026_10)
+///     .retry_policy(policy)
+///     .build()
+///     .unwrap();
+/// ```
+#[derive(Clone)]
+pub struct RetryPolicy {
+    max_attempts: u64,
+    backoff: Backoff,
+    retry_on: RetryOn,
+}
+
+impl RetryPolicy {
+    /// Create a policy making at most `max_attempts` attempts (at least one attempt is always made)
+    pub fn new(max_attempts: u64) -> RetryPolicy {
+        RetryPolicy {
+            max_attempts: max_attempts.max(1),
+            ..RetryPolicy::default()
+        }
+    }
+
+    /// Never retry a failed query
+    pub fn no_retry() -> RetryPolicy {
+        RetryPolicy::new(1)
+    }
+
+    /// Set the maximum number of attempts (at least one attempt is always made)
+    pub fn max_attempts(mut self, max_attempts: u64) -> RetryPolicy {
+        self.max_attempts = max_attempts.max(1);
+        self
+    }
+
+    /// Set the delay applied between two attempts
+    pub fn backoff(mut self, backoff: Backoff) -> RetryPolicy {
+        self.backoff = backoff;
+        self
+    }
+
+    /// Only retry the errors for which the predicate returns `true`
+    pub fn retry_on<F>(mut self, predicate: F) -> RetryPolicy
+    where
+        F: Fn(&ShopifyAPIError) -> bool + Send + Sync + 'static,
+    {
+        self.retry_on = Arc::new(predicate);
+        self
+    }
+
+    /// Get the maximum number of attempts
+    pub fn get_max_attempts(&self) -> u64 {
+        self.max_attempts
+    }
+
+    /// Get the delay applied between two attempts
+    pub fn get_backoff(&self) -> &Backoff {
+        &self.backoff
+    }
+
+    /// Get the delay to wait before the given retry (starting at 1) after an error
+    ///
+    /// The delay requested by Shopify (e.g. the `Retry-After` header) takes precedence over the backoff.
+    pub fn delay(&self, retry: u64, error: &ShopifyAPIError) -> Duration {
+        error
+            .retry_after()
+            .unwrap_or_else(|| self.backoff.delay(retry))
+    }
+
+    /// Check if the given error should be retried
+    pub fn should_retry(&self, error: &ShopifyAPIError) -> bool {
+        (self.retry_on)(error)
+    }
+}
+
+impl Default for RetryPolicy {
+    /// 10 attempts with an exponential backoff from 500ms to 10s, retrying the errors
+    /// that may succeed when sent again (see [`ShopifyAPIError::is_retryable`])
+    fn default() -> Self {
+        RetryPolicy {
+            max_attempts: 10,
+            backoff: Backoff::exponential(Duration::from_millis(500), Duration::from_secs(10)),
+            retry_on: Arc::new(ShopifyAPIError::is_retryable),
+        }
+    }
+}
+
+impl fmt::Debug for RetryPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RetryPolicy")
+            .field("max_attempts", &self.max_attempts)
+            .field("backoff", &self.backoff)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Decide how a THROTTLED GraphQL query is sent again, before the [`RetryPolicy`] takes over
+///
+/// Unlike REST's leaky bucket counting requests, the GraphQL bucket counts cost points restored
+/// at a fixed rate. A throttled query waits until the points it requested are restored, the wait
+/// being multiplied after every throttle so clients sharing the bucket back off. Without the cost
+/// of the query, the delay comes from the plan of the shop, or from the `fallback` backoff.
+/// Once `max_waits` is reached, the query fails with [`ShopifyAPIError::Throttled`].
+/// # Example
+/// ```
+/// use std::time::Duration;
+/// use shopify_api::*;
+/// use shopify_api::retry::GraphQLThrottleRetry;
+///
+/// let throttle_retry = GraphQLThrottleRetry::new(4)
+///     .multiplier(2.0)
+///     .max_delay(Duration::from_secs(10));
+///
+/// // 100 points missing at 50 points per second
+/// let restore = Some(Duration::from_secs(2));
+/// assert_eq!(throttle_retry.delay(1, restore), Duration::from_secs(2));
+/// assert_eq!(throttle_retry.delay(2, restore), Duration::from_secs(4));
+/// assert_eq!(throttle_retry.delay(3, restore), Duration::from_secs(8));
+/// assert_eq!(throttle_retry.delay(4, restore), Duration::from_secs(10));
+///
+/// let shopify = Shopify::new("myshop", "myapikey", ShopifyAPIVersion::V2026_10, None)
+///     .with_graphql_throttle_retry(throttle_retry);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct GraphQLThrottleRetry {
+    max_waits: u32,
+    multiplier: f64,
+    max_delay: Duration,
+    fallback: Backoff,
+}
+
+impl GraphQLThrottleRetry {
+    /// Create a strategy waiting at most `max_waits` times, 0 leaving THROTTLED queries to the retry policy
+    pub fn new(max_waits: u32) -> GraphQLThrottleRetry {
+        GraphQLThrottleRetry {
+            max_waits,
+            ..GraphQLThrottleRetry::default()
+        }
+    }
+
+    /// Set the factor applied to the wait after every throttle of the same query (at least 1)
+    pub fn multiplier(mut self, multiplier: f64) -> GraphQLThrottleRetry {
+        self.multiplier = multiplier.max(1.0);
+        self
+    }
+
+    /// Set the maximum delay of a single wait
+    pub fn max_delay(mut self, max_delay: Duration) -> GraphQLThrottleRetry {
+        self.max_delay = max_delay;
+        self
+    }
+
+    /// Set the backoff used when neither the cost of the query nor the plan of the shop is known
+    pub fn fallback(mut self, fallback: Backoff) -> GraphQLThrottleRetry {
+        self.fallback = fallback;
+        self
+    }
+
+    /// Get the maximum number of waits of a query
+    pub fn get_max_waits(&self) -> u32 {
+        self.max_waits
+    }
+
+    /// Get the delay to wait before the given resend (starting at 1)
+    ///
+    /// `restore_delay` is the time needed to restore the points of the query, if known.
+    pub fn delay(&self, wait: u32, restore_delay: Option<Duration>) -> Duration {
+        match restore_delay {
+            Some(restore_delay) => {
+                let exponent = wait.saturating_sub(1).min(31) as i32;
+                // Computed in f64, as a large multiplier overflows a `Duration`
+                let delay = restore_delay.as_secs_f64() * self.multiplier.powi(exponent);
+                Duration::try_from_secs_f64(delay)
+                    .map_or(self.max_delay, |delay| delay.min(self.max_delay))
+            }
+            None => self.fallback.delay(wait as u64).min(self.max_delay),
+        }
+    }
+}
+
+impl Default for GraphQLThrottleRetry {
+    /// 5 waits doubling the restore delay, each capped at 60s, with an exponential fallback
+    /// from 1s to 30s
+    fn default() -> Self {
+        GraphQLThrottleRetry {
+            max_waits: 5,
+            multiplier: 2.0,
+            max_delay: Duration::from_secs(60),
+            fallback: Backoff::exponential(Duration::from_secs(1), Duration::from_secs(30)),
+        }
+    }
+}
+
+/// Error of a query that the retry loop gave up on
+pub(crate) struct Failed {
+    pub(crate) error: ShopifyAPIError,
+    pub(crate) attempts: u64,
+}
+
+/// Run an async query until it succeeds, the policy gives up or the token is cancelled
+///
+/// `on_retry` is called with the error and the delay before every retry.
+pub(crate) async fn retry<'a, Fut, F, Args, Out>(
+    policy: &RetryPolicy,
+    cancellation_token: Option<&CancellationToken>,
+    on_retry: &(dyn Fn(&ShopifyAPIError, Duration) + Sync),
+    func: Fut,
+    args: &'a Args,
+) -> Result<Out, Failed>
+where
+    Fut: Fn(&'a Args) -> F,
+    F: Future<Output = Result<Out, ShopifyAPIError>>,
+{
+    let mut attempt: u64 = 1;
+
+    loop {
+        let result = match cancellation_token {
+            Some(token) => token.run(func(args)).await.and_then(|result| result),
+            None => func(args).await,
+        };
+        let error = match result {
+            Ok(result) => return Ok(result),
+            Err(error) => error,
+        };
+
+        if attempt >= policy.max_attempts
+            || !policy.should_retry(&error)
+            || matches!(error, ShopifyAPIError::Cancelled)
+        {
+            return Err(Failed {
+                error,
+                attempts: attempt,
+            });
+        }
+
+        log::debug!("shopify query failed (attempt {attempt}), retrying: {error}");
+
+        let delay = policy.delay(attempt, &error);
+        on_retry(&error, delay);
+        if !delay.is_zero() {
+            let slept = match cancellation_token {
+                Some(token) => token.run(utils::sleep(delay)).await,
+                None => {
+                    utils::sleep(delay).await;
+                    Ok(())
+                }
+            };
+            if let Err(error) = slept {
+                return Err(Failed {
+                    error,
+                    attempts: attempt,
+                });
+            }
+        }
+
+        attempt += 1;
+    }
+}
+
+/// Run a blocking query until it succeeds, the policy gives up or the token is cancelled
+///
+/// A blocking attempt cannot be interrupted, so the token is only checked between attempts.
+#[cfg(feature = "blocking")]
+pub(crate) fn retry_blocking<'a, F, Args, Out>(
+    policy: &RetryPolicy,
+    cancellation_token: Option<&CancellationToken>,
+    on_retry: &(dyn Fn(&ShopifyAPIError, Duration) + Sync),
+    func: F,
+    args: &'a Args,
+) -> Result<Out, Failed>
+where
+    F: Fn(&'a Args) -> Result<Out, ShopifyAPIError>,
+{
+    let mut attempt: u64 = 1;
+
+    loop {
+        if cancellation_token.is_some_and(CancellationToken::is_cancelled) {
+            return Err(Failed {
+                error: ShopifyAPIError::Cancelled,
+                attempts: attempt - 1,
+            });
+        }
+
+        let error = match func(args) {
+            Ok(result) => return Ok(result),
+            Err(error) => error,
+        };
+
+        if attempt >= policy.max_attempts || !policy.should_retry(&error) {
+            return Err(Failed {
+                error,
+                attempts: attempt,
+            });
+        }
+
+        log::debug!("shopify query failed (attempt {attempt}), retrying: {error}");
+
+        let delay = policy.delay(attempt, &error);
+        on_retry(&error, delay);
+        if !delay.is_zero() {
+            std::thread::sleep(delay);
+        }
+
+        attempt += 1;
+    }
+}
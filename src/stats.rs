@@ -0,0 +1,121 @@
+use std::{
+    sync::atomic::{AtomicU64, Ordering},
+    time::Duration,
+};
+
+use crate::{
+    rate_limit::{RateLimitState, ThrottleReason},
+    Shopify, ShopifyAPIError,
+};
+
+/// Counters of the requests sent by a client and its clones
+/// # Example
+/// ```
+/// use shopify_api::*;
+///
+/// let shopify = Shopify::new("myshop", "myapikey", ShopifyAPIVersion::V2026_10, None);
+/// let stats = shopify.stats();
+///
+/// assert_eq!(stats.requests, 0);
+/// assert_eq!(stats.average_latency, None);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ShopifyStats {
+    /// Requests sent, counting every attempt
+    pub requests: u64,
+    /// Attempts made after a failed attempt
+    pub retries: u64,
+    /// Responses rejected with a 429 status
+    pub rate_limited: u64,
+    /// GraphQL queries rejected with a `THROTTLED` error
+    pub throttled: u64,
+    /// Average time between sending a request and reading its whole response
+    ///
+    /// Latencies are not measured on wasm32.
+    pub average_latency: Option<Duration>,
+    /// Rate limit usage reported by the last responses
+    pub rate_limit: RateLimitState,
+}
+
+#[derive(Debug, Default)]
+pub(crate) struct StatsCounters {
+    requests: AtomicU64,
+    retries: AtomicU64,
+    rate_limited: AtomicU64,
+    throttled: AtomicU64,
+    latency_micros: AtomicU64,
+    latency_count: AtomicU64,
+}
+
+impl StatsCounters {
+    pub(crate) fn record_request(&self) {
+        self.requests.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub(crate) fn record_latency(&self, latency: Duration) {
+        let micros = u64::try_from(latency.as_micros()).unwrap_or(u64::MAX);
+        self.latency_micros.fetch_add(micros, Ordering::Relaxed);
+        self.latency_count.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub(crate) fn record_rate_limited(&self) {
+        self.rate_limited.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub(crate) fn record_throttled(&self) {
+        self.throttled.fetch_add(1, Ordering::Relaxed);
+    }
+
+    fn reset(&self) {
+        for counter in [
+            &self.requests,
+            &self.retries,
+            &self.rate_limited,
+            &self.throttled,
+            &self.latency_micros,
+            &self.latency_count,
+        ] {
+            counter.store(0, Ordering::Relaxed);
+        }
+    }
+}
+
+impl Shopify {
+    /// Record an attempt about to be retried after the given delay
+    pub(crate) fn retrying(&self, error: &ShopifyAPIError, delay: Duration) {
+        self.stats.retries.fetch_add(1, Ordering::Relaxed);
+
+        match error {
+            ShopifyAPIError::RateLimited { .. } => {
+                self.throttled(ThrottleReason::RateLimited, delay)
+            }
+            ShopifyAPIError::Throttled => self.throttled(ThrottleReason::Throttled, delay),
+            _ => {}
+        }
+    }
+
+    /// Get the counters of the requests sent by this client and its clones
+    ///
+    /// The counters start when the client is created, or when they are reset with
+    /// [`Shopify::reset_stats`].
+    pub fn stats(&self) -> ShopifyStats {
+        let latency_count = self.stats.latency_count.load(Ordering::Relaxed);
+        let average_latency = (latency_count > 0).then(|| {
+            Duration::from_micros(self.stats.latency_micros.load(Ordering::Relaxed) / latency_count)
+        });
+
+        ShopifyStats {
+            requests: self.stats.requests.load(Ordering::Relaxed),
+            retries: self.stats.retries.load(Ordering::Relaxed),
+            rate_limited: self.stats.rate_limited.load(Ordering::Relaxed),
+            throttled: self.stats.throttled.load(Ordering::Relaxed),
+            average_latency,
+            rate_limit: self.rate_limit_state(),
+        }
+    }
+
+    /// Reset the counters returned by [`Shopify::stats`]
+    pub fn reset_stats(&self) {
+        self.stats.reset();
+    }
+}
@@ -1,6 +1,67 @@
-use std::future::Future;
+use std::{future::Future, time::Duration};
+
+use crate::retry::RetryPolicy;
+
+/// Wait for the given duration without blocking the thread
+///
+/// The timer of the runtime selected with the `tokio` (default), `async-std` or `smol` feature is used
+/// on native targets, and the browser timers on wasm32.
+/// # Example
+/// ```
+/// use std::time::Duration;
+/// use shopify_api::utils::sleep;
+///
+/// #[tokio::main]
+/// async fn main() {
+///     sleep(Duration::from_millis(10)).await;
+/// }
+/// ```
+pub async fn sleep(duration: Duration) {
+    #[cfg(all(not(target_arch = "wasm32"), feature = "tokio"))]
+    tokio::time::sleep(duration).await;
+
+    #[cfg(all(
+        not(target_arch = "wasm32"),
+        not(feature = "tokio"),
+        feature = "async-std"
+    ))]
+    async_std::task::sleep(duration).await;
+
+    #[cfg(all(
+        not(target_arch = "wasm32"),
+        not(feature = "tokio"),
+        not(feature = "async-std"),
+        feature = "smol"
+    ))]
+    smol::Timer::after(duration).await;
+
+    #[cfg(target_arch = "wasm32")]
+    gloo_timers::future::sleep(duration).await;
+}
+
+/// Monotonic time in seconds, used to estimate the rate limit buckets and the latencies
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) fn now() -> Option<f64> {
+    static START: std::sync::OnceLock<std::time::Instant> = std::sync::OnceLock::new();
+    Some(
+        START
+            .get_or_init(std::time::Instant::now)
+            .elapsed()
+            .as_secs_f64(),
+    )
+}
+
+/// There is no monotonic clock in the standard library on wasm32
+#[cfg(target_arch = "wasm32")]
+pub(crate) fn now() -> Option<f64> {
+    None
+}
 
 /// Retry function for async functions
+///
+/// The attempts are spaced with the backoff of the default [`RetryPolicy`]: an exponential
+/// delay with jitter, from 500ms up to 10s. Use a [`RetryPolicy`] on the client to choose
+/// which errors are retried.
 /// # Example
 /// ```
 /// use shopify_api::utils::retry_async;
@@ -34,24 +95,24 @@ where
         panic!("Max retries cannot be 0");
     }
 
-    let mut count: u64 = 0;
-    let mut result: Result<Out, ErrOut> = func(args).await;
-
-    while count < max_retries - 1 && result.is_err() {
-        let executed_func = func(args);
+    let backoff = RetryPolicy::default().get_backoff().clone();
+    let mut attempt: u64 = 1;
 
-        result = executed_func.await;
-        if result.is_ok() {
+    loop {
+        let result = func(args).await;
+        if result.is_ok() || attempt >= max_retries {
             return result;
         }
 
-        count += 1;
+        sleep(backoff.delay(attempt)).await;
+        attempt += 1;
     }
-
-    result
 }
 
 /// Retry function for sync functions
+///
+/// The attempts are spaced with the backoff of the default [`RetryPolicy`], blocking the
+/// thread in between. There is no delay on wasm32, where the thread cannot sleep.
 /// # Example
 /// ```
 /// use shopify_api::utils::retry_sync;
@@ -83,26 +144,47 @@ where
         panic!("Max retries cannot be 0");
     }
 
-    let mut count: u64 = 0;
-    let mut result: Result<Out, ErrOut> = func(args);
+    let mut attempt: u64 = 1;
 
-    while count < max_retries - 1 && result.is_err() {
-        let executed_func = func(args);
-
-        result = executed_func;
-        if result.is_ok() {
+    loop {
+        let result = func(args);
+        if result.is_ok() || attempt >= max_retries {
             return result;
         }
 
-        count += 1;
+        #[cfg(not(target_arch = "wasm32"))]
+        std::thread::sleep(RetryPolicy::default().get_backoff().delay(attempt));
+        attempt += 1;
     }
-
-    result
 }
 
-#[derive(Clone, Debug)]
+/// Step of a path that could not be read, `depth` being the position of the step (starting at 1)
+#[derive(Clone, Debug, PartialEq, thiserror::Error)]
 pub enum ReadJsonTreeError {
+    /// Kept for compatibility, no longer returned
+    #[error("JSON not found")]
     JsonNotFound,
+
+    #[error("key {key:?} missing at depth {depth} (available keys: {available_keys:?})")]
+    KeyNotFound {
+        key: String,
+        depth: usize,
+        available_keys: Vec<String>,
+    },
+
+    #[error("index {index} out of bounds at depth {depth} (length: {len})")]
+    IndexOutOfBounds {
+        index: usize,
+        depth: usize,
+        len: usize,
+    },
+
+    #[error("expected {expected} at depth {depth}, found {found}")]
+    WrongType {
+        expected: &'static str,
+        found: &'static str,
+        depth: usize,
+    },
 }
 
 #[derive(Clone, Debug)]
@@ -146,7 +228,10 @@ pub enum ReadJsonTreeSteps<'a> {
 ///
 /// println!("{:?}", success_read);
 /// assert_eq!(success_read.unwrap(), &json!("d"));
-/// assert!(error_read.is_err());
+/// assert_eq!(
+///     error_read.unwrap_err().to_string(),
+///     r#"key "d" missing at depth 3 (available keys: ["c"])"#
+/// );
 /// ```
 /// # Errors
 /// This function returns an error if the json tree is not found
@@ -156,20 +241,54 @@ pub fn read_json_tree<'a>(
 ) -> Result<&'a serde_json::Value, ReadJsonTreeError> {
     let mut actual_value: &serde_json::Value = json_value;
 
-    for step in path {
+    for (depth, step) in path.iter().enumerate().map(|(i, step)| (i + 1, step)) {
         match step {
             ReadJsonTreeSteps::Key(key) => {
-                actual_value = actual_value
-                    .get(key)
-                    .ok_or(ReadJsonTreeError::JsonNotFound)?;
+                let object = actual_value
+                    .as_object()
+                    .ok_or(ReadJsonTreeError::WrongType {
+                        expected: "object",
+                        found: json_type(actual_value),
+                        depth,
+                    })?;
+                actual_value = object
+                    .get(*key)
+                    .ok_or_else(|| ReadJsonTreeError::KeyNotFound {
+                        key: key.to_string(),
+                        depth,
+                        available_keys: object.keys().cloned().collect(),
+                    })?;
             }
             ReadJsonTreeSteps::Index(index) => {
-                actual_value = actual_value
+                let array = actual_value
+                    .as_array()
+                    .ok_or(ReadJsonTreeError::WrongType {
+                        expected: "array",
+                        found: json_type(actual_value),
+                        depth,
+                    })?;
+                actual_value = array
                     .get(*index)
-                    .ok_or(ReadJsonTreeError::JsonNotFound)?;
+                    .ok_or(ReadJsonTreeError::IndexOutOfBounds {
+                        index: *index,
+                        depth,
+                        len: array.len(),
+                    })?;
             }
         }
     }
 
     Ok(actual_value)
 }
+
+/// Name of the type of a JSON value, for error messages
+fn json_type(value: &serde_json::Value) -> &'static str {
+    match value {
+        serde_json::Value::Null => "null",
+        serde_json::Value::Bool(_) => "boolean",
+        serde_json::Value::Number(_) => "number",
+        serde_json::Value::String(_) => "string",
+        serde_json::Value::Array(_) => "array",
+        serde_json::Value::Object(_) => "object",
+    }
+}